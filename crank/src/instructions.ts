@@ -103,14 +103,15 @@ export async function buildStartRound(
   payer: PublicKey,
   roundId: number
 ): Promise<TransactionInstruction> {
+  const configPda = getConfigPda();
   const roundPda = getRoundPda(roundId);
   const vaultAta = await getAssociatedTokenAddress(USDC_MINT, roundPda, true);
 
-  return await (program.methods as any)
+  const ix: TransactionInstruction = await (program.methods as any)
     .startRound(new BN(roundId))
     .accounts({
       payer,
-      config: getConfigPda(),
+      config: configPda,
       round: roundPda,
       vaultUsdcAta: vaultAta,
       usdcMint: USDC_MINT,
@@ -119,6 +120,12 @@ export async function buildStartRound(
       systemProgram: SystemProgram.programId,
     })
     .instruction();
+  // The IDL marks config read-only, but start_round advances
+  // config.last_round_id, so the program requires it writable.
+  for (const key of ix.keys) {
+    if (key.pubkey.equals(configPda)) key.isWritable = true;
+  }
+  return ix;
 }
 
 export async function buildLockRound(
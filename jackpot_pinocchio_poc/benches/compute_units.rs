@@ -4,6 +4,7 @@
 /// Output:
 ///   - `target/benches/mx_compute_units.md`  — Pinocchio ↔ Anchor matrix
 ///   - `target/benches/compute_units.md`      — Pinocchio-only (init ix)
+///   - `target/benches/cu_regressions.md`     — baseline diff (only with `CU_BASELINE`)
 use std::str::FromStr;
 use mollusk_svm::Mollusk;
 use mollusk_svm::program::create_program_account_loader_v3;
@@ -14,14 +15,18 @@ use solana_pubkey::Pubkey;
 
 use jackpot_pinocchio_poc::{
     anchor_compat::{account_discriminator, instruction_discriminator},
+    instruction_layouts::{encode_begin_degen_execution, RoundId},
     legacy_layouts::{
         CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN,
         PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
-        ROUND_STATUS_CLAIMED, ROUND_STATUS_OPEN, ROUND_STATUS_SETTLED,
+        MAX_PARTICIPANTS, ROUND_STATUS_CLAIMED, ROUND_STATUS_OPEN, ROUND_STATUS_SETTLED,
+        ROUND_STATUS_VRF_REQUESTED,
         ConfigView, DegenClaimView, DegenConfigView, ParticipantView,
         RoundLifecycleView,
         DEGEN_MODE_EXECUTING, DEGEN_MODE_VRF_READY,
+        SPL_STATE_OFFSET, SPL_TOKEN_ACCOUNT_LEN, TOKEN_ACCOUNT_STATE_INITIALIZED,
     },
+    runtime::vrf_program::VRF_PROGRAM_IDENTITY,
 };
 
 fn main() {
@@ -158,7 +163,7 @@ fn main() {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(config_pda, false),
             AccountMeta::new(start_round_pda, false),
             AccountMeta::new(vault_ata_start, false),
             AccountMeta::new_readonly(usdc_mint, false),
@@ -168,9 +173,15 @@ fn main() {
         ],
         data: encode_round_id_ix("start_round", start_round_id),
     };
+    // start_round only accepts last_round_id + 1.
+    let mut start_round_config =
+        config_account_with_usdc(&program_id, config_bump, payer, usdc_mint, 25, 1_000_000, 30, 1, 2);
+    let mut start_round_config_view = ConfigView::read_from_account_data(&start_round_config.data).unwrap();
+    start_round_config_view.set_last_round_id(start_round_id - 1);
+    start_round_config_view.write_to_account_data(&mut start_round_config.data).unwrap();
     let start_round_accounts = vec![
         (payer, signer_account()),
-        (config_pda, config_account_with_usdc(&program_id, config_bump, payer, usdc_mint, 25, 1_000_000, 30, 1, 2)),
+        (config_pda, start_round_config),
         (start_round_pda, Account::new(1_000_000_000, ROUND_ACCOUNT_LEN, &program_id)),
         (vault_ata_start, token_account(&token_program, usdc_mint, start_round_pda, 0)),
         (usdc_mint, Account::new(1_000_000_000, 0, &token_program)),
@@ -221,13 +232,7 @@ fn main() {
     let treasury_usdc_ata = Pubkey::new_unique();
     let receiver_token_ata = Pubkey::new_unique();
 
-    let mut begin_degen_data = Vec::with_capacity(61);
-    begin_degen_data.extend_from_slice(&instruction_discriminator("begin_degen_execution"));
-    begin_degen_data.extend_from_slice(&degen_round_id.to_le_bytes());
-    begin_degen_data.push(0); // candidate_rank
-    begin_degen_data.extend_from_slice(&token_index.to_le_bytes());
-    begin_degen_data.extend_from_slice(&777u64.to_le_bytes());
-    begin_degen_data.extend_from_slice(&[33u8; 32]);
+    let begin_degen_data = encode_begin_degen_execution(RoundId(degen_round_id), 0, token_index, 777, [33u8; 32]);
 
     let begin_degen_ix = Instruction {
         program_id,
@@ -344,6 +349,31 @@ fn main() {
         (token_program, create_program_account_loader_v3(&token_program)),
     ];
 
+    // ─── vrf_callback (settle_round) ────────────────────────────────────
+    // The settlement instruction: no CPI, just the Fenwick descent over a
+    // full round and the winner writes.
+    let settle_round_id = 47u64;
+    let (settle_round_pda, _) =
+        Pubkey::find_program_address(&[b"round", &settle_round_id.to_le_bytes()], &program_id);
+    let vrf_identity = Pubkey::new_from_array(VRF_PROGRAM_IDENTITY.to_bytes());
+    let mut settle_data = Vec::with_capacity(40);
+    settle_data.extend_from_slice(&instruction_discriminator("vrf_callback"));
+    settle_data.extend_from_slice(&[7u8; 32]);
+    let settle_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(vrf_identity, true),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(settle_round_pda, false),
+        ],
+        data: settle_data,
+    };
+    let settle_accounts = vec![
+        (vrf_identity, signer_account()),
+        (config_pda, config_account(&program_id, config_bump, admin, 25, 1_000_000, 30, 1, 2)),
+        (settle_round_pda, settle_round_account(&program_id, settle_round_id, MAX_PARTICIPANTS)),
+    ];
+
     // ═══════════════════════════════════════════════════════════════════
     // 1. MATRIX BENCH — Pinocchio vs Anchor (non-CPI instructions)
     //    CPI-heavy instructions (deposit, cancel_round, claim_refund,
@@ -353,7 +383,8 @@ fn main() {
     //         optional accounts (vrf_payer_authority/vrf_payer_usdc_ata)
     //      b) Anchor's #[account(init)] needs real system-program CPI
     //      c) Anchor's Account<Mint> needs 82-byte SPL Mint layout
-    //    VRF instructions are excluded because they CPI to Switchboard.
+    //    VRF requests are excluded because they CPI to Switchboard; the
+    //    vrf_callback that settles the round is CPI-free and included.
     // ═══════════════════════════════════════════════════════════════════
 
     // Set clock for lock_round (requires now >= round.end_ts=120)
@@ -367,29 +398,136 @@ fn main() {
         .bench(("lock_round", &lock_round_ix, &lock_round_accounts))
         .bench(("close_participant", &close_participant_ix, &close_participant_accounts))
         .bench(("finalize_degen_success", &finalize_ix, &finalize_accounts))
+        .bench(("vrf_callback", &settle_ix, &settle_accounts))
         .must_pass(true)
         .out_dir("../target/benches")
         .execute();
 
     // ═══════════════════════════════════════════════════════════════════
-    // 2. PINOCCHIO-ONLY — all 9 instructions (includes init & CPI ix
+    // 2. PINOCCHIO-ONLY — all 10 instructions (includes init & CPI ix
     //    that need Pinocchio-specific mock account layouts)
     // ═══════════════════════════════════════════════════════════════════
+    let pinocchio_cases: Vec<BenchCase> = vec![
+        ("init_config", &init_config_ix, init_config_accounts.as_slice()),
+        ("update_config", &update_config_ix, update_config_accounts.as_slice()),
+        ("upsert_degen_config", &upsert_degen_ix, upsert_degen_accounts.as_slice()),
+        ("admin_force_cancel", &force_cancel_ix, force_cancel_accounts.as_slice()),
+        ("start_round", &start_round_ix, start_round_accounts.as_slice()),
+        ("close_participant", &close_participant_ix, close_participant_accounts.as_slice()),
+        ("begin_degen_execution", &begin_degen_ix, begin_degen_accounts.as_slice()),
+        ("claim_degen_fallback", &claim_fallback_ix, claim_fallback_accounts.as_slice()),
+        ("finalize_degen_success", &finalize_ix, finalize_accounts.as_slice()),
+        ("vrf_callback", &settle_ix, settle_accounts.as_slice()),
+    ];
     let mut mollusk_p = Mollusk::new(&program_id, "jackpot_pinocchio_poc");
     mollusk_p.add_program(&token_program, "token_stub_program");
-    MolluskComputeUnitBencher::new(mollusk_p)
-        .bench(("init_config", &init_config_ix, &init_config_accounts))
-        .bench(("update_config", &update_config_ix, &update_config_accounts))
-        .bench(("upsert_degen_config", &upsert_degen_ix, &upsert_degen_accounts))
-        .bench(("admin_force_cancel", &force_cancel_ix, &force_cancel_accounts))
-        .bench(("start_round", &start_round_ix, &start_round_accounts))
-        .bench(("close_participant", &close_participant_ix, &close_participant_accounts))
-        .bench(("begin_degen_execution", &begin_degen_ix, &begin_degen_accounts))
-        .bench(("claim_degen_fallback", &claim_fallback_ix, &claim_fallback_accounts))
-        .bench(("finalize_degen_success", &finalize_ix, &finalize_accounts))
-        .must_pass(true)
-        .out_dir("../target/benches")
-        .execute();
+    let mut bencher = MolluskComputeUnitBencher::new(mollusk_p);
+    for case in &pinocchio_cases {
+        bencher = bencher.bench(*case);
+    }
+    bencher.must_pass(true).out_dir("../target/benches").execute();
+
+    // ═══════════════════════════════════════════════════════════════════
+    // 3. OPTIONAL CU REGRESSION GATE — see `run_cu_regression_gate`
+    // ═══════════════════════════════════════════════════════════════════
+    if let Ok(baseline_path) = std::env::var("CU_BASELINE") {
+        let mut mollusk_gate = Mollusk::new(&program_id, "jackpot_pinocchio_poc");
+        mollusk_gate.add_program(&token_program, "token_stub_program");
+        run_cu_regression_gate(&mollusk_gate, &pinocchio_cases, &baseline_path);
+    }
+}
+
+type BenchCase<'a> = (&'a str, &'a Instruction, &'a [(Pubkey, Account)]);
+
+const DEFAULT_CU_TOLERANCE_PCT: f64 = 10.0;
+
+/// Re-measures the Pinocchio-only cases and compares them with a committed
+/// baseline JSON (`{"<instruction>": <cu>, ...}`).
+///
+///   - `CU_BASELINE=benches/cu_baseline.json` enables the gate (paths are
+///     relative to the crate root).
+///   - `CU_TOLERANCE_PCT` overrides the allowed increase (default 10%).
+///   - `CU_BASELINE_UPDATE=1` rewrites the baseline with the measured values
+///     instead of checking it.
+///
+/// Writes `target/benches/cu_regressions.md` and exits non-zero when any
+/// instruction exceeds `baseline * (1 + tolerance)`.
+fn run_cu_regression_gate(mollusk: &Mollusk, cases: &[BenchCase], baseline_path: &str) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(baseline_path);
+    let measured: Vec<(&str, u64)> = cases
+        .iter()
+        .map(|(name, ix, accounts)| (*name, mollusk.process_instruction(ix, accounts).compute_units_consumed))
+        .collect();
+
+    if std::env::var("CU_BASELINE_UPDATE").is_ok_and(|v| v == "1") {
+        std::fs::write(&path, encode_cu_baseline(&measured)).expect("failed to write CU baseline");
+        println!("CU baseline updated: {}", path.display());
+        return;
+    }
+
+    let tolerance_pct = std::env::var("CU_TOLERANCE_PCT")
+        .ok()
+        .map(|v| v.parse::<f64>().expect("CU_TOLERANCE_PCT must be a number"))
+        .unwrap_or(DEFAULT_CU_TOLERANCE_PCT);
+    let raw = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read CU baseline {}: {err}", path.display()));
+    let baseline = parse_cu_baseline(&raw);
+
+    let mut table = format!(
+        "#### CU regression gate (tolerance {tolerance_pct}%)\n\n\
+         | Instruction | Baseline | Measured | Delta | Status |\n\
+         | :--- | ---: | ---: | ---: | :---: |\n"
+    );
+    let mut regressions = 0usize;
+    for (name, cu) in &measured {
+        let Some(&(_, base)) = baseline.iter().find(|(n, _)| n == name) else {
+            table.push_str(&format!("| `{name}` | — | {cu} | — | no baseline |\n"));
+            continue;
+        };
+        let delta_pct = (*cu as f64 - base as f64) * 100.0 / base as f64;
+        let regressed = *cu as f64 > base as f64 * (1.0 + tolerance_pct / 100.0);
+        if regressed {
+            regressions += 1;
+        }
+        table.push_str(&format!(
+            "| `{name}` | {base} | {cu} | {delta_pct:+.1}% | {} |\n",
+            if regressed { "REGRESSED" } else { "ok" },
+        ));
+    }
+
+    println!("{table}");
+    std::fs::create_dir_all("../target/benches").ok();
+    std::fs::write("../target/benches/cu_regressions.md", &table).ok();
+    if regressions > 0 {
+        eprintln!("{regressions} instruction(s) exceeded the CU baseline by more than {tolerance_pct}%");
+        std::process::exit(1);
+    }
+}
+
+/// Minimal parser for the flat `{"name": number}` baseline object.
+fn parse_cu_baseline(raw: &str) -> Vec<(String, u64)> {
+    raw.trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (key, value) = entry
+                .split_once(':')
+                .unwrap_or_else(|| panic!("malformed CU baseline entry: {entry}"));
+            let key = key.trim().trim_matches('"').to_string();
+            let value = value
+                .trim()
+                .parse::<u64>()
+                .unwrap_or_else(|_| panic!("non-integer CU for '{key}' in baseline"));
+            (key, value)
+        })
+        .collect()
+}
+
+fn encode_cu_baseline(measured: &[(&str, u64)]) -> String {
+    let body: Vec<String> = measured.iter().map(|(name, cu)| format!("  \"{name}\": {cu}")).collect();
+    format!("{{\n{}\n}}\n", body.join(",\n"))
 }
 
 // ─── Helper functions (same as mollusk_smoke.rs) ────────────────────────────
@@ -461,6 +599,24 @@ fn round_account(program_id: &Pubkey, round_id: u64, status: u8) -> Account {
     account
 }
 
+/// A VRF-requested round with `participants` one-per-leaf deposits, so
+/// settlement walks a populated Fenwick tree.
+fn settle_round_account(program_id: &Pubkey, round_id: u64, participants: usize) -> Account {
+    let mut account = round_account(program_id, round_id, ROUND_STATUS_VRF_REQUESTED);
+    let mut round = RoundLifecycleView::read_from_account_data(&account.data).unwrap();
+    round.total_tickets = 0;
+    round.participants_count = participants as u16;
+    round.write_to_account_data(&mut account.data).unwrap();
+    for index in 1..=participants {
+        let participant = Pubkey::new_unique().to_bytes();
+        RoundLifecycleView::write_participant_pubkey_to_account_data(&mut account.data, index - 1, &participant)
+            .unwrap();
+        RoundLifecycleView::apply_deposit_to_account_data(&mut account.data, index, 1 + (index as u64 * 7919) % 97)
+            .unwrap();
+    }
+    account
+}
+
 fn participant_account(program_id: &Pubkey, bump: u8, round: Pubkey, user: Pubkey) -> Account {
     let mut account = Account::new(222_000, PARTICIPANT_ACCOUNT_LEN, program_id);
     account.data[..8].copy_from_slice(&account_discriminator("Participant"));
@@ -563,12 +719,11 @@ fn degen_claim_account(
 fn token_account(token_program: &Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
     // Full 165-byte SPL Token Account layout so Anchor's TokenAccount::unpack() works.
     // Pinocchio and the token stub only read the first 72 bytes, so this is safe.
-    const SPL_TOKEN_ACCOUNT_LEN: usize = 165;
     let mut account = Account::new(1_000_000_000, SPL_TOKEN_ACCOUNT_LEN, token_program);
     account.data[..32].copy_from_slice(&mint.to_bytes());
     account.data[32..64].copy_from_slice(&owner.to_bytes());
     account.data[64..72].copy_from_slice(&amount.to_le_bytes());
-    account.data[108] = 1; // AccountState::Initialized
+    account.data[SPL_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_INITIALIZED;
     account
 }
 
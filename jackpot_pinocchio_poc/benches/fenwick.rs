@@ -0,0 +1,174 @@
+/// Host-side micro-benchmark of the round's Fenwick tree on a full
+/// `MAX_PARTICIPANTS` tree, next to a linear scan over plain per-participant
+/// weights (the layout the tree replaces).
+///
+/// Wall-clock numbers come from the host CPU, not the SBF VM, so they only
+/// rank the approaches. The path counts are exact and track CU cost: each is
+/// one 8-byte node read (descent, scan) or read-modify-write (add/sub) of
+/// round data, before the guards' own reads (bit_add's capacity check,
+/// bit_sub's leaf check).
+/// Tree build is measured separately from the per-operation costs.
+///
+/// Run with `cargo bench --bench fenwick --features linear-scan`.
+///
+/// Output:
+///   - `target/benches/fenwick.md`
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use jackpot_pinocchio_poc::{
+    anchor_compat::account_discriminator,
+    legacy_layouts::{RoundLifecycleView, MAX_PARTICIPANTS, ROUND_ACCOUNT_LEN, ROUND_STATUS_OPEN},
+    winner_selection::linear_find_winner,
+};
+
+const ITERATIONS: u32 = 2_000;
+
+fn main() {
+    let weights: Vec<u64> = (0..MAX_PARTICIPANTS as u64).map(|i| 1 + (i * 7919) % 97).collect();
+    let total: u64 = weights.iter().sum();
+    let full_tree = build_tree(&weights);
+    // Spread query targets over the whole ticket range.
+    let targets: Vec<u64> = (0..MAX_PARTICIPANTS as u64)
+        .map(|i| 1 + (i * total) / MAX_PARTICIPANTS as u64)
+        .collect();
+
+    let build = time_per_iter(1, || {
+        black_box(build_tree(black_box(&weights)));
+    });
+
+    let find_prefix = time_per_iter(targets.len() as u32, || {
+        for target in &targets {
+            black_box(RoundLifecycleView::bit_find_prefix_in_account_data(&full_tree, *target).unwrap());
+        }
+    });
+
+    let linear_scan = time_per_iter(targets.len() as u32, || {
+        for target in &targets {
+            black_box(linear_find_winner(black_box(&weights), *target));
+        }
+    });
+
+    let mut tree = full_tree.clone();
+    let add_sub = time_per_iter(2 * MAX_PARTICIPANTS as u32, || {
+        for index in 1..=MAX_PARTICIPANTS {
+            RoundLifecycleView::bit_add_in_account_data(&mut tree, index, 3).unwrap();
+            RoundLifecycleView::bit_sub_in_account_data(&mut tree, index, 3).unwrap();
+        }
+    });
+    assert_eq!(tree, full_tree, "bit_add/bit_sub must cancel out");
+
+    let mut tree = full_tree.clone();
+    let add = time_per_iter(MAX_PARTICIPANTS as u32, || {
+        for index in 1..=MAX_PARTICIPANTS {
+            RoundLifecycleView::bit_add_in_account_data(&mut tree, index, 1).unwrap();
+        }
+    });
+    let sub = time_per_iter(MAX_PARTICIPANTS as u32, || {
+        for index in 1..=MAX_PARTICIPANTS {
+            RoundLifecycleView::bit_sub_in_account_data(&mut tree, index, 1).unwrap();
+        }
+    });
+
+    let update_touches = avg(update_path_len);
+    let find_touches = targets
+        .iter()
+        .map(|target| find_prefix_reads(&weights, *target))
+        .sum::<u64>() as f64
+        / targets.len() as f64;
+    let linear_touches = targets
+        .iter()
+        .map(|target| linear_find_winner(&weights, *target) as u64)
+        .sum::<u64>() as f64
+        / targets.len() as f64;
+
+    let mut table = format!(
+        "#### Fenwick tree, {MAX_PARTICIPANTS} participants ({ITERATIONS} iterations, host wall clock)\n\n\
+         | Operation | ns/op | Nodes on path (avg) |\n\
+         | :--- | ---: | ---: |\n"
+    );
+    let build_touches = update_touches * MAX_PARTICIPANTS as f64;
+    table.push_str(&row("tree build (200 deposits, per build)", build, build_touches));
+    table.push_str(&row("bit_find_prefix", find_prefix, find_touches));
+    table.push_str(&row("linear scan (baseline)", linear_scan, linear_touches));
+    table.push_str(&row("bit_add", add, update_touches));
+    table.push_str(&row("bit_sub", sub, update_touches));
+    table.push_str(&row("bit_add + bit_sub", add_sub, update_touches));
+
+    println!("{table}");
+    std::fs::create_dir_all("../target/benches").ok();
+    std::fs::write("../target/benches/fenwick.md", &table).ok();
+}
+
+fn build_tree(weights: &[u64]) -> Vec<u8> {
+    let mut data = vec![0u8; ROUND_ACCOUNT_LEN];
+    data[..8].copy_from_slice(&account_discriminator("Round"));
+    RoundLifecycleView {
+        round_id: 1,
+        status: ROUND_STATUS_OPEN,
+        bump: 255,
+        start_ts: 1,
+        end_ts: 0,
+        first_deposit_ts: 1,
+        total_usdc: 0,
+        total_tickets: 0,
+        participants_count: weights.len() as u16,
+    }
+    .write_to_account_data(&mut data)
+    .unwrap();
+    for (index_zero_based, weight) in weights.iter().enumerate() {
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, index_zero_based + 1, *weight).unwrap();
+    }
+    data
+}
+
+/// Nodes rewritten by `bit_add`/`bit_sub` starting at leaf `index`.
+fn update_path_len(mut index: usize) -> u64 {
+    let mut touched = 0;
+    while index <= MAX_PARTICIPANTS {
+        touched += 1;
+        index += index & index.wrapping_neg();
+    }
+    touched
+}
+
+/// Nodes read by `bit_find_prefix` for `target`: one per descent step whose
+/// candidate index stays within the tree.
+fn find_prefix_reads(weights: &[u64], target: u64) -> u64 {
+    let node = |index: usize| -> u64 {
+        let covered = index - (index & index.wrapping_neg())..index;
+        weights.iter().take(index).skip(covered.start).sum()
+    };
+    let mut step = MAX_PARTICIPANTS.next_power_of_two();
+    let (mut idx, mut cur, mut reads) = (0usize, 0u64, 0u64);
+    while step > 0 {
+        let next = idx + step;
+        if next <= MAX_PARTICIPANTS {
+            reads += 1;
+            if cur + node(next) < target {
+                idx = next;
+                cur += node(next);
+            }
+        }
+        step >>= 1;
+    }
+    reads
+}
+
+fn avg(per_index: impl Fn(usize) -> u64) -> f64 {
+    (1..=MAX_PARTICIPANTS).map(per_index).sum::<u64>() as f64 / MAX_PARTICIPANTS as f64
+}
+
+/// Mean duration of one operation, given `ops_per_call` operations per call.
+fn time_per_iter(ops_per_call: u32, mut call: impl FnMut()) -> Duration {
+    call();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        call();
+    }
+    start.elapsed() / (ITERATIONS * ops_per_call)
+}
+
+fn row(name: &str, per_op: Duration, touches: f64) -> String {
+    format!("| `{name}` | {} | {touches:.1} |\n", per_op.as_nanos())
+}
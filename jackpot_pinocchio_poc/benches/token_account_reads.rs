@@ -0,0 +1,73 @@
+/// Host-side micro-benchmark of `TokenAccountWithAmountView::read_from_account_data`
+/// on the 72-byte synthetic token layout the handler tests use and on a full
+/// 165-byte SPL Token account, as the runtime and the CU bench see it.
+///
+/// The read touches mint, owner and amount at fixed offsets (0, 32, 64) and
+/// never looks past byte 72, so both buffers cost the same: supporting the
+/// full SPL layout adds no per-read overhead. Both reads are checked to
+/// return the same view before timing.
+///
+/// Wall-clock numbers come from the host CPU, not the SBF VM, so they only
+/// compare the two layouts against each other.
+///
+/// Run with `cargo bench --bench token_account_reads`.
+///
+/// Output:
+///   - `target/benches/token_account_reads.md`
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use jackpot_pinocchio_poc::legacy_layouts::{
+    TokenAccountWithAmountView, SPL_AMOUNT_OFFSET, SPL_STATE_OFFSET, SPL_TOKEN_ACCOUNT_LEN,
+    TOKEN_ACCOUNT_STATE_INITIALIZED, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+};
+
+const ITERATIONS: u32 = 200_000;
+
+fn main() {
+    let synthetic = token_account(TOKEN_ACCOUNT_WITH_AMOUNT_LEN);
+    let mut full = token_account(SPL_TOKEN_ACCOUNT_LEN);
+    full[SPL_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_INITIALIZED;
+
+    let synthetic_view = TokenAccountWithAmountView::read_from_account_data(&synthetic).unwrap();
+    let full_view = TokenAccountWithAmountView::read_from_account_data(&full).unwrap();
+    assert_eq!(synthetic_view, full_view, "both layouts must read the same mint/owner/amount");
+    assert_eq!(full_view.amount, 1_234_567);
+
+    let synthetic_read = time_per_read(&synthetic);
+    let full_read = time_per_read(&full);
+
+    let mut table = format!(
+        "#### Token account reads ({ITERATIONS} iterations, host wall clock)\n\n\
+         | Layout | Bytes | ns/read |\n\
+         | :--- | ---: | ---: |\n"
+    );
+    table.push_str(&row("synthetic mint/owner/amount", TOKEN_ACCOUNT_WITH_AMOUNT_LEN, synthetic_read));
+    table.push_str(&row("full SPL Token account", SPL_TOKEN_ACCOUNT_LEN, full_read));
+
+    println!("{table}");
+    std::fs::create_dir_all("../target/benches").ok();
+    std::fs::write("../target/benches/token_account_reads.md", &table).ok();
+}
+
+fn token_account(len: usize) -> Vec<u8> {
+    let mut data = vec![0u8; len];
+    data[..32].copy_from_slice(&[1u8; 32]);
+    data[32..64].copy_from_slice(&[2u8; 32]);
+    data[SPL_AMOUNT_OFFSET..SPL_AMOUNT_OFFSET + 8].copy_from_slice(&1_234_567u64.to_le_bytes());
+    data
+}
+
+/// Mean duration of one `read_from_account_data` call on `data`.
+fn time_per_read(data: &[u8]) -> Duration {
+    black_box(TokenAccountWithAmountView::read_from_account_data(data).unwrap());
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(TokenAccountWithAmountView::read_from_account_data(black_box(data)).unwrap());
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn row(name: &str, len: usize, per_read: Duration) -> String {
+    format!("| {name} | {len} | {} |\n", per_read.as_nanos())
+}
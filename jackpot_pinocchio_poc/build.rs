@@ -79,7 +79,8 @@ pub const VRF_PROGRAM_IDENTITY: Address = Address::new_from_array({});
 }
 
 fn generate_discriminators(out_dir: &PathBuf) {
-    // All 23 instruction names (namespace "global")
+    // All 30 instruction names (namespace "global"); `mock_settle` is only
+    // routed when the `devnet` feature is enabled.
     let ix_names: &[&str] = &[
         "upsert_degen_config",
         "init_config",
@@ -98,6 +99,7 @@ fn generate_discriminators(out_dir: &PathBuf) {
         "close_round",
         "request_vrf",
         "vrf_callback",
+        "settle_with_slot_hash",
         "request_degen_vrf",
         "degen_vrf_callback",
         "begin_degen_execution",
@@ -105,15 +107,23 @@ fn generate_discriminators(out_dir: &PathBuf) {
         "auto_claim_degen_fallback",
         "claim_degen",
         "finalize_degen_success",
+        "recover_stuck_execution",
+        "update_degen_timeout",
+        "set_vrf_queue",
+        "set_fee_split",
+        "mock_settle",
     ];
 
-    // All 5 account names (namespace "account")
+    // All 7 account names (namespace "account"); `VrfQueueConfig` and
+    // `FeeSplitConfig` have no Anchor counterpart.
     let acct_names: &[&str] = &[
         "Config",
         "Round",
         "Participant",
         "DegenClaim",
         "DegenConfig",
+        "VrfQueueConfig",
+        "FeeSplitConfig",
     ];
 
     let mut code = String::from(
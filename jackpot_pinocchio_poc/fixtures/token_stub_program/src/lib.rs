@@ -17,6 +17,20 @@ entrypoint!(process_instruction, 8);
 /// Amount field offset within the 72-byte synthetic layout.
 const AMOUNT_OFFSET: usize = 64;
 
+/// The subset of SPL Token's `TokenError` codes the stub can return, so
+/// failures decode like the real token program's.
+#[repr(u32)]
+enum TokenStubError {
+    InsufficientFunds = 1,
+    Overflow = 14,
+}
+
+impl From<TokenStubError> for ProgramError {
+    fn from(value: TokenStubError) -> Self {
+        ProgramError::Custom(value as u32)
+    }
+}
+
 pub fn process_instruction(
     _program_id: &Address,
     accounts: &[AccountView],
@@ -76,8 +90,7 @@ fn process_transfer(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
             from_data[AMOUNT_OFFSET + 7],
         ]);
         if from_amount < amount {
-            // InsufficientFunds  — use a generic custom error
-            return Err(ProgramError::Custom(1));
+            return Err(TokenStubError::InsufficientFunds.into());
         }
     }
 
@@ -94,7 +107,9 @@ fn process_transfer(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
             from_data[AMOUNT_OFFSET + 6],
             from_data[AMOUNT_OFFSET + 7],
         ]);
-        let next = current.checked_sub(amount).ok_or(ProgramError::Custom(1))?;
+        let next = current
+            .checked_sub(amount)
+            .ok_or(ProgramError::from(TokenStubError::InsufficientFunds))?;
         from_data[AMOUNT_OFFSET..AMOUNT_OFFSET + 8]
             .copy_from_slice(&next.to_le_bytes());
     }
@@ -115,7 +130,9 @@ fn process_transfer(accounts: &[AccountView], data: &[u8]) -> ProgramResult {
             to_data[AMOUNT_OFFSET + 6],
             to_data[AMOUNT_OFFSET + 7],
         ]);
-        let next = current.checked_add(amount).ok_or(ProgramError::Custom(2))?;
+        let next = current
+            .checked_add(amount)
+            .ok_or(ProgramError::from(TokenStubError::Overflow))?;
         to_data[AMOUNT_OFFSET..AMOUNT_OFFSET + 8]
             .copy_from_slice(&next.to_le_bytes());
     }
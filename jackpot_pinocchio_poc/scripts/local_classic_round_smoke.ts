@@ -144,7 +144,7 @@ async function main() {
       programId: PROGRAM_ID,
       keys: [
         { pubkey: payer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: configPda, isSigner: false, isWritable: false },
+        { pubkey: configPda, isSigner: false, isWritable: true },
         { pubkey: roundPda, isSigner: false, isWritable: true },
         { pubkey: vaultAta, isSigner: false, isWritable: true },
         { pubkey: usdcMint, isSigner: false, isWritable: false },
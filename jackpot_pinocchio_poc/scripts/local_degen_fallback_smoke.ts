@@ -36,7 +36,7 @@ const VRF_PROGRAM_ID = new PublicKey(
 const VRF_QUEUE_PUBKEY = new PublicKey(
   process.env.PINOCCHIO_VRF_QUEUE_PUBKEY ?? fail("PINOCCHIO_VRF_QUEUE_PUBKEY is required"),
 );
-const ROUND_ID = Number(process.env.PINOCCHIO_ROUND_ID ?? "81");
+const ROUND_ID = Number(process.env.PINOCCHIO_ROUND_ID ?? "1");
 const DEPOSIT_RAW = BigInt(process.env.PINOCCHIO_DEPOSIT_RAW ?? "10000000");
 const RANDOMNESS_HEX =
   process.env.PINOCCHIO_DEGEN_RANDOMNESS_HEX ?? "1111111111111111111111111111111111111111111111111111111111111111";
@@ -161,7 +161,7 @@ async function main() {
       programId: PROGRAM_ID,
       keys: [
         { pubkey: payer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: configPda, isSigner: false, isWritable: false },
+        { pubkey: configPda, isSigner: false, isWritable: true },
         { pubkey: roundPda, isSigner: false, isWritable: true },
         { pubkey: vaultAta, isSigner: false, isWritable: true },
         { pubkey: usdcMint, isSigner: false, isWritable: false },
@@ -5,6 +5,12 @@
 # Output:
 #   target/benches/mx_compute_units.md  — Pinocchio ↔ Anchor matrix
 #   target/benches/compute_units.md     — Pinocchio-only (init ix)
+#   target/benches/fenwick.md           — Fenwick tree vs linear scan (host)
+#
+# Regression gate (optional):
+#   CU_BASELINE=benches/cu_baseline.json ./scripts/run_cu_bench.sh
+#   CU_TOLERANCE_PCT=5 … overrides the default 10% tolerance
+#   CU_BASELINE_UPDATE=1 … rewrites the baseline with measured values
 set -euo pipefail
 
 ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
@@ -60,7 +66,9 @@ cp -R "$ROOT_DIR/fixtures/token_stub_program" "$TMP_DIR/token_stub_program"
 echo "→ Running CU benchmark…"
 cd "$ROOT_DIR"
 cargo bench --bench compute_units
+cargo bench --bench fenwick
 
 echo ""
 echo "✓ Done — see target/benches/mx_compute_units.md  (matrix)"
 echo "         and target/benches/compute_units.md     (pinocchio-only)"
+echo "         and target/benches/fenwick.md           (Fenwick micro-bench)"
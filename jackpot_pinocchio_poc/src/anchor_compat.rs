@@ -15,6 +15,17 @@ pub const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
 // Re-export the precomputed constants for direct access where preferred.
 pub use precomputed::*;
 
+// Account discriminators for hot-path owner/layout validation. These are
+// `sha256("account:<StructName>")[..8]` emitted by `build.rs`; renaming an
+// Anchor account struct requires updating `acct_names` there as well.
+pub const CONFIG_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_CONFIG;
+pub const ROUND_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_ROUND;
+pub const PARTICIPANT_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_PARTICIPANT;
+pub const DEGEN_CLAIM_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_DEGENCLAIM;
+pub const DEGEN_CONFIG_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_DEGENCONFIG;
+pub const VRF_QUEUE_CONFIG_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_VRFQUEUECONFIG;
+pub const FEE_SPLIT_CONFIG_DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = precomputed::ACCT_FEESPLITCONFIG;
+
 #[inline(always)]
 pub fn instruction_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
     match name {
@@ -35,6 +46,7 @@ pub fn instruction_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
         "close_round"            => precomputed::IX_CLOSE_ROUND,
         "request_vrf"            => precomputed::IX_REQUEST_VRF,
         "vrf_callback"           => precomputed::IX_VRF_CALLBACK,
+        "settle_with_slot_hash"  => precomputed::IX_SETTLE_WITH_SLOT_HASH,
         "request_degen_vrf"      => precomputed::IX_REQUEST_DEGEN_VRF,
         "degen_vrf_callback"     => precomputed::IX_DEGEN_VRF_CALLBACK,
         "begin_degen_execution"  => precomputed::IX_BEGIN_DEGEN_EXECUTION,
@@ -42,6 +54,12 @@ pub fn instruction_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
         "auto_claim_degen_fallback" => precomputed::IX_AUTO_CLAIM_DEGEN_FALLBACK,
         "claim_degen"            => precomputed::IX_CLAIM_DEGEN,
         "finalize_degen_success" => precomputed::IX_FINALIZE_DEGEN_SUCCESS,
+        "recover_stuck_execution" => precomputed::IX_RECOVER_STUCK_EXECUTION,
+        "update_degen_timeout"   => precomputed::IX_UPDATE_DEGEN_TIMEOUT,
+        "set_vrf_queue"          => precomputed::IX_SET_VRF_QUEUE,
+        "set_fee_split"          => precomputed::IX_SET_FEE_SPLIT,
+        #[cfg(feature = "devnet")]
+        "mock_settle"            => precomputed::IX_MOCK_SETTLE,
         #[cfg(test)]
         unknown => runtime_discriminator("global", unknown),
         #[cfg(not(test))]
@@ -57,6 +75,8 @@ pub fn account_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
         "Participant" => precomputed::ACCT_PARTICIPANT,
         "DegenClaim"  => precomputed::ACCT_DEGENCLAIM,
         "DegenConfig" => precomputed::ACCT_DEGENCONFIG,
+        "VrfQueueConfig" => precomputed::ACCT_VRFQUEUECONFIG,
+        "FeeSplitConfig" => precomputed::ACCT_FEESPLITCONFIG,
         #[cfg(test)]
         unknown => runtime_discriminator("account", unknown),
         #[cfg(not(test))]
@@ -64,6 +84,38 @@ pub fn account_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
     }
 }
 
+/// A freshly created account: every data byte is still zero.
+#[inline(always)]
+pub fn is_uninitialized(data: &[u8]) -> bool {
+    data.iter().all(|byte| *byte == 0)
+}
+
+#[inline(always)]
+pub fn has_discriminator(data: &[u8], discriminator: &[u8; ANCHOR_DISCRIMINATOR_LEN]) -> bool {
+    data.get(..ANCHOR_DISCRIMINATOR_LEN) == Some(discriminator.as_slice())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountInitState {
+    Uninitialized,
+    Initialized,
+}
+
+/// Classifies an init-if-needed account. Exactly one of `is_uninitialized`
+/// and `has_discriminator` must hold; anything else (e.g. a zeroed header
+/// with stray body bytes) is `None` and must be rejected by the caller.
+#[inline(always)]
+pub fn account_init_state(
+    data: &[u8],
+    discriminator: &[u8; ANCHOR_DISCRIMINATOR_LEN],
+) -> Option<AccountInitState> {
+    match (is_uninitialized(data), has_discriminator(data, discriminator)) {
+        (true, false) => Some(AccountInitState::Uninitialized),
+        (false, true) => Some(AccountInitState::Initialized),
+        _ => None,
+    }
+}
+
 // ── Test-only runtime fallback using sha2 ──
 
 #[cfg(test)]
@@ -79,25 +131,45 @@ fn runtime_discriminator(namespace: &str, name: &str) -> [u8; ANCHOR_DISCRIMINAT
     out
 }
 
+/// Panics if any two `names` share an instruction discriminator, naming
+/// both colliding instructions.
+#[cfg(test)]
+pub(crate) fn assert_no_discriminator_collisions(names: &[&str]) {
+    for (i, first) in names.iter().enumerate() {
+        let first_disc = instruction_discriminator(first);
+        for second in &names[i + 1..] {
+            assert_ne!(
+                first_disc,
+                instruction_discriminator(second),
+                "discriminator collision between '{first}' and '{second}'"
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Every instruction dispatched by the runtime routers.
+    const INSTRUCTION_NAMES: [&str; 29] = [
+        "upsert_degen_config", "init_config", "update_config",
+        "transfer_admin", "set_treasury_usdc_ata",
+        "lock_round", "start_round", "admin_force_cancel",
+        "deposit_any", "cancel_round", "claim_refund",
+        "claim", "auto_claim", "close_participant", "close_round",
+        "request_vrf", "vrf_callback", "settle_with_slot_hash",
+        "request_degen_vrf", "degen_vrf_callback",
+        "begin_degen_execution", "claim_degen_fallback",
+        "auto_claim_degen_fallback",
+        "claim_degen", "finalize_degen_success",
+        "recover_stuck_execution", "update_degen_timeout",
+        "set_vrf_queue", "set_fee_split",
+    ];
+
     #[test]
     fn precomputed_instruction_discriminators_match_sha256() {
-        let names = [
-            "upsert_degen_config", "init_config", "update_config",
-            "transfer_admin", "set_treasury_usdc_ata",
-            "lock_round", "start_round", "admin_force_cancel",
-            "deposit_any", "cancel_round", "claim_refund",
-            "claim", "auto_claim", "close_participant", "close_round",
-            "request_vrf", "vrf_callback",
-            "request_degen_vrf", "degen_vrf_callback",
-            "begin_degen_execution", "claim_degen_fallback",
-            "auto_claim_degen_fallback",
-            "claim_degen", "finalize_degen_success",
-        ];
-        for name in names {
+        for name in INSTRUCTION_NAMES {
             let precomputed = instruction_discriminator(name);
             let runtime = runtime_discriminator("global", name);
             assert_eq!(precomputed, runtime, "mismatch for instruction '{name}'");
@@ -107,7 +179,9 @@ mod tests {
 
     #[test]
     fn precomputed_account_discriminators_match_sha256() {
-        let names = ["Config", "Round", "Participant", "DegenClaim", "DegenConfig"];
+        let names = [
+            "Config", "Round", "Participant", "DegenClaim", "DegenConfig", "VrfQueueConfig", "FeeSplitConfig",
+        ];
         for name in names {
             let precomputed = account_discriminator(name);
             let runtime = runtime_discriminator("account", name);
@@ -115,4 +189,73 @@ mod tests {
             assert_ne!(precomputed, [0u8; 8], "zero discriminator for '{name}'");
         }
     }
+
+    #[test]
+    fn account_discriminator_consts_match_runtime_lookup() {
+        let pairs = [
+            ("Config", CONFIG_DISCRIMINATOR),
+            ("Round", ROUND_DISCRIMINATOR),
+            ("Participant", PARTICIPANT_DISCRIMINATOR),
+            ("DegenClaim", DEGEN_CLAIM_DISCRIMINATOR),
+            ("DegenConfig", DEGEN_CONFIG_DISCRIMINATOR),
+            ("VrfQueueConfig", VRF_QUEUE_CONFIG_DISCRIMINATOR),
+            ("FeeSplitConfig", FEE_SPLIT_CONFIG_DISCRIMINATOR),
+        ];
+        for (name, expected) in pairs {
+            assert_eq!(account_discriminator(name), expected, "stale const for account '{name}'");
+            assert_eq!(runtime_discriminator("account", name), expected, "stale const for account '{name}'");
+        }
+    }
+
+    #[test]
+    fn router_instruction_discriminators_are_unique() {
+        assert_no_discriminator_collisions(&INSTRUCTION_NAMES);
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn devnet_mock_settle_discriminator_is_precomputed_and_unique() {
+        assert_eq!(instruction_discriminator("mock_settle"), runtime_discriminator("global", "mock_settle"));
+        let mut names = INSTRUCTION_NAMES.to_vec();
+        names.push("mock_settle");
+        assert_no_discriminator_collisions(&names);
+    }
+
+    #[test]
+    #[should_panic(expected = "discriminator collision between 'claim' and 'claim'")]
+    fn collision_check_reports_both_names() {
+        assert_no_discriminator_collisions(&["claim", "lock_round", "claim"]);
+    }
+
+    #[test]
+    fn init_state_requires_exactly_one_of_zeroed_or_discriminator() {
+        let mut data = [0u8; 64];
+        assert!(is_uninitialized(&data));
+        assert!(!has_discriminator(&data, &DEGEN_CLAIM_DISCRIMINATOR));
+        assert_eq!(
+            account_init_state(&data, &DEGEN_CLAIM_DISCRIMINATOR),
+            Some(AccountInitState::Uninitialized)
+        );
+
+        data[..8].copy_from_slice(&DEGEN_CLAIM_DISCRIMINATOR);
+        data[40] = 7;
+        assert_eq!(
+            account_init_state(&data, &DEGEN_CLAIM_DISCRIMINATOR),
+            Some(AccountInitState::Initialized)
+        );
+        assert_eq!(account_init_state(&data, &PARTICIPANT_DISCRIMINATOR), None);
+
+        // Zeroed header with a few stray body bytes.
+        data[..8].fill(0);
+        assert!(!is_uninitialized(&data));
+        assert_eq!(account_init_state(&data, &DEGEN_CLAIM_DISCRIMINATOR), None);
+
+        // Discriminator torn by a single flipped byte.
+        data[..8].copy_from_slice(&DEGEN_CLAIM_DISCRIMINATOR);
+        data[3] ^= 1;
+        assert_eq!(account_init_state(&data, &DEGEN_CLAIM_DISCRIMINATOR), None);
+
+        assert!(!has_discriminator(&DEGEN_CLAIM_DISCRIMINATOR[..4], &DEGEN_CLAIM_DISCRIMINATOR));
+        assert!(is_uninitialized(&[]));
+    }
 }
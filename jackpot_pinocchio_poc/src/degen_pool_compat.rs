@@ -22,17 +22,51 @@ pub fn pool_snapshot_sha256() -> &'static str {
     live_generated_pool::DEGEN_POOL_SNAPSHOT_SHA256
 }
 
+/// Number of candidate mints in the live pool; valid token indices are
+/// `0..degen_pool_len()`.
+pub fn degen_pool_len() -> usize {
+    pool_len()
+}
+
+pub fn is_valid_token_index(index: u32) -> bool {
+    (index as usize) < degen_pool_len()
+}
+
+/// Mint at `index` in the live pool, or `None` unless
+/// `index < degen_pool_len()` (see [`is_valid_token_index`]).
 pub fn degen_token_mint_by_index(index: u32) -> Option<[u8; 32]> {
     live_generated_pool::DEGEN_POOL.get(index as usize).copied()
 }
 
+/// Ranked candidate indices for `randomness`, distinct within `count`.
+///
+/// Each rank hashes `randomness || pool_version || rank || nonce` and takes
+/// the digest modulo the pool size; on a collision with an earlier rank the
+/// nonce is bumped and the rank is rehashed. This rejection sampling yields
+/// the same distinct indices as the Anchor program's `utils.rs`, which a
+/// seeded shuffle would not, so claims stay verifiable by both.
+///
+/// Panics if `count` exceeds the pool size, since distinct indices cannot
+/// exist; see [`derive_degen_candidates`] for a checked variant.
 pub fn derive_degen_candidate_indices(
     randomness: &[u8; 32],
     pool_version: u32,
     count: usize,
 ) -> Vec<u32> {
+    derive_distinct_indices(randomness, pool_version, pool_len(), count)
+        .expect("degen candidate count exceeds pool size")
+}
+
+fn derive_distinct_indices(
+    randomness: &[u8; 32],
+    pool_version: u32,
+    pool_len: usize,
+    count: usize,
+) -> Option<Vec<u32>> {
+    if count > pool_len {
+        return None;
+    }
     let mut selected = Vec::with_capacity(count);
-    let pool_len = pool_len();
 
     for rank in 0..count {
         let mut nonce: u32 = 0;
@@ -53,7 +87,19 @@ pub fn derive_degen_candidate_indices(
         }
     }
 
-    selected
+    Some(selected)
+}
+
+/// The full candidate window a winner may swap into: `window` distinct pool
+/// indices, in rank order (entry `r` equals
+/// [`derive_degen_candidate_index_at_rank`] for rank `r`). `None` if the
+/// pool holds fewer than `window` mints.
+pub fn derive_degen_candidates(
+    randomness: &[u8; 32],
+    pool_version: u32,
+    window: u8,
+) -> Option<Vec<u32>> {
+    derive_distinct_indices(randomness, pool_version, pool_len(), window as usize)
 }
 
 pub fn derive_degen_candidate_index_at_rank(
@@ -67,6 +113,7 @@ pub fn derive_degen_candidate_index_at_rank(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::legacy_layouts::DEGEN_CANDIDATE_WINDOW;
 
     #[test]
     fn derives_stable_candidate_indices_from_live_pool() {
@@ -76,6 +123,47 @@ mod tests {
         assert_eq!(derive_degen_candidate_index_at_rank(&randomness, 1, 9), 3958);
     }
 
+    #[test]
+    fn candidate_window_is_distinct_and_in_range() {
+        let randomness = [0x5au8; 32];
+        let candidates = derive_degen_candidates(&randomness, 1, DEGEN_CANDIDATE_WINDOW).unwrap();
+
+        assert_eq!(candidates.len(), DEGEN_CANDIDATE_WINDOW as usize);
+        assert_eq!(candidates[0], derive_degen_candidate_index_at_rank(&randomness, 1, 0));
+        for (rank, index) in candidates.iter().enumerate() {
+            assert!(is_valid_token_index(*index));
+            assert!(!candidates[..rank].contains(index));
+        }
+    }
+
+    #[test]
+    fn window_larger_than_pool_is_rejected() {
+        let randomness = [3u8; 32];
+        assert_eq!(derive_distinct_indices(&randomness, 1, 5, 6), None);
+
+        let mut full = derive_distinct_indices(&randomness, 1, 5, 5).unwrap();
+        full.sort_unstable();
+        assert_eq!(full, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn candidate_windows_never_repeat_across_seeds() {
+        for seed in 0u32..256 {
+            let mut randomness = [0u8; 32];
+            randomness[..4].copy_from_slice(&seed.to_le_bytes());
+            // A pool barely larger than the window forces many collisions.
+            for pool_len in [DEGEN_CANDIDATE_WINDOW as usize + 1, pool_len()] {
+                let window =
+                    derive_distinct_indices(&randomness, 1, pool_len, DEGEN_CANDIDATE_WINDOW as usize)
+                        .unwrap();
+                for (rank, index) in window.iter().enumerate() {
+                    assert!((*index as usize) < pool_len);
+                    assert!(!window[..rank].contains(index), "seed {seed} repeats {index}");
+                }
+            }
+        }
+    }
+
     #[test]
     fn returns_live_pool_version_and_mints() {
         assert_eq!(pool_version(), 1);
@@ -84,4 +172,18 @@ mod tests {
         assert!(degen_token_mint_by_index(0).is_some());
         assert!(degen_token_mint_by_index(4533).is_none());
     }
+
+    #[test]
+    fn token_index_bounds_follow_pool_len() {
+        let len = degen_pool_len();
+        assert!(len > 0);
+        assert_eq!(len, pool_len());
+        assert!(is_valid_token_index(0));
+        assert!(degen_token_mint_by_index(0).is_some());
+        let last = (len - 1) as u32;
+        assert!(is_valid_token_index(last));
+        assert!(degen_token_mint_by_index(last).is_some());
+        assert!(!is_valid_token_index(len as u32));
+        assert!(degen_token_mint_by_index(len as u32).is_none());
+    }
 }
@@ -1,7 +1,12 @@
 use pinocchio::error::ProgramError;
 
+/// Custom error codes returned as `ProgramError::Custom`, numbered exactly as
+/// the Anchor program's `ErrorCode` (6000 + declaration order) so clients
+/// decode both programs' failures the same way. Codes past Anchor's last one
+/// are Pinocchio-only; new codes are only ever appended.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
+#[non_exhaustive]
 pub enum JackpotCompatError {
     Paused = 6000,
     InvalidFeeBps = 6001,
@@ -51,6 +56,10 @@ pub enum JackpotCompatError {
     InvalidVrfPayerAta = 6045,
     DegenOutputNotReceived = 6046,
     DegenFallbackTooEarly = 6047,
+    DegenCandidateMismatch = 6048,
+    InvalidFallbackTimeout = 6049,
+    TokenAccountFrozen = 6050,
+    VrfTimeoutNotReached = 6051,
 }
 
 impl From<JackpotCompatError> for ProgramError {
@@ -58,3 +67,220 @@ impl From<JackpotCompatError> for ProgramError {
         ProgramError::Custom(value as u32)
     }
 }
+
+#[cfg(feature = "error-table")]
+const ERROR_TABLE: [(u32, &str); 52] = [
+    (JackpotCompatError::Paused as u32, "Paused"),
+    (JackpotCompatError::InvalidFeeBps as u32, "InvalidFeeBps"),
+    (JackpotCompatError::InvalidTicketUnit as u32, "InvalidTicketUnit"),
+    (JackpotCompatError::RoundNotOpen as u32, "RoundNotOpen"),
+    (JackpotCompatError::RoundNotLocked as u32, "RoundNotLocked"),
+    (JackpotCompatError::RoundNotVrfRequested as u32, "RoundNotVrfRequested"),
+    (JackpotCompatError::RoundNotSettled as u32, "RoundNotSettled"),
+    (JackpotCompatError::RoundAlreadyClaimed as u32, "RoundAlreadyClaimed"),
+    (JackpotCompatError::NoDepositsYet as u32, "NoDepositsYet"),
+    (JackpotCompatError::NotEnoughParticipants as u32, "NotEnoughParticipants"),
+    (JackpotCompatError::NotEnoughTickets as u32, "NotEnoughTickets"),
+    (JackpotCompatError::RoundNotEnded as u32, "RoundNotEnded"),
+    (JackpotCompatError::InvalidVault as u32, "InvalidVault"),
+    (JackpotCompatError::InvalidTreasury as u32, "InvalidTreasury"),
+    (JackpotCompatError::InvalidUserUsdcAta as u32, "InvalidUserUsdcAta"),
+    (JackpotCompatError::InvalidUsdcBalanceBefore as u32, "InvalidUsdcBalanceBefore"),
+    (JackpotCompatError::SlippageExceeded as u32, "SlippageExceeded"),
+    (JackpotCompatError::DepositTooSmall as u32, "DepositTooSmall"),
+    (JackpotCompatError::MaxParticipantsReached as u32, "MaxParticipantsReached"),
+    (JackpotCompatError::OnlyWinnerCanClaim as u32, "OnlyWinnerCanClaim"),
+    (JackpotCompatError::MathOverflow as u32, "MathOverflow"),
+    (JackpotCompatError::Unauthorized as u32, "Unauthorized"),
+    (JackpotCompatError::RoundNotCancellable as u32, "RoundNotCancellable"),
+    (JackpotCompatError::NoDepositToRefund as u32, "NoDepositToRefund"),
+    (JackpotCompatError::AlreadyRefunded as u32, "AlreadyRefunded"),
+    (JackpotCompatError::RoundNotCloseable as u32, "RoundNotCloseable"),
+    (JackpotCompatError::VaultNotEmpty as u32, "VaultNotEmpty"),
+    (JackpotCompatError::RoundExpired as u32, "RoundExpired"),
+    (JackpotCompatError::InvalidRoundDuration as u32, "InvalidRoundDuration"),
+    (JackpotCompatError::ParticipantRoundMismatch as u32, "ParticipantRoundMismatch"),
+    (JackpotCompatError::MaxDepositExceeded as u32, "MaxDepositExceeded"),
+    (JackpotCompatError::InvalidAdmin as u32, "InvalidAdmin"),
+    (JackpotCompatError::ParticipantNotEmpty as u32, "ParticipantNotEmpty"),
+    (JackpotCompatError::CancelNotAllowed as u32, "CancelNotAllowed"),
+    (JackpotCompatError::DegenClaimLocked as u32, "DegenClaimLocked"),
+    (JackpotCompatError::DegenVrfNotRequested as u32, "DegenVrfNotRequested"),
+    (JackpotCompatError::DegenVrfNotReady as u32, "DegenVrfNotReady"),
+    (JackpotCompatError::DegenAlreadyRequested as u32, "DegenAlreadyRequested"),
+    (JackpotCompatError::DegenAlreadyClaimed as u32, "DegenAlreadyClaimed"),
+    (JackpotCompatError::InvalidDegenClaim as u32, "InvalidDegenClaim"),
+    (JackpotCompatError::InvalidDegenCandidate as u32, "InvalidDegenCandidate"),
+    (JackpotCompatError::UnauthorizedDegenExecutor as u32, "UnauthorizedDegenExecutor"),
+    (JackpotCompatError::InvalidDegenExecutionState as u32, "InvalidDegenExecutionState"),
+    (JackpotCompatError::InvalidDegenExecutorAta as u32, "InvalidDegenExecutorAta"),
+    (JackpotCompatError::InvalidDegenReceiverAta as u32, "InvalidDegenReceiverAta"),
+    (JackpotCompatError::InvalidVrfPayerAta as u32, "InvalidVrfPayerAta"),
+    (JackpotCompatError::DegenOutputNotReceived as u32, "DegenOutputNotReceived"),
+    (JackpotCompatError::DegenFallbackTooEarly as u32, "DegenFallbackTooEarly"),
+    (JackpotCompatError::DegenCandidateMismatch as u32, "DegenCandidateMismatch"),
+    (JackpotCompatError::InvalidFallbackTimeout as u32, "InvalidFallbackTimeout"),
+    (JackpotCompatError::TokenAccountFrozen as u32, "TokenAccountFrozen"),
+    (JackpotCompatError::VrfTimeoutNotReached as u32, "VrfTimeoutNotReached"),
+];
+
+/// Every `JackpotCompatError` as `(code, variant name)`, in code order, for
+/// clients decoding `ProgramError::Custom(code)`.
+#[cfg(feature = "error-table")]
+pub fn error_table() -> &'static [(u32, &'static str)] {
+    &ERROR_TABLE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [JackpotCompatError; 52] = [
+        JackpotCompatError::Paused,
+        JackpotCompatError::InvalidFeeBps,
+        JackpotCompatError::InvalidTicketUnit,
+        JackpotCompatError::RoundNotOpen,
+        JackpotCompatError::RoundNotLocked,
+        JackpotCompatError::RoundNotVrfRequested,
+        JackpotCompatError::RoundNotSettled,
+        JackpotCompatError::RoundAlreadyClaimed,
+        JackpotCompatError::NoDepositsYet,
+        JackpotCompatError::NotEnoughParticipants,
+        JackpotCompatError::NotEnoughTickets,
+        JackpotCompatError::RoundNotEnded,
+        JackpotCompatError::InvalidVault,
+        JackpotCompatError::InvalidTreasury,
+        JackpotCompatError::InvalidUserUsdcAta,
+        JackpotCompatError::InvalidUsdcBalanceBefore,
+        JackpotCompatError::SlippageExceeded,
+        JackpotCompatError::DepositTooSmall,
+        JackpotCompatError::MaxParticipantsReached,
+        JackpotCompatError::OnlyWinnerCanClaim,
+        JackpotCompatError::MathOverflow,
+        JackpotCompatError::Unauthorized,
+        JackpotCompatError::RoundNotCancellable,
+        JackpotCompatError::NoDepositToRefund,
+        JackpotCompatError::AlreadyRefunded,
+        JackpotCompatError::RoundNotCloseable,
+        JackpotCompatError::VaultNotEmpty,
+        JackpotCompatError::RoundExpired,
+        JackpotCompatError::InvalidRoundDuration,
+        JackpotCompatError::ParticipantRoundMismatch,
+        JackpotCompatError::MaxDepositExceeded,
+        JackpotCompatError::InvalidAdmin,
+        JackpotCompatError::ParticipantNotEmpty,
+        JackpotCompatError::CancelNotAllowed,
+        JackpotCompatError::DegenClaimLocked,
+        JackpotCompatError::DegenVrfNotRequested,
+        JackpotCompatError::DegenVrfNotReady,
+        JackpotCompatError::DegenAlreadyRequested,
+        JackpotCompatError::DegenAlreadyClaimed,
+        JackpotCompatError::InvalidDegenClaim,
+        JackpotCompatError::InvalidDegenCandidate,
+        JackpotCompatError::UnauthorizedDegenExecutor,
+        JackpotCompatError::InvalidDegenExecutionState,
+        JackpotCompatError::InvalidDegenExecutorAta,
+        JackpotCompatError::InvalidDegenReceiverAta,
+        JackpotCompatError::InvalidVrfPayerAta,
+        JackpotCompatError::DegenOutputNotReceived,
+        JackpotCompatError::DegenFallbackTooEarly,
+        JackpotCompatError::DegenCandidateMismatch,
+        JackpotCompatError::InvalidFallbackTimeout,
+        JackpotCompatError::TokenAccountFrozen,
+        JackpotCompatError::VrfTimeoutNotReached,
+    ];
+
+    #[test]
+    fn codes_match_anchor_error_code_order() {
+        let anchor_source = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../jackpot_anchor_v4/programs/jackpot/src/errors.rs"
+        ));
+        let anchor_variants: Vec<&str> = anchor_source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#') && !line.starts_with("//") && line.ends_with(','))
+            .map(|line| line.trim_end_matches(','))
+            .collect();
+        assert!(anchor_variants.len() <= ALL.len());
+
+        for (offset, error) in ALL.into_iter().enumerate() {
+            let code = error as u32;
+            assert_eq!(code, 6000 + offset as u32, "{error:?} is out of sequence");
+            assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+            if let Some(anchor_name) = anchor_variants.get(offset) {
+                assert_eq!(*anchor_name, format!("{error:?}"), "code {code}");
+            }
+        }
+        assert_eq!(ALL.last().map(|error| *error as u32), Some(6051));
+    }
+
+    #[cfg(feature = "error-table")]
+    #[test]
+    fn error_table_lists_every_variant() {
+        // Exhaustive on purpose: a new variant does not compile until it is
+        // named here and added to `ERROR_TABLE`.
+        fn name(error: JackpotCompatError) -> &'static str {
+            match error {
+                JackpotCompatError::Paused => "Paused",
+                JackpotCompatError::InvalidFeeBps => "InvalidFeeBps",
+                JackpotCompatError::InvalidTicketUnit => "InvalidTicketUnit",
+                JackpotCompatError::RoundNotOpen => "RoundNotOpen",
+                JackpotCompatError::RoundNotLocked => "RoundNotLocked",
+                JackpotCompatError::RoundNotVrfRequested => "RoundNotVrfRequested",
+                JackpotCompatError::RoundNotSettled => "RoundNotSettled",
+                JackpotCompatError::RoundAlreadyClaimed => "RoundAlreadyClaimed",
+                JackpotCompatError::NoDepositsYet => "NoDepositsYet",
+                JackpotCompatError::NotEnoughParticipants => "NotEnoughParticipants",
+                JackpotCompatError::NotEnoughTickets => "NotEnoughTickets",
+                JackpotCompatError::RoundNotEnded => "RoundNotEnded",
+                JackpotCompatError::InvalidVault => "InvalidVault",
+                JackpotCompatError::InvalidTreasury => "InvalidTreasury",
+                JackpotCompatError::InvalidUserUsdcAta => "InvalidUserUsdcAta",
+                JackpotCompatError::InvalidUsdcBalanceBefore => "InvalidUsdcBalanceBefore",
+                JackpotCompatError::SlippageExceeded => "SlippageExceeded",
+                JackpotCompatError::DepositTooSmall => "DepositTooSmall",
+                JackpotCompatError::MaxParticipantsReached => "MaxParticipantsReached",
+                JackpotCompatError::OnlyWinnerCanClaim => "OnlyWinnerCanClaim",
+                JackpotCompatError::MathOverflow => "MathOverflow",
+                JackpotCompatError::Unauthorized => "Unauthorized",
+                JackpotCompatError::RoundNotCancellable => "RoundNotCancellable",
+                JackpotCompatError::NoDepositToRefund => "NoDepositToRefund",
+                JackpotCompatError::AlreadyRefunded => "AlreadyRefunded",
+                JackpotCompatError::RoundNotCloseable => "RoundNotCloseable",
+                JackpotCompatError::VaultNotEmpty => "VaultNotEmpty",
+                JackpotCompatError::RoundExpired => "RoundExpired",
+                JackpotCompatError::InvalidRoundDuration => "InvalidRoundDuration",
+                JackpotCompatError::ParticipantRoundMismatch => "ParticipantRoundMismatch",
+                JackpotCompatError::MaxDepositExceeded => "MaxDepositExceeded",
+                JackpotCompatError::InvalidAdmin => "InvalidAdmin",
+                JackpotCompatError::ParticipantNotEmpty => "ParticipantNotEmpty",
+                JackpotCompatError::CancelNotAllowed => "CancelNotAllowed",
+                JackpotCompatError::DegenClaimLocked => "DegenClaimLocked",
+                JackpotCompatError::DegenVrfNotRequested => "DegenVrfNotRequested",
+                JackpotCompatError::DegenVrfNotReady => "DegenVrfNotReady",
+                JackpotCompatError::DegenAlreadyRequested => "DegenAlreadyRequested",
+                JackpotCompatError::DegenAlreadyClaimed => "DegenAlreadyClaimed",
+                JackpotCompatError::InvalidDegenClaim => "InvalidDegenClaim",
+                JackpotCompatError::InvalidDegenCandidate => "InvalidDegenCandidate",
+                JackpotCompatError::UnauthorizedDegenExecutor => "UnauthorizedDegenExecutor",
+                JackpotCompatError::InvalidDegenExecutionState => "InvalidDegenExecutionState",
+                JackpotCompatError::InvalidDegenExecutorAta => "InvalidDegenExecutorAta",
+                JackpotCompatError::InvalidDegenReceiverAta => "InvalidDegenReceiverAta",
+                JackpotCompatError::InvalidVrfPayerAta => "InvalidVrfPayerAta",
+                JackpotCompatError::DegenOutputNotReceived => "DegenOutputNotReceived",
+                JackpotCompatError::DegenFallbackTooEarly => "DegenFallbackTooEarly",
+                JackpotCompatError::DegenCandidateMismatch => "DegenCandidateMismatch",
+                JackpotCompatError::InvalidFallbackTimeout => "InvalidFallbackTimeout",
+                JackpotCompatError::TokenAccountFrozen => "TokenAccountFrozen",
+                JackpotCompatError::VrfTimeoutNotReached => "VrfTimeoutNotReached",
+            }
+        }
+
+        let table = error_table();
+        assert_eq!(table.len(), ALL.len());
+        for (entry, error) in table.iter().zip(ALL) {
+            assert_eq!(*entry, (error as u32, name(error)));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Compact `sol_log_data` events for off-chain indexers.
+//!
+//! Every event is a single log-data segment: a one-byte tag followed by
+//! little-endian fixed-width fields. Unlike Anchor's `emit!`, there is no
+//! 8-byte discriminator or borsh framing.
+
+use crate::legacy_layouts::PUBKEY_LEN;
+
+pub const EVENT_TAG_ROUND_SETTLED: u8 = 1;
+pub const EVENT_TAG_DEGEN_CLAIMED: u8 = 2;
+
+/// tag + round_id + winner + winning_ticket
+pub const ROUND_SETTLED_EVENT_LEN: usize = 1 + 8 + PUBKEY_LEN + 8;
+/// tag + round_id + token_mint + payout
+pub const DEGEN_CLAIMED_EVENT_LEN: usize = 1 + 8 + PUBKEY_LEN + 8;
+
+pub fn encode_round_settled(
+    round_id: u64,
+    winner: &[u8; PUBKEY_LEN],
+    winning_ticket: u64,
+) -> [u8; ROUND_SETTLED_EVENT_LEN] {
+    encode_tagged(EVENT_TAG_ROUND_SETTLED, round_id, winner, winning_ticket)
+}
+
+pub fn encode_degen_claimed(
+    round_id: u64,
+    token_mint: &[u8; PUBKEY_LEN],
+    payout: u64,
+) -> [u8; DEGEN_CLAIMED_EVENT_LEN] {
+    encode_tagged(EVENT_TAG_DEGEN_CLAIMED, round_id, token_mint, payout)
+}
+
+pub fn emit_round_settled(round_id: u64, winner: &[u8; PUBKEY_LEN], winning_ticket: u64) {
+    log_data(&encode_round_settled(round_id, winner, winning_ticket));
+}
+
+pub fn emit_degen_claimed(round_id: u64, token_mint: &[u8; PUBKEY_LEN], payout: u64) {
+    log_data(&encode_degen_claimed(round_id, token_mint, payout));
+}
+
+fn encode_tagged(
+    tag: u8,
+    round_id: u64,
+    key: &[u8; PUBKEY_LEN],
+    value: u64,
+) -> [u8; 1 + 8 + PUBKEY_LEN + 8] {
+    let mut out = [0u8; 1 + 8 + PUBKEY_LEN + 8];
+    out[0] = tag;
+    out[1..9].copy_from_slice(&round_id.to_le_bytes());
+    out[9..9 + PUBKEY_LEN].copy_from_slice(key);
+    out[9 + PUBKEY_LEN..].copy_from_slice(&value.to_le_bytes());
+    out
+}
+
+/// Syscalls only exist on the SBF target; host builds (unit tests, benches
+/// harness) compile this to a no-op.
+#[cfg(any(target_os = "solana", target_arch = "bpf"))]
+fn log_data(data: &[u8]) {
+    let segments: [&[u8]; 1] = [data];
+    unsafe {
+        pinocchio::syscalls::sol_log_data(segments.as_ptr() as *const u8, segments.len() as u64);
+    }
+}
+
+#[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+fn log_data(_data: &[u8]) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_settled_layout_is_tag_round_winner_ticket() {
+        let encoded = encode_round_settled(81, &[7u8; 32], 0x0102_0304_0506_0708);
+
+        assert_eq!(encoded.len(), ROUND_SETTLED_EVENT_LEN);
+        assert_eq!(encoded[0], EVENT_TAG_ROUND_SETTLED);
+        assert_eq!(&encoded[1..9], &81u64.to_le_bytes());
+        assert_eq!(&encoded[9..41], &[7u8; 32]);
+        assert_eq!(&encoded[41..49], &[8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn degen_claimed_layout_is_tag_round_mint_payout() {
+        let encoded = encode_degen_claimed(45, &[3u8; 32], 997_500);
+
+        assert_eq!(encoded.len(), DEGEN_CLAIMED_EVENT_LEN);
+        assert_eq!(encoded[0], EVENT_TAG_DEGEN_CLAIMED);
+        assert_eq!(&encoded[1..9], &45u64.to_le_bytes());
+        assert_eq!(&encoded[9..41], &[3u8; 32]);
+        assert_eq!(&encoded[41..49], &997_500u64.to_le_bytes());
+    }
+
+    #[test]
+    fn event_tags_are_distinct() {
+        assert_ne!(EVENT_TAG_ROUND_SETTLED, EVENT_TAG_DEGEN_CLAIMED);
+    }
+}
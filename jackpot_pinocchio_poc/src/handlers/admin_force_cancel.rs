@@ -15,8 +15,7 @@ pub fn process_anchor_bytes(
     round_account_data: &mut [u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "admin_force_cancel")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "admin_force_cancel")?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
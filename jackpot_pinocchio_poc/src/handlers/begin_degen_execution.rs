@@ -1,11 +1,12 @@
 use pinocchio::error::ProgramError;
 
 use crate::{
+    degen_pool_compat::degen_token_mint_by_index,
     errors::JackpotCompatError,
-    handlers::degen_common::{ClaimAmountsCompat, compute_claim_amounts, map_layout_err},
+    handlers::degen_common::{ClaimAmountsCompat, degen_claim_amounts, map_layout_err, vrf_reimbursement_due},
     instruction_layouts::BeginDegenExecutionArgsCompat,
     legacy_layouts::{
-        ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, TokenAccountCoreView,
+        ConfigView, DegenClaimView, DegenConfigView, LayoutError, RoundLifecycleView, RoundVrfMeta, TokenAccountCoreView,
         TokenAccountWithAmountView, DEGEN_CLAIM_STATUS_EXECUTING, DEGEN_CLAIM_STATUS_VRF_READY,
         DEGEN_FALLBACK_REASON_NONE, DEGEN_MODE_EXECUTING, DEGEN_MODE_VRF_READY, ROUND_STATUS_SETTLED,
     },
@@ -31,8 +32,7 @@ pub fn process_anchor_bytes(
     vrf_payer_usdc_ata_data: Option<&[u8]>,
     ix_data: &[u8],
 ) -> Result<ClaimAmountsCompat, ProgramError> {
-    let args =
-        BeginDegenExecutionArgsCompat::parse(ix_data).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = BeginDegenExecutionArgsCompat::parse(ix_data)?;
     let config = ConfigView::read_from_account_data(config_account_data).map_err(map_layout_err)?;
     let degen_config = DegenConfigView::read_from_account_data(degen_config_account_data)
         .map_err(map_layout_err)?;
@@ -59,18 +59,25 @@ pub fn process_anchor_bytes(
     if round.status != ROUND_STATUS_SETTLED {
         return Err(JackpotCompatError::RoundNotSettled.into());
     }
-    if RoundLifecycleView::read_degen_mode_status_from_account_data(round_account_data).map_err(map_layout_err)?
-        != DEGEN_MODE_VRF_READY
-    {
-        return Err(JackpotCompatError::DegenVrfNotReady.into());
-    }
+    RoundLifecycleView::require_degen_mode(round_account_data, DEGEN_MODE_VRF_READY).map_err(|err| match err {
+        LayoutError::InconsistentDegenState => JackpotCompatError::DegenVrfNotReady.into(),
+        err => map_layout_err(err),
+    })?;
     if degen_claim.status != DEGEN_CLAIM_STATUS_VRF_READY {
         return Err(JackpotCompatError::DegenVrfNotReady.into());
     }
-    if degen_claim.round != round_pubkey || degen_claim.round_id != args.round_id {
+    // The swap output must reach the round's winner; the claim's copy of the
+    // winner is only trusted once it matches the round.
+    let round_winner = RoundLifecycleView::read_winner_from_account_data(round_account_data).map_err(map_layout_err)?;
+    if degen_claim.round != round_pubkey || degen_claim.round_id != args.round_id || degen_claim.winner != round_winner {
         return Err(JackpotCompatError::InvalidDegenClaim.into());
     }
-    if vault_pubkey != RoundLifecycleView::read_vault_pubkey_from_account_data(round_account_data).map_err(map_layout_err)?
+    // Same window bound as `claim_degen`: only the ranks VRF offered the
+    // winner may be executed.
+    if args.candidate_rank >= degen_claim.candidate_window {
+        return Err(JackpotCompatError::InvalidDegenCandidate.into());
+    }
+    if vault_pubkey != RoundLifecycleView::require_vault_set(round_account_data).map_err(map_layout_err)?
         || vault.mint != config.usdc_mint
         || vault.owner != round_pubkey
     {
@@ -79,17 +86,22 @@ pub fn process_anchor_bytes(
     if treasury_usdc_ata_pubkey != config.treasury_usdc_ata || treasury_usdc_ata.mint != config.usdc_mint {
         return Err(JackpotCompatError::InvalidTreasury.into());
     }
-    if receiver_token_ata.owner != degen_claim.winner
+    if receiver_token_ata.owner != round_winner
         || receiver_token_ata.mint != selected_token_mint_pubkey
     {
         return Err(JackpotCompatError::InvalidDegenReceiverAta.into());
     }
+    // The mint account and `token_index` arrive independently; the swap
+    // target recorded on the claim must be the pool's mint at that index.
+    if degen_token_mint_by_index(args.token_index) != Some(selected_token_mint_pubkey) {
+        return Err(JackpotCompatError::InvalidDegenCandidate.into());
+    }
 
-    let reimburse_vrf = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data).map_err(map_layout_err)? != [0u8; 32]
-        && RoundLifecycleView::read_vrf_reimbursed_from_account_data(round_account_data).map_err(map_layout_err)? == 0;
+    let vrf_meta = RoundLifecycleView::read_vrf_meta_from_account_data(round_account_data).map_err(map_layout_err)?;
+    let reimburse_vrf = vrf_reimbursement_due(&config, &vrf_meta);
 
     if reimburse_vrf {
-        let expected_vrf_payer = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data).map_err(map_layout_err)?;
+        let expected_vrf_payer = vrf_meta.payer;
         let authority = vrf_payer_authority_pubkey.ok_or::<ProgramError>(JackpotCompatError::InvalidVrfPayerAta.into())?;
         if authority != expected_vrf_payer {
             return Err(JackpotCompatError::InvalidVrfPayerAta.into());
@@ -101,12 +113,13 @@ pub fn process_anchor_bytes(
         }
     }
 
-    let amounts = compute_claim_amounts(round.total_usdc, config.fee_bps, reimburse_vrf)?;
+    let amounts = degen_claim_amounts(&degen_claim, round.total_usdc, config.fee_bps, reimburse_vrf)?;
 
     RoundLifecycleView::write_degen_mode_status_to_account_data(round_account_data, DEGEN_MODE_EXECUTING)
         .map_err(map_layout_err)?;
     if amounts.vrf_reimburse > 0 {
-        RoundLifecycleView::write_vrf_reimbursed_to_account_data(round_account_data, 1).map_err(map_layout_err)?;
+        RoundLifecycleView::write_vrf_meta_to_account_data(round_account_data, &RoundVrfMeta { reimbursed: true, ..vrf_meta })
+            .map_err(map_layout_err)?;
     }
 
     degen_claim.status = DEGEN_CLAIM_STATUS_EXECUTING;
@@ -122,6 +135,8 @@ pub fn process_anchor_bytes(
     degen_claim.route_hash = args.route_hash;
     degen_claim.claimed_at = 0;
     degen_claim.fulfilled_at = now_ts;
+    degen_claim.set_execution_started_at(now_ts);
+    degen_claim.set_fees_paid(true);
     degen_claim.write_to_account_data(degen_claim_account_data).map_err(map_layout_err)?;
 
     Ok(amounts)
@@ -131,7 +146,8 @@ pub fn process_anchor_bytes(
 mod tests {
     use super::*;
     use crate::{
-        anchor_compat::{account_discriminator, instruction_discriminator},
+        anchor_compat::account_discriminator,
+        instruction_layouts::{encode_begin_degen_execution, RoundId},
         legacy_layouts::{
             ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, TokenAccountWithAmountView,
             CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
@@ -156,7 +172,7 @@ mod tests {
         let receiver_token_ata = [12u8; 32];
         let usdc_mint = [2u8; 32];
         let token_index = 42u32;
-        let selected_token_mint = [11u8; 32];
+        let selected_token_mint = degen_token_mint_by_index(token_index).unwrap();
 
         let mut config = [0u8; CONFIG_ACCOUNT_LEN];
         config[..8].copy_from_slice(&account_discriminator("Config"));
@@ -243,13 +259,7 @@ mod tests {
         let treasury_ata = token_account(usdc_mint, [7u8; 32], 0);
         let receiver_ata = token_account(selected_token_mint, winner, 500);
 
-        let mut ix = Vec::new();
-        ix.extend_from_slice(&instruction_discriminator("begin_degen_execution"));
-        ix.extend_from_slice(&81u64.to_le_bytes());
-        ix.push(0);
-        ix.extend_from_slice(&token_index.to_le_bytes());
-        ix.extend_from_slice(&777u64.to_le_bytes());
-        ix.extend_from_slice(&[33u8; 32]);
+        let ix = encode_begin_degen_execution(RoundId(81), 0, token_index, 777, [33u8; 32]);
 
         let amounts = process_anchor_bytes(
             executor,
@@ -276,6 +286,7 @@ mod tests {
         assert_eq!(amounts.payout, 997_500);
         assert_eq!(amounts.fee, 2_500);
         assert_eq!(RoundLifecycleView::read_degen_mode_status_from_account_data(&round).unwrap(), 3);
+        RoundLifecycleView::verify_degen_mode_consistency(&round).unwrap();
         let claim = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
         assert_eq!(claim.status, 3);
         assert_eq!(claim.selected_candidate_rank, 0);
@@ -288,5 +299,952 @@ mod tests {
         assert_eq!(claim.payout_raw, 997_500);
         assert_eq!(claim.route_hash, [33u8; 32]);
         assert_eq!(claim.fulfilled_at, 1_001);
+        assert_eq!(claim.execution_started_at(), 1_001);
+        assert!(claim.fees_paid());
+    }
+}
+
+#[cfg(test)]
+mod begin_degen_negative {
+    use super::*;
+    use crate::{
+        anchor_compat::account_discriminator,
+        instruction_layouts::{encode_begin_degen_execution, RoundId},
+        legacy_layouts::{
+            CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN, DEGEN_MODE_CLAIMED,
+            DEGEN_MODE_NONE, ROUND_ACCOUNT_LEN, ROUND_STATUS_LOCKED, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+        },
+    };
+
+    const EXECUTOR: [u8; 32] = [5u8; 32];
+    const ROUND_KEY: [u8; 32] = [8u8; 32];
+    const WINNER: [u8; 32] = [9u8; 32];
+    const TREASURY: [u8; 32] = [3u8; 32];
+    const USDC_MINT: [u8; 32] = [2u8; 32];
+    const RECEIVER_ATA: [u8; 32] = [12u8; 32];
+    const VRF_PAYER: [u8; 32] = [10u8; 32];
+
+    fn sample_config() -> [u8; CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        ConfigView {
+            admin: [7u8; 32],
+            usdc_mint: USDC_MINT,
+            treasury_usdc_ata: TREASURY,
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn sample_degen_config() -> [u8; DEGEN_CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
+        DegenConfigView {
+            executor: EXECUTOR,
+            fallback_timeout_sec: 300,
+            bump: 201,
+            reserved: [0u8; 27],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn sample_round() -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView {
+            round_id: 81,
+            status: ROUND_STATUS_SETTLED,
+            bump: 202,
+            start_ts: 10,
+            end_ts: 130,
+            first_deposit_ts: 25,
+            total_usdc: 1_000_000,
+            total_tickets: 200,
+            participants_count: 2,
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data[48..80].copy_from_slice(&ROUND_KEY);
+        RoundLifecycleView::write_winner_to_account_data(&mut data, &WINNER).unwrap();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut data, DEGEN_MODE_VRF_READY).unwrap();
+        data
+    }
+
+    fn sample_degen_claim() -> [u8; DEGEN_CLAIM_ACCOUNT_LEN] {
+        let mut data = [0u8; DEGEN_CLAIM_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenClaim"));
+        DegenClaimView {
+            round: ROUND_KEY,
+            winner: WINNER,
+            round_id: 81,
+            status: DEGEN_CLAIM_STATUS_VRF_READY,
+            bump: 203,
+            selected_candidate_rank: u8::MAX,
+            fallback_reason: 0,
+            token_index: 0,
+            pool_version: 1,
+            candidate_window: 30,
+            padding0: [0u8; 7],
+            requested_at: 777,
+            fulfilled_at: 900,
+            claimed_at: 0,
+            fallback_after_ts: 1_200,
+            payout_raw: 0,
+            min_out_raw: 0,
+            receiver_pre_balance: 0,
+            token_mint: [0u8; 32],
+            executor: [0u8; 32],
+            receiver_token_ata: [0u8; 32],
+            randomness: [7u8; 32],
+            route_hash: [0u8; 32],
+            reserved: [0u8; 32],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn token_account(mint: [u8; 32], owner: [u8; 32], amount: u64) -> [u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN] {
+        let mut data = [0u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        data[..32].copy_from_slice(&mint);
+        data[32..64].copy_from_slice(&owner);
+        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, amount).unwrap();
+        data
+    }
+
+    /// Pool mint at the default `token_index` (42).
+    fn token_mint() -> [u8; 32] {
+        degen_token_mint_by_index(42).unwrap()
+    }
+
+    fn begin_ix(round_id: u64, candidate_rank: u8, token_index: u32) -> Vec<u8> {
+        encode_begin_degen_execution(RoundId(round_id), candidate_rank, token_index, 777, [33u8; 32])
+    }
+
+    #[test]
+    fn accepts_valid_inputs() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn re_begin_after_recovery_does_not_charge_fee_again() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        let mut view = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        view.payout_raw = 997_500;
+        view.set_fees_paid(true);
+        view.write_to_account_data(&mut degen_claim).unwrap();
+
+        let amounts = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap();
+
+        assert_eq!(amounts, ClaimAmountsCompat { payout: 997_500, fee: 0, vrf_reimburse: 0 });
+    }
+
+    #[test]
+    fn rejects_wrong_executor() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            [6u8; 32],
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, [6u8; 32], 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::UnauthorizedDegenExecutor.into());
+        assert_eq!(round, sample_round(), "round mutated on rejection");
+        assert_eq!(degen_claim, sample_degen_claim(), "degen_claim mutated on rejection");
+    }
+
+    #[test]
+    fn rejects_executor_ata_with_wrong_mint() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(token_mint(), EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutorAta.into());
+    }
+
+    #[test]
+    fn rejects_executor_ata_with_wrong_owner() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, WINNER, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutorAta.into());
+    }
+
+    #[test]
+    fn rejects_non_empty_executor_ata() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 1),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutorAta.into());
+    }
+
+    #[test]
+    fn rejects_round_id_mismatch() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(82, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn rejects_unsettled_round() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_status_to_account_data(&mut round, ROUND_STATUS_LOCKED).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::RoundNotSettled.into());
+    }
+
+    #[test]
+    fn rejects_non_degen_round() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round, DEGEN_MODE_NONE).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::DegenVrfNotReady.into());
+    }
+
+    #[test]
+    fn rejects_round_already_executing_or_claimed() {
+        for mode in [DEGEN_MODE_EXECUTING, DEGEN_MODE_CLAIMED] {
+            let mut round = sample_round();
+            let mut degen_claim = sample_degen_claim();
+            RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round, mode).unwrap();
+
+            let err = process_anchor_bytes(
+                EXECUTOR,
+                ROUND_KEY,
+                ROUND_KEY,
+                TREASURY,
+                token_mint(),
+                RECEIVER_ATA,
+                None,
+                1_001,
+                &sample_config(),
+                &sample_degen_config(),
+                &mut round,
+                &mut degen_claim,
+                &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+                &token_account(USDC_MINT, EXECUTOR, 0),
+                &token_account(USDC_MINT, [7u8; 32], 0),
+                &token_account(token_mint(), WINNER, 500),
+                None,
+                &begin_ix(81, 0, 42),
+            )
+            .unwrap_err();
+            assert_eq!(err, JackpotCompatError::DegenVrfNotReady.into());
+        }
+    }
+
+    #[test]
+    fn rejects_claim_not_vrf_ready() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { status: DEGEN_CLAIM_STATUS_EXECUTING, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::DegenVrfNotReady.into());
+    }
+
+    #[test]
+    fn rejects_claim_for_other_round() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { round: [1u8; 32], ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenClaim.into());
+    }
+
+    #[test]
+    fn rejects_claim_with_other_round_id() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { round_id: 80, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenClaim.into());
+    }
+
+    /// Bounded by the window recorded on the claim, not the current default.
+    #[test]
+    fn rejects_candidate_rank_outside_window() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { candidate_window: 12, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 12, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenCandidate.into());
+    }
+
+    #[test]
+    fn rejects_wrong_vault_key() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            [1u8; 32],
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVault.into());
+    }
+
+    #[test]
+    fn rejects_round_without_recorded_vault() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_vault_pubkey_to_account_data(&mut round, &[0u8; 32]).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            [0u8; 32],
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVault.into());
+    }
+
+    #[test]
+    fn rejects_vault_with_wrong_mint() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(token_mint(), ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVault.into());
+    }
+
+    #[test]
+    fn rejects_wrong_treasury_key() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            [1u8; 32],
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+    }
+
+    #[test]
+    fn rejects_treasury_with_wrong_mint() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(token_mint(), [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+    }
+
+    #[test]
+    fn rejects_receiver_ata_not_owned_by_winner() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), EXECUTOR, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenReceiverAta.into());
+    }
+
+    #[test]
+    fn rejects_degen_claim_winner_that_differs_from_round_winner() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { winner: EXECUTOR, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), EXECUTOR, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenClaim.into());
+    }
+
+    #[test]
+    fn rejects_receiver_ata_for_other_mint() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(USDC_MINT, WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenReceiverAta.into());
+    }
+
+    #[test]
+    fn rejects_selected_mint_that_is_not_the_pool_mint_for_token_index() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        let other_mint = degen_token_mint_by_index(43).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            other_mint,
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(other_mint, WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenCandidate.into());
+    }
+
+    #[test]
+    fn rejects_token_index_outside_the_pool() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, crate::degen_pool_compat::degen_pool_len() as u32),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenCandidate.into());
+    }
+
+    #[test]
+    fn rejects_missing_vrf_payer_accounts() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_vrf_payer_to_account_data(&mut round, &VRF_PAYER).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            None,
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            None,
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVrfPayerAta.into());
+    }
+
+    #[test]
+    fn rejects_wrong_vrf_payer_authority() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_vrf_payer_to_account_data(&mut round, &VRF_PAYER).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            Some([1u8; 32]),
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            Some(&token_account(USDC_MINT, [1u8; 32], 0)),
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVrfPayerAta.into());
+    }
+
+    #[test]
+    fn rejects_vrf_payer_ata_with_wrong_mint() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        RoundLifecycleView::write_vrf_payer_to_account_data(&mut round, &VRF_PAYER).unwrap();
+
+        let err = process_anchor_bytes(
+            EXECUTOR,
+            ROUND_KEY,
+            ROUND_KEY,
+            TREASURY,
+            token_mint(),
+            RECEIVER_ATA,
+            Some(VRF_PAYER),
+            1_001,
+            &sample_config(),
+            &sample_degen_config(),
+            &mut round,
+            &mut degen_claim,
+            &token_account(USDC_MINT, ROUND_KEY, 1_000_000),
+            &token_account(USDC_MINT, EXECUTOR, 0),
+            &token_account(USDC_MINT, [7u8; 32], 0),
+            &token_account(token_mint(), WINNER, 500),
+            Some(&token_account(token_mint(), VRF_PAYER, 0)),
+            &begin_ix(81, 0, 42),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVrfPayerAta.into());
     }
 }
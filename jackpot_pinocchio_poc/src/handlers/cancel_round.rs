@@ -5,7 +5,7 @@ use crate::{
     instruction_layouts::parse_round_id_ix,
     legacy_layouts::{
         ConfigView, ParticipantView, RoundLifecycleView, TokenAccountCoreView, ROUND_STATUS_CANCELLED,
-        ROUND_STATUS_OPEN, PUBKEY_LEN,
+        ROUND_STATUS_OPEN, PUBKEY_LEN, require_participant_belongs_to_round,
     },
 };
 
@@ -20,8 +20,7 @@ pub fn process_anchor_bytes(
     user_usdc_ata_data: &[u8],
     ix_data: &[u8],
 ) -> Result<u64, ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "cancel_round")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "cancel_round")?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -37,9 +36,10 @@ pub fn process_anchor_bytes(
     if participant.user != user_pubkey {
         return Err(JackpotCompatError::Unauthorized.into());
     }
-    if participant.round != round_pubkey {
-        return Err(JackpotCompatError::Unauthorized.into());
-    }
+    // Anchor's cancel_round constraint reports a foreign participant as
+    // `Unauthorized` rather than `ParticipantRoundMismatch`.
+    require_participant_belongs_to_round(&participant, &round_pubkey)
+        .map_err(|_| ProgramError::from(JackpotCompatError::Unauthorized))?;
 
     let refund_amount = participant.usdc_total;
     if refund_amount == 0 {
@@ -110,9 +110,9 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            ConfigView, ParticipantView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
-            PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_CANCELLED, ROUND_STATUS_OPEN,
-            TOKEN_ACCOUNT_CORE_LEN,
+            ConfigView, LayoutError, ParticipantView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
+            PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_FENWICK_NODE_COUNT,
+            ROUND_STATUS_CANCELLED, ROUND_STATUS_OPEN, TOKEN_ACCOUNT_CORE_LEN,
         },
     };
 
@@ -227,6 +227,96 @@ mod tests {
         assert_eq!(participant_view.tickets_total, 0);
     }
 
+    fn cancel_ix(round_id: u64) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("cancel_round"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn cancel_round_keeps_fenwick_in_sync_with_totals() {
+        let user = [7u8; 32];
+        let round = [4u8; 32];
+        let vault = [8u8; 32];
+        let usdc_mint = [9u8; 32];
+        let config = sample_config([1u8; 32], usdc_mint);
+        let mut round_data = sample_round(81, ROUND_STATUS_OPEN, vault);
+        let mut participant = sample_participant(round, user, 1_000_000);
+        let vault_data = token_account(usdc_mint, round);
+        let user_ata = token_account(usdc_mint, user);
+
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&round_data, 100).unwrap(), 1);
+
+        process_anchor_bytes(
+            user,
+            round,
+            vault,
+            &config,
+            &mut round_data,
+            &mut participant,
+            &vault_data,
+            &user_ata,
+            &cancel_ix(81),
+        )
+        .unwrap();
+
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.total_usdc, 0);
+        assert_eq!(round_view.total_tickets, 0);
+        // No ticket weight is left anywhere on the participant's Fenwick path.
+        assert_eq!(
+            RoundLifecycleView::bit_find_prefix_in_account_data(&round_data, 1).unwrap(),
+            ROUND_FENWICK_NODE_COUNT,
+        );
+        assert_eq!(
+            RoundLifecycleView::bit_sub_in_account_data(&mut round_data, 1, 1),
+            Err(LayoutError::MathOverflow),
+        );
+    }
+
+    #[test]
+    fn rejects_second_refund_for_zeroed_participant() {
+        let user = [7u8; 32];
+        let round = [4u8; 32];
+        let vault = [8u8; 32];
+        let usdc_mint = [9u8; 32];
+        let config = sample_config([1u8; 32], usdc_mint);
+        let mut round_data = sample_round(81, ROUND_STATUS_OPEN, vault);
+        let mut participant = sample_participant(round, user, 1_000_000);
+        let vault_data = token_account(usdc_mint, round);
+        let user_ata = token_account(usdc_mint, user);
+
+        process_anchor_bytes(
+            user,
+            round,
+            vault,
+            &config,
+            &mut round_data,
+            &mut participant,
+            &vault_data,
+            &user_ata,
+            &cancel_ix(81),
+        )
+        .unwrap();
+        let round_after_first = round_data;
+
+        let err = process_anchor_bytes(
+            user,
+            round,
+            vault,
+            &config,
+            &mut round_data,
+            &mut participant,
+            &vault_data,
+            &user_ata,
+            &cancel_ix(81),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::NoDepositToRefund.into());
+        assert_eq!(round_data, round_after_first);
+    }
+
     #[test]
     fn rejects_cancel_when_other_deposits_exist() {
         let user = [7u8; 32];
@@ -25,8 +25,7 @@ pub fn process_anchor_bytes(
     vrf_payer_usdc_ata_data: Option<&[u8]>,
     ix_data: &[u8],
 ) -> Result<ClaimAmountsCompat, ProgramError> {
-    let _round_id =
-        parse_round_id_ix(ix_data, "claim").map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "claim")?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -74,7 +73,8 @@ pub fn process_anchor_bytes(
 
     let vrf_payer_key =
         RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data).map_err(map_layout_err)?;
-    let has_vrf_payer = vrf_payer_key != [0u8; 32]
+    let has_vrf_payer = config.vrf_reimbursement_enabled()
+        && vrf_payer_key != [0u8; 32]
         && RoundLifecycleView::read_vrf_reimbursed_from_account_data(round_account_data)
             .map_err(map_layout_err)?
             == 0;
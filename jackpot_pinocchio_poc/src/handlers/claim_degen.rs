@@ -1,17 +1,18 @@
 use pinocchio::error::ProgramError;
 
 use crate::{
+    degen_pool_compat::derive_degen_candidate_index_at_rank,
     errors::JackpotCompatError,
     instruction_layouts::ClaimDegenArgsCompat,
     legacy_layouts::{
-        ConfigView, DegenClaimView, RoundLifecycleView, TokenAccountCoreView,
+        ConfigView, DegenClaimView, RoundLifecycleView, RoundVrfMeta, TokenAccountCoreView,
         DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED, DEGEN_CLAIM_STATUS_VRF_READY,
         DEGEN_FALLBACK_REASON_NONE, DEGEN_MODE_CLAIMED, DEGEN_MODE_VRF_READY,
         PUBKEY_LEN, ROUND_STATUS_CLAIMED, ROUND_STATUS_SETTLED,
     },
 };
 
-use super::degen_common::{ClaimAmountsCompat, compute_claim_amounts, map_layout_err};
+use super::degen_common::{ClaimAmountsCompat, degen_claim_amounts, map_layout_err, vrf_reimbursement_due};
 
 /// Pure-logic handler for `claim_degen`.
 ///
@@ -36,8 +37,7 @@ pub fn process_anchor_bytes(
     vrf_payer_usdc_ata_data: Option<&[u8]>,
     ix_data: &[u8],
 ) -> Result<ClaimAmountsCompat, ProgramError> {
-    let args = ClaimDegenArgsCompat::parse(ix_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = ClaimDegenArgsCompat::parse(ix_data)?;
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
@@ -73,10 +73,23 @@ pub fn process_anchor_bytes(
         return Err(JackpotCompatError::InvalidDegenClaim.into());
     }
 
+    // --- Candidate derivation: token_index must be the one VRF picked for the rank ---
+    if args.candidate_rank >= degen_claim.candidate_window {
+        return Err(JackpotCompatError::InvalidDegenCandidate.into());
+    }
+    let expected_token_index = derive_degen_candidate_index_at_rank(
+        &degen_claim.randomness,
+        degen_claim.pool_version,
+        args.candidate_rank as usize,
+    );
+    if args.token_index != expected_token_index {
+        return Err(JackpotCompatError::DegenCandidateMismatch.into());
+    }
+
     // --- Vault validation ---
     let vault = TokenAccountCoreView::read_from_account_data(vault_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
-    if RoundLifecycleView::read_vault_pubkey_from_account_data(round_account_data)
+    if RoundLifecycleView::require_vault_set(round_account_data)
         .map_err(map_layout_err)?
         != vault_pubkey
         || vault.mint != config.usdc_mint
@@ -102,17 +115,12 @@ pub fn process_anchor_bytes(
     }
 
     // --- VRF payer reimbursement ---
-    let reimburse_vrf = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data)
-        .map_err(map_layout_err)?
-        != [0u8; 32]
-        && RoundLifecycleView::read_vrf_reimbursed_from_account_data(round_account_data)
-            .map_err(map_layout_err)?
-            == 0;
+    let vrf_meta = RoundLifecycleView::read_vrf_meta_from_account_data(round_account_data)
+        .map_err(map_layout_err)?;
+    let reimburse_vrf = vrf_reimbursement_due(&config, &vrf_meta);
 
     if reimburse_vrf {
-        let vrf_payer_key =
-            RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data)
-                .map_err(map_layout_err)?;
+        let vrf_payer_key = vrf_meta.payer;
         if vrf_payer_authority_pubkey != Some(vrf_payer_key) {
             return Err(JackpotCompatError::InvalidVrfPayerAta.into());
         }
@@ -128,7 +136,7 @@ pub fn process_anchor_bytes(
     }
 
     // --- Compute amounts ---
-    let amounts = compute_claim_amounts(round.total_usdc, config.fee_bps, reimburse_vrf)?;
+    let amounts = degen_claim_amounts(&degen_claim, round.total_usdc, config.fee_bps, reimburse_vrf)?;
 
     // --- State updates ---
     RoundLifecycleView::write_status_to_account_data(round_account_data, ROUND_STATUS_CLAIMED)
@@ -139,8 +147,11 @@ pub fn process_anchor_bytes(
     )
     .map_err(map_layout_err)?;
     if amounts.vrf_reimburse > 0 {
-        RoundLifecycleView::write_vrf_reimbursed_to_account_data(round_account_data, 1)
-            .map_err(map_layout_err)?;
+        RoundLifecycleView::write_vrf_meta_to_account_data(
+            round_account_data,
+            &RoundVrfMeta { reimbursed: true, ..vrf_meta },
+        )
+        .map_err(map_layout_err)?;
     }
 
     degen_claim.status = DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED;
@@ -169,7 +180,7 @@ mod tests {
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
             ConfigView, DegenClaimView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
-            DEGEN_CLAIM_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
+            DEGEN_CANDIDATE_WINDOW, DEGEN_CLAIM_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
             ROUND_STATUS_SETTLED,
         },
     };
@@ -264,6 +275,11 @@ mod tests {
         data
     }
 
+    /// Token index the sample claim's randomness selects at `rank`.
+    fn derived_token_index(rank: u8) -> u32 {
+        derive_degen_candidate_index_at_rank(&[7u8; 32], 1, rank as usize)
+    }
+
     fn build_claim_degen_ix(round_id: u64, candidate_rank: u8, token_index: u32) -> Vec<u8> {
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("claim_degen"));
@@ -282,7 +298,7 @@ mod tests {
         let winner_usdc_ata = token_account([2u8; 32], [9u8; 32]);
         let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
 
-        let token_index = 42u32;
+        let token_index = derived_token_index(0);
         let ix = build_claim_degen_ix(81, 0, token_index);
 
         let amounts = process_anchor_bytes(
@@ -330,7 +346,7 @@ mod tests {
         let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
         let vrf_payer_usdc_ata = token_account([2u8; 32], [10u8; 32]);
 
-        let token_index = 42u32;
+        let token_index = derived_token_index(0);
         let ix = build_claim_degen_ix(81, 0, token_index);
 
         let amounts = process_anchor_bytes(
@@ -365,7 +381,7 @@ mod tests {
         let winner_usdc_ata = token_account([2u8; 32], [11u8; 32]);
         let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
 
-        let token_index = 42u32;
+        let token_index = derived_token_index(0);
         let ix = build_claim_degen_ix(81, 0, token_index);
 
         let err = process_anchor_bytes(
@@ -388,4 +404,69 @@ mod tests {
 
         assert_eq!(err, JackpotCompatError::OnlyWinnerCanClaim.into());
     }
+
+    fn run_claim_degen(candidate_rank: u8, token_index: u32) -> Result<ClaimAmountsCompat, ProgramError> {
+        run_claim_degen_in_window(DEGEN_CANDIDATE_WINDOW, candidate_rank, token_index)
+    }
+
+    /// Like `run_claim_degen`, with the claim recording `candidate_window`.
+    fn run_claim_degen_in_window(
+        candidate_window: u8,
+        candidate_rank: u8,
+        token_index: u32,
+    ) -> Result<ClaimAmountsCompat, ProgramError> {
+        let config = sample_config();
+        let mut round = sample_round(false);
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { candidate_window, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+        let vault_data = token_account([2u8; 32], [8u8; 32]);
+        let winner_usdc_ata = token_account([2u8; 32], [9u8; 32]);
+        let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
+        let ix = build_claim_degen_ix(81, candidate_rank, token_index);
+
+        process_anchor_bytes(
+            [9u8; 32],
+            [8u8; 32],
+            [8u8; 32],
+            1_001,
+            &config,
+            &mut round,
+            &mut degen_claim,
+            &vault_data,
+            &winner_usdc_ata,
+            [3u8; 32],
+            &treasury_usdc_ata,
+            None,
+            None,
+            &ix,
+        )
+    }
+
+    #[test]
+    fn claim_degen_accepts_derived_index_for_later_rank() {
+        let rank = DEGEN_CANDIDATE_WINDOW - 1;
+        run_claim_degen(rank, derived_token_index(rank)).unwrap();
+    }
+
+    #[test]
+    fn claim_degen_rejects_index_not_derived_for_rank() {
+        // Index valid for rank 1 but submitted as rank 0.
+        let err = run_claim_degen(0, derived_token_index(1)).unwrap_err();
+        assert_eq!(err, JackpotCompatError::DegenCandidateMismatch.into());
+    }
+
+    #[test]
+    fn claim_degen_rejects_rank_outside_candidate_window() {
+        let err = run_claim_degen(DEGEN_CANDIDATE_WINDOW, 0).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenCandidate.into());
+    }
+
+    #[test]
+    fn claim_degen_bounds_rank_by_window_recorded_on_claim() {
+        run_claim_degen_in_window(12, 11, derived_token_index(11)).unwrap();
+        let err = run_claim_degen_in_window(12, 12, derived_token_index(12)).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenCandidate.into());
+    }
 }
@@ -4,14 +4,14 @@ use crate::{
     errors::JackpotCompatError,
     instruction_layouts::parse_round_id_u8_ix,
     legacy_layouts::{
-        ConfigView, DegenClaimView, RoundLifecycleView, TokenAccountCoreView,
+        ConfigView, DegenClaimView, RoundLifecycleView, RoundVrfMeta, TokenAccountCoreView,
         DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK, DEGEN_CLAIM_STATUS_VRF_READY,
         DEGEN_MODE_CLAIMED, DEGEN_MODE_VRF_READY, ROUND_STATUS_CLAIMED, ROUND_STATUS_SETTLED,
         PUBKEY_LEN,
     },
 };
 
-use super::degen_common::{ClaimAmountsCompat, compute_claim_amounts, map_layout_err};
+use super::degen_common::{ClaimAmountsCompat, degen_claim_amounts, map_layout_err, vrf_reimbursement_due};
 
 #[allow(clippy::too_many_arguments)]
 pub fn process_anchor_bytes(
@@ -59,9 +59,7 @@ pub fn process_anchor_bytes_named(
     ix_data: &[u8],
     ix_name: &str,
 ) -> Result<ClaimAmountsCompat, ProgramError> {
-    let (round_id, fallback_reason) =
-        parse_round_id_u8_ix(ix_data, ix_name)
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let (round_id, fallback_reason) = parse_round_id_u8_ix(ix_data, ix_name)?;
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
@@ -72,9 +70,7 @@ pub fn process_anchor_bytes_named(
     if degen_claim.status != DEGEN_CLAIM_STATUS_VRF_READY {
         return Err(JackpotCompatError::InvalidDegenExecutionState.into());
     }
-    if now_ts < degen_claim.fallback_after_ts {
-        return Err(JackpotCompatError::DegenFallbackTooEarly.into());
-    }
+    degen_claim.require_fallback_ready(now_ts).map_err(map_layout_err)?;
     if round.status != ROUND_STATUS_SETTLED {
         return Err(JackpotCompatError::RoundNotSettled.into());
     }
@@ -99,7 +95,7 @@ pub fn process_anchor_bytes_named(
 
     let vault = TokenAccountCoreView::read_from_account_data(vault_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
-    if RoundLifecycleView::read_vault_pubkey_from_account_data(round_account_data)
+    if RoundLifecycleView::require_vault_set(round_account_data)
         .map_err(map_layout_err)?
         != vault_pubkey
         || vault.mint != config.usdc_mint
@@ -122,16 +118,12 @@ pub fn process_anchor_bytes_named(
         return Err(JackpotCompatError::InvalidTreasury.into());
     }
 
-    let reimburse_vrf = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data)
-        .map_err(map_layout_err)?
-        != [0u8; 32]
-        && RoundLifecycleView::read_vrf_reimbursed_from_account_data(round_account_data)
-            .map_err(map_layout_err)?
-            == 0;
+    let vrf_meta = RoundLifecycleView::read_vrf_meta_from_account_data(round_account_data)
+        .map_err(map_layout_err)?;
+    let reimburse_vrf = vrf_reimbursement_due(&config, &vrf_meta);
 
     if reimburse_vrf {
-        let vrf_payer_key = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data)
-            .map_err(map_layout_err)?;
+        let vrf_payer_key = vrf_meta.payer;
         if vrf_payer_authority_pubkey != Some(vrf_payer_key) {
             return Err(JackpotCompatError::InvalidVrfPayerAta.into());
         }
@@ -146,15 +138,18 @@ pub fn process_anchor_bytes_named(
         }
     }
 
-    let amounts = compute_claim_amounts(round.total_usdc, config.fee_bps, reimburse_vrf)?;
+    let amounts = degen_claim_amounts(&degen_claim, round.total_usdc, config.fee_bps, reimburse_vrf)?;
 
     RoundLifecycleView::write_status_to_account_data(round_account_data, ROUND_STATUS_CLAIMED)
         .map_err(map_layout_err)?;
     RoundLifecycleView::write_degen_mode_status_to_account_data(round_account_data, DEGEN_MODE_CLAIMED)
         .map_err(map_layout_err)?;
     if amounts.vrf_reimburse > 0 {
-        RoundLifecycleView::write_vrf_reimbursed_to_account_data(round_account_data, 1)
-            .map_err(map_layout_err)?;
+        RoundLifecycleView::write_vrf_meta_to_account_data(
+            round_account_data,
+            &RoundVrfMeta { reimbursed: true, ..vrf_meta },
+        )
+        .map_err(map_layout_err)?;
     }
 
     degen_claim.status = DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK;
@@ -315,6 +310,7 @@ mod tests {
             RoundLifecycleView::read_degen_mode_status_from_account_data(&round).unwrap(),
             DEGEN_MODE_CLAIMED
         );
+        RoundLifecycleView::verify_degen_mode_consistency(&round).unwrap();
         let degen_claim = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
         assert_eq!(degen_claim.status, DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK);
         assert_eq!(degen_claim.fallback_reason, 4);
@@ -354,4 +350,142 @@ mod tests {
 
         assert_eq!(err, JackpotCompatError::DegenFallbackTooEarly.into());
     }
+    #[test]
+    fn claim_degen_fallback_skips_vrf_reimbursement_when_disabled() {
+        let run = |config: &[u8]| {
+            let mut round = sample_round(true);
+            let mut degen_claim = sample_degen_claim();
+            let vault_data = token_account([2u8; 32], [8u8; 32]);
+            let winner_usdc_ata = token_account([2u8; 32], [9u8; 32]);
+            let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
+            let vrf_payer_usdc_ata = token_account([2u8; 32], [10u8; 32]);
+            let mut ix = Vec::new();
+            ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+            ix.extend_from_slice(&81u64.to_le_bytes());
+            ix.push(4);
+
+            let amounts = process_anchor_bytes(
+                [9u8; 32],
+                [8u8; 32],
+                [8u8; 32],
+                1_001,
+                config,
+                &mut round,
+                &mut degen_claim,
+                &vault_data,
+                &winner_usdc_ata,
+                [3u8; 32],
+                &treasury_usdc_ata,
+                Some([10u8; 32]),
+                Some(&vrf_payer_usdc_ata),
+                &ix,
+            )
+            .unwrap();
+            (amounts, RoundLifecycleView::read_vrf_reimbursed_from_account_data(&round).unwrap())
+        };
+
+        let enabled = sample_config();
+        let (amounts, reimbursed) = run(&enabled);
+        assert_eq!(amounts.vrf_reimburse, 200_000);
+        assert_eq!(amounts.payout, 798_000);
+        assert_eq!(reimbursed, 1);
+
+        let mut disabled = sample_config();
+        let mut view = ConfigView::read_from_account_data(&disabled).unwrap();
+        view.set_vrf_reimbursement_enabled(false);
+        view.write_to_account_data(&mut disabled).unwrap();
+        let (amounts, reimbursed) = run(&disabled);
+        assert_eq!(amounts.vrf_reimburse, 0);
+        assert_eq!(amounts.payout, 997_500);
+        assert_eq!(reimbursed, 0);
+    }
+
+    #[test]
+    fn claim_degen_fallback_reimburses_vrf_payer_only_once() {
+        let config = sample_config();
+        let mut round = sample_round(true);
+        let vault_data = token_account([2u8; 32], [8u8; 32]);
+        let winner_usdc_ata = token_account([2u8; 32], [9u8; 32]);
+        let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
+        let vrf_payer_usdc_ata = token_account([2u8; 32], [10u8; 32]);
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(4);
+
+        let run = |round: &mut [u8]| {
+            let mut degen_claim = sample_degen_claim();
+            process_anchor_bytes(
+                [9u8; 32],
+                [8u8; 32],
+                [8u8; 32],
+                1_001,
+                &config,
+                round,
+                &mut degen_claim,
+                &vault_data,
+                &winner_usdc_ata,
+                [3u8; 32],
+                &treasury_usdc_ata,
+                Some([10u8; 32]),
+                Some(&vrf_payer_usdc_ata),
+                &ix,
+            )
+        };
+
+        let first = run(&mut round).unwrap();
+        assert_eq!(first.vrf_reimburse, 200_000);
+        assert!(RoundLifecycleView::read_vrf_meta_from_account_data(&round).unwrap().reimbursed);
+
+        // A claimed round cannot fall back again at all.
+        assert_eq!(run(&mut round).unwrap_err(), JackpotCompatError::RoundNotSettled.into());
+
+        // Even if the round is walked back to VRF_READY (e.g. an execution
+        // reverted after begin already reimbursed), the flag stops a repeat.
+        RoundLifecycleView::write_status_to_account_data(&mut round, ROUND_STATUS_SETTLED).unwrap();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round, DEGEN_MODE_VRF_READY).unwrap();
+        let second = run(&mut round).unwrap();
+        assert_eq!(second.vrf_reimburse, 0);
+        assert_eq!(second.payout, 997_500);
+        assert_eq!(first.vrf_reimburse + second.vrf_reimburse, 200_000);
+    }
+
+    #[test]
+    fn claim_degen_fallback_after_recovery_pays_only_recorded_payout() {
+        let config = sample_config();
+        let mut round = sample_round(false);
+        let mut degen_claim = sample_degen_claim();
+        let mut view = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        view.payout_raw = 997_500;
+        view.set_fees_paid(true);
+        view.write_to_account_data(&mut degen_claim).unwrap();
+        let vault_data = token_account([2u8; 32], [8u8; 32]);
+        let winner_usdc_ata = token_account([2u8; 32], [9u8; 32]);
+        let treasury_usdc_ata = token_account([2u8; 32], [7u8; 32]);
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(4);
+
+        let amounts = process_anchor_bytes(
+            [9u8; 32],
+            [8u8; 32],
+            [8u8; 32],
+            1_001,
+            &config,
+            &mut round,
+            &mut degen_claim,
+            &vault_data,
+            &winner_usdc_ata,
+            [3u8; 32],
+            &treasury_usdc_ata,
+            None,
+            None,
+            &ix,
+        )
+        .unwrap();
+
+        // begin_degen_execution already sent the fee to the treasury.
+        assert_eq!(amounts, ClaimAmountsCompat { payout: 997_500, fee: 0, vrf_reimburse: 0 });
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     instruction_layouts::parse_round_id_ix,
     legacy_layouts::{
         ConfigView, ParticipantView, RoundLifecycleView, TokenAccountCoreView, ROUND_STATUS_CANCELLED,
-        PUBKEY_LEN,
+        PUBKEY_LEN, require_participant_belongs_to_round,
     },
 };
 
@@ -20,8 +20,7 @@ pub fn process_anchor_bytes(
     user_usdc_ata_data: &[u8],
     ix_data: &[u8],
 ) -> Result<u64, ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "claim_refund")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "claim_refund")?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -37,9 +36,7 @@ pub fn process_anchor_bytes(
     if participant.user != user_pubkey {
         return Err(JackpotCompatError::Unauthorized.into());
     }
-    if participant.round != round_pubkey {
-        return Err(JackpotCompatError::ParticipantRoundMismatch.into());
-    }
+    require_participant_belongs_to_round(&participant, &round_pubkey).map_err(map_layout_err)?;
 
     let refund_amount = participant.usdc_total;
     if refund_amount == 0 {
@@ -71,6 +68,9 @@ pub fn process_anchor_bytes(
 fn map_layout_err(err: crate::legacy_layouts::LayoutError) -> ProgramError {
     match err {
         crate::legacy_layouts::LayoutError::MathOverflow => JackpotCompatError::MathOverflow.into(),
+        crate::legacy_layouts::LayoutError::ParticipantRoundMismatch => {
+            JackpotCompatError::ParticipantRoundMismatch.into()
+        }
         _ => ProgramError::InvalidAccountData,
     }
 }
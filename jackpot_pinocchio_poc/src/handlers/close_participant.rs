@@ -2,10 +2,11 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     errors::JackpotCompatError,
+    handlers::degen_common::map_layout_err,
     instruction_layouts::parse_round_id_ix,
     legacy_layouts::{
         ParticipantView, RoundLifecycleView, ROUND_STATUS_CANCELLED, ROUND_STATUS_CLAIMED,
-        PUBKEY_LEN,
+        PUBKEY_LEN, require_participant_belongs_to_round,
     },
 };
 
@@ -16,8 +17,7 @@ pub fn process_anchor_bytes(
     participant_account_data: &[u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "close_participant")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "close_participant")?;
 
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -30,9 +30,7 @@ pub fn process_anchor_bytes(
         return Err(JackpotCompatError::RoundNotCloseable.into());
     }
 
-    if participant.round != round_pubkey {
-        return Err(JackpotCompatError::ParticipantRoundMismatch.into());
-    }
+    require_participant_belongs_to_round(&participant, &round_pubkey).map_err(map_layout_err)?;
 
     if participant.user != user_pubkey {
         return Err(JackpotCompatError::Unauthorized.into());
@@ -122,6 +120,44 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn rejects_participant_of_another_round() {
+        let round_id = 81u64;
+        let user_pubkey = [5u8; 32];
+        let round_data = sample_round(round_id, ROUND_STATUS_CLAIMED);
+        let participant_data = sample_participant([6u8; 32], user_pubkey, 100, 1_000_000);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("close_participant"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+
+        let err = process_anchor_bytes(user_pubkey, [4u8; 32], &round_data, &participant_data, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::ParticipantRoundMismatch.into());
+    }
+
+    #[test]
+    fn allows_close_for_refunded_participant_in_cancelled_round() {
+        let round_id = 81u64;
+        let round_pubkey = [4u8; 32];
+        let user_pubkey = [5u8; 32];
+        let round_data = sample_round(round_id, ROUND_STATUS_CANCELLED);
+        // claim_refund zeroes both totals.
+        let participant_data = sample_participant(round_pubkey, user_pubkey, 0, 0);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("close_participant"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+
+        process_anchor_bytes(
+            user_pubkey,
+            round_pubkey,
+            &round_data,
+            &participant_data,
+            &ix,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn rejects_non_empty_cancelled_participant() {
         let round_id = 81u64;
@@ -15,8 +15,7 @@ pub fn process_anchor_bytes(
     vault_account_data: &[u8],
     ix_data: &[u8],
 ) -> Result<u64, ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "close_round")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "close_round")?;
 
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -1,6 +1,9 @@
 use pinocchio::error::ProgramError;
 
-use crate::{errors::JackpotCompatError, legacy_layouts::LayoutError};
+use crate::{
+    errors::JackpotCompatError,
+    legacy_layouts::{ConfigView, DegenClaimView, LayoutError, RoundLifecycleView, RoundVrfMeta},
+};
 
 const BPS_DENOMINATOR: u64 = 10_000;
 const VRF_REIMBURSEMENT_USDC: u64 = 200_000;
@@ -12,6 +15,34 @@ pub struct ClaimAmountsCompat {
     pub vrf_reimburse: u64,
 }
 
+/// Where a degen claim's fee goes once `set_fee_split` has named a second
+/// recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSplitCompat {
+    pub treasury: u64,
+    pub second_recipient: u64,
+}
+
+/// Gives the second recipient `fee * fee_split_bps / 10_000`, rounded down,
+/// and leaves the remainder with the treasury so no lamport of fee is lost.
+pub fn split_fee(fee: u64, fee_split_bps: u16) -> Result<FeeSplitCompat, JackpotCompatError> {
+    if u64::from(fee_split_bps) > BPS_DENOMINATOR {
+        return Err(JackpotCompatError::InvalidFeeBps);
+    }
+    let second_recipient = (u128::from(fee) * u128::from(fee_split_bps) / u128::from(BPS_DENOMINATOR)) as u64;
+    Ok(FeeSplitCompat {
+        treasury: fee - second_recipient,
+        second_recipient,
+    })
+}
+
+/// Pot left for fee and payout once the VRF payer has been reimbursed.
+pub fn pot_after_reimbursement(total_usdc: u64, vrf_reimburse: u64) -> Result<u64, JackpotCompatError> {
+    total_usdc
+        .checked_sub(vrf_reimburse)
+        .ok_or(JackpotCompatError::MathOverflow)
+}
+
 pub fn compute_claim_amounts(
     total_usdc: u64,
     fee_bps: u16,
@@ -23,9 +54,7 @@ pub fn compute_claim_amounts(
     } else {
         0
     };
-    let pot_after_reimburse = total_usdc
-        .checked_sub(vrf_reimburse)
-        .ok_or_else(overflow)?;
+    let pot_after_reimburse = pot_after_reimbursement(total_usdc, vrf_reimburse)?;
     let fee = ((pot_after_reimburse as u128)
         .checked_mul(fee_bps as u128)
         .ok_or_else(overflow)?)
@@ -39,9 +68,91 @@ pub fn compute_claim_amounts(
     })
 }
 
+/// [`compute_claim_amounts`] for a degen claim. Once `begin_degen_execution`
+/// has paid the fee and VRF reimbursement, a claim recovered back to
+/// `VRF_READY` owes only the payout it recorded, whichever path settles it.
+pub fn degen_claim_amounts(
+    degen_claim: &DegenClaimView,
+    total_usdc: u64,
+    fee_bps: u16,
+    reimburse_vrf: bool,
+) -> Result<ClaimAmountsCompat, ProgramError> {
+    if degen_claim.fees_paid() {
+        return Ok(ClaimAmountsCompat {
+            payout: degen_claim.payout_raw,
+            fee: 0,
+            vrf_reimburse: 0,
+        });
+    }
+    compute_claim_amounts(total_usdc, fee_bps, reimburse_vrf)
+}
+
+/// A round owes the VRF payer a reimbursement when reimbursement is enabled
+/// for the deployment, a payer was recorded, and it has not been paid yet.
+pub fn should_reimburse_vrf(config: &ConfigView, round_account_data: &[u8]) -> Result<bool, ProgramError> {
+    let vrf_meta = RoundLifecycleView::read_vrf_meta_from_account_data(round_account_data).map_err(map_layout_err)?;
+    Ok(vrf_reimbursement_due(config, &vrf_meta))
+}
+
+/// Same decision as [`should_reimburse_vrf`] for callers that already hold
+/// the round's [`RoundVrfMeta`] and need the payer afterwards.
+pub fn vrf_reimbursement_due(config: &ConfigView, vrf_meta: &RoundVrfMeta) -> bool {
+    config.vrf_reimbursement_enabled() && vrf_meta.payer != [0u8; 32] && !vrf_meta.reimbursed
+}
+
 pub fn map_layout_err(err: LayoutError) -> ProgramError {
     match err {
         LayoutError::MathOverflow => JackpotCompatError::MathOverflow.into(),
+        LayoutError::ProgramPaused => JackpotCompatError::Paused.into(),
+        LayoutError::FallbackNotReady => JackpotCompatError::DegenFallbackTooEarly.into(),
+        LayoutError::RoundFull => JackpotCompatError::MaxParticipantsReached.into(),
+        LayoutError::InvalidFeeBps => JackpotCompatError::InvalidFeeBps.into(),
+        LayoutError::InvalidTicketUnit => JackpotCompatError::InvalidTicketUnit.into(),
+        LayoutError::InvalidRoundDuration => JackpotCompatError::InvalidRoundDuration.into(),
+        LayoutError::InvalidFallbackTimeout => JackpotCompatError::InvalidFallbackTimeout.into(),
+        LayoutError::RoundIdOutOfSequence => ProgramError::InvalidInstructionData,
+        LayoutError::ParticipantRoundMismatch => JackpotCompatError::ParticipantRoundMismatch.into(),
+        LayoutError::VaultNotSet => JackpotCompatError::InvalidVault.into(),
         _ => ProgramError::InvalidAccountData,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pot_after_reimbursement_subtracts_vrf_reimbursement() {
+        // Winner-is-vrf-payer fallback: 1_000_000 pot, 200_000 reimbursed.
+        assert_eq!(pot_after_reimbursement(1_000_000, VRF_REIMBURSEMENT_USDC), Ok(800_000));
+        assert_eq!(pot_after_reimbursement(1_000_000, 0), Ok(1_000_000));
+    }
+
+    #[test]
+    fn pot_after_reimbursement_rejects_underflow() {
+        assert_eq!(pot_after_reimbursement(100, 101), Err(JackpotCompatError::MathOverflow));
+    }
+
+    #[test]
+    fn compute_claim_amounts_takes_fee_from_reimbursed_pot() {
+        let amounts = compute_claim_amounts(1_000_000, 25, true).unwrap();
+        assert_eq!(amounts.vrf_reimburse, 200_000);
+        assert_eq!(amounts.fee, 2_000);
+        assert_eq!(amounts.payout, 798_000);
+    }
+
+    #[test]
+    fn split_fee_rounds_second_recipient_down() {
+        assert_eq!(split_fee(2_500, 0), Ok(FeeSplitCompat { treasury: 2_500, second_recipient: 0 }));
+        assert_eq!(split_fee(1_999, 3_333), Ok(FeeSplitCompat { treasury: 1_333, second_recipient: 666 }));
+        assert_eq!(
+            split_fee(u64::MAX, 10_000),
+            Ok(FeeSplitCompat { treasury: 0, second_recipient: u64::MAX })
+        );
+    }
+
+    #[test]
+    fn split_fee_rejects_share_over_denominator() {
+        assert_eq!(split_fee(2_500, 10_001), Err(JackpotCompatError::InvalidFeeBps));
+    }
+}
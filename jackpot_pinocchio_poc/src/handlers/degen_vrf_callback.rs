@@ -5,13 +5,13 @@ use crate::{
     instruction_layouts::parse_degen_vrf_callback_ix,
     legacy_layouts::{
         ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView,
-        DEGEN_CANDIDATE_WINDOW, DEGEN_CLAIM_STATUS_VRF_READY, DEGEN_CLAIM_STATUS_VRF_REQUESTED,
+        DEGEN_CLAIM_STATUS_VRF_READY, DEGEN_CLAIM_STATUS_VRF_REQUESTED,
         DEGEN_FALLBACK_REASON_NONE, DEGEN_MODE_VRF_READY, DEGEN_MODE_VRF_REQUESTED,
         DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC, ROUND_STATUS_SETTLED, PUBKEY_LEN,
     },
 };
 
-use super::degen_common::{compute_claim_amounts, map_layout_err};
+use super::degen_common::{compute_claim_amounts, map_layout_err, should_reimburse_vrf};
 
 const DEGEN_POOL_VERSION: u32 = 1;
 
@@ -24,8 +24,7 @@ pub fn process_anchor_bytes(
     degen_config_account_data: Option<&[u8]>,
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let randomness =
-        parse_degen_vrf_callback_ix(ix_data).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let randomness = parse_degen_vrf_callback_ix(ix_data)?;
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
@@ -54,6 +53,11 @@ pub fn process_anchor_bytes(
     if degen_claim.status != DEGEN_CLAIM_STATUS_VRF_REQUESTED {
         return Err(JackpotCompatError::DegenVrfNotRequested.into());
     }
+    // A fulfillment can't predate its request; a clock that says otherwise
+    // would also put `fallback_after_ts` in the past.
+    if now_ts < degen_claim.requested_at {
+        return Err(JackpotCompatError::InvalidDegenClaim.into());
+    }
 
     let fallback_timeout_sec = match degen_config_account_data {
         Some(data) if !data.is_empty() => {
@@ -69,12 +73,7 @@ pub fn process_anchor_bytes(
     };
     let fallback_after_ts = checked_add_i64(now_ts, fallback_timeout_sec as i64)?;
 
-    let reimburse_vrf = RoundLifecycleView::read_vrf_payer_from_account_data(round_account_data)
-        .map_err(map_layout_err)?
-        != [0u8; 32]
-        && RoundLifecycleView::read_vrf_reimbursed_from_account_data(round_account_data)
-            .map_err(map_layout_err)?
-            == 0;
+    let reimburse_vrf = should_reimburse_vrf(&config, round_account_data)?;
     let payout_raw = compute_claim_amounts(round.total_usdc, config.fee_bps, reimburse_vrf)?.payout;
 
     degen_claim.status = DEGEN_CLAIM_STATUS_VRF_READY;
@@ -83,7 +82,6 @@ pub fn process_anchor_bytes(
     degen_claim.fallback_reason = DEGEN_FALLBACK_REASON_NONE;
     degen_claim.token_index = 0;
     degen_claim.pool_version = DEGEN_POOL_VERSION;
-    degen_claim.candidate_window = DEGEN_CANDIDATE_WINDOW;
     degen_claim.padding0 = [0u8; 7];
     degen_claim.token_mint = [0u8; 32];
     degen_claim.fulfilled_at = now_ts;
@@ -115,8 +113,8 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            ConfigView, DegenClaimView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
-            DEGEN_CLAIM_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED,
+            ConfigView, DegenClaimView, RoundLifecycleView, CONFIG_ACCOUNT_LEN, DEGEN_CANDIDATE_WINDOW,
+            DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CLAIM_STATUS_EXECUTING, ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED,
         },
     };
 
@@ -231,4 +229,84 @@ mod tests {
             DEGEN_MODE_VRF_READY
         );
     }
+
+    fn run_callback(round: &mut [u8], degen_claim: &mut [u8], randomness: [u8; 32]) -> Result<(), ProgramError> {
+        run_callback_at(1_000, round, degen_claim, randomness)
+    }
+
+    fn run_callback_at(
+        now_ts: i64,
+        round: &mut [u8],
+        degen_claim: &mut [u8],
+        randomness: [u8; 32],
+    ) -> Result<(), ProgramError> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("degen_vrf_callback"));
+        ix.extend_from_slice(&randomness);
+        process_anchor_bytes([8u8; 32], now_ts, &sample_config(), round, degen_claim, None, &ix)
+    }
+
+    #[test]
+    fn callback_keeps_candidate_window_recorded_at_request() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        DegenClaimView { candidate_window: 12, ..DegenClaimView::read_from_account_data(&degen_claim).unwrap() }
+            .write_to_account_data(&mut degen_claim)
+            .unwrap();
+
+        run_callback(&mut round, &mut degen_claim, [7u8; 32]).unwrap();
+
+        assert_eq!(DegenClaimView::read_from_account_data(&degen_claim).unwrap().candidate_window, 12);
+    }
+
+    #[test]
+    fn rejects_callback_on_executing_claim() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        let mut claim = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        claim.status = DEGEN_CLAIM_STATUS_EXECUTING;
+        claim.write_to_account_data(&mut degen_claim).unwrap();
+        let before = degen_claim;
+
+        assert_eq!(
+            run_callback(&mut round, &mut degen_claim, [7u8; 32]),
+            Err(JackpotCompatError::DegenVrfNotRequested.into())
+        );
+        assert_eq!(degen_claim, before);
+    }
+
+    #[test]
+    fn rejects_replayed_callback() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        run_callback(&mut round, &mut degen_claim, [7u8; 32]).unwrap();
+
+        // Even with the round still flagged as requested, a ready claim keeps
+        // its first randomness.
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round, DEGEN_MODE_VRF_REQUESTED).unwrap();
+        assert_eq!(
+            run_callback(&mut round, &mut degen_claim, [8u8; 32]),
+            Err(JackpotCompatError::DegenVrfNotRequested.into())
+        );
+        assert_eq!(DegenClaimView::read_from_account_data(&degen_claim).unwrap().randomness, [7u8; 32]);
+    }
+
+    #[test]
+    fn rejects_fulfillment_before_request() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        let before = degen_claim;
+
+        assert_eq!(
+            run_callback_at(776, &mut round, &mut degen_claim, [7u8; 32]),
+            Err(JackpotCompatError::InvalidDegenClaim.into())
+        );
+        assert_eq!(degen_claim, before);
+
+        run_callback_at(777, &mut round, &mut degen_claim, [7u8; 32]).unwrap();
+        let parsed = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        assert_eq!(parsed.requested_at, 777);
+        assert_eq!(parsed.fulfilled_at, 777);
+        assert_eq!(parsed.fallback_after_ts, 777 + DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC as i64);
+    }
 }
@@ -1,7 +1,7 @@
 use pinocchio::error::ProgramError;
 
 use crate::{
-    anchor_compat::account_discriminator,
+    anchor_compat::{AccountInitState, PARTICIPANT_DISCRIMINATOR, account_discriminator, account_init_state},
     errors::JackpotCompatError,
     handlers::degen_common::map_layout_err,
     instruction_layouts::DepositAnyArgsCompat,
@@ -24,15 +24,13 @@ pub fn process_anchor_bytes(
     vault_account_data: &[u8],
     ix_data: &[u8],
 ) -> Result<u64, ProgramError> {
-    let args = DepositAnyArgsCompat::parse(ix_data).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = DepositAnyArgsCompat::parse(ix_data)?;
     let config = ConfigView::read_from_account_data(config_account_data).map_err(map_layout_err)?;
+    config.require_not_paused().map_err(map_layout_err)?;
     let mut round = RoundLifecycleView::read_from_account_data(round_account_data).map_err(map_layout_err)?;
     let user_usdc_ata = TokenAccountWithAmountView::read_from_account_data(user_usdc_ata_data).map_err(map_layout_err)?;
     let vault_ata = TokenAccountWithAmountView::read_from_account_data(vault_account_data).map_err(map_layout_err)?;
 
-    if config.paused {
-        return Err(JackpotCompatError::Paused.into());
-    }
     if round.round_id != args.round_id {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -77,13 +75,11 @@ pub fn process_anchor_bytes(
     )?;
 
     if participant.round != round_pubkey {
+        round.require_room_for_participant().map_err(map_layout_err)?;
         let next = round
             .participants_count
             .checked_add(1)
             .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-        if next as usize > crate::legacy_layouts::MAX_PARTICIPANTS {
-            return Err(JackpotCompatError::MaxParticipantsReached.into());
-        }
 
         participant.round = round_pubkey;
         participant.user = user_pubkey;
@@ -107,7 +103,7 @@ pub fn process_anchor_bytes(
     }
     if round.end_ts == 0 && round.participants_count >= config.min_participants {
         round.end_ts = current_unix_timestamp
-            .checked_add(config.round_duration_sec as i64)
+            .checked_add(config.round_duration_i64())
             .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
     }
 
@@ -129,10 +125,6 @@ pub fn process_anchor_bytes(
         .checked_add(1)
         .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
 
-    round.total_tickets = round
-        .total_tickets
-        .checked_add(tickets_added)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
     round.total_usdc = round
         .total_usdc
         .checked_add(delta)
@@ -140,7 +132,7 @@ pub fn process_anchor_bytes(
 
     round.write_to_account_data(round_account_data).map_err(map_layout_err)?;
     ParticipantView::write_to_account_data(&participant, participant_account_data).map_err(map_layout_err)?;
-    RoundLifecycleView::bit_add_in_account_data(round_account_data, participant.index as usize, tickets_added)
+    RoundLifecycleView::increment_participant_tickets(round_account_data, participant.index as usize, tickets_added)
         .map_err(map_layout_err)?;
 
     Ok(delta)
@@ -155,8 +147,9 @@ fn read_or_init_participant(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let is_zeroed = participant_account_data.iter().all(|byte| *byte == 0);
-    if is_zeroed {
+    let state = account_init_state(participant_account_data, &PARTICIPANT_DISCRIMINATOR)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    if state == AccountInitState::Uninitialized {
         participant_account_data[..8].copy_from_slice(&account_discriminator("Participant"));
         let participant = ParticipantView {
             round: [0u8; 32],
@@ -174,10 +167,6 @@ fn read_or_init_participant(
         return Ok(participant);
     }
 
-    if participant_account_data.get(..8) != Some(&account_discriminator("Participant")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
     ParticipantView::read_from_account_data(participant_account_data).map_err(map_layout_err)
 }
 
@@ -186,10 +175,11 @@ mod tests {
     use super::*;
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
+        instruction_layouts::{RoundId, encode_deposit_any},
         legacy_layouts::{
             ConfigView, RoundLifecycleView, TokenAccountWithAmountView, CONFIG_ACCOUNT_LEN,
             PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_OPEN,
-            TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+            TOKEN_ACCOUNT_WITH_AMOUNT_LEN, MAX_PARTICIPANTS,
         },
     };
 
@@ -290,12 +280,53 @@ mod tests {
         assert_eq!(round_view.end_ts, 1_120);
         assert_eq!(round_view.total_tickets, 2);
         assert_eq!(round_view.total_usdc, 20_000);
+        RoundLifecycleView::verify_fenwick_consistency(&round_data).unwrap();
         assert_eq!(
             RoundLifecycleView::read_participant_pubkey_from_account_data(&round_data, 1).unwrap(),
             user
         );
     }
 
+    #[test]
+    fn first_deposit_anchors_round_timer() {
+        let user = [4u8; 32];
+        let round = [8u8; 32];
+        let vault = [9u8; 32];
+        let config = sample_config();
+        let mut round_data = sample_round(81, vault);
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        let vault_ata = token_account(0, round);
+
+        for (now_ts, user_amount) in [(1_000, 20_000), (1_050, 40_000)] {
+            let ix = encode_deposit_any(&DepositAnyArgsCompat {
+                round_id: RoundId(81),
+                usdc_balance_before: user_amount - 10_000,
+                min_out: 10_000,
+            });
+            process_anchor_bytes(
+                user,
+                round,
+                vault,
+                55,
+                now_ts,
+                &config,
+                &mut round_data,
+                &mut participant_data,
+                &token_account(user_amount, user),
+                &vault_ata,
+                &ix,
+            )
+            .unwrap();
+            let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+            assert_eq!(round_view.first_deposit_ts, 1_000);
+        }
+
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        let config_view = ConfigView::read_from_account_data(&config).unwrap();
+        assert!(!round_view.is_expired(&config_view, 1_119));
+        assert!(round_view.is_expired(&config_view, 1_120));
+    }
+
     #[test]
     fn rejects_expired_round() {
         let user = [4u8; 32];
@@ -335,4 +366,219 @@ mod tests {
 
         assert_eq!(err, JackpotCompatError::RoundExpired.into());
     }
+
+    #[test]
+    fn rejects_deposit_when_paused() {
+        let user = [4u8; 32];
+        let round = [8u8; 32];
+        let vault = [9u8; 32];
+        let mut config = sample_config();
+        let paused = ConfigView {
+            paused: true,
+            ..ConfigView::read_from_account_data(&config).unwrap()
+        };
+        paused.write_to_account_data(&mut config).unwrap();
+        let mut round_data = sample_round(81, vault);
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        let user_ata = token_account(40_000, user);
+        let vault_ata = token_account(0, round);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("deposit_any"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.extend_from_slice(&20_000u64.to_le_bytes());
+        ix.extend_from_slice(&20_000u64.to_le_bytes());
+
+        let err = process_anchor_bytes(
+            user,
+            round,
+            vault,
+            55,
+            1_000,
+            &config,
+            &mut round_data,
+            &mut participant_data,
+            &user_ata,
+            &vault_ata,
+            &ix,
+        )
+        .unwrap_err();
+
+        assert_eq!(err, JackpotCompatError::Paused.into());
+        assert_eq!(participant_data, [0u8; PARTICIPANT_ACCOUNT_LEN]);
+    }
+
+    fn deposit_delta(delta: u64) -> (Result<u64, ProgramError>, [u8; ROUND_ACCOUNT_LEN], [u8; PARTICIPANT_ACCOUNT_LEN]) {
+        deposit_delta_with_min_out(delta, 0)
+    }
+
+    fn deposit_delta_with_min_out(
+        delta: u64,
+        min_out: u64,
+    ) -> (Result<u64, ProgramError>, [u8; ROUND_ACCOUNT_LEN], [u8; PARTICIPANT_ACCOUNT_LEN]) {
+        let user = [4u8; 32];
+        let round = [8u8; 32];
+        let vault = [9u8; 32];
+        let config = sample_config();
+        let mut round_data = sample_round(81, vault);
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        let user_ata = token_account(40_000, user);
+        let vault_ata = token_account(0, round);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("deposit_any"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.extend_from_slice(&(40_000 - delta).to_le_bytes());
+        ix.extend_from_slice(&min_out.to_le_bytes());
+
+        let result = process_anchor_bytes(
+            user,
+            round,
+            vault,
+            55,
+            1_000,
+            &config,
+            &mut round_data,
+            &mut participant_data,
+            &user_ata,
+            &vault_ata,
+            &ix,
+        );
+        (result, round_data, participant_data)
+    }
+
+    #[test]
+    fn rejects_deposit_below_one_ticket_unit() {
+        let (result, round_data, participant_data) = deposit_delta(9_999);
+
+        assert_eq!(result.unwrap_err(), JackpotCompatError::DepositTooSmall.into());
+        assert_eq!(round_data, sample_round(81, [9u8; 32]));
+        assert_eq!(participant_data, [0u8; PARTICIPANT_ACCOUNT_LEN]);
+    }
+
+    #[test]
+    fn accepts_deposit_of_exactly_one_ticket_unit() {
+        let (result, round_data, participant_data) = deposit_delta(10_000);
+
+        assert_eq!(result.unwrap(), 10_000);
+        let participant = ParticipantView::read_from_account_data(&participant_data).unwrap();
+        assert_eq!(participant.tickets_total, 1);
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.participants_count, 2);
+        assert_eq!(round_view.total_tickets, 1);
+    }
+
+    #[test]
+    fn rejects_swap_output_below_min_out() {
+        let (result, round_data, participant_data) = deposit_delta_with_min_out(19_999, 20_000);
+
+        assert_eq!(result.unwrap_err(), JackpotCompatError::SlippageExceeded.into());
+        assert_eq!(round_data, sample_round(81, [9u8; 32]));
+        assert_eq!(participant_data, [0u8; PARTICIPANT_ACCOUNT_LEN]);
+    }
+
+    #[test]
+    fn credits_swap_output_meeting_min_out() {
+        let (result, round_data, participant_data) = deposit_delta_with_min_out(25_000, 20_000);
+
+        assert_eq!(result.unwrap(), 25_000);
+        let participant = ParticipantView::read_from_account_data(&participant_data).unwrap();
+        assert_eq!(participant.tickets_total, 2);
+        assert_eq!(participant.usdc_total, 25_000);
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.total_usdc, 25_000);
+        assert_eq!(round_view.total_tickets, 2);
+        assert_eq!(RoundLifecycleView::bit_point_query_in_account_data(&round_data, 2).unwrap(), 2);
+    }
+
+    fn deposit_into_round_with(
+        participants_count: u16,
+        participant_data: &mut [u8; PARTICIPANT_ACCOUNT_LEN],
+    ) -> (Result<u64, ProgramError>, [u8; ROUND_ACCOUNT_LEN]) {
+        let user = [4u8; 32];
+        let round = [8u8; 32];
+        let vault = [9u8; 32];
+        let config = sample_config();
+        let mut round_data = sample_round(81, vault);
+        let mut round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        round_view.participants_count = participants_count;
+        round_view.write_to_account_data(&mut round_data).unwrap();
+        let user_ata = token_account(40_000, user);
+        let vault_ata = token_account(0, round);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("deposit_any"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.extend_from_slice(&30_000u64.to_le_bytes());
+        ix.extend_from_slice(&0u64.to_le_bytes());
+
+        let result = process_anchor_bytes(
+            user,
+            round,
+            vault,
+            55,
+            1_000,
+            &config,
+            &mut round_data,
+            participant_data,
+            &user_ata,
+            &vault_ata,
+            &ix,
+        );
+        (result, round_data)
+    }
+
+    #[test]
+    fn accepts_new_participant_into_last_free_slot() {
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        let (result, round_data) =
+            deposit_into_round_with((MAX_PARTICIPANTS - 1) as u16, &mut participant_data);
+
+        assert_eq!(result.unwrap(), 10_000);
+        let participant = ParticipantView::read_from_account_data(&participant_data).unwrap();
+        assert_eq!(participant.index as usize, MAX_PARTICIPANTS);
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.participants_count as usize, MAX_PARTICIPANTS);
+    }
+
+    #[test]
+    fn rejects_new_participant_when_round_is_full() {
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        let (result, round_data) =
+            deposit_into_round_with(MAX_PARTICIPANTS as u16, &mut participant_data);
+
+        assert_eq!(result.unwrap_err(), JackpotCompatError::MaxParticipantsReached.into());
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.participants_count as usize, MAX_PARTICIPANTS);
+    }
+
+    #[test]
+    fn existing_participant_can_top_up_a_full_round() {
+        let mut participant_data = [0u8; PARTICIPANT_ACCOUNT_LEN];
+        participant_data[..8].copy_from_slice(&account_discriminator("Participant"));
+        ParticipantView {
+            round: [8u8; 32],
+            user: [4u8; 32],
+            index: MAX_PARTICIPANTS as u16,
+            bump: 55,
+            tickets_total: 3,
+            usdc_total: 30_000,
+            deposits_count: 1,
+            reserved: [0u8; 16],
+        }
+        .write_to_account_data(&mut participant_data)
+        .unwrap();
+
+        let (result, round_data) =
+            deposit_into_round_with(MAX_PARTICIPANTS as u16, &mut participant_data);
+
+        assert_eq!(result.unwrap(), 10_000);
+        let participant = ParticipantView::read_from_account_data(&participant_data).unwrap();
+        assert_eq!(participant.index as usize, MAX_PARTICIPANTS);
+        assert_eq!(participant.tickets_total, 4);
+        assert_eq!(participant.deposits_count, 2);
+        let round_view = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(round_view.participants_count as usize, MAX_PARTICIPANTS);
+        assert_eq!(round_view.total_tickets, 1);
+    }
 }
@@ -22,8 +22,7 @@ pub fn process_anchor_bytes(
     receiver_token_ata_data: &[u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let round_id = parse_round_id_ix(ix_data, "finalize_degen_success")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = parse_round_id_ix(ix_data, "finalize_degen_success")?;
     let degen_config = DegenConfigView::read_from_account_data(degen_config_account_data)
         .map_err(map_layout_err)?;
     let mut round = RoundLifecycleView::read_from_account_data(round_account_data).map_err(map_layout_err)?;
@@ -56,12 +55,12 @@ pub fn process_anchor_bytes(
     {
         return Err(JackpotCompatError::InvalidDegenReceiverAta.into());
     }
-    let expected_min_balance = degen_claim
-        .receiver_pre_balance
-        .checked_add(degen_claim.min_out_raw)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    if receiver_token_ata.amount < expected_min_balance {
-        return Err(JackpotCompatError::DegenOutputNotReceived.into());
+    let received = receiver_token_ata
+        .amount
+        .checked_sub(degen_claim.receiver_pre_balance)
+        .ok_or::<ProgramError>(JackpotCompatError::DegenOutputNotReceived.into())?;
+    if received < degen_claim.min_out_raw {
+        return Err(JackpotCompatError::SlippageExceeded.into());
     }
     if executor_usdc_ata.owner != executor_pubkey || executor_usdc_ata.amount != 0 {
         return Err(JackpotCompatError::InvalidDegenExecutorAta.into());
@@ -98,18 +97,24 @@ mod tests {
         data
     }
 
-    #[test]
-    fn finalize_degen_success_marks_claimed_swapped() {
-        let executor = [5u8; 32];
+    const EXECUTOR: [u8; 32] = [5u8; 32];
+    const WINNER: [u8; 32] = [9u8; 32];
+    const TOKEN_MINT: [u8; 32] = [11u8; 32];
+    const RECEIVER_TOKEN_ATA: [u8; 32] = [12u8; 32];
+    const RECEIVER_PRE_BALANCE: u64 = 500;
+    const MIN_OUT_RAW: u64 = 777;
+
+    /// Runs finalize against an EXECUTING claim whose receiver ATA now holds
+    /// `receiver_amount`; returns the round and claim data afterwards.
+    fn run_finalize(
+        receiver_amount: u64,
+    ) -> (Result<(), ProgramError>, [u8; ROUND_ACCOUNT_LEN], [u8; DEGEN_CLAIM_ACCOUNT_LEN]) {
         let round_key = [8u8; 32];
-        let winner = [9u8; 32];
-        let token_mint = [11u8; 32];
-        let receiver_token_ata = [12u8; 32];
 
         let mut degen_config = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
         degen_config[..8].copy_from_slice(&account_discriminator("DegenConfig"));
         DegenConfigView {
-            executor,
+            executor: EXECUTOR,
             fallback_timeout_sec: 300,
             bump: 201,
             reserved: [0u8; 27],
@@ -132,14 +137,14 @@ mod tests {
         }
         .write_to_account_data(&mut round)
         .unwrap();
-        RoundLifecycleView::write_winner_to_account_data(&mut round, &winner).unwrap();
+        RoundLifecycleView::write_winner_to_account_data(&mut round, &WINNER).unwrap();
         RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round, 3).unwrap();
 
         let mut degen_claim = [0u8; DEGEN_CLAIM_ACCOUNT_LEN];
         degen_claim[..8].copy_from_slice(&account_discriminator("DegenClaim"));
         DegenClaimView {
             round: round_key,
-            winner,
+            winner: WINNER,
             round_id: 81,
             status: DEGEN_CLAIM_STATUS_EXECUTING,
             bump: 203,
@@ -154,11 +159,11 @@ mod tests {
             claimed_at: 0,
             fallback_after_ts: 1_200,
             payout_raw: 997_500,
-            min_out_raw: 777,
-            receiver_pre_balance: 500,
-            token_mint,
-            executor,
-            receiver_token_ata,
+            min_out_raw: MIN_OUT_RAW,
+            receiver_pre_balance: RECEIVER_PRE_BALANCE,
+            token_mint: TOKEN_MINT,
+            executor: EXECUTOR,
+            receiver_token_ata: RECEIVER_TOKEN_ATA,
             randomness: [7u8; 32],
             route_hash: [33u8; 32],
             reserved: [0u8; 32],
@@ -166,16 +171,16 @@ mod tests {
         .write_to_account_data(&mut degen_claim)
         .unwrap();
 
-        let executor_ata = token_account([2u8; 32], executor, 0);
-        let receiver_ata = token_account(token_mint, winner, 1_500);
+        let executor_ata = token_account([2u8; 32], EXECUTOR, 0);
+        let receiver_ata = token_account(TOKEN_MINT, WINNER, receiver_amount);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("finalize_degen_success"));
         ix.extend_from_slice(&81u64.to_le_bytes());
 
-        process_anchor_bytes(
-            executor,
-            receiver_token_ata,
+        let result = process_anchor_bytes(
+            EXECUTOR,
+            RECEIVER_TOKEN_ATA,
             1_234,
             &degen_config,
             &mut round,
@@ -183,14 +188,41 @@ mod tests {
             &executor_ata,
             &receiver_ata,
             &ix,
-        )
-        .unwrap();
+        );
+        (result, round, degen_claim)
+    }
+
+    #[test]
+    fn finalize_degen_success_marks_claimed_swapped() {
+        let (result, round, degen_claim) = run_finalize(1_500);
+        result.unwrap();
 
         let round_view = RoundLifecycleView::read_from_account_data(&round).unwrap();
         assert_eq!(round_view.status, 4);
         assert_eq!(RoundLifecycleView::read_degen_mode_status_from_account_data(&round).unwrap(), 4);
+        RoundLifecycleView::verify_degen_mode_consistency(&round).unwrap();
         let claim = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
         assert_eq!(claim.status, 4);
         assert_eq!(claim.claimed_at, 1_234);
     }
+
+    #[test]
+    fn finalize_accepts_output_exactly_at_min_out() {
+        let (result, _, _) = run_finalize(RECEIVER_PRE_BALANCE + MIN_OUT_RAW);
+        result.unwrap();
+    }
+
+    #[test]
+    fn finalize_rejects_output_one_below_min_out() {
+        let (result, _, degen_claim) = run_finalize(RECEIVER_PRE_BALANCE + MIN_OUT_RAW - 1);
+        assert_eq!(result, Err(JackpotCompatError::SlippageExceeded.into()));
+        let claim = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_EXECUTING);
+    }
+
+    #[test]
+    fn finalize_rejects_decreased_receiver_balance() {
+        let (result, _, _) = run_finalize(RECEIVER_PRE_BALANCE - 1);
+        assert_eq!(result, Err(JackpotCompatError::DegenOutputNotReceived.into()));
+    }
 }
@@ -2,34 +2,21 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     anchor_compat::account_discriminator,
-    errors::JackpotCompatError,
     instruction_layouts::InitConfigArgsCompat,
     legacy_layouts::{CONFIG_ACCOUNT_LEN, ConfigView, PUBKEY_LEN},
 };
 
+use super::degen_common::map_layout_err;
+
 pub fn process_anchor_bytes(
     admin_pubkey: [u8; PUBKEY_LEN],
     config_account_data: &mut [u8],
     config_bump: u8,
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let args = InitConfigArgsCompat::parse(ix_data).map_err(|_| ProgramError::InvalidInstructionData)?;
-
-    if args.fee_bps > 10_000 {
-        return Err(JackpotCompatError::InvalidFeeBps.into());
-    }
-    if args.ticket_unit == 0 {
-        return Err(JackpotCompatError::InvalidTicketUnit.into());
-    }
-    if args.round_duration_sec == 0 {
-        return Err(JackpotCompatError::InvalidRoundDuration.into());
-    }
-    if config_account_data.len() != CONFIG_ACCOUNT_LEN {
-        return Err(ProgramError::InvalidAccountData);
-    }
+    let args = InitConfigArgsCompat::parse(ix_data)?;
 
-    config_account_data[..8].copy_from_slice(&account_discriminator("Config"));
-    ConfigView {
+    let config = ConfigView {
         admin: admin_pubkey,
         usdc_mint: args.usdc_mint,
         treasury_usdc_ata: args.treasury_usdc_ata,
@@ -42,9 +29,16 @@ pub fn process_anchor_bytes(
         bump: config_bump,
         max_deposit_per_user: args.max_deposit_per_user,
         reserved: [0u8; 24],
+    };
+    config.validate().map_err(map_layout_err)?;
+    if config_account_data.len() != CONFIG_ACCOUNT_LEN {
+        return Err(ProgramError::InvalidAccountData);
     }
-    .write_to_account_data(config_account_data)
-    .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    config_account_data[..8].copy_from_slice(&account_discriminator("Config"));
+    config
+        .write_to_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
 
     Ok(())
 }
@@ -52,21 +46,26 @@ pub fn process_anchor_bytes(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::anchor_compat::instruction_discriminator;
+    use crate::{anchor_compat::instruction_discriminator, errors::JackpotCompatError};
 
-    #[test]
-    fn initializes_live_config_layout() {
-        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+    fn init_config_ix(fee_bps: u16, ticket_unit: u64, round_duration_sec: u32) -> Vec<u8> {
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("init_config"));
         ix.extend_from_slice(&[2u8; 32]);
         ix.extend_from_slice(&[3u8; 32]);
-        ix.extend_from_slice(&25u16.to_le_bytes());
-        ix.extend_from_slice(&10_000u64.to_le_bytes());
-        ix.extend_from_slice(&120u32.to_le_bytes());
+        ix.extend_from_slice(&fee_bps.to_le_bytes());
+        ix.extend_from_slice(&ticket_unit.to_le_bytes());
+        ix.extend_from_slice(&round_duration_sec.to_le_bytes());
         ix.extend_from_slice(&0u16.to_le_bytes());
         ix.extend_from_slice(&0u64.to_le_bytes());
         ix.extend_from_slice(&1_000_000u64.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn initializes_live_config_layout() {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        let ix = init_config_ix(25, 10_000, 120);
 
         process_anchor_bytes([7u8; 32], &mut data, 254, &ix).unwrap();
 
@@ -79,4 +78,19 @@ mod tests {
         assert_eq!(config.bump, 254);
         assert!(!config.paused);
     }
+
+    #[test]
+    fn rejects_invalid_config_without_writing() {
+        let cases = [
+            (init_config_ix(10_001, 10_000, 120), JackpotCompatError::InvalidFeeBps),
+            (init_config_ix(25, 0, 120), JackpotCompatError::InvalidTicketUnit),
+            (init_config_ix(25, 10_000, 0), JackpotCompatError::InvalidRoundDuration),
+        ];
+        for (ix, expected) in cases {
+            let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+            let err = process_anchor_bytes([7u8; 32], &mut data, 254, &ix).unwrap_err();
+            assert_eq!(err, expected.into());
+            assert_eq!(data, [0u8; CONFIG_ACCOUNT_LEN]);
+        }
+    }
 }
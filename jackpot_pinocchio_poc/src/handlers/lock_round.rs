@@ -15,8 +15,7 @@ pub fn process_anchor_bytes(
     current_unix_timestamp: i64,
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let _round_id = parse_round_id_ix(ix_data, "lock_round")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let _round_id = parse_round_id_ix(ix_data, "lock_round")?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -29,15 +28,19 @@ pub fn process_anchor_bytes(
     if round.first_deposit_ts == 0 {
         return Err(JackpotCompatError::NoDepositsYet.into());
     }
-    if round.participants_count < config.min_participants {
-        return Err(JackpotCompatError::NotEnoughParticipants.into());
-    }
-    if round.total_tickets < config.min_total_tickets {
-        return Err(JackpotCompatError::NotEnoughTickets.into());
-    }
+    round.check_lockable(&config).map_err(|_| {
+        if round.participants_count < config.min_participants {
+            ProgramError::from(JackpotCompatError::NotEnoughParticipants)
+        } else {
+            JackpotCompatError::NotEnoughTickets.into()
+        }
+    })?;
     if current_unix_timestamp < round.end_ts {
         return Err(JackpotCompatError::RoundNotEnded.into());
     }
+    round
+        .require_expired(&config, current_unix_timestamp)
+        .map_err(|_| ProgramError::from(JackpotCompatError::RoundNotEnded))?;
 
     RoundLifecycleView::write_status_to_account_data(round_account_data, ROUND_STATUS_LOCKED)
         .map_err(|_| ProgramError::AccountDataTooSmall)?;
@@ -51,7 +54,7 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            ConfigView, RoundLifecycleView, CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+            ConfigView, LayoutError, RoundLifecycleView, CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
             ROUND_STATUS_LOCKED, ROUND_STATUS_OPEN,
         },
     };
@@ -78,6 +81,8 @@ mod tests {
         data
     }
 
+    /// `end_ts` is 130, but the round timer from `first_deposit_ts` only
+    /// runs out at 145; locking needs both.
     fn sample_round() -> [u8; ROUND_ACCOUNT_LEN] {
         let mut data = [0u8; ROUND_ACCOUNT_LEN];
         data[..8].copy_from_slice(&account_discriminator("Round"));
@@ -106,7 +111,7 @@ mod tests {
         ix.extend_from_slice(&instruction_discriminator("lock_round"));
         ix.extend_from_slice(&81u64.to_le_bytes());
 
-        process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 130, &ix).unwrap();
+        process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 145, &ix).unwrap();
 
         let parsed = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
         assert_eq!(parsed.status, ROUND_STATUS_LOCKED);
@@ -122,8 +127,119 @@ mod tests {
         ix.extend_from_slice(&instruction_discriminator("lock_round"));
         ix.extend_from_slice(&81u64.to_le_bytes());
 
-        let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 129, &ix)
+        for now in [129, 144] {
+            let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, now, &ix)
+                .unwrap_err();
+            assert_eq!(err, JackpotCompatError::RoundNotEnded.into());
+        }
+    }
+
+    #[test]
+    fn rejects_lock_before_end_ts_after_timer_elapsed() {
+        let config_data = sample_config();
+        // `end_ts` was set when the second participant joined at 80, so it
+        // is later than `first_deposit_ts + round_duration_sec` (145).
+        let mut round_data = sample_round();
+        RoundLifecycleView {
+            end_ts: 200,
+            ..RoundLifecycleView::read_from_account_data(&round_data).unwrap()
+        }
+        .write_to_account_data(&mut round_data)
+        .unwrap();
+
+        for now in [145, 199] {
+            let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, now, &lock_ix())
+                .unwrap_err();
+            assert_eq!(err, JackpotCompatError::RoundNotEnded.into());
+        }
+        process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 200, &lock_ix()).unwrap();
+        assert_eq!(
+            RoundLifecycleView::read_from_account_data(&round_data).unwrap().status,
+            ROUND_STATUS_LOCKED
+        );
+    }
+
+    fn round_with_totals(participants_count: u16, total_tickets: u64) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = sample_round();
+        RoundLifecycleView {
+            participants_count,
+            total_tickets,
+            ..RoundLifecycleView::read_from_account_data(&data).unwrap()
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn lock_ix() -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("lock_round"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn rejects_lock_below_participant_and_ticket_minimums() {
+        let config_data = sample_config();
+
+        let mut round_data = round_with_totals(1, 200);
+        let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 130, &lock_ix())
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::NotEnoughParticipants.into());
+
+        let mut round_data = round_with_totals(2, 199);
+        let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 130, &lock_ix())
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::NotEnoughTickets.into());
+        assert_eq!(
+            RoundLifecycleView::read_from_account_data(&round_data).unwrap().status,
+            ROUND_STATUS_OPEN
+        );
+    }
+
+    #[test]
+    fn accepts_lock_at_and_above_minimums() {
+        let config_data = sample_config();
+        let config = ConfigView::read_from_account_data(&config_data).unwrap();
+
+        for (participants_count, total_tickets) in [(2, 200), (3, 201)] {
+            let mut round_data = round_with_totals(participants_count, total_tickets);
+            let round = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+            assert_eq!(round.check_lockable(&config), Ok(()));
+
+            process_anchor_bytes([8u8; 32], &config_data, &mut round_data, 145, &lock_ix()).unwrap();
+            assert_eq!(
+                RoundLifecycleView::read_from_account_data(&round_data).unwrap().status,
+                ROUND_STATUS_LOCKED
+            );
+        }
+    }
+
+    #[test]
+    fn round_expires_exactly_at_duration_boundary() {
+        let config_data = sample_config();
+        let config = ConfigView::read_from_account_data(&config_data).unwrap();
+        let round = RoundLifecycleView::read_from_account_data(&sample_round()).unwrap();
+
+        assert!(!round.is_expired(&config, 144));
+        assert_eq!(round.require_expired(&config, 144), Err(LayoutError::RoundStillOpen));
+        assert!(round.is_expired(&config, 145));
+        assert_eq!(round.require_expired(&config, 145), Ok(()));
+    }
+
+    #[test]
+    fn round_without_deposits_never_expires() {
+        let config = ConfigView::read_from_account_data(&sample_config()).unwrap();
+        let mut round_data = sample_round();
+        let round = RoundLifecycleView {
+            first_deposit_ts: 0,
+            ..RoundLifecycleView::read_from_account_data(&round_data).unwrap()
+        };
+        round.write_to_account_data(&mut round_data).unwrap();
+
+        assert!(!round.is_expired(&config, i64::MAX));
+        let err = process_anchor_bytes([8u8; 32], &sample_config(), &mut round_data, i64::MAX, &lock_ix())
             .unwrap_err();
-        assert_eq!(err, JackpotCompatError::RoundNotEnded.into());
+        assert_eq!(err, JackpotCompatError::NoDepositsYet.into());
     }
 }
@@ -0,0 +1,151 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    handlers::settle_round::settle_with_stored_randomness,
+    instruction_layouts::MockSettleArgsCompat,
+    legacy_layouts::{ConfigView, RoundLifecycleView, PUBKEY_LEN, ROUND_STATUS_LOCKED},
+};
+
+/// Admin-only settlement of a `Locked` round with caller-supplied randomness,
+/// skipping the VRF oracle. Mirrors Anchor's `devnet`-only `mock_settle`.
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    config_account_data: &[u8],
+    round_account_data: &mut [u8],
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let args = MockSettleArgsCompat::parse(ix_data)?;
+    let config = ConfigView::read_from_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let round = RoundLifecycleView::read_from_account_data(round_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+    if round.status != ROUND_STATUS_LOCKED {
+        return Err(JackpotCompatError::RoundNotLocked.into());
+    }
+    if round.participants_count < config.min_participants {
+        return Err(JackpotCompatError::NotEnoughParticipants.into());
+    }
+    if round.total_tickets < config.min_total_tickets {
+        return Err(JackpotCompatError::NotEnoughTickets.into());
+    }
+
+    RoundLifecycleView::write_randomness_to_account_data(round_account_data, &args.randomness)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    settle_with_stored_randomness(round_account_data, round.total_tickets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::{account_discriminator, instruction_discriminator},
+        legacy_layouts::{
+            CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED,
+        },
+    };
+
+    const ADMIN: [u8; 32] = [7u8; 32];
+
+    fn sample_config() -> [u8; CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        ConfigView {
+            admin: ADMIN,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn sample_round(status: u8) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView {
+            round_id: 81,
+            status,
+            bump: 201,
+            start_ts: 10,
+            end_ts: 130,
+            first_deposit_ts: 25,
+            total_usdc: 1_250_000,
+            total_tickets: 200,
+            participants_count: 2,
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data[176..208].copy_from_slice(&[11u8; 32]);
+        data[208..240].copy_from_slice(&[22u8; 32]);
+        RoundLifecycleView::write_bit_node_to_account_data(&mut data, 1, 100).unwrap();
+        let mut idx = 2usize;
+        while idx <= 128 {
+            RoundLifecycleView::write_bit_node_to_account_data(&mut data, idx, 200).unwrap();
+            idx <<= 1;
+        }
+        data
+    }
+
+    fn mock_settle_ix(randomness: [u8; 32]) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("mock_settle"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.extend_from_slice(&randomness);
+        ix
+    }
+
+    #[test]
+    fn settles_locked_round_like_anchor_mock_settle() {
+        let config_data = sample_config();
+        let mut round_data = sample_round(ROUND_STATUS_LOCKED);
+        // First 16 bytes little-endian = 149 -> ticket 150 -> second participant.
+        let mut randomness = [0u8; 32];
+        randomness[0] = 149;
+
+        process_anchor_bytes(ADMIN, &config_data, &mut round_data, &mock_settle_ix(randomness)).unwrap();
+
+        let parsed = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(parsed.status, ROUND_STATUS_SETTLED);
+        assert_eq!(RoundLifecycleView::read_randomness_from_account_data(&round_data).unwrap(), randomness);
+        assert_eq!(RoundLifecycleView::read_winning_ticket_from_account_data(&round_data).unwrap(), 150);
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(&round_data).unwrap(), [22u8; 32]);
+    }
+
+    #[test]
+    fn rejects_non_admin() {
+        let config_data = sample_config();
+        let mut round_data = sample_round(ROUND_STATUS_LOCKED);
+        let before = round_data;
+
+        let err = process_anchor_bytes([8u8; 32], &config_data, &mut round_data, &mock_settle_ix([1u8; 32]))
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::Unauthorized.into());
+        assert_eq!(round_data, before);
+    }
+
+    #[test]
+    fn rejects_round_that_is_not_locked() {
+        let config_data = sample_config();
+        let mut round_data = sample_round(ROUND_STATUS_VRF_REQUESTED);
+        let before = round_data;
+
+        let err = process_anchor_bytes(ADMIN, &config_data, &mut round_data, &mock_settle_ix([1u8; 32]))
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::RoundNotLocked.into());
+        assert_eq!(round_data, before);
+    }
+}
@@ -1,6 +1,9 @@
 pub mod init_config;
 pub mod update_config;
 pub mod upsert_degen_config;
+pub mod update_degen_timeout;
+pub mod set_vrf_queue;
+pub mod set_fee_split;
 pub mod transfer_admin;
 pub mod set_treasury_usdc_ata;
 pub mod start_round;
@@ -15,6 +18,10 @@ pub mod claim;
 pub mod auto_claim;
 pub mod request_vrf;
 pub mod vrf_callback;
+pub mod settle_round;
+pub mod settle_with_slot_hash;
+#[cfg(feature = "devnet")]
+pub mod mock_settle;
 pub mod request_degen_vrf;
 pub mod degen_vrf_callback;
 pub mod degen_common;
@@ -23,3 +30,4 @@ pub mod claim_degen;
 
 pub mod begin_degen_execution;
 pub mod finalize_degen_success;
+pub mod recover_stuck_execution;
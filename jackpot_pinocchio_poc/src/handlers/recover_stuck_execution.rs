@@ -0,0 +1,392 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    handlers::degen_common::map_layout_err,
+    instruction_layouts::parse_round_id_ix,
+    legacy_layouts::{
+        ConfigView, DegenClaimView, RoundLifecycleView, TokenAccountWithAmountView,
+        DEGEN_CLAIM_STATUS_EXECUTING, DEGEN_CLAIM_STATUS_VRF_READY, DEGEN_FALLBACK_REASON_NONE,
+        DEGEN_MODE_EXECUTING, DEGEN_MODE_VRF_READY, ROUND_STATUS_SETTLED, PUBKEY_LEN,
+    },
+};
+
+/// How long a claim may sit in `EXECUTING` (measured from
+/// `execution_started_at`) before the admin is allowed to revert it.
+pub const DEGEN_STUCK_EXECUTION_TIMEOUT_SEC: i64 = 3_600;
+
+/// Reverts a degen claim stuck in `EXECUTING` back to `VRF_READY` so the
+/// USDC fallback path opens up again.
+///
+/// `begin_degen_execution` already moved the payout out of the vault to the
+/// executor, and the fee (plus any VRF reimbursement) out to the treasury /
+/// VRF payer. The program cannot pull the payout back on its own, so the
+/// executor (or admin) must first return `payout_raw` USDC to the vault;
+/// the revert is refused otherwise. The fee and VRF reimbursement stay
+/// paid: the claim keeps its `fees_paid` flag, so the fallback or a new
+/// `begin_degen_execution` only pays the recorded payout.
+///
+/// The receiver token account recorded at begin must still hold less than
+/// `receiver_pre_balance + min_out_raw`. Once the swap output landed the
+/// winner has been paid in tokens and `finalize_degen_success` is the only
+/// way forward; reverting would pay them a second time in USDC.
+#[allow(clippy::too_many_arguments)]
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    round_pubkey: [u8; PUBKEY_LEN],
+    vault_pubkey: [u8; PUBKEY_LEN],
+    receiver_token_ata_pubkey: [u8; PUBKEY_LEN],
+    now_ts: i64,
+    config_account_data: &[u8],
+    round_account_data: &mut [u8],
+    degen_claim_account_data: &mut [u8],
+    vault_account_data: &[u8],
+    receiver_token_ata_data: &[u8],
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let round_id = parse_round_id_ix(ix_data, "recover_stuck_execution")?;
+    let config = ConfigView::read_from_account_data(config_account_data).map_err(map_layout_err)?;
+    let round = RoundLifecycleView::read_from_account_data(round_account_data).map_err(map_layout_err)?;
+    let mut degen_claim =
+        DegenClaimView::read_from_account_data(degen_claim_account_data).map_err(map_layout_err)?;
+    let vault = TokenAccountWithAmountView::read_from_account_data(vault_account_data).map_err(map_layout_err)?;
+    let receiver_token_ata =
+        TokenAccountWithAmountView::read_from_account_data(receiver_token_ata_data).map_err(map_layout_err)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+    if round.round_id != round_id {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if round.status != ROUND_STATUS_SETTLED {
+        return Err(JackpotCompatError::RoundNotSettled.into());
+    }
+    if degen_claim.status != DEGEN_CLAIM_STATUS_EXECUTING
+        || RoundLifecycleView::read_degen_mode_status_from_account_data(round_account_data).map_err(map_layout_err)?
+            != DEGEN_MODE_EXECUTING
+    {
+        return Err(JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+    if degen_claim.round != round_pubkey || degen_claim.round_id != round_id {
+        return Err(JackpotCompatError::InvalidDegenClaim.into());
+    }
+    if !degen_claim.is_stale_executing(now_ts, DEGEN_STUCK_EXECUTION_TIMEOUT_SEC) {
+        return Err(JackpotCompatError::DegenFallbackTooEarly.into());
+    }
+    if vault_pubkey != RoundLifecycleView::require_vault_set(round_account_data).map_err(map_layout_err)?
+        || vault.mint != config.usdc_mint
+        || vault.owner != round_pubkey
+    {
+        return Err(JackpotCompatError::InvalidVault.into());
+    }
+
+    if receiver_token_ata_pubkey != degen_claim.receiver_token_ata
+        || receiver_token_ata.mint != degen_claim.token_mint
+        || receiver_token_ata.owner != degen_claim.winner
+    {
+        return Err(JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+    let swapped_balance = degen_claim
+        .receiver_pre_balance
+        .checked_add(degen_claim.min_out_raw)
+        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
+    if receiver_token_ata.amount >= swapped_balance {
+        return Err(JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+    if vault.amount < degen_claim.payout_raw {
+        return Err(JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+
+    RoundLifecycleView::write_degen_mode_status_to_account_data(round_account_data, DEGEN_MODE_VRF_READY)
+        .map_err(map_layout_err)?;
+
+    degen_claim.status = DEGEN_CLAIM_STATUS_VRF_READY;
+    degen_claim.selected_candidate_rank = u8::MAX;
+    degen_claim.fallback_reason = DEGEN_FALLBACK_REASON_NONE;
+    degen_claim.token_index = 0;
+    degen_claim.token_mint = [0u8; 32];
+    degen_claim.executor = [0u8; 32];
+    degen_claim.receiver_token_ata = [0u8; 32];
+    degen_claim.receiver_pre_balance = 0;
+    degen_claim.min_out_raw = 0;
+    degen_claim.route_hash = [0u8; 32];
+    degen_claim.set_execution_started_at(0);
+    degen_claim.set_fees_paid(true);
+    degen_claim.write_to_account_data(degen_claim_account_data).map_err(map_layout_err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::{account_discriminator, instruction_discriminator},
+        legacy_layouts::{
+            CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+            TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+        },
+    };
+
+    const ADMIN: [u8; 32] = [7u8; 32];
+    const ROUND_KEY: [u8; 32] = [8u8; 32];
+    const VAULT_KEY: [u8; 32] = [10u8; 32];
+    const RECEIVER_ATA_KEY: [u8; 32] = [12u8; 32];
+    const REQUESTED_AT: i64 = 1_000;
+    const EXECUTION_STARTED_AT: i64 = 1_100;
+
+    fn sample_config() -> [u8; CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        ConfigView {
+            admin: ADMIN,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    fn sample_round() -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView {
+            round_id: 81,
+            status: ROUND_STATUS_SETTLED,
+            bump: 201,
+            start_ts: 10,
+            end_ts: 130,
+            first_deposit_ts: 25,
+            total_usdc: 1_000_000,
+            total_tickets: 200,
+            participants_count: 2,
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        RoundLifecycleView::write_vault_pubkey_to_account_data(&mut data, &VAULT_KEY).unwrap();
+        RoundLifecycleView::write_winner_to_account_data(&mut data, &[9u8; 32]).unwrap();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut data, DEGEN_MODE_EXECUTING).unwrap();
+        data
+    }
+
+    fn executing_claim() -> [u8; DEGEN_CLAIM_ACCOUNT_LEN] {
+        let mut data = [0u8; DEGEN_CLAIM_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenClaim"));
+        let mut view = DegenClaimView {
+            round: ROUND_KEY,
+            winner: [9u8; 32],
+            round_id: 81,
+            status: DEGEN_CLAIM_STATUS_EXECUTING,
+            bump: 250,
+            selected_candidate_rank: 0,
+            fallback_reason: DEGEN_FALLBACK_REASON_NONE,
+            token_index: 42,
+            pool_version: 1,
+            candidate_window: 30,
+            padding0: [0u8; 7],
+            requested_at: REQUESTED_AT,
+            fulfilled_at: 1_100,
+            claimed_at: 0,
+            fallback_after_ts: 1_300,
+            payout_raw: 997_500,
+            min_out_raw: 777,
+            receiver_pre_balance: 500,
+            token_mint: [11u8; 32],
+            executor: [5u8; 32],
+            receiver_token_ata: [12u8; 32],
+            randomness: [4u8; 32],
+            route_hash: [33u8; 32],
+            reserved: [0u8; 32],
+        };
+        view.set_execution_started_at(EXECUTION_STARTED_AT);
+        view.set_fees_paid(true);
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn token_account(mint: [u8; 32], owner: [u8; 32], amount: u64) -> [u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN] {
+        let mut data = [0u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        data[..32].copy_from_slice(&mint);
+        data[32..64].copy_from_slice(&owner);
+        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, amount).unwrap();
+        data
+    }
+
+    fn vault_with(amount: u64) -> [u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN] {
+        token_account([2u8; 32], ROUND_KEY, amount)
+    }
+
+    /// Receiver still at its pre-swap balance: the swap never landed.
+    fn receiver_with(amount: u64) -> [u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN] {
+        token_account([11u8; 32], [9u8; 32], amount)
+    }
+
+    fn recover_ix() -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("recover_stuck_execution"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix
+    }
+
+    /// Already past the timeout measured from `requested_at`, but not from
+    /// `execution_started_at`.
+    #[test]
+    fn rejects_recovery_before_timeout() {
+        let config = sample_config();
+        let mut round = sample_round();
+        let mut claim = executing_claim();
+        let vault = vault_with(1_000_000);
+
+        let err = process_anchor_bytes(
+            ADMIN,
+            ROUND_KEY,
+            VAULT_KEY,
+            RECEIVER_ATA_KEY,
+            EXECUTION_STARTED_AT + DEGEN_STUCK_EXECUTION_TIMEOUT_SEC,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault,
+            &receiver_with(500),
+            &recover_ix(),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::DegenFallbackTooEarly.into());
+        assert_eq!(
+            DegenClaimView::read_from_account_data(&claim).unwrap().status,
+            DEGEN_CLAIM_STATUS_EXECUTING
+        );
+    }
+
+    #[test]
+    fn rejects_recovery_from_non_admin_or_unfunded_vault() {
+        let config = sample_config();
+        let now = EXECUTION_STARTED_AT + DEGEN_STUCK_EXECUTION_TIMEOUT_SEC + 1;
+
+        let mut round = sample_round();
+        let mut claim = executing_claim();
+        let err = process_anchor_bytes(
+            [5u8; 32],
+            ROUND_KEY,
+            VAULT_KEY,
+            RECEIVER_ATA_KEY,
+            now,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault_with(1_000_000),
+            &receiver_with(500),
+            &recover_ix(),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::Unauthorized.into());
+
+        let err = process_anchor_bytes(
+            ADMIN,
+            ROUND_KEY,
+            VAULT_KEY,
+            RECEIVER_ATA_KEY,
+            now,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault_with(997_499),
+            &receiver_with(500),
+            &recover_ix(),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+
+    #[test]
+    fn rejects_recovery_once_swap_output_landed() {
+        let config = sample_config();
+        let mut round = sample_round();
+        let mut claim = executing_claim();
+
+        let err = process_anchor_bytes(
+            ADMIN,
+            ROUND_KEY,
+            VAULT_KEY,
+            RECEIVER_ATA_KEY,
+            EXECUTION_STARTED_AT + DEGEN_STUCK_EXECUTION_TIMEOUT_SEC + 1,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault_with(997_500),
+            &receiver_with(500 + 777),
+            &recover_ix(),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutionState.into());
+
+        let err = process_anchor_bytes(
+            ADMIN,
+            ROUND_KEY,
+            VAULT_KEY,
+            [13u8; 32],
+            EXECUTION_STARTED_AT + DEGEN_STUCK_EXECUTION_TIMEOUT_SEC + 1,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault_with(997_500),
+            &receiver_with(500),
+            &recover_ix(),
+        )
+        .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidDegenExecutionState.into());
+    }
+
+    #[test]
+    fn reverts_stale_execution_to_vrf_ready() {
+        let config = sample_config();
+        let mut round = sample_round();
+        let mut claim = executing_claim();
+        // The fee already reached the treasury at begin: only the payout has
+        // to be back in the vault.
+        let vault = vault_with(997_500);
+
+        process_anchor_bytes(
+            ADMIN,
+            ROUND_KEY,
+            VAULT_KEY,
+            RECEIVER_ATA_KEY,
+            EXECUTION_STARTED_AT + DEGEN_STUCK_EXECUTION_TIMEOUT_SEC + 1,
+            &config,
+            &mut round,
+            &mut claim,
+            &vault,
+            &receiver_with(500),
+            &recover_ix(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            RoundLifecycleView::read_degen_mode_status_from_account_data(&round).unwrap(),
+            DEGEN_MODE_VRF_READY
+        );
+        let parsed = DegenClaimView::read_from_account_data(&claim).unwrap();
+        assert_eq!(parsed.status, DEGEN_CLAIM_STATUS_VRF_READY);
+        assert_eq!(parsed.selected_candidate_rank, u8::MAX);
+        assert_eq!(parsed.executor, [0u8; 32]);
+        assert_eq!(parsed.token_mint, [0u8; 32]);
+        assert_eq!(parsed.receiver_token_ata, [0u8; 32]);
+        assert_eq!(parsed.min_out_raw, 0);
+        assert_eq!(parsed.payout_raw, 997_500);
+        assert_eq!(parsed.randomness, [4u8; 32]);
+        assert_eq!(parsed.fallback_after_ts, 1_300);
+        assert_eq!(parsed.execution_started_at(), 0);
+        assert!(parsed.fees_paid());
+    }
+}
@@ -2,10 +2,11 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     anchor_compat::account_discriminator,
+    degen_pool_compat::degen_pool_len,
     errors::JackpotCompatError,
     instruction_layouts::parse_round_id_ix,
     legacy_layouts::{
-        DegenClaimView, RoundLifecycleView, DEGEN_CANDIDATE_WINDOW,
+        DegenClaimView, DegenConfigView, RoundLifecycleView, DEGEN_CANDIDATE_WINDOW,
         DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED,
         DEGEN_CLAIM_STATUS_EXECUTING, DEGEN_CLAIM_STATUS_VRF_READY,
         DEGEN_CLAIM_STATUS_VRF_REQUESTED, DEGEN_FALLBACK_REASON_NONE, DEGEN_MODE_CLAIMED,
@@ -16,6 +17,7 @@ use crate::{
 
 const DEGEN_POOL_VERSION: u32 = 1;
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_anchor_bytes(
     winner_pubkey: [u8; PUBKEY_LEN],
     round_pubkey: [u8; PUBKEY_LEN],
@@ -23,10 +25,10 @@ pub fn process_anchor_bytes(
     now_ts: i64,
     round_account_data: &mut [u8],
     degen_claim_account_data: &mut [u8],
+    degen_config_account_data: Option<&[u8]>,
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let round_id =
-        parse_round_id_ix(ix_data, "request_degen_vrf").map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = parse_round_id_ix(ix_data, "request_degen_vrf")?;
 
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -78,6 +80,8 @@ pub fn process_anchor_bytes(
         }
     }
 
+    let candidate_window = resolve_candidate_window(degen_config_account_data, degen_pool_len())?;
+
     RoundLifecycleView::write_degen_mode_status_to_account_data(
         round_account_data,
         DEGEN_MODE_VRF_REQUESTED,
@@ -87,14 +91,14 @@ pub fn process_anchor_bytes(
     let initialized = DegenClaimView {
         round: round_pubkey,
         winner: winner_pubkey,
-        round_id,
+        round_id: round_id.get(),
         status: DEGEN_CLAIM_STATUS_VRF_REQUESTED,
         bump: degen_claim_bump,
         selected_candidate_rank: u8::MAX,
         fallback_reason: DEGEN_FALLBACK_REASON_NONE,
         token_index: 0,
         pool_version: DEGEN_POOL_VERSION,
-        candidate_window: DEGEN_CANDIDATE_WINDOW,
+        candidate_window,
         padding0: [0u8; 7],
         requested_at: now_ts,
         fulfilled_at: 0,
@@ -118,6 +122,21 @@ pub fn process_anchor_bytes(
     Ok(())
 }
 
+/// Window recorded on the new claim: the degen config's, or
+/// `DEGEN_CANDIDATE_WINDOW` when the config is absent or never initialized.
+/// A window the pool cannot fill is rejected rather than clamped.
+fn resolve_candidate_window(degen_config_account_data: Option<&[u8]>, pool_len: usize) -> Result<u8, ProgramError> {
+    let window = match degen_config_account_data {
+        Some(data) if !data.is_empty() => DegenConfigView::read_from_account_data(data)
+            .map_err(|_| ProgramError::InvalidAccountData)?
+            .candidate_window(),
+        _ => DEGEN_CANDIDATE_WINDOW,
+    };
+    DegenConfigView::validate_candidate_window(window, pool_len)
+        .map_err(|_| JackpotCompatError::InvalidDegenCandidate)?;
+    Ok(window)
+}
+
 fn map_layout_err(err: crate::legacy_layouts::LayoutError) -> ProgramError {
     match err {
         crate::legacy_layouts::LayoutError::MathOverflow => JackpotCompatError::MathOverflow.into(),
@@ -131,8 +150,8 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            DegenClaimView, RoundLifecycleView, DEGEN_CLAIM_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
-            ROUND_STATUS_SETTLED,
+            DegenClaimView, RoundLifecycleView, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN,
+            ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED,
         },
     };
 
@@ -210,6 +229,7 @@ mod tests {
             777,
             &mut round,
             &mut degen_claim,
+            None,
             &ix,
         )
         .unwrap();
@@ -227,4 +247,51 @@ mod tests {
             DEGEN_MODE_VRF_REQUESTED
         );
     }
+
+    fn degen_config_with_window(window: u8) -> [u8; DEGEN_CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
+        let mut view = DegenConfigView {
+            executor: [4u8; 32],
+            fallback_timeout_sec: 300,
+            bump: 250,
+            reserved: [0u8; 27],
+        };
+        view.set_candidate_window(window);
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn copies_configured_candidate_window_into_claim() {
+        let mut round = sample_round();
+        let mut degen_claim = sample_degen_claim();
+        let degen_config = degen_config_with_window(12);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("request_degen_vrf"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        process_anchor_bytes([9u8; 32], [8u8; 32], 203, 777, &mut round, &mut degen_claim, Some(&degen_config), &ix)
+            .unwrap();
+
+        let parsed = DegenClaimView::read_from_account_data(&degen_claim).unwrap();
+        assert_eq!(parsed.candidate_window, 12);
+    }
+
+    #[test]
+    fn candidate_window_falls_back_to_default_and_rejects_window_past_pool() {
+        assert_eq!(resolve_candidate_window(None, 869), Ok(DEGEN_CANDIDATE_WINDOW));
+        assert_eq!(resolve_candidate_window(Some(&[]), 869), Ok(DEGEN_CANDIDATE_WINDOW));
+        assert_eq!(resolve_candidate_window(Some(&degen_config_with_window(0)), 869), Ok(DEGEN_CANDIDATE_WINDOW));
+        assert_eq!(resolve_candidate_window(Some(&degen_config_with_window(12)), 12), Ok(12));
+        assert_eq!(
+            resolve_candidate_window(Some(&degen_config_with_window(12)), 11),
+            Err(JackpotCompatError::InvalidDegenCandidate.into())
+        );
+        assert_eq!(
+            resolve_candidate_window(Some(&degen_config_with_window(12)[..DEGEN_CONFIG_ACCOUNT_LEN - 1]), 869),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
 }
@@ -0,0 +1,140 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    instruction_layouts::SetFeeSplitArgsCompat,
+    legacy_layouts::{AccountLayout, ConfigView, FeeSplitConfigView, PUBKEY_LEN},
+};
+
+const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Stores the second fee recipient and its share of every degen fee, and
+/// flags the config so degen claims must pass the PDA. `fee_split_bps == 0`
+/// turns the split back off.
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    config_account_data: &mut [u8],
+    fee_split_config_account_data: &mut [u8],
+    fee_split_config_bump: u8,
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let args = SetFeeSplitArgsCompat::parse(ix_data)?;
+
+    let mut config = ConfigView::read_from_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+    if args.fee_split_bps > BPS_DENOMINATOR {
+        return Err(JackpotCompatError::InvalidFeeBps.into());
+    }
+    if args.fee_split_bps > 0
+        && (args.recipient_usdc_ata == [0u8; PUBKEY_LEN] || args.recipient_usdc_ata == config.treasury_usdc_ata)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if fee_split_config_account_data.len() < FeeSplitConfigView::LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    fee_split_config_account_data[..8].copy_from_slice(&FeeSplitConfigView::DISCRIMINATOR);
+    FeeSplitConfigView {
+        recipient_usdc_ata: args.recipient_usdc_ata,
+        fee_split_bps: args.fee_split_bps,
+        bump: fee_split_config_bump,
+    }
+    .write_to_account_data(fee_split_config_account_data)
+    .map_err(|_| ProgramError::AccountDataTooSmall)?;
+
+    config.set_fee_split_enabled(args.fee_split_bps != 0);
+    config
+        .write_to_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::account_discriminator,
+        instruction_layouts::encode_set_fee_split,
+        legacy_layouts::{CONFIG_ACCOUNT_LEN, FEE_SPLIT_CONFIG_ACCOUNT_LEN},
+    };
+
+    fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
+        let view = ConfigView {
+            admin,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        };
+
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn ix(recipient_usdc_ata: [u8; 32], fee_split_bps: u16) -> Vec<u8> {
+        encode_set_fee_split(&SetFeeSplitArgsCompat { recipient_usdc_ata, fee_split_bps })
+    }
+
+    #[test]
+    fn stores_split_and_toggles_config_flag() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut split_data = [0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN];
+        assert!(!ConfigView::read_from_account_data(&config_data).unwrap().fee_split_enabled());
+
+        process_anchor_bytes(admin, &mut config_data, &mut split_data, 249, &ix([6u8; 32], 3_333)).unwrap();
+
+        assert_eq!(
+            FeeSplitConfigView::from_account_data(&split_data).unwrap(),
+            FeeSplitConfigView { recipient_usdc_ata: [6u8; 32], fee_split_bps: 3_333, bump: 249 }
+        );
+        let mut expected_config = ConfigView::read_from_account_data(&sample_config(admin)).unwrap();
+        expected_config.set_fee_split_enabled(true);
+        assert_eq!(ConfigView::read_from_account_data(&config_data), Ok(expected_config));
+
+        process_anchor_bytes(admin, &mut config_data, &mut split_data, 249, &ix([6u8; 32], 0)).unwrap();
+        assert_eq!(FeeSplitConfigView::from_account_data(&split_data).unwrap().fee_split_bps, 0);
+        assert_eq!(config_data, sample_config(admin));
+    }
+
+    #[test]
+    fn rejects_non_admin_bad_bps_and_bad_recipient() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut split_data = [0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN];
+
+        assert_eq!(
+            process_anchor_bytes([8u8; 32], &mut config_data, &mut split_data, 249, &ix([6u8; 32], 2_500)),
+            Err(JackpotCompatError::Unauthorized.into())
+        );
+        assert_eq!(
+            process_anchor_bytes(admin, &mut config_data, &mut split_data, 249, &ix([6u8; 32], 10_001)),
+            Err(JackpotCompatError::InvalidFeeBps.into())
+        );
+        assert_eq!(
+            process_anchor_bytes(admin, &mut config_data, &mut split_data, 249, &ix([0u8; 32], 2_500)),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            process_anchor_bytes(admin, &mut config_data, &mut split_data, 249, &ix([3u8; 32], 2_500)),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(split_data, [0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN]);
+        assert_eq!(config_data, sample_config(admin));
+    }
+}
@@ -14,8 +14,7 @@ pub fn process_anchor_bytes(
     expected_owner_pubkey: [u8; PUBKEY_LEN],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    parse_no_arg_ix(ix_data, "set_treasury_usdc_ata")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    parse_no_arg_ix(ix_data, "set_treasury_usdc_ata")?;
 
     let mut config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -100,4 +99,42 @@ mod tests {
         let parsed = ConfigView::read_from_account_data(&config_data).unwrap();
         assert_eq!(parsed.treasury_usdc_ata, new_treasury_pubkey);
     }
+
+    #[test]
+    fn rejects_treasury_owned_by_someone_else() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let before = config_data;
+
+        let mut token_account = [0u8; TOKEN_ACCOUNT_CORE_LEN];
+        token_account[..32].copy_from_slice(&[2u8; 32]);
+        token_account[32..64].copy_from_slice(&[6u8; 32]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_treasury_usdc_ata"));
+
+        let err = process_anchor_bytes(admin, &mut config_data, [4u8; 32], &token_account, [5u8; 32], &ix)
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+        assert_eq!(config_data, before);
+    }
+
+    #[test]
+    fn rejects_treasury_with_non_usdc_mint() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let before = config_data;
+
+        let mut token_account = [0u8; TOKEN_ACCOUNT_CORE_LEN];
+        token_account[..32].copy_from_slice(&[8u8; 32]);
+        token_account[32..64].copy_from_slice(&[5u8; 32]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_treasury_usdc_ata"));
+
+        let err = process_anchor_bytes(admin, &mut config_data, [4u8; 32], &token_account, [5u8; 32], &ix)
+            .unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+        assert_eq!(config_data, before);
+    }
 }
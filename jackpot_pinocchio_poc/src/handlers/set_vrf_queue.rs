@@ -0,0 +1,121 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    instruction_layouts::SetVrfQueueArgsCompat,
+    legacy_layouts::{AccountLayout, ConfigView, VrfQueueConfigView, PUBKEY_LEN},
+};
+
+/// Stores the oracle queue `request_vrf` and `request_degen_vrf` check
+/// against, overriding the build-time `DEFAULT_QUEUE`, and marks the config
+/// so those requests can no longer leave the PDA out.
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    config_account_data: &mut [u8],
+    vrf_queue_config_account_data: &mut [u8],
+    vrf_queue_config_bump: u8,
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let args = SetVrfQueueArgsCompat::parse(ix_data)?;
+
+    let mut config = ConfigView::read_from_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+    if args.queue == [0u8; PUBKEY_LEN] {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if vrf_queue_config_account_data.len() < VrfQueueConfigView::LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    vrf_queue_config_account_data[..8].copy_from_slice(&VrfQueueConfigView::DISCRIMINATOR);
+    VrfQueueConfigView {
+        queue: args.queue,
+        bump: vrf_queue_config_bump,
+    }
+    .write_to_account_data(vrf_queue_config_account_data)
+    .map_err(|_| ProgramError::AccountDataTooSmall)?;
+
+    config.mark_vrf_queue_overridden();
+    config
+        .write_to_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::account_discriminator,
+        instruction_layouts::encode_set_vrf_queue,
+        legacy_layouts::{CONFIG_ACCOUNT_LEN, VRF_QUEUE_CONFIG_ACCOUNT_LEN},
+    };
+
+    fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
+        let view = ConfigView {
+            admin,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        };
+
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn stores_queue_and_bump() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut queue_data = [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+        assert!(!ConfigView::read_from_account_data(&config_data).unwrap().vrf_queue_overridden());
+
+        let ix = encode_set_vrf_queue(&SetVrfQueueArgsCompat { queue: [6u8; 32] });
+        process_anchor_bytes(admin, &mut config_data, &mut queue_data, 250, &ix).unwrap();
+
+        let parsed = VrfQueueConfigView::from_account_data(&queue_data).unwrap();
+        assert_eq!(parsed, VrfQueueConfigView { queue: [6u8; 32], bump: 250 });
+        let mut expected_config = ConfigView::read_from_account_data(&sample_config(admin)).unwrap();
+        expected_config.mark_vrf_queue_overridden();
+        assert_eq!(ConfigView::read_from_account_data(&config_data), Ok(expected_config));
+
+        let ix = encode_set_vrf_queue(&SetVrfQueueArgsCompat { queue: [8u8; 32] });
+        process_anchor_bytes(admin, &mut config_data, &mut queue_data, 250, &ix).unwrap();
+        assert_eq!(VrfQueueConfigView::from_account_data(&queue_data).unwrap().queue, [8u8; 32]);
+    }
+
+    #[test]
+    fn rejects_non_admin_and_zero_queue() {
+        let mut config_data = sample_config([7u8; 32]);
+        let mut queue_data = [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+
+        let ix = encode_set_vrf_queue(&SetVrfQueueArgsCompat { queue: [6u8; 32] });
+        assert_eq!(
+            process_anchor_bytes([8u8; 32], &mut config_data, &mut queue_data, 250, &ix),
+            Err(JackpotCompatError::Unauthorized.into())
+        );
+
+        let ix = encode_set_vrf_queue(&SetVrfQueueArgsCompat { queue: [0u8; 32] });
+        assert_eq!(
+            process_anchor_bytes([7u8; 32], &mut config_data, &mut queue_data, 250, &ix),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(queue_data, [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN]);
+        assert_eq!(config_data, sample_config([7u8; 32]));
+    }
+}
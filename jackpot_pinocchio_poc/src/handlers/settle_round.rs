@@ -0,0 +1,143 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    handlers::degen_common::map_layout_err,
+    legacy_layouts::{RoundLifecycleView, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED},
+    randomness_source::{RandomnessSource, VrfRandomness},
+    winner_selection::compute_winning_ticket,
+};
+
+/// Settles a `VrfRequested` round from the randomness already stored on it:
+/// reduces it to a winning ticket, walks the Fenwick tree to the owning
+/// participant, then writes `winning_ticket`, `winner` and `Settled`.
+///
+/// All-zero randomness is treated as "not delivered yet" and rejected.
+pub fn process_settle_round(round_account_data: &mut [u8]) -> Result<(), ProgramError> {
+    let round = RoundLifecycleView::read_from_account_data(round_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if round.status != ROUND_STATUS_VRF_REQUESTED {
+        return Err(JackpotCompatError::RoundNotVrfRequested.into());
+    }
+    let stored = RoundLifecycleView::read_randomness_from_account_data(round_account_data)
+        .map_err(map_layout_err)?;
+    VrfRandomness(stored).randomness().map_err(map_layout_err)?;
+
+    settle_with_stored_randomness(round_account_data, round.total_tickets)
+}
+
+/// Shared tail of `process_settle_round`, `vrf_callback` and
+/// `settle_with_slot_hash`; the caller has
+/// already checked status and written the randomness.
+pub(crate) fn settle_with_stored_randomness(
+    round_account_data: &mut [u8],
+    total_tickets: u64,
+) -> Result<(), ProgramError> {
+    if total_tickets == 0 {
+        return Err(JackpotCompatError::NotEnoughTickets.into());
+    }
+    let randomness = RoundLifecycleView::read_randomness_from_account_data(round_account_data)
+        .map_err(map_layout_err)?;
+    let winning_ticket = compute_winning_ticket(&randomness, total_tickets);
+    let winner_idx = RoundLifecycleView::bit_find_prefix_in_account_data(round_account_data, winning_ticket)
+        .map_err(map_layout_err)?;
+    let winner = RoundLifecycleView::read_participant_pubkey_from_account_data(
+        round_account_data,
+        winner_idx - 1,
+    )
+    .map_err(map_layout_err)?;
+
+    RoundLifecycleView::write_winning_ticket_to_account_data(round_account_data, winning_ticket)
+        .map_err(map_layout_err)?;
+    RoundLifecycleView::write_winner_to_account_data(round_account_data, &winner)
+        .map_err(map_layout_err)?;
+    RoundLifecycleView::write_status_to_account_data(round_account_data, ROUND_STATUS_SETTLED)
+        .map_err(map_layout_err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{anchor_compat::account_discriminator, legacy_layouts::ROUND_ACCOUNT_LEN};
+
+    /// Two participants with 100 tickets each: tickets 1..=100 belong to
+    /// `[11; 32]`, 101..=200 to `[22; 32]`.
+    fn sample_round(status: u8, randomness: [u8; 32]) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView {
+            round_id: 81,
+            status,
+            bump: 201,
+            start_ts: 10,
+            end_ts: 130,
+            first_deposit_ts: 25,
+            total_usdc: 1_250_000,
+            total_tickets: 200,
+            participants_count: 2,
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data[176..208].copy_from_slice(&[11u8; 32]);
+        data[208..240].copy_from_slice(&[22u8; 32]);
+        RoundLifecycleView::write_bit_node_to_account_data(&mut data, 1, 100).unwrap();
+        let mut idx = 2usize;
+        while idx <= 128 {
+            RoundLifecycleView::write_bit_node_to_account_data(&mut data, idx, 200).unwrap();
+            idx <<= 1;
+        }
+        RoundLifecycleView::write_randomness_to_account_data(&mut data, &randomness).unwrap();
+        data
+    }
+
+    fn randomness_with_first_half(value: u128) -> [u8; 32] {
+        let mut randomness = [0u8; 32];
+        randomness[..16].copy_from_slice(&value.to_le_bytes());
+        randomness
+    }
+
+    #[test]
+    fn settles_to_second_participant_for_known_randomness() {
+        // 149 % 200 + 1 = ticket 150, inside the second participant's range.
+        let mut round_data = sample_round(ROUND_STATUS_VRF_REQUESTED, randomness_with_first_half(149));
+
+        process_settle_round(&mut round_data).unwrap();
+
+        let parsed = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
+        assert_eq!(parsed.status, ROUND_STATUS_SETTLED);
+        assert_eq!(RoundLifecycleView::read_winning_ticket_from_account_data(&round_data).unwrap(), 150);
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(&round_data).unwrap(), [22u8; 32]);
+    }
+
+    #[test]
+    fn boundary_ticket_belongs_to_first_participant() {
+        let mut round_data = sample_round(ROUND_STATUS_VRF_REQUESTED, randomness_with_first_half(99));
+
+        process_settle_round(&mut round_data).unwrap();
+
+        assert_eq!(RoundLifecycleView::read_winning_ticket_from_account_data(&round_data).unwrap(), 100);
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(&round_data).unwrap(), [11u8; 32]);
+    }
+
+    #[test]
+    fn rejects_round_not_vrf_requested() {
+        let mut round_data = sample_round(ROUND_STATUS_SETTLED, randomness_with_first_half(149));
+        let before = round_data;
+
+        let err = process_settle_round(&mut round_data).unwrap_err();
+        assert_eq!(err, JackpotCompatError::RoundNotVrfRequested.into());
+        assert_eq!(round_data, before);
+    }
+
+    #[test]
+    fn rejects_missing_randomness() {
+        let mut round_data = sample_round(ROUND_STATUS_VRF_REQUESTED, [0u8; 32]);
+        let before = round_data;
+
+        let err = process_settle_round(&mut round_data).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+        assert_eq!(round_data, before);
+    }
+}
@@ -0,0 +1,183 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    handlers::settle_round::settle_with_stored_randomness,
+    instruction_layouts::parse_round_id_ix,
+    legacy_layouts::{ConfigView, RoundLifecycleView, PUBKEY_LEN, ROUND_STATUS_VRF_REQUESTED},
+    randomness_source::{RandomnessSource, SlotHashRandomness},
+};
+
+/// Admin-only settlement of a `VrfRequested` round from the SlotHashes
+/// sysvar, for when the VRF oracle never calls back. Pinocchio-only; the
+/// Anchor program has no equivalent.
+///
+/// Opens `config.vrf_timeout_sec()` after the round's `end_ts`: rounds record
+/// no VRF request time, and `request_vrf` cannot run before `end_ts`. A zero
+/// timeout keeps the fallback disabled.
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    now_ts: i64,
+    config_account_data: &[u8],
+    round_account_data: &mut [u8],
+    slot_hashes_account_data: &[u8],
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let round_id = parse_round_id_ix(ix_data, "settle_with_slot_hash")?;
+    let config = ConfigView::read_from_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let round = RoundLifecycleView::read_from_account_data(round_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+    if round.status != ROUND_STATUS_VRF_REQUESTED {
+        return Err(JackpotCompatError::RoundNotVrfRequested.into());
+    }
+    require_vrf_timeout_elapsed(&config, round.end_ts, now_ts)?;
+
+    let randomness = SlotHashRandomness {
+        sysvar_data: slot_hashes_account_data,
+        round_id: round_id.get(),
+    }
+    .randomness()
+    .map_err(|_| ProgramError::InvalidAccountData)?;
+    RoundLifecycleView::write_randomness_to_account_data(round_account_data, &randomness)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    settle_with_stored_randomness(round_account_data, round.total_tickets)
+}
+
+fn require_vrf_timeout_elapsed(config: &ConfigView, end_ts: i64, now_ts: i64) -> Result<(), ProgramError> {
+    let timeout = config.vrf_timeout_sec();
+    if timeout == 0 || now_ts < end_ts.saturating_add(i64::from(timeout)) {
+        return Err(JackpotCompatError::VrfTimeoutNotReached.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::account_discriminator,
+        instruction_layouts::{encode_round_id_ix, RoundId},
+        legacy_layouts::{CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_LOCKED, ROUND_STATUS_SETTLED},
+    };
+
+    const ADMIN: [u8; 32] = [7u8; 32];
+    const END_TS: i64 = 130;
+    const TIMEOUT: u32 = 3_600;
+
+    fn sample_config(vrf_timeout_sec: u32) -> [u8; CONFIG_ACCOUNT_LEN] {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        let mut config = ConfigView {
+            admin: ADMIN,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        };
+        config.set_vrf_timeout_sec(vrf_timeout_sec);
+        config.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    /// Tickets 1..=100 belong to `[11; 32]`, 101..=200 to `[22; 32]`.
+    fn sample_round(status: u8) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView {
+            round_id: 81,
+            status,
+            bump: 201,
+            start_ts: 10,
+            end_ts: END_TS,
+            first_deposit_ts: 25,
+            total_usdc: 1_250_000,
+            total_tickets: 0,
+            participants_count: 2,
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        RoundLifecycleView::write_participant_pubkey_to_account_data(&mut data, 0, &[11u8; 32]).unwrap();
+        RoundLifecycleView::write_participant_pubkey_to_account_data(&mut data, 1, &[22u8; 32]).unwrap();
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 1, 100).unwrap();
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 2, 100).unwrap();
+        data
+    }
+
+    /// One SlotHashes entry whose hash reduces, for round 81, to ticket 150.
+    fn slot_hashes() -> Vec<u8> {
+        let mut hash = [0u8; 32];
+        hash[0] = 149 ^ 81;
+        let mut data = 1u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&9_000u64.to_le_bytes());
+        data.extend_from_slice(&hash);
+        data
+    }
+
+    fn run(admin: [u8; 32], now_ts: i64, config: &[u8], round: &mut [u8]) -> Result<(), ProgramError> {
+        let ix = encode_round_id_ix("settle_with_slot_hash", RoundId(81));
+        process_anchor_bytes(admin, now_ts, config, round, &slot_hashes(), &ix)
+    }
+
+    #[test]
+    fn settles_from_newest_slot_hash_after_timeout() {
+        let config = sample_config(TIMEOUT);
+        let mut round = sample_round(ROUND_STATUS_VRF_REQUESTED);
+
+        run(ADMIN, END_TS + i64::from(TIMEOUT), &config, &mut round).unwrap();
+
+        let parsed = RoundLifecycleView::read_from_account_data(&round).unwrap();
+        assert_eq!(parsed.status, ROUND_STATUS_SETTLED);
+        assert_eq!(RoundLifecycleView::read_winning_ticket_from_account_data(&round).unwrap(), 150);
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(&round).unwrap(), [22u8; 32]);
+    }
+
+    #[test]
+    fn rejects_before_timeout_or_with_fallback_disabled() {
+        for (vrf_timeout_sec, now_ts) in [(TIMEOUT, END_TS + i64::from(TIMEOUT) - 1), (0, i64::MAX)] {
+            let config = sample_config(vrf_timeout_sec);
+            let mut round = sample_round(ROUND_STATUS_VRF_REQUESTED);
+            let before = round;
+
+            assert_eq!(
+                run(ADMIN, now_ts, &config, &mut round),
+                Err(JackpotCompatError::VrfTimeoutNotReached.into())
+            );
+            assert_eq!(round, before);
+        }
+    }
+
+    #[test]
+    fn rejects_non_admin() {
+        let config = sample_config(TIMEOUT);
+        let mut round = sample_round(ROUND_STATUS_VRF_REQUESTED);
+
+        assert_eq!(
+            run([8u8; 32], END_TS + i64::from(TIMEOUT), &config, &mut round),
+            Err(JackpotCompatError::Unauthorized.into())
+        );
+    }
+
+    #[test]
+    fn rejects_round_without_pending_vrf() {
+        let config = sample_config(TIMEOUT);
+        for status in [ROUND_STATUS_LOCKED, ROUND_STATUS_SETTLED] {
+            let mut round = sample_round(status);
+            assert_eq!(
+                run(ADMIN, END_TS + i64::from(TIMEOUT), &config, &mut round),
+                Err(JackpotCompatError::RoundNotVrfRequested.into())
+            );
+        }
+    }
+}
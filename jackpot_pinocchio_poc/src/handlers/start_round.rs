@@ -4,28 +4,34 @@ use crate::{
     anchor_compat::account_discriminator,
     errors::JackpotCompatError,
     handlers::degen_common::map_layout_err,
-    instruction_layouts::parse_round_id_ix,
+    instruction_layouts::{parse_round_id_ix, RoundId},
     legacy_layouts::{
         ConfigView, RoundLifecycleView, TokenAccountCoreView, ROUND_ACCOUNT_LEN, ROUND_STATUS_OPEN,
     },
 };
 
+/// Initializes a zeroed `round` account. `round_id` must be >= 1
+/// ([`RoundId::MIN`]); round 0 is rejected as `InvalidInstructionData`.
+/// It must also be the config's `last_round_id + 1`, which is then advanced
+/// (see [`ConfigView::require_next_round_id`]).
 pub fn process_anchor_bytes(
     round_pubkey: [u8; 32],
     vault_pubkey: [u8; 32],
     usdc_mint_pubkey: [u8; 32],
     round_bump: u8,
     current_unix_timestamp: i64,
-    config_account_data: &[u8],
+    config_account_data: &mut [u8],
     round_account_data: &mut [u8],
     vault_account_data: &[u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let round_id = parse_round_id_ix(ix_data, "start_round").map_err(|_| ProgramError::InvalidInstructionData)?;
-    let config = ConfigView::read_from_account_data(config_account_data).map_err(map_layout_err)?;
-    if config.paused {
-        return Err(JackpotCompatError::Paused.into());
+    let round_id = parse_round_id_ix(ix_data, "start_round")?;
+    if round_id < RoundId::MIN {
+        return Err(ProgramError::InvalidInstructionData);
     }
+    let mut config = ConfigView::read_from_account_data(config_account_data).map_err(map_layout_err)?;
+    config.require_not_paused().map_err(map_layout_err)?;
+    config.require_next_round_id(round_id.get()).map_err(map_layout_err)?;
     if config.usdc_mint != usdc_mint_pubkey {
         return Err(JackpotCompatError::InvalidVault.into());
     }
@@ -41,7 +47,7 @@ pub fn process_anchor_bytes(
 
     round_account_data[..8].copy_from_slice(&account_discriminator("Round"));
     RoundLifecycleView {
-        round_id,
+        round_id: round_id.get(),
         status: ROUND_STATUS_OPEN,
         bump: round_bump,
         start_ts: current_unix_timestamp,
@@ -56,6 +62,9 @@ pub fn process_anchor_bytes(
     RoundLifecycleView::write_vault_pubkey_to_account_data(round_account_data, &vault_pubkey)
         .map_err(map_layout_err)?;
 
+    config.set_last_round_id(round_id.get());
+    config.write_to_account_data(config_account_data).map_err(map_layout_err)?;
+
     Ok(())
 }
 
@@ -69,10 +78,10 @@ mod tests {
         },
     };
 
-    fn config_data(usdc_mint: [u8; 32], paused: bool) -> [u8; CONFIG_ACCOUNT_LEN] {
+    fn config_data(usdc_mint: [u8; 32], paused: bool, last_round_id: u64) -> [u8; CONFIG_ACCOUNT_LEN] {
         let mut data = [0u8; CONFIG_ACCOUNT_LEN];
         data[..8].copy_from_slice(&account_discriminator("Config"));
-        ConfigView {
+        let mut config = ConfigView {
             admin: [7u8; 32],
             usdc_mint,
             treasury_usdc_ata: [3u8; 32],
@@ -85,9 +94,9 @@ mod tests {
             bump: 254,
             max_deposit_per_user: 1_000_000,
             reserved: [0u8; 24],
-        }
-        .write_to_account_data(&mut data)
-        .unwrap();
+        };
+        config.set_last_round_id(last_round_id);
+        config.write_to_account_data(&mut data).unwrap();
         data
     }
 
@@ -103,7 +112,7 @@ mod tests {
         let round_pubkey = [8u8; 32];
         let vault_pubkey = [9u8; 32];
         let usdc_mint = [2u8; 32];
-        let config = config_data(usdc_mint, false);
+        let mut config = config_data(usdc_mint, false, 80);
         let vault = token_account_core(usdc_mint, round_pubkey);
         let mut round = [0u8; ROUND_ACCOUNT_LEN];
 
@@ -117,7 +126,7 @@ mod tests {
             usdc_mint,
             203,
             1_234,
-            &config,
+            &mut config,
             &mut round,
             &vault,
             &ix,
@@ -140,7 +149,7 @@ mod tests {
         let round_pubkey = [8u8; 32];
         let vault_pubkey = [9u8; 32];
         let usdc_mint = [2u8; 32];
-        let config = config_data(usdc_mint, true);
+        let mut config = config_data(usdc_mint, true, 80);
         let vault = token_account_core(usdc_mint, round_pubkey);
         let mut round = [0u8; ROUND_ACCOUNT_LEN];
 
@@ -154,7 +163,7 @@ mod tests {
             usdc_mint,
             203,
             1_234,
-            &config,
+            &mut config,
             &mut round,
             &vault,
             &ix,
@@ -163,4 +172,61 @@ mod tests {
 
         assert_eq!(err, JackpotCompatError::Paused.into());
     }
+
+    #[test]
+    fn rejects_round_zero_and_accepts_round_one() {
+        let round_pubkey = [8u8; 32];
+        let vault_pubkey = [9u8; 32];
+        let usdc_mint = [2u8; 32];
+        let mut config = config_data(usdc_mint, false, 0);
+        let vault = token_account_core(usdc_mint, round_pubkey);
+
+        let mut round = [0u8; ROUND_ACCOUNT_LEN];
+        let err = start(round_pubkey, vault_pubkey, &mut config, &mut round, &vault, 0).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+        assert!(round.iter().all(|byte| *byte == 0));
+
+        start(round_pubkey, vault_pubkey, &mut config, &mut round, &vault, 1).unwrap();
+        assert_eq!(RoundLifecycleView::read_from_account_data(&round).unwrap().round_id, 1);
+    }
+
+    #[test]
+    fn requires_sequential_round_ids() {
+        let round_pubkey = [8u8; 32];
+        let vault_pubkey = [9u8; 32];
+        let usdc_mint = [2u8; 32];
+        let mut config = config_data(usdc_mint, false, 0);
+        let vault = token_account_core(usdc_mint, round_pubkey);
+
+        for round_id in 1..=3u64 {
+            let mut round = [0u8; ROUND_ACCOUNT_LEN];
+            start(round_pubkey, vault_pubkey, &mut config, &mut round, &vault, round_id).unwrap();
+            assert_eq!(ConfigView::read_from_account_data(&config).unwrap().last_round_id(), round_id);
+        }
+
+        let mut round = [0u8; ROUND_ACCOUNT_LEN];
+        let repeat = start(round_pubkey, vault_pubkey, &mut config, &mut round, &vault, 3).unwrap_err();
+        assert_eq!(repeat, ProgramError::InvalidInstructionData);
+        #[cfg(not(feature = "devnet"))]
+        {
+            let gap = start(round_pubkey, vault_pubkey, &mut config, &mut round, &vault, 5).unwrap_err();
+            assert_eq!(gap, ProgramError::InvalidInstructionData);
+        }
+        assert!(round.iter().all(|byte| *byte == 0));
+        assert_eq!(ConfigView::read_from_account_data(&config).unwrap().last_round_id(), 3);
+    }
+
+    fn start(
+        round_pubkey: [u8; 32],
+        vault_pubkey: [u8; 32],
+        config: &mut [u8],
+        round: &mut [u8],
+        vault: &[u8],
+        round_id: u64,
+    ) -> Result<(), ProgramError> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("start_round"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+        process_anchor_bytes(round_pubkey, vault_pubkey, [2u8; 32], 203, 1_234, config, round, vault, &ix)
+    }
 }
@@ -11,8 +11,7 @@ pub fn process_anchor_bytes(
     config_account_data: &mut [u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let args = TransferAdminArgsCompat::parse(ix_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = TransferAdminArgsCompat::parse(ix_data)?;
 
     let mut config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -6,13 +6,14 @@ use crate::{
     legacy_layouts::{ConfigView, PUBKEY_LEN},
 };
 
+use super::degen_common::map_layout_err;
+
 pub fn process_anchor_bytes(
     admin_pubkey: [u8; PUBKEY_LEN],
     config_account_data: &mut [u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let args = UpdateConfigArgsCompat::parse(ix_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = UpdateConfigArgsCompat::parse(ix_data)?;
 
     let mut config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -22,21 +23,12 @@ pub fn process_anchor_bytes(
     }
 
     if let Some(v) = args.fee_bps {
-        if v > 10_000 {
-            return Err(JackpotCompatError::InvalidFeeBps.into());
-        }
         config.fee_bps = v;
     }
     if let Some(v) = args.ticket_unit {
-        if v == 0 {
-            return Err(JackpotCompatError::InvalidTicketUnit.into());
-        }
         config.ticket_unit = v;
     }
     if let Some(v) = args.round_duration_sec {
-        if v == 0 {
-            return Err(JackpotCompatError::InvalidRoundDuration.into());
-        }
         config.round_duration_sec = v;
     }
     if let Some(v) = args.min_participants {
@@ -51,7 +43,14 @@ pub fn process_anchor_bytes(
     if let Some(v) = args.max_deposit_per_user {
         config.max_deposit_per_user = v;
     }
+    if let Some(v) = args.vrf_timeout_sec {
+        config.set_vrf_timeout_sec(v);
+    }
+    if let Some(v) = args.last_round_id {
+        config.advance_last_round_id(v).map_err(map_layout_err)?;
+    }
 
+    config.validate().map_err(map_layout_err)?;
     config
         .write_to_account_data(config_account_data)
         .map_err(|_| ProgramError::AccountDataTooSmall)?;
@@ -64,7 +63,12 @@ mod tests {
     use super::*;
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
-        legacy_layouts::{ConfigView, CONFIG_ACCOUNT_LEN},
+        handlers,
+        instruction_layouts::{encode_round_id_ix, encode_update_config, RoundId},
+        legacy_layouts::{
+            ConfigView, CONFIG_ACCOUNT_LEN, MAX_VRF_TIMEOUT_SEC, MIN_VRF_TIMEOUT_SEC, ROUND_ACCOUNT_LEN,
+            TOKEN_ACCOUNT_CORE_LEN,
+        },
     };
 
     fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
@@ -122,4 +126,138 @@ mod tests {
         assert!(parsed.paused);
         assert_eq!(parsed.max_deposit_per_user, 2_000_000);
     }
+
+    #[test]
+    fn update_config_is_allowed_while_paused() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let paused = ConfigView {
+            paused: true,
+            ..ConfigView::read_from_account_data(&config_data).unwrap()
+        };
+        paused.write_to_account_data(&mut config_data).unwrap();
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.extend_from_slice(&[0u8; 5]);
+        ix.push(1);
+        ix.push(0);
+        ix.push(0);
+
+        process_anchor_bytes(admin, &mut config_data, &ix).unwrap();
+
+        let parsed = ConfigView::read_from_account_data(&config_data).unwrap();
+        assert!(!parsed.paused);
+        assert_eq!(parsed.fee_bps, 25);
+    }
+
+    #[test]
+    fn rejects_update_that_breaks_config_invariants() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let before = config_data;
+
+        // fee_bps = 60 (valid) alongside ticket_unit = 0: nothing is persisted.
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.push(1);
+        ix.extend_from_slice(&60u16.to_le_bytes());
+        ix.push(1);
+        ix.extend_from_slice(&0u64.to_le_bytes());
+        ix.extend_from_slice(&[0u8; 5]);
+
+        let err = process_anchor_bytes(admin, &mut config_data, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTicketUnit.into());
+        assert_eq!(config_data, before);
+    }
+
+    #[test]
+    fn all_absent_update_is_a_no_op() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let before = config_data;
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.extend_from_slice(&[0u8; 7]);
+
+        process_anchor_bytes(admin, &mut config_data, &ix).unwrap();
+        assert_eq!(config_data, before);
+    }
+
+    #[test]
+    fn sets_vrf_timeout_within_bounds() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let update = |vrf_timeout_sec| {
+            encode_update_config(&UpdateConfigArgsCompat {
+                vrf_timeout_sec: Some(vrf_timeout_sec),
+                ..UpdateConfigArgsCompat::default()
+            })
+        };
+
+        process_anchor_bytes(admin, &mut config_data, &update(3_600)).unwrap();
+        assert_eq!(ConfigView::read_from_account_data(&config_data).unwrap().vrf_timeout_sec(), 3_600);
+
+        let before = config_data;
+        for vrf_timeout_sec in [MIN_VRF_TIMEOUT_SEC - 1, MAX_VRF_TIMEOUT_SEC + 1] {
+            assert_eq!(
+                process_anchor_bytes(admin, &mut config_data, &update(vrf_timeout_sec)),
+                Err(JackpotCompatError::InvalidFallbackTimeout.into())
+            );
+            assert_eq!(config_data, before);
+        }
+
+        process_anchor_bytes(admin, &mut config_data, &update(0)).unwrap();
+        assert_eq!(ConfigView::read_from_account_data(&config_data).unwrap().vrf_timeout_sec(), 0);
+    }
+
+    #[test]
+    fn seeds_last_round_id_on_config_that_predates_the_counter() {
+        let admin = [7u8; 32];
+        // Deployed before `last_round_id` existed: rounds up to 147 were
+        // started, but the reserved bytes are still zero.
+        let mut config_data = sample_config(admin);
+        assert_eq!(ConfigView::read_from_account_data(&config_data).unwrap().last_round_id(), 0);
+        let seed = |last_round_id| {
+            encode_update_config(&UpdateConfigArgsCompat {
+                last_round_id: Some(last_round_id),
+                ..UpdateConfigArgsCompat::default()
+            })
+        };
+
+        process_anchor_bytes(admin, &mut config_data, &seed(147)).unwrap();
+
+        let round_pubkey = [8u8; 32];
+        let mut vault = [0u8; TOKEN_ACCOUNT_CORE_LEN];
+        vault[..32].copy_from_slice(&[2u8; 32]);
+        vault[32..64].copy_from_slice(&round_pubkey);
+        let start = |config_data: &mut [u8], round_id: u64| {
+            let mut round = [0u8; ROUND_ACCOUNT_LEN];
+            handlers::start_round::process_anchor_bytes(
+                round_pubkey,
+                [9u8; 32],
+                [2u8; 32],
+                201,
+                1_234,
+                config_data,
+                &mut round,
+                &vault,
+                &encode_round_id_ix("start_round", RoundId(round_id)),
+            )
+        };
+        assert_eq!(start(&mut config_data, 1), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(start(&mut config_data, 147), Err(ProgramError::InvalidInstructionData));
+        start(&mut config_data, 148).unwrap();
+
+        // The counter only moves forward, even for the admin.
+        let before = config_data;
+        assert_eq!(
+            process_anchor_bytes(admin, &mut config_data, &seed(147)),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(config_data, before);
+        process_anchor_bytes(admin, &mut config_data, &seed(148)).unwrap();
+        assert_eq!(ConfigView::read_from_account_data(&config_data).unwrap().last_round_id(), 148);
+    }
 }
@@ -0,0 +1,140 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::JackpotCompatError,
+    instruction_layouts::UpdateDegenTimeoutArgsCompat,
+    legacy_layouts::{ConfigView, DegenConfigView, DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC, PUBKEY_LEN},
+};
+
+/// Rewrites only `fallback_timeout_sec`; the executor and bump set by
+/// `upsert_degen_config` are left as they are.
+pub fn process_anchor_bytes(
+    admin_pubkey: [u8; PUBKEY_LEN],
+    config_account_data: &[u8],
+    degen_config_account_data: &mut [u8],
+    ix_data: &[u8],
+) -> Result<(), ProgramError> {
+    let args = UpdateDegenTimeoutArgsCompat::parse(ix_data)?;
+
+    let config = ConfigView::read_from_account_data(config_account_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if config.admin != admin_pubkey {
+        return Err(JackpotCompatError::Unauthorized.into());
+    }
+
+    let timeout = if args.fallback_timeout_sec == 0 {
+        DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC
+    } else {
+        args.fallback_timeout_sec
+    };
+    DegenConfigView::validate_timeout(timeout)
+        .map_err(|_| JackpotCompatError::InvalidFallbackTimeout)?;
+    DegenConfigView::write_fallback_timeout_to_account_data(degen_config_account_data, timeout)
+        .map_err(|_| ProgramError::AccountDataTooSmall)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        anchor_compat::{account_discriminator, instruction_discriminator},
+        legacy_layouts::{CONFIG_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN},
+    };
+
+    fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
+        let view = ConfigView {
+            admin,
+            usdc_mint: [2u8; 32],
+            treasury_usdc_ata: [3u8; 32],
+            fee_bps: 25,
+            ticket_unit: 10_000,
+            round_duration_sec: 120,
+            min_participants: 2,
+            min_total_tickets: 200,
+            paused: false,
+            bump: 254,
+            max_deposit_per_user: 1_000_000,
+            reserved: [0u8; 24],
+        };
+
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Config"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn sample_degen_config() -> [u8; DEGEN_CONFIG_ACCOUNT_LEN] {
+        let view = DegenConfigView {
+            executor: [9u8; 32],
+            fallback_timeout_sec: 300,
+            bump: 201,
+            reserved: [0u8; 27],
+        };
+
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn update_ix(timeout: u32) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+        ix.extend_from_slice(&timeout.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn updates_timeout_and_preserves_executor() {
+        let admin = [7u8; 32];
+        let config_data = sample_config(admin);
+        let mut degen_data = sample_degen_config();
+
+        process_anchor_bytes(admin, &config_data, &mut degen_data, &update_ix(900)).unwrap();
+
+        let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(parsed.executor, [9u8; 32]);
+        assert_eq!(parsed.bump, 201);
+        assert_eq!(parsed.fallback_timeout_sec, 900);
+        assert_eq!(&degen_data[..8], &account_discriminator("DegenConfig"));
+    }
+
+    #[test]
+    fn zero_timeout_falls_back_to_default() {
+        let admin = [7u8; 32];
+        let config_data = sample_config(admin);
+        let mut degen_data = sample_degen_config();
+        DegenConfigView::write_fallback_timeout_to_account_data(&mut degen_data, 60).unwrap();
+
+        process_anchor_bytes(admin, &config_data, &mut degen_data, &update_ix(0)).unwrap();
+
+        let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(parsed.fallback_timeout_sec, DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC);
+    }
+
+    #[test]
+    fn rejects_out_of_range_timeout() {
+        let admin = [7u8; 32];
+        let config_data = sample_config(admin);
+        let mut degen_data = sample_degen_config();
+        let before = degen_data;
+
+        let err = process_anchor_bytes(admin, &config_data, &mut degen_data, &update_ix(1)).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidFallbackTimeout.into());
+        assert_eq!(degen_data, before);
+    }
+
+    #[test]
+    fn rejects_non_admin() {
+        let config_data = sample_config([7u8; 32]);
+        let mut degen_data = sample_degen_config();
+        let before = degen_data;
+
+        let err = process_anchor_bytes([8u8; 32], &config_data, &mut degen_data, &update_ix(900)).unwrap_err();
+        assert_eq!(err, JackpotCompatError::Unauthorized.into());
+        assert_eq!(degen_data, before);
+    }
+}
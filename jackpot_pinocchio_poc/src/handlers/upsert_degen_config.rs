@@ -2,13 +2,17 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     anchor_compat::account_discriminator,
+    degen_pool_compat::degen_pool_len,
     errors::JackpotCompatError,
     instruction_layouts::UpsertDegenConfigArgsCompat,
-    legacy_layouts::{ConfigView, DegenConfigView, DEGEN_CONFIG_ACCOUNT_LEN, PUBKEY_LEN},
+    legacy_layouts::{AccountLayout, ConfigView, DegenConfigView, DEGEN_CONFIG_ACCOUNT_LEN, PUBKEY_LEN},
 };
 
 pub const DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC: u32 = 300;
 
+/// Creates or overwrites the degen config. Settings kept in `reserved`
+/// survive an update unless the instruction sets them, so omitting
+/// `candidate_window` keeps the stored one.
 pub fn process_anchor_bytes(
     admin_pubkey: [u8; PUBKEY_LEN],
     config_account_data: &[u8],
@@ -16,8 +20,7 @@ pub fn process_anchor_bytes(
     degen_config_bump: u8,
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let args = UpsertDegenConfigArgsCompat::parse(ix_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let args = UpsertDegenConfigArgsCompat::parse(ix_data)?;
 
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
@@ -32,19 +35,29 @@ pub fn process_anchor_bytes(
         return Err(ProgramError::AccountDataTooSmall);
     }
 
+    let reserved = DegenConfigView::from_account_data(degen_config_account_data)
+        .map(|existing| existing.reserved)
+        .unwrap_or([0u8; 27]);
     degen_config_account_data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
     let timeout = if args.fallback_timeout_sec == 0 {
         DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC
     } else {
         args.fallback_timeout_sec
     };
+    DegenConfigView::validate_timeout(timeout)
+        .map_err(|_| JackpotCompatError::InvalidFallbackTimeout)?;
 
-    let view = DegenConfigView {
+    let mut view = DegenConfigView {
         executor: args.executor,
         fallback_timeout_sec: timeout,
         bump: degen_config_bump,
-        reserved: [0u8; 27],
+        reserved,
     };
+    if let Some(window) = args.candidate_window {
+        DegenConfigView::validate_candidate_window(window, degen_pool_len())
+            .map_err(|_| JackpotCompatError::InvalidDegenCandidate)?;
+        view.set_candidate_window(window);
+    }
     view.write_to_account_data(degen_config_account_data)
         .map_err(|_| ProgramError::AccountDataTooSmall)?;
 
@@ -56,7 +69,11 @@ mod tests {
     use super::*;
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
-        legacy_layouts::{ConfigView, CONFIG_ACCOUNT_LEN},
+        instruction_layouts::encode_upsert_degen_config,
+        legacy_layouts::{
+            ConfigView, CONFIG_ACCOUNT_LEN, DEGEN_CANDIDATE_WINDOW, MAX_FALLBACK_TIMEOUT_SEC,
+            MIN_FALLBACK_TIMEOUT_SEC,
+        },
     };
 
     fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
@@ -99,4 +116,74 @@ mod tests {
         assert_eq!(parsed.fallback_timeout_sec, DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC);
         assert_eq!(parsed.bump, 201);
     }
+
+    fn upsert_with_timeout(timeout: u32) -> Result<[u8; DEGEN_CONFIG_ACCOUNT_LEN], ProgramError> {
+        let admin = [7u8; 32];
+        let config_data = sample_config(admin);
+        let mut degen_data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
+        ix.extend_from_slice(&[9u8; 32]);
+        ix.extend_from_slice(&timeout.to_le_bytes());
+
+        process_anchor_bytes(admin, &config_data, &mut degen_data, 201, &ix)?;
+        Ok(degen_data)
+    }
+
+    #[test]
+    fn accepts_timeouts_within_bounds() {
+        for timeout in [MIN_FALLBACK_TIMEOUT_SEC, 3_600, MAX_FALLBACK_TIMEOUT_SEC] {
+            let degen_data = upsert_with_timeout(timeout).unwrap();
+            let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+            assert_eq!(parsed.fallback_timeout_sec, timeout);
+        }
+    }
+
+    #[test]
+    fn rejects_timeouts_outside_bounds() {
+        for timeout in [1, MIN_FALLBACK_TIMEOUT_SEC - 1, MAX_FALLBACK_TIMEOUT_SEC + 1, u32::MAX] {
+            assert_eq!(
+                upsert_with_timeout(timeout).unwrap_err(),
+                JackpotCompatError::InvalidFallbackTimeout.into()
+            );
+        }
+    }
+
+    #[test]
+    fn sets_candidate_window_and_keeps_reserved_on_update() {
+        let admin = [7u8; 32];
+        let config_data = sample_config(admin);
+        let mut degen_data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        let upsert = |candidate_window| {
+            encode_upsert_degen_config(&UpsertDegenConfigArgsCompat {
+                executor: [9u8; 32],
+                fallback_timeout_sec: 600,
+                candidate_window,
+            })
+        };
+
+        process_anchor_bytes(admin, &config_data, &mut degen_data, 201, &upsert(None)).unwrap();
+        let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(parsed.candidate_window(), DEGEN_CANDIDATE_WINDOW);
+
+        process_anchor_bytes(admin, &config_data, &mut degen_data, 201, &upsert(Some(12))).unwrap();
+        let mut stored = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(stored.candidate_window(), 12);
+
+        // Bytes this handler does not know about survive too.
+        stored.reserved[26] = 0xab;
+        stored.write_to_account_data(&mut degen_data).unwrap();
+        process_anchor_bytes(admin, &config_data, &mut degen_data, 201, &upsert(None)).unwrap();
+        let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(parsed.candidate_window(), 12);
+        assert_eq!(parsed.reserved, stored.reserved);
+
+        let before = degen_data;
+        assert_eq!(
+            process_anchor_bytes(admin, &config_data, &mut degen_data, 201, &upsert(Some(0))),
+            Err(JackpotCompatError::InvalidDegenCandidate.into())
+        );
+        assert_eq!(degen_data, before);
+    }
 }
@@ -2,10 +2,9 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     errors::JackpotCompatError,
+    handlers::settle_round::settle_with_stored_randomness,
     instruction_layouts::parse_vrf_callback_ix,
-    legacy_layouts::{
-        ConfigView, RoundLifecycleView, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED,
-    },
+    legacy_layouts::{ConfigView, RoundLifecycleView, ROUND_STATUS_VRF_REQUESTED},
 };
 
 pub fn process_anchor_bytes(
@@ -13,7 +12,7 @@ pub fn process_anchor_bytes(
     round_account_data: &mut [u8],
     ix_data: &[u8],
 ) -> Result<(), ProgramError> {
-    let randomness = parse_vrf_callback_ix(ix_data).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let randomness = parse_vrf_callback_ix(ix_data)?;
     let config = ConfigView::read_from_account_data(config_account_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round = RoundLifecycleView::read_from_account_data(round_account_data)
@@ -29,35 +28,9 @@ pub fn process_anchor_bytes(
         return Err(JackpotCompatError::NotEnoughTickets.into());
     }
 
-    let mut bytes16 = [0u8; 16];
-    bytes16.copy_from_slice(&randomness[..16]);
-    let randomness_u128 = u128::from_le_bytes(bytes16);
-    let winning_ticket = (randomness_u128 % (round.total_tickets as u128)) as u64 + 1;
-    let winner_idx = RoundLifecycleView::bit_find_prefix_in_account_data(round_account_data, winning_ticket)
-        .map_err(map_layout_err)?;
-    let winner = RoundLifecycleView::read_participant_pubkey_from_account_data(
-        round_account_data,
-        winner_idx - 1,
-    )
-    .map_err(map_layout_err)?;
-
     RoundLifecycleView::write_randomness_to_account_data(round_account_data, &randomness)
-        .map_err(map_layout_err)?;
-    RoundLifecycleView::write_winning_ticket_to_account_data(round_account_data, winning_ticket)
-        .map_err(map_layout_err)?;
-    RoundLifecycleView::write_winner_to_account_data(round_account_data, &winner)
-        .map_err(map_layout_err)?;
-    RoundLifecycleView::write_status_to_account_data(round_account_data, ROUND_STATUS_SETTLED)
-        .map_err(map_layout_err)?;
-
-    Ok(())
-}
-
-fn map_layout_err(err: crate::legacy_layouts::LayoutError) -> ProgramError {
-    match err {
-        crate::legacy_layouts::LayoutError::MathOverflow => JackpotCompatError::MathOverflow.into(),
-        _ => ProgramError::InvalidAccountData,
-    }
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    settle_with_stored_randomness(round_account_data, round.total_tickets)
 }
 
 #[cfg(test)]
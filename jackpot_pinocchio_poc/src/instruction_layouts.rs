@@ -1,37 +1,95 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
 use crate::{
     anchor_compat::instruction_discriminator,
-    legacy_layouts::{LayoutError, PUBKEY_LEN},
+    legacy_layouts::PUBKEY_LEN,
 };
 
 pub const UPSERT_DEGEN_CONFIG_IX_LEN: usize = 8 + PUBKEY_LEN + 4;
+pub const UPDATE_DEGEN_TIMEOUT_IX_LEN: usize = 8 + 4;
 pub const INIT_CONFIG_IX_LEN: usize = 8 + PUBKEY_LEN + PUBKEY_LEN + 2 + 8 + 4 + 2 + 8 + 8;
 pub const TRANSFER_ADMIN_IX_LEN: usize = 8 + PUBKEY_LEN;
+pub const SET_VRF_QUEUE_IX_LEN: usize = 8 + PUBKEY_LEN;
+pub const SET_FEE_SPLIT_IX_LEN: usize = 8 + PUBKEY_LEN + 2;
 pub const ROUND_ID_IX_LEN: usize = 8 + 8;
 pub const ROUND_ID_U8_IX_LEN: usize = 8 + 8 + 1;
 pub const VRF_CALLBACK_IX_LEN: usize = 8 + 32;
+#[cfg(feature = "devnet")]
+pub const MOCK_SETTLE_IX_LEN: usize = 8 + 8 + 32;
 pub const DEGEN_VRF_CALLBACK_IX_LEN: usize = 8 + 32;
 pub const BEGIN_DEGEN_EXECUTION_IX_LEN: usize = 8 + 8 + 1 + 4 + 8 + 32;
 pub const CLAIM_DEGEN_IX_LEN: usize = 8 + 8 + 1 + 4;
 pub const DEPOSIT_ANY_IX_LEN: usize = 8 + 8 + 8 + 8;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum InstructionLayoutError {
-    SliceTooShort,
-    WrongDiscriminator,
+pub enum InstructionParseError {
+    TooShort,
+    BadDiscriminator,
+    TrailingBytes,
     InvalidOptionTag,
     InvalidBool,
 }
 
-impl From<LayoutError> for InstructionLayoutError {
-    fn from(value: LayoutError) -> Self {
-        match value {
-            LayoutError::SliceTooShort => Self::SliceTooShort,
-            LayoutError::InvalidBool => Self::InvalidBool,
-            LayoutError::MathOverflow => Self::SliceTooShort,
-        }
+/// Every parse failure surfaces as `InvalidInstructionData`, so handlers can
+/// apply `?` to the parsers directly.
+impl From<InstructionParseError> for ProgramError {
+    fn from(_: InstructionParseError) -> Self {
+        ProgramError::InvalidInstructionData
+    }
+}
+
+/// Round identifier as carried in instruction data and `round` PDA seeds.
+/// Serialized exactly like the raw `u64` (8 LE bytes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RoundId(pub u64);
+
+impl RoundId {
+    /// Lowest id `start_round` accepts. Round ids start at 1 so that a zeroed
+    /// `round_id` field never names a live round.
+    pub const MIN: Self = Self(1);
+
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    pub const fn get(self) -> u64 {
+        self.0
     }
 }
 
+impl From<u64> for RoundId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RoundId> for u64 {
+    fn from(value: RoundId) -> Self {
+        value.0
+    }
+}
+
+/// Account views still store the raw `u64`; allow direct comparison so
+/// handlers can check `round.round_id != args.round_id` without unwrapping.
+impl PartialEq<u64> for RoundId {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<RoundId> for u64 {
+    fn eq(&self, other: &RoundId) -> bool {
+        *self == other.0
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InitConfigArgsCompat {
@@ -46,14 +104,15 @@ pub struct InitConfigArgsCompat {
 }
 
 impl InitConfigArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < INIT_CONFIG_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("init_config");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
+        require_no_trailing_bytes(ix_data, INIT_CONFIG_IX_LEN)?;
 
         let mut offset = 8usize;
         Ok(Self {
@@ -69,9 +128,23 @@ impl InitConfigArgsCompat {
     }
 }
 
+pub fn encode_init_config(args: &InitConfigArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(INIT_CONFIG_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("init_config"));
+    ix.extend_from_slice(&args.usdc_mint);
+    ix.extend_from_slice(&args.treasury_usdc_ata);
+    ix.extend_from_slice(&args.fee_bps.to_le_bytes());
+    ix.extend_from_slice(&args.ticket_unit.to_le_bytes());
+    ix.extend_from_slice(&args.round_duration_sec.to_le_bytes());
+    ix.extend_from_slice(&args.min_participants.to_le_bytes());
+    ix.extend_from_slice(&args.min_total_tickets.to_le_bytes());
+    ix.extend_from_slice(&args.max_deposit_per_user.to_le_bytes());
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BeginDegenExecutionArgsCompat {
-    pub round_id: u64,
+    pub round_id: RoundId,
     pub candidate_rank: u8,
     pub token_index: u32,
     pub min_out_raw: u64,
@@ -79,24 +152,25 @@ pub struct BeginDegenExecutionArgsCompat {
 }
 
 impl BeginDegenExecutionArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < BEGIN_DEGEN_EXECUTION_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("begin_degen_execution");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
+        require_no_trailing_bytes(ix_data, BEGIN_DEGEN_EXECUTION_IX_LEN)?;
 
-        let round_id = u64::from_le_bytes(
-            ix_data[8..16].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+        let round_id = RoundId::from_le_bytes(
+            ix_data[8..16].try_into().map_err(|_| InstructionParseError::TooShort)?,
         );
         let candidate_rank = ix_data[16];
         let token_index = u32::from_le_bytes(
-            ix_data[17..21].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+            ix_data[17..21].try_into().map_err(|_| InstructionParseError::TooShort)?,
         );
         let min_out_raw = u64::from_le_bytes(
-            ix_data[21..29].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+            ix_data[21..29].try_into().map_err(|_| InstructionParseError::TooShort)?,
         );
         let mut route_hash = [0u8; 32];
         route_hash.copy_from_slice(&ix_data[29..61]);
@@ -111,29 +185,49 @@ impl BeginDegenExecutionArgsCompat {
     }
 }
 
+/// Builds `begin_degen_execution` instruction data; the inverse of
+/// [`BeginDegenExecutionArgsCompat::parse`].
+pub fn encode_begin_degen_execution(
+    round_id: RoundId,
+    candidate_rank: u8,
+    token_index: u32,
+    min_out_raw: u64,
+    route_hash: [u8; 32],
+) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(BEGIN_DEGEN_EXECUTION_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("begin_degen_execution"));
+    ix.extend_from_slice(&round_id.to_le_bytes());
+    ix.push(candidate_rank);
+    ix.extend_from_slice(&token_index.to_le_bytes());
+    ix.extend_from_slice(&min_out_raw.to_le_bytes());
+    ix.extend_from_slice(&route_hash);
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ClaimDegenArgsCompat {
-    pub round_id: u64,
+    pub round_id: RoundId,
     pub candidate_rank: u8,
     pub token_index: u32,
 }
 
 impl ClaimDegenArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < CLAIM_DEGEN_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("claim_degen");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
+        require_no_trailing_bytes(ix_data, CLAIM_DEGEN_IX_LEN)?;
 
-        let round_id = u64::from_le_bytes(
-            ix_data[8..16].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+        let round_id = RoundId::from_le_bytes(
+            ix_data[8..16].try_into().map_err(|_| InstructionParseError::TooShort)?,
         );
         let candidate_rank = ix_data[16];
         let token_index = u32::from_le_bytes(
-            ix_data[17..21].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+            ix_data[17..21].try_into().map_err(|_| InstructionParseError::TooShort)?,
         );
 
         Ok(Self {
@@ -144,51 +238,73 @@ impl ClaimDegenArgsCompat {
     }
 }
 
+pub fn encode_claim_degen(args: &ClaimDegenArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(CLAIM_DEGEN_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("claim_degen"));
+    ix.extend_from_slice(&args.round_id.to_le_bytes());
+    ix.push(args.candidate_rank);
+    ix.extend_from_slice(&args.token_index.to_le_bytes());
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DepositAnyArgsCompat {
-    pub round_id: u64,
+    pub round_id: RoundId,
     pub usdc_balance_before: u64,
     pub min_out: u64,
 }
 
 impl DepositAnyArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < DEPOSIT_ANY_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("deposit_any");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
+        require_no_trailing_bytes(ix_data, DEPOSIT_ANY_IX_LEN)?;
 
         Ok(Self {
-            round_id: u64::from_le_bytes(
-                ix_data[8..16].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+            round_id: RoundId::from_le_bytes(
+                ix_data[8..16].try_into().map_err(|_| InstructionParseError::TooShort)?,
             ),
             usdc_balance_before: u64::from_le_bytes(
-                ix_data[16..24].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+                ix_data[16..24].try_into().map_err(|_| InstructionParseError::TooShort)?,
             ),
             min_out: u64::from_le_bytes(
-                ix_data[24..32].try_into().map_err(|_| InstructionLayoutError::SliceTooShort)?,
+                ix_data[24..32].try_into().map_err(|_| InstructionParseError::TooShort)?,
             ),
         })
     }
 }
 
+pub fn encode_deposit_any(args: &DepositAnyArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(DEPOSIT_ANY_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("deposit_any"));
+    ix.extend_from_slice(&args.round_id.to_le_bytes());
+    ix.extend_from_slice(&args.usdc_balance_before.to_le_bytes());
+    ix.extend_from_slice(&args.min_out.to_le_bytes());
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UpsertDegenConfigArgsCompat {
     pub executor: [u8; PUBKEY_LEN],
     pub fallback_timeout_sec: u32,
+    /// Pinocchio-only trailing field. Anchor clients stop after
+    /// `fallback_timeout_sec`, which parses as `None`.
+    pub candidate_window: Option<u8>,
 }
 
 impl UpsertDegenConfigArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < UPSERT_DEGEN_CONFIG_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("upsert_degen_config");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
 
         let mut offset = 8usize;
@@ -201,28 +317,113 @@ impl UpsertDegenConfigArgsCompat {
             ix_data[offset + 2],
             ix_data[offset + 3],
         ]);
+        offset += 4;
+        let candidate_window = if offset == ix_data.len() {
+            None
+        } else {
+            read_option_u8(ix_data, &mut offset)?
+        };
+        require_no_trailing_bytes(ix_data, offset)?;
 
         Ok(Self {
             executor,
             fallback_timeout_sec,
+            candidate_window,
         })
     }
 }
 
+pub fn encode_upsert_degen_config(args: &UpsertDegenConfigArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(UPSERT_DEGEN_CONFIG_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
+    ix.extend_from_slice(&args.executor);
+    ix.extend_from_slice(&args.fallback_timeout_sec.to_le_bytes());
+    if let Some(window) = args.candidate_window {
+        ix.extend_from_slice(&[1, window]);
+    }
+    ix
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateDegenTimeoutArgsCompat {
+    pub fallback_timeout_sec: u32,
+}
+
+impl UpdateDegenTimeoutArgsCompat {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
+        if ix_data.len() < UPDATE_DEGEN_TIMEOUT_IX_LEN {
+            return Err(InstructionParseError::TooShort);
+        }
+        let expected = instruction_discriminator("update_degen_timeout");
+        if ix_data[..8] != expected {
+            return Err(InstructionParseError::BadDiscriminator);
+        }
+        require_no_trailing_bytes(ix_data, UPDATE_DEGEN_TIMEOUT_IX_LEN)?;
+
+        let fallback_timeout_sec = u32::from_le_bytes([ix_data[8], ix_data[9], ix_data[10], ix_data[11]]);
+        Ok(Self { fallback_timeout_sec })
+    }
+}
+
+pub fn encode_update_degen_timeout(args: &UpdateDegenTimeoutArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(UPDATE_DEGEN_TIMEOUT_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+    ix.extend_from_slice(&args.fallback_timeout_sec.to_le_bytes());
+    ix
+}
+
+#[cfg(feature = "devnet")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockSettleArgsCompat {
+    pub round_id: RoundId,
+    pub randomness: [u8; 32],
+}
+
+#[cfg(feature = "devnet")]
+impl MockSettleArgsCompat {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
+        if ix_data.len() < MOCK_SETTLE_IX_LEN {
+            return Err(InstructionParseError::TooShort);
+        }
+        let expected = instruction_discriminator("mock_settle");
+        if ix_data[..8] != expected {
+            return Err(InstructionParseError::BadDiscriminator);
+        }
+        require_no_trailing_bytes(ix_data, MOCK_SETTLE_IX_LEN)?;
+
+        let round_id = RoundId::from_le_bytes(
+            ix_data[8..16].try_into().map_err(|_| InstructionParseError::TooShort)?,
+        );
+        let mut randomness = [0u8; 32];
+        randomness.copy_from_slice(&ix_data[16..48]);
+        Ok(Self { round_id, randomness })
+    }
+}
+
+#[cfg(feature = "devnet")]
+pub fn encode_mock_settle(args: &MockSettleArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(MOCK_SETTLE_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("mock_settle"));
+    ix.extend_from_slice(&args.round_id.to_le_bytes());
+    ix.extend_from_slice(&args.randomness);
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TransferAdminArgsCompat {
     pub new_admin: [u8; PUBKEY_LEN],
 }
 
 impl TransferAdminArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < TRANSFER_ADMIN_IX_LEN {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("transfer_admin");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
+        require_no_trailing_bytes(ix_data, TRANSFER_ADMIN_IX_LEN)?;
 
         let mut new_admin = [0u8; PUBKEY_LEN];
         new_admin.copy_from_slice(&ix_data[8..8 + PUBKEY_LEN]);
@@ -230,6 +431,74 @@ impl TransferAdminArgsCompat {
     }
 }
 
+pub fn encode_transfer_admin(args: &TransferAdminArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(TRANSFER_ADMIN_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("transfer_admin"));
+    ix.extend_from_slice(&args.new_admin);
+    ix
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetVrfQueueArgsCompat {
+    pub queue: [u8; PUBKEY_LEN],
+}
+
+impl SetVrfQueueArgsCompat {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
+        if ix_data.len() < SET_VRF_QUEUE_IX_LEN {
+            return Err(InstructionParseError::TooShort);
+        }
+        let expected = instruction_discriminator("set_vrf_queue");
+        if ix_data[..8] != expected {
+            return Err(InstructionParseError::BadDiscriminator);
+        }
+        require_no_trailing_bytes(ix_data, SET_VRF_QUEUE_IX_LEN)?;
+
+        let mut queue = [0u8; PUBKEY_LEN];
+        queue.copy_from_slice(&ix_data[8..8 + PUBKEY_LEN]);
+        Ok(Self { queue })
+    }
+}
+
+pub fn encode_set_vrf_queue(args: &SetVrfQueueArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(SET_VRF_QUEUE_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("set_vrf_queue"));
+    ix.extend_from_slice(&args.queue);
+    ix
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetFeeSplitArgsCompat {
+    pub recipient_usdc_ata: [u8; PUBKEY_LEN],
+    pub fee_split_bps: u16,
+}
+
+impl SetFeeSplitArgsCompat {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
+        if ix_data.len() < SET_FEE_SPLIT_IX_LEN {
+            return Err(InstructionParseError::TooShort);
+        }
+        let expected = instruction_discriminator("set_fee_split");
+        if ix_data[..8] != expected {
+            return Err(InstructionParseError::BadDiscriminator);
+        }
+        require_no_trailing_bytes(ix_data, SET_FEE_SPLIT_IX_LEN)?;
+
+        let mut recipient_usdc_ata = [0u8; PUBKEY_LEN];
+        recipient_usdc_ata.copy_from_slice(&ix_data[8..8 + PUBKEY_LEN]);
+        let fee_split_bps = u16::from_le_bytes([ix_data[8 + PUBKEY_LEN], ix_data[9 + PUBKEY_LEN]]);
+        Ok(Self { recipient_usdc_ata, fee_split_bps })
+    }
+}
+
+pub fn encode_set_fee_split(args: &SetFeeSplitArgsCompat) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(SET_FEE_SPLIT_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("set_fee_split"));
+    ix.extend_from_slice(&args.recipient_usdc_ata);
+    ix.extend_from_slice(&args.fee_split_bps.to_le_bytes());
+    ix
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct UpdateConfigArgsCompat {
     pub fee_bps: Option<u16>,
@@ -239,20 +508,24 @@ pub struct UpdateConfigArgsCompat {
     pub min_total_tickets: Option<u64>,
     pub paused: Option<bool>,
     pub max_deposit_per_user: Option<u64>,
+    /// Pinocchio-only trailing fields. Anchor clients stop after
+    /// `max_deposit_per_user`, and anything not sent parses as `None`.
+    pub vrf_timeout_sec: Option<u32>,
+    pub last_round_id: Option<u64>,
 }
 
 impl UpdateConfigArgsCompat {
-    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionLayoutError> {
+    pub fn parse(ix_data: &[u8]) -> Result<Self, InstructionParseError> {
         if ix_data.len() < 8 {
-            return Err(InstructionLayoutError::SliceTooShort);
+            return Err(InstructionParseError::TooShort);
         }
         let expected = instruction_discriminator("update_config");
         if ix_data[..8] != expected {
-            return Err(InstructionLayoutError::WrongDiscriminator);
+            return Err(InstructionParseError::BadDiscriminator);
         }
 
         let mut offset = 8usize;
-        Ok(Self {
+        let args = Self {
             fee_bps: read_option_u16(ix_data, &mut offset)?,
             ticket_unit: read_option_u64(ix_data, &mut offset)?,
             round_duration_sec: read_option_u32(ix_data, &mut offset)?,
@@ -260,30 +533,76 @@ impl UpdateConfigArgsCompat {
             min_total_tickets: read_option_u64(ix_data, &mut offset)?,
             paused: read_option_bool(ix_data, &mut offset)?,
             max_deposit_per_user: read_option_u64(ix_data, &mut offset)?,
-        })
+            vrf_timeout_sec: if offset == ix_data.len() {
+                None
+            } else {
+                read_option_u32(ix_data, &mut offset)?
+            },
+            last_round_id: if offset == ix_data.len() {
+                None
+            } else {
+                read_option_u64(ix_data, &mut offset)?
+            },
+        };
+        require_no_trailing_bytes(ix_data, offset)?;
+        Ok(args)
     }
 }
 
-pub fn parse_no_arg_ix(ix_data: &[u8], ix_name: &str) -> Result<(), InstructionLayoutError> {
+pub fn encode_update_config(args: &UpdateConfigArgsCompat) -> Vec<u8> {
+    fn push_option<const N: usize>(ix: &mut Vec<u8>, value: Option<[u8; N]>) {
+        match value {
+            Some(bytes) => {
+                ix.push(1);
+                ix.extend_from_slice(&bytes);
+            }
+            None => ix.push(0),
+        }
+    }
+
+    let mut ix = Vec::new();
+    ix.extend_from_slice(&instruction_discriminator("update_config"));
+    push_option(&mut ix, args.fee_bps.map(u16::to_le_bytes));
+    push_option(&mut ix, args.ticket_unit.map(u64::to_le_bytes));
+    push_option(&mut ix, args.round_duration_sec.map(u32::to_le_bytes));
+    push_option(&mut ix, args.min_participants.map(u16::to_le_bytes));
+    push_option(&mut ix, args.min_total_tickets.map(u64::to_le_bytes));
+    push_option(&mut ix, args.paused.map(|v| [u8::from(v)]));
+    push_option(&mut ix, args.max_deposit_per_user.map(u64::to_le_bytes));
+    if args.vrf_timeout_sec.is_some() || args.last_round_id.is_some() {
+        push_option(&mut ix, args.vrf_timeout_sec.map(u32::to_le_bytes));
+    }
+    if args.last_round_id.is_some() {
+        push_option(&mut ix, args.last_round_id.map(u64::to_le_bytes));
+    }
+    ix
+}
+
+pub fn parse_no_arg_ix(ix_data: &[u8], ix_name: &str) -> Result<(), InstructionParseError> {
     if ix_data.len() < 8 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let expected = instruction_discriminator(ix_name);
     if ix_data[..8] != expected {
-        return Err(InstructionLayoutError::WrongDiscriminator);
+        return Err(InstructionParseError::BadDiscriminator);
     }
-    Ok(())
+    require_no_trailing_bytes(ix_data, 8)
 }
 
-pub fn parse_round_id_ix(ix_data: &[u8], ix_name: &str) -> Result<u64, InstructionLayoutError> {
+pub fn encode_no_arg_ix(ix_name: &str) -> Vec<u8> {
+    instruction_discriminator(ix_name).to_vec()
+}
+
+pub fn parse_round_id_ix(ix_data: &[u8], ix_name: &str) -> Result<RoundId, InstructionParseError> {
     if ix_data.len() < ROUND_ID_IX_LEN {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let expected = instruction_discriminator(ix_name);
     if ix_data[..8] != expected {
-        return Err(InstructionLayoutError::WrongDiscriminator);
+        return Err(InstructionParseError::BadDiscriminator);
     }
-    Ok(u64::from_le_bytes([
+    require_no_trailing_bytes(ix_data, ROUND_ID_IX_LEN)?;
+    Ok(RoundId::from_le_bytes([
         ix_data[8],
         ix_data[9],
         ix_data[10],
@@ -295,18 +614,26 @@ pub fn parse_round_id_ix(ix_data: &[u8], ix_name: &str) -> Result<u64, Instructi
     ]))
 }
 
+pub fn encode_round_id_ix(ix_name: &str, round_id: RoundId) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(ROUND_ID_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator(ix_name));
+    ix.extend_from_slice(&round_id.to_le_bytes());
+    ix
+}
+
 pub fn parse_round_id_u8_ix(
     ix_data: &[u8],
     ix_name: &str,
-) -> Result<(u64, u8), InstructionLayoutError> {
+) -> Result<(RoundId, u8), InstructionParseError> {
     if ix_data.len() < ROUND_ID_U8_IX_LEN {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let expected = instruction_discriminator(ix_name);
     if ix_data[..8] != expected {
-        return Err(InstructionLayoutError::WrongDiscriminator);
+        return Err(InstructionParseError::BadDiscriminator);
     }
-    let round_id = u64::from_le_bytes([
+    require_no_trailing_bytes(ix_data, ROUND_ID_U8_IX_LEN)?;
+    let round_id = RoundId::from_le_bytes([
         ix_data[8],
         ix_data[9],
         ix_data[10],
@@ -319,40 +646,71 @@ pub fn parse_round_id_u8_ix(
     Ok((round_id, ix_data[16]))
 }
 
-pub fn parse_vrf_callback_ix(ix_data: &[u8]) -> Result<[u8; 32], InstructionLayoutError> {
+pub fn encode_round_id_u8_ix(ix_name: &str, round_id: RoundId, value: u8) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(ROUND_ID_U8_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator(ix_name));
+    ix.extend_from_slice(&round_id.to_le_bytes());
+    ix.push(value);
+    ix
+}
+
+pub fn parse_vrf_callback_ix(ix_data: &[u8]) -> Result<[u8; 32], InstructionParseError> {
     if ix_data.len() < VRF_CALLBACK_IX_LEN {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let expected = instruction_discriminator("vrf_callback");
     if ix_data[..8] != expected {
-        return Err(InstructionLayoutError::WrongDiscriminator);
+        return Err(InstructionParseError::BadDiscriminator);
     }
+    require_no_trailing_bytes(ix_data, VRF_CALLBACK_IX_LEN)?;
     let mut randomness = [0u8; 32];
     randomness.copy_from_slice(&ix_data[8..40]);
     Ok(randomness)
 }
 
-pub fn parse_degen_vrf_callback_ix(ix_data: &[u8]) -> Result<[u8; 32], InstructionLayoutError> {
+pub fn encode_vrf_callback_ix(randomness: [u8; 32]) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(VRF_CALLBACK_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("vrf_callback"));
+    ix.extend_from_slice(&randomness);
+    ix
+}
+
+pub fn parse_degen_vrf_callback_ix(ix_data: &[u8]) -> Result<[u8; 32], InstructionParseError> {
     if ix_data.len() < DEGEN_VRF_CALLBACK_IX_LEN {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let expected = instruction_discriminator("degen_vrf_callback");
     if ix_data[..8] != expected {
-        return Err(InstructionLayoutError::WrongDiscriminator);
+        return Err(InstructionParseError::BadDiscriminator);
     }
+    require_no_trailing_bytes(ix_data, DEGEN_VRF_CALLBACK_IX_LEN)?;
     let mut randomness = [0u8; 32];
     randomness.copy_from_slice(&ix_data[8..40]);
     Ok(randomness)
 }
 
-fn read_option_tag(data: &[u8], offset: &mut usize) -> Result<u8, InstructionLayoutError> {
+pub fn encode_degen_vrf_callback_ix(randomness: [u8; 32]) -> Vec<u8> {
+    let mut ix = Vec::with_capacity(DEGEN_VRF_CALLBACK_IX_LEN);
+    ix.extend_from_slice(&instruction_discriminator("degen_vrf_callback"));
+    ix.extend_from_slice(&randomness);
+    ix
+}
+
+fn require_no_trailing_bytes(ix_data: &[u8], expected_len: usize) -> Result<(), InstructionParseError> {
+    if ix_data.len() > expected_len {
+        return Err(InstructionParseError::TrailingBytes);
+    }
+    Ok(())
+}
+
+fn read_option_tag(data: &[u8], offset: &mut usize) -> Result<u8, InstructionParseError> {
     if data.len() < *offset + 1 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = data[*offset];
     *offset += 1;
     if out > 1 {
-        return Err(InstructionLayoutError::InvalidOptionTag);
+        return Err(InstructionParseError::InvalidOptionTag);
     }
     Ok(out)
 }
@@ -360,9 +718,9 @@ fn read_option_tag(data: &[u8], offset: &mut usize) -> Result<u8, InstructionLay
 fn read_fixed_pubkey(
     data: &[u8],
     offset: &mut usize,
-) -> Result<[u8; PUBKEY_LEN], InstructionLayoutError> {
+) -> Result<[u8; PUBKEY_LEN], InstructionParseError> {
     if data.len() < *offset + PUBKEY_LEN {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let mut out = [0u8; PUBKEY_LEN];
     out.copy_from_slice(&data[*offset..*offset + PUBKEY_LEN]);
@@ -370,18 +728,18 @@ fn read_fixed_pubkey(
     Ok(out)
 }
 
-fn read_fixed_u16(data: &[u8], offset: &mut usize) -> Result<u16, InstructionLayoutError> {
+fn read_fixed_u16(data: &[u8], offset: &mut usize) -> Result<u16, InstructionParseError> {
     if data.len() < *offset + 2 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u16::from_le_bytes([data[*offset], data[*offset + 1]]);
     *offset += 2;
     Ok(out)
 }
 
-fn read_fixed_u32(data: &[u8], offset: &mut usize) -> Result<u32, InstructionLayoutError> {
+fn read_fixed_u32(data: &[u8], offset: &mut usize) -> Result<u32, InstructionParseError> {
     if data.len() < *offset + 4 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u32::from_le_bytes([
         data[*offset],
@@ -393,9 +751,9 @@ fn read_fixed_u32(data: &[u8], offset: &mut usize) -> Result<u32, InstructionLay
     Ok(out)
 }
 
-fn read_fixed_u64(data: &[u8], offset: &mut usize) -> Result<u64, InstructionLayoutError> {
+fn read_fixed_u64(data: &[u8], offset: &mut usize) -> Result<u64, InstructionParseError> {
     if data.len() < *offset + 8 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u64::from_le_bytes([
         data[*offset],
@@ -411,24 +769,36 @@ fn read_fixed_u64(data: &[u8], offset: &mut usize) -> Result<u64, InstructionLay
     Ok(out)
 }
 
-fn read_option_u16(data: &[u8], offset: &mut usize) -> Result<Option<u16>, InstructionLayoutError> {
+fn read_option_u8(data: &[u8], offset: &mut usize) -> Result<Option<u8>, InstructionParseError> {
+    if read_option_tag(data, offset)? == 0 {
+        return Ok(None);
+    }
+    if data.len() < *offset + 1 {
+        return Err(InstructionParseError::TooShort);
+    }
+    let out = data[*offset];
+    *offset += 1;
+    Ok(Some(out))
+}
+
+fn read_option_u16(data: &[u8], offset: &mut usize) -> Result<Option<u16>, InstructionParseError> {
     if read_option_tag(data, offset)? == 0 {
         return Ok(None);
     }
     if data.len() < *offset + 2 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u16::from_le_bytes([data[*offset], data[*offset + 1]]);
     *offset += 2;
     Ok(Some(out))
 }
 
-fn read_option_u32(data: &[u8], offset: &mut usize) -> Result<Option<u32>, InstructionLayoutError> {
+fn read_option_u32(data: &[u8], offset: &mut usize) -> Result<Option<u32>, InstructionParseError> {
     if read_option_tag(data, offset)? == 0 {
         return Ok(None);
     }
     if data.len() < *offset + 4 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u32::from_le_bytes([
         data[*offset],
@@ -440,12 +810,12 @@ fn read_option_u32(data: &[u8], offset: &mut usize) -> Result<Option<u32>, Instr
     Ok(Some(out))
 }
 
-fn read_option_u64(data: &[u8], offset: &mut usize) -> Result<Option<u64>, InstructionLayoutError> {
+fn read_option_u64(data: &[u8], offset: &mut usize) -> Result<Option<u64>, InstructionParseError> {
     if read_option_tag(data, offset)? == 0 {
         return Ok(None);
     }
     if data.len() < *offset + 8 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = u64::from_le_bytes([
         data[*offset],
@@ -461,17 +831,17 @@ fn read_option_u64(data: &[u8], offset: &mut usize) -> Result<Option<u64>, Instr
     Ok(Some(out))
 }
 
-fn read_option_bool(data: &[u8], offset: &mut usize) -> Result<Option<bool>, InstructionLayoutError> {
+fn read_option_bool(data: &[u8], offset: &mut usize) -> Result<Option<bool>, InstructionParseError> {
     if read_option_tag(data, offset)? == 0 {
         return Ok(None);
     }
     if data.len() < *offset + 1 {
-        return Err(InstructionLayoutError::SliceTooShort);
+        return Err(InstructionParseError::TooShort);
     }
     let out = match data[*offset] {
         0 => false,
         1 => true,
-        _ => return Err(InstructionLayoutError::InvalidBool),
+        _ => return Err(InstructionParseError::InvalidBool),
     };
     *offset += 1;
     Ok(Some(out))
@@ -491,6 +861,45 @@ mod tests {
         let parsed = UpsertDegenConfigArgsCompat::parse(&ix).unwrap();
         assert_eq!(parsed.executor, [9u8; 32]);
         assert_eq!(parsed.fallback_timeout_sec, 300);
+        assert_eq!(parsed.candidate_window, None);
+
+        ix.extend_from_slice(&[1, 12]);
+        assert_eq!(UpsertDegenConfigArgsCompat::parse(&ix).unwrap().candidate_window, Some(12));
+
+        ix.push(0);
+        assert_eq!(UpsertDegenConfigArgsCompat::parse(&ix), Err(InstructionParseError::TrailingBytes));
+        ix.truncate(ix.len() - 3);
+        ix.push(2);
+        assert_eq!(UpsertDegenConfigArgsCompat::parse(&ix), Err(InstructionParseError::InvalidOptionTag));
+    }
+
+    #[test]
+    fn parses_update_degen_timeout_anchor_bytes() {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+        ix.extend_from_slice(&900u32.to_le_bytes());
+
+        let parsed = UpdateDegenTimeoutArgsCompat::parse(&ix).unwrap();
+        assert_eq!(parsed.fallback_timeout_sec, 900);
+
+        ix.push(0);
+        assert_eq!(UpdateDegenTimeoutArgsCompat::parse(&ix), Err(InstructionParseError::TrailingBytes));
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn parses_mock_settle_anchor_bytes() {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("mock_settle"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.extend_from_slice(&[5u8; 32]);
+
+        let parsed = MockSettleArgsCompat::parse(&ix).unwrap();
+        assert_eq!(parsed.round_id, RoundId(81));
+        assert_eq!(parsed.randomness, [5u8; 32]);
+
+        ix.push(0);
+        assert_eq!(MockSettleArgsCompat::parse(&ix), Err(InstructionParseError::TrailingBytes));
     }
 
     #[test]
@@ -549,6 +958,16 @@ mod tests {
         assert_eq!(parsed, 81);
     }
 
+    #[test]
+    fn round_id_serializes_like_raw_u64() {
+        let raw = 0x0102_0304_0506_0708u64;
+        let id = RoundId(raw);
+        assert_eq!(id.to_le_bytes(), raw.to_le_bytes());
+        assert_eq!(RoundId::from_le_bytes(raw.to_le_bytes()), id);
+        assert_eq!(u64::from(id), raw);
+        assert_eq!(core::mem::size_of::<RoundId>(), 8);
+    }
+
     #[test]
     fn parses_round_id_u8_ix() {
         let mut ix = Vec::new();
@@ -557,7 +976,7 @@ mod tests {
         ix.push(7);
 
         let parsed = parse_round_id_u8_ix(&ix, "claim_degen_fallback").unwrap();
-        assert_eq!(parsed, (81, 7));
+        assert_eq!(parsed, (RoundId(81), 7));
     }
 
     #[test]
@@ -587,6 +1006,23 @@ mod tests {
         assert_eq!(parsed.route_hash, [9u8; 32]);
     }
 
+    #[test]
+    fn begin_degen_execution_encoding_round_trips() {
+        let cases = [
+            (RoundId(1), 0u8, 0u32, 0u64, [0u8; 32]),
+            (RoundId(81), 4, 123, 777, [9u8; 32]),
+            (RoundId(u64::MAX), u8::MAX, u32::MAX, u64::MAX, [0xffu8; 32]),
+        ];
+        for (round_id, candidate_rank, token_index, min_out_raw, route_hash) in cases {
+            let ix = encode_begin_degen_execution(round_id, candidate_rank, token_index, min_out_raw, route_hash);
+            assert_eq!(ix.len(), BEGIN_DEGEN_EXECUTION_IX_LEN);
+            assert_eq!(
+                BeginDegenExecutionArgsCompat::parse(&ix).unwrap(),
+                BeginDegenExecutionArgsCompat { round_id, candidate_rank, token_index, min_out_raw, route_hash }
+            );
+        }
+    }
+
     #[test]
     fn parses_claim_degen_ix() {
         let mut ix = Vec::new();
@@ -601,4 +1037,192 @@ mod tests {
         assert_eq!(parsed.token_index, 456);
     }
 
+    #[test]
+    fn round_id_ix_reports_each_parse_error() {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("lock_round"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        assert_eq!(parse_round_id_ix(&ix[..15], "lock_round"), Err(InstructionParseError::TooShort));
+        assert_eq!(parse_round_id_ix(&ix, "close_round"), Err(InstructionParseError::BadDiscriminator));
+        ix.push(0);
+        assert_eq!(parse_round_id_ix(&ix, "lock_round"), Err(InstructionParseError::TrailingBytes));
+        assert_eq!(
+            ProgramError::from(InstructionParseError::TrailingBytes),
+            ProgramError::InvalidInstructionData
+        );
+    }
+
+    #[test]
+    fn args_parsers_reject_trailing_bytes() {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("deposit_any"));
+        ix.extend_from_slice(&[0u8; 24]);
+        DepositAnyArgsCompat::parse(&ix).unwrap();
+        ix.push(0);
+        assert_eq!(DepositAnyArgsCompat::parse(&ix), Err(InstructionParseError::TrailingBytes));
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        assert_eq!(
+            parse_round_id_u8_ix(&ix, "claim_degen_fallback"),
+            Err(InstructionParseError::TooShort)
+        );
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.extend_from_slice(&[0u8; 7]);
+        UpdateConfigArgsCompat::parse(&ix).unwrap();
+        ix.push(0);
+        UpdateConfigArgsCompat::parse(&ix).unwrap();
+        ix.push(0);
+        UpdateConfigArgsCompat::parse(&ix).unwrap();
+        ix.push(0);
+        assert_eq!(UpdateConfigArgsCompat::parse(&ix), Err(InstructionParseError::TrailingBytes));
+    }
+
+    #[test]
+    fn update_config_reports_bad_option_tag_and_bool() {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.push(2);
+        assert_eq!(UpdateConfigArgsCompat::parse(&ix), Err(InstructionParseError::InvalidOptionTag));
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_config"));
+        ix.extend_from_slice(&[0u8; 5]);
+        ix.extend_from_slice(&[1, 2]);
+        ix.push(0);
+        assert_eq!(UpdateConfigArgsCompat::parse(&ix), Err(InstructionParseError::InvalidBool));
+    }
+
+    #[test]
+    fn update_config_round_trips_every_presence_mask() {
+        for mask in 0u16..512 {
+            let present = |bit: u16| mask & (1 << bit) != 0;
+            let args = UpdateConfigArgsCompat {
+                fee_bps: present(0).then_some(250),
+                ticket_unit: present(1).then_some(10_000),
+                round_duration_sec: present(2).then_some(60),
+                min_participants: present(3).then_some(3),
+                min_total_tickets: present(4).then_some(300),
+                paused: present(5).then_some(true),
+                max_deposit_per_user: present(6).then_some(5_000_000),
+                vrf_timeout_sec: present(7).then_some(3_600),
+                last_round_id: present(8).then_some(147),
+            };
+
+            let ix = encode_update_config(&args);
+            assert_eq!(UpdateConfigArgsCompat::parse(&ix), Ok(args), "mask {mask:#011b}");
+        }
+        assert_eq!(
+            UpdateConfigArgsCompat::parse(&encode_update_config(&UpdateConfigArgsCompat::default())),
+            Ok(UpdateConfigArgsCompat::default())
+        );
+    }
+
+    #[test]
+    fn update_config_rejects_bad_tag_after_present_field() {
+        let mut ix = encode_update_config(&UpdateConfigArgsCompat {
+            fee_bps: Some(25),
+            ..UpdateConfigArgsCompat::default()
+        });
+        // ticket_unit's presence byte.
+        ix[8 + 3] = 0xff;
+        assert_eq!(UpdateConfigArgsCompat::parse(&ix), Err(InstructionParseError::InvalidOptionTag));
+    }
+
+    #[test]
+    fn struct_args_round_trip_through_encoders() {
+        let init = InitConfigArgsCompat {
+            usdc_mint: [1u8; PUBKEY_LEN],
+            treasury_usdc_ata: [2u8; PUBKEY_LEN],
+            fee_bps: 250,
+            ticket_unit: 10_000,
+            round_duration_sec: 60,
+            min_participants: 2,
+            min_total_tickets: 200,
+            max_deposit_per_user: 5_000_000,
+        };
+        let ix = encode_init_config(&init);
+        assert_eq!(ix.len(), INIT_CONFIG_IX_LEN);
+        assert_eq!(InitConfigArgsCompat::parse(&ix), Ok(init));
+
+        let claim = ClaimDegenArgsCompat { round_id: RoundId(81), candidate_rank: 3, token_index: 456 };
+        let ix = encode_claim_degen(&claim);
+        assert_eq!(ix.len(), CLAIM_DEGEN_IX_LEN);
+        assert_eq!(ClaimDegenArgsCompat::parse(&ix), Ok(claim));
+
+        let deposit = DepositAnyArgsCompat { round_id: RoundId(81), usdc_balance_before: 7, min_out: u64::MAX };
+        let ix = encode_deposit_any(&deposit);
+        assert_eq!(ix.len(), DEPOSIT_ANY_IX_LEN);
+        assert_eq!(DepositAnyArgsCompat::parse(&ix), Ok(deposit));
+
+        let upsert = UpsertDegenConfigArgsCompat {
+            executor: [9u8; PUBKEY_LEN],
+            fallback_timeout_sec: 300,
+            candidate_window: None,
+        };
+        let ix = encode_upsert_degen_config(&upsert);
+        assert_eq!(ix.len(), UPSERT_DEGEN_CONFIG_IX_LEN);
+        assert_eq!(UpsertDegenConfigArgsCompat::parse(&ix), Ok(upsert));
+
+        let upsert = UpsertDegenConfigArgsCompat { candidate_window: Some(12), ..upsert };
+        let ix = encode_upsert_degen_config(&upsert);
+        assert_eq!(ix.len(), UPSERT_DEGEN_CONFIG_IX_LEN + 2);
+        assert_eq!(UpsertDegenConfigArgsCompat::parse(&ix), Ok(upsert));
+
+        let timeout = UpdateDegenTimeoutArgsCompat { fallback_timeout_sec: 600 };
+        let ix = encode_update_degen_timeout(&timeout);
+        assert_eq!(ix.len(), UPDATE_DEGEN_TIMEOUT_IX_LEN);
+        assert_eq!(UpdateDegenTimeoutArgsCompat::parse(&ix), Ok(timeout));
+
+        let transfer = TransferAdminArgsCompat { new_admin: [4u8; PUBKEY_LEN] };
+        let ix = encode_transfer_admin(&transfer);
+        assert_eq!(ix.len(), TRANSFER_ADMIN_IX_LEN);
+        assert_eq!(TransferAdminArgsCompat::parse(&ix), Ok(transfer));
+
+        let queue = SetVrfQueueArgsCompat { queue: [6u8; PUBKEY_LEN] };
+        let ix = encode_set_vrf_queue(&queue);
+        assert_eq!(ix.len(), SET_VRF_QUEUE_IX_LEN);
+        assert_eq!(SetVrfQueueArgsCompat::parse(&ix), Ok(queue));
+
+        let fee_split = SetFeeSplitArgsCompat { recipient_usdc_ata: [6u8; PUBKEY_LEN], fee_split_bps: 2_500 };
+        let ix = encode_set_fee_split(&fee_split);
+        assert_eq!(ix.len(), SET_FEE_SPLIT_IX_LEN);
+        assert_eq!(SetFeeSplitArgsCompat::parse(&ix), Ok(fee_split));
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn mock_settle_round_trips_through_encoder() {
+        let args = MockSettleArgsCompat { round_id: RoundId(81), randomness: [5u8; 32] };
+        let ix = encode_mock_settle(&args);
+        assert_eq!(ix.len(), MOCK_SETTLE_IX_LEN);
+        assert_eq!(MockSettleArgsCompat::parse(&ix), Ok(args));
+    }
+
+    #[test]
+    fn generic_ix_shapes_round_trip_through_encoders() {
+        assert_eq!(parse_no_arg_ix(&encode_no_arg_ix("init_config"), "init_config"), Ok(()));
+
+        let ix = encode_round_id_ix("lock_round", RoundId(81));
+        assert_eq!(ix.len(), ROUND_ID_IX_LEN);
+        assert_eq!(parse_round_id_ix(&ix, "lock_round"), Ok(RoundId(81)));
+        assert_eq!(parse_round_id_ix(&ix, "start_round"), Err(InstructionParseError::BadDiscriminator));
+
+        let ix = encode_round_id_u8_ix("claim_degen_fallback", RoundId(81), 2);
+        assert_eq!(ix.len(), ROUND_ID_U8_IX_LEN);
+        assert_eq!(parse_round_id_u8_ix(&ix, "claim_degen_fallback"), Ok((RoundId(81), 2)));
+
+        let randomness = [7u8; 32];
+        let ix = encode_vrf_callback_ix(randomness);
+        assert_eq!(ix.len(), VRF_CALLBACK_IX_LEN);
+        assert_eq!(parse_vrf_callback_ix(&ix), Ok(randomness));
+        let ix = encode_degen_vrf_callback_ix(randomness);
+        assert_eq!(ix.len(), DEGEN_VRF_CALLBACK_IX_LEN);
+        assert_eq!(parse_degen_vrf_callback_ix(&ix), Ok(randomness));
+        assert_eq!(parse_vrf_callback_ix(&ix), Err(InstructionParseError::BadDiscriminator));
+    }
 }
@@ -1,10 +1,42 @@
-use crate::anchor_compat::ANCHOR_DISCRIMINATOR_LEN;
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{AccountView, error::ProgramError};
+
+use crate::{
+    anchor_compat::{
+        ANCHOR_DISCRIMINATOR_LEN, CONFIG_DISCRIMINATOR, DEGEN_CLAIM_DISCRIMINATOR, DEGEN_CONFIG_DISCRIMINATOR,
+        FEE_SPLIT_CONFIG_DISCRIMINATOR, PARTICIPANT_DISCRIMINATOR, ROUND_DISCRIMINATOR,
+        VRF_QUEUE_CONFIG_DISCRIMINATOR,
+    },
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LayoutError {
     SliceTooShort,
     InvalidBool,
     MathOverflow,
+    ProgramPaused,
+    RoundNotEligible,
+    RoundStillOpen,
+    FallbackNotReady,
+    InconsistentDegenState,
+    InvalidAccountHeader,
+    InconsistentFenwick,
+    InvalidFallbackTimeout,
+    WinnerMismatch,
+    RoundFull,
+    InvalidFeeBps,
+    InvalidTicketUnit,
+    InvalidRoundDuration,
+    InvalidMinParticipants,
+    RoundIdOutOfSequence,
+    InvalidRoundStatus,
+    ParticipantRoundMismatch,
+    VaultNotSet,
+    WrongLength,
+    RandomnessUnavailable,
+    InvalidCandidateWindow,
 }
 
 pub const PUBKEY_LEN: usize = 32;
@@ -16,8 +48,24 @@ pub const DEGEN_CLAIM_BODY_LEN: usize = 340;
 pub const DEGEN_CLAIM_ACCOUNT_LEN: usize = ANCHOR_DISCRIMINATOR_LEN + DEGEN_CLAIM_BODY_LEN;
 pub const PARTICIPANT_BODY_LEN: usize = 103;
 pub const PARTICIPANT_ACCOUNT_LEN: usize = ANCHOR_DISCRIMINATOR_LEN + PARTICIPANT_BODY_LEN;
+pub const VRF_QUEUE_CONFIG_BODY_LEN: usize = PUBKEY_LEN + 1;
+pub const VRF_QUEUE_CONFIG_ACCOUNT_LEN: usize = ANCHOR_DISCRIMINATOR_LEN + VRF_QUEUE_CONFIG_BODY_LEN;
+pub const FEE_SPLIT_CONFIG_BODY_LEN: usize = PUBKEY_LEN + 2 + 1;
+pub const FEE_SPLIT_CONFIG_ACCOUNT_LEN: usize = ANCHOR_DISCRIMINATOR_LEN + FEE_SPLIT_CONFIG_BODY_LEN;
 pub const TOKEN_ACCOUNT_CORE_LEN: usize = 64;
 pub const TOKEN_ACCOUNT_WITH_AMOUNT_LEN: usize = 72;
+/// Classic SPL Token (and the shared Token-2022 prefix) stores `amount` right after mint + owner.
+pub const SPL_AMOUNT_OFFSET: usize = 64;
+/// Length of a full SPL Token account (without Token-2022 extensions).
+pub const SPL_TOKEN_ACCOUNT_LEN: usize = 165;
+/// Offset of the `AccountState` byte in the full SPL Token layout.
+pub const SPL_STATE_OFFSET: usize = 108;
+pub const TOKEN_ACCOUNT_STATE_UNINITIALIZED: u8 = 0;
+pub const TOKEN_ACCOUNT_STATE_INITIALIZED: u8 = 1;
+pub const TOKEN_ACCOUNT_STATE_FROZEN: u8 = 2;
+/// Returned for buffers that stop short of the state byte, such as the
+/// 72-byte mint/owner/amount layout handlers and tests pass around.
+pub const TOKEN_ACCOUNT_STATE_UNKNOWN: u8 = u8::MAX;
 pub const MAX_PARTICIPANTS: usize = 200;
 pub const ROUND_PARTICIPANTS_BYTES_LEN: usize = PUBKEY_LEN * MAX_PARTICIPANTS;
 pub const ROUND_FENWICK_NODE_COUNT: usize = MAX_PARTICIPANTS + 1;
@@ -43,6 +91,16 @@ pub const DEGEN_MODE_CLAIMED: u8 = 4;
 pub const DEGEN_CANDIDATE_WINDOW: u8 = 30;
 pub const DEGEN_FALLBACK_REASON_NONE: u8 = 0;
 pub const DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC: u32 = 300;
+/// One minute is the shortest window in which an executor can realistically
+/// land a swap; anything shorter lets the winner skip straight to fallback.
+pub const MIN_FALLBACK_TIMEOUT_SEC: u32 = 60;
+/// A week caps how long a winner's payout can sit with an unresponsive executor.
+pub const MAX_FALLBACK_TIMEOUT_SEC: u32 = 7 * 24 * 60 * 60;
+/// Shortest non-zero `vrf_timeout_sec`: the oracle gets at least five
+/// minutes before the admin may settle from slot hashes instead.
+pub const MIN_VRF_TIMEOUT_SEC: u32 = 5 * 60;
+/// Longest `vrf_timeout_sec`, the same week `MAX_FALLBACK_TIMEOUT_SEC` allows.
+pub const MAX_VRF_TIMEOUT_SEC: u32 = 7 * 24 * 60 * 60;
 
 const ROUND_ROUND_ID_OFFSET: usize = 0;
 const ROUND_STATUS_OFFSET: usize = 8;
@@ -63,6 +121,12 @@ const ROUND_VRF_PAYER_OFFSET: usize = ROUND_BIT_OFFSET + ROUND_FENWICK_BYTES_LEN
 const ROUND_VRF_REIMBURSED_OFFSET: usize = ROUND_VRF_PAYER_OFFSET + PUBKEY_LEN;
 const ROUND_RESERVED_OFFSET: usize = ROUND_VRF_REIMBURSED_OFFSET + 1;
 
+const DEGEN_CONFIG_FALLBACK_TIMEOUT_OFFSET: usize = PUBKEY_LEN;
+/// Index into `DegenConfigView::reserved` of the candidate window a new
+/// degen claim offers the winner. Zero-filled configs keep
+/// `DEGEN_CANDIDATE_WINDOW`.
+const DEGEN_CONFIG_CANDIDATE_WINDOW_INDEX: usize = 0;
+
 const DEGEN_CLAIM_ROUND_OFFSET: usize = 0;
 const DEGEN_CLAIM_WINNER_OFFSET: usize = DEGEN_CLAIM_ROUND_OFFSET + PUBKEY_LEN;
 const DEGEN_CLAIM_ROUND_ID_OFFSET: usize = DEGEN_CLAIM_WINNER_OFFSET + PUBKEY_LEN;
@@ -87,6 +151,38 @@ const DEGEN_CLAIM_RECEIVER_TOKEN_ATA_OFFSET: usize = DEGEN_CLAIM_EXECUTOR_OFFSET
 const DEGEN_CLAIM_RANDOMNESS_OFFSET: usize = DEGEN_CLAIM_RECEIVER_TOKEN_ATA_OFFSET + PUBKEY_LEN;
 const DEGEN_CLAIM_ROUTE_HASH_OFFSET: usize = DEGEN_CLAIM_RANDOMNESS_OFFSET + 32;
 const DEGEN_CLAIM_RESERVED_OFFSET: usize = DEGEN_CLAIM_ROUTE_HASH_OFFSET + 32;
+/// Range of `DegenClaimView::reserved` holding `execution_started_at` (i64
+/// LE), when `begin_degen_execution` moved the claim to `EXECUTING`. Zero on
+/// claims that began before the field existed.
+const DEGEN_CLAIM_EXECUTION_STARTED_AT_RANGE: core::ops::Range<usize> = 0..8;
+/// Index into `DegenClaimView::reserved` set once `begin_degen_execution`
+/// has paid the fee and any VRF reimbursement out of the vault, so a later
+/// fallback or re-begin after recovery pays only the recorded payout.
+const DEGEN_CLAIM_FEES_PAID_INDEX: usize = 8;
+
+/// Index into `ConfigView::reserved` of the VRF reimbursement kill switch.
+/// Stored inverted (non-zero = disabled) so zero-filled legacy configs keep
+/// reimbursing the VRF payer.
+///
+/// Bytes 3..8, 16..18 and 22..24 are still free, which is not enough for a
+/// pubkey: the oracle queue override and the second fee recipient live in
+/// their own `VrfQueueConfigView` and `FeeSplitConfigView` PDAs.
+const CONFIG_VRF_REIMBURSEMENT_DISABLED_INDEX: usize = 0;
+/// Index into `ConfigView::reserved` set by `set_vrf_queue`. Once set, VRF
+/// requests must pass the `VrfQueueConfigView` PDA, so leaving it out no
+/// longer falls back to `DEFAULT_QUEUE`.
+const CONFIG_VRF_QUEUE_OVERRIDDEN_INDEX: usize = 1;
+/// Index into `ConfigView::reserved` set while `set_fee_split` has given the
+/// second recipient a non-zero share. Degen claims must then pass the
+/// `FeeSplitConfigView` PDA and that recipient's ATA.
+const CONFIG_FEE_SPLIT_ENABLED_INDEX: usize = 2;
+/// Range of `ConfigView::reserved` holding the last started round id (u64
+/// LE). Zero-filled configs have started no round yet, so the next id is 1.
+const CONFIG_LAST_ROUND_ID_RANGE: core::ops::Range<usize> = 8..16;
+/// Range of `ConfigView::reserved` holding `vrf_timeout_sec` (u32 LE), how
+/// long after `end_ts` a VRF-requested round may be settled from slot
+/// hashes instead. Zero-filled configs keep the fallback disabled.
+const CONFIG_VRF_TIMEOUT_SEC_RANGE: core::ops::Range<usize> = 18..22;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConfigView {
@@ -105,6 +201,119 @@ pub struct ConfigView {
 }
 
 impl ConfigView {
+    /// Invariants every persisted config must satisfy; checked in the same
+    /// order Anchor's `init_config`/`update_config` report them.
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        if self.fee_bps > 10_000 {
+            return Err(LayoutError::InvalidFeeBps);
+        }
+        if self.ticket_unit == 0 {
+            return Err(LayoutError::InvalidTicketUnit);
+        }
+        if self.round_duration_sec == 0 {
+            return Err(LayoutError::InvalidRoundDuration);
+        }
+        if self.min_participants == 0 {
+            return Err(LayoutError::InvalidMinParticipants);
+        }
+        let vrf_timeout_sec = self.vrf_timeout_sec();
+        if vrf_timeout_sec != 0 && !(MIN_VRF_TIMEOUT_SEC..=MAX_VRF_TIMEOUT_SEC).contains(&vrf_timeout_sec) {
+            return Err(LayoutError::InvalidFallbackTimeout);
+        }
+        Ok(())
+    }
+
+    /// Guard for user-facing instructions (`start_round`, `deposit_any`);
+    /// admin instructions stay callable while the program is paused.
+    pub fn require_not_paused(&self) -> Result<(), LayoutError> {
+        if self.paused {
+            Err(LayoutError::ProgramPaused)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `round_duration_sec` widened for `i64` timestamp math; every `u32`
+    /// fits, so this never wraps or goes negative.
+    pub fn round_duration_i64(&self) -> i64 {
+        i64::from(self.round_duration_sec)
+    }
+
+    /// Whether settlement may carve the VRF reimbursement out of the pot.
+    pub fn vrf_reimbursement_enabled(&self) -> bool {
+        self.reserved[CONFIG_VRF_REIMBURSEMENT_DISABLED_INDEX] == 0
+    }
+
+    pub fn set_vrf_reimbursement_enabled(&mut self, enabled: bool) {
+        self.reserved[CONFIG_VRF_REIMBURSEMENT_DISABLED_INDEX] = u8::from(!enabled);
+    }
+
+    pub fn vrf_queue_overridden(&self) -> bool {
+        self.reserved[CONFIG_VRF_QUEUE_OVERRIDDEN_INDEX] != 0
+    }
+
+    pub fn mark_vrf_queue_overridden(&mut self) {
+        self.reserved[CONFIG_VRF_QUEUE_OVERRIDDEN_INDEX] = 1;
+    }
+
+    pub fn fee_split_enabled(&self) -> bool {
+        self.reserved[CONFIG_FEE_SPLIT_ENABLED_INDEX] != 0
+    }
+
+    pub fn set_fee_split_enabled(&mut self, enabled: bool) {
+        self.reserved[CONFIG_FEE_SPLIT_ENABLED_INDEX] = u8::from(enabled);
+    }
+
+    /// Id of the most recently started round, 0 before the first one.
+    pub fn last_round_id(&self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.reserved[CONFIG_LAST_ROUND_ID_RANGE]);
+        u64::from_le_bytes(bytes)
+    }
+
+    pub fn set_last_round_id(&mut self, round_id: u64) {
+        self.reserved[CONFIG_LAST_ROUND_ID_RANGE].copy_from_slice(&round_id.to_le_bytes());
+    }
+
+    /// Seconds past `end_ts` after which `settle_with_slot_hash` may settle
+    /// a round whose VRF never arrived; 0 disables the fallback.
+    pub fn vrf_timeout_sec(&self) -> u32 {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&self.reserved[CONFIG_VRF_TIMEOUT_SEC_RANGE]);
+        u32::from_le_bytes(bytes)
+    }
+
+    pub fn set_vrf_timeout_sec(&mut self, vrf_timeout_sec: u32) {
+        self.reserved[CONFIG_VRF_TIMEOUT_SEC_RANGE].copy_from_slice(&vrf_timeout_sec.to_le_bytes());
+    }
+
+    /// Admin catch-up for configs that predate the counter: moves
+    /// `last_round_id` up to the newest round already started. It never
+    /// moves back, so ids that were handed out cannot be started again.
+    pub fn advance_last_round_id(&mut self, round_id: u64) -> Result<(), LayoutError> {
+        if round_id < self.last_round_id() {
+            return Err(LayoutError::RoundIdOutOfSequence);
+        }
+        self.set_last_round_id(round_id);
+        Ok(())
+    }
+
+    /// `start_round` must use `last_round_id + 1`, so ids are never reused
+    /// or skipped. `devnet` builds only require the id to move forward, so a
+    /// config that predates the counter can catch up with existing rounds.
+    pub fn require_next_round_id(&self, round_id: u64) -> Result<(), LayoutError> {
+        let last = self.last_round_id();
+        #[cfg(feature = "devnet")]
+        let in_sequence = round_id > last;
+        #[cfg(not(feature = "devnet"))]
+        let in_sequence = last.checked_add(1) == Some(round_id);
+        if in_sequence {
+            Ok(())
+        } else {
+            Err(LayoutError::RoundIdOutOfSequence)
+        }
+    }
+
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
         if data.len() < CONFIG_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -112,6 +321,13 @@ impl ConfigView {
         Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..CONFIG_ACCOUNT_LEN])
     }
 
+    /// [`Self::read_from_account_data`] for a whole account: trailing bytes
+    /// are rejected with `WrongLength` instead of ignored.
+    pub fn read_from_account_data_exact(data: &[u8]) -> Result<Self, LayoutError> {
+        require_exact_len(data, CONFIG_ACCOUNT_LEN)?;
+        Self::read_from_account_data(data)
+    }
+
     pub fn write_to_account_data(&self, data: &mut [u8]) -> Result<(), LayoutError> {
         if data.len() < CONFIG_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -180,6 +396,25 @@ pub struct DegenConfigView {
     pub reserved: [u8; 27],
 }
 
+/// Oracle queue set by `set_vrf_queue`. Only this program writes it; Anchor
+/// has no counterpart, and the VRF requests fall back to the build-time
+/// `DEFAULT_QUEUE` while it does not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VrfQueueConfigView {
+    pub queue: [u8; PUBKEY_LEN],
+    pub bump: u8,
+}
+
+/// Second fee recipient set by `set_fee_split`: degen claims send
+/// `fee * fee_split_bps / 10_000` to `recipient_usdc_ata` and the rest to
+/// the treasury. Pinocchio-only, like `VrfQueueConfigView`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSplitConfigView {
+    pub recipient_usdc_ata: [u8; PUBKEY_LEN],
+    pub fee_split_bps: u16,
+    pub bump: u8,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DegenClaimView {
     pub round: [u8; PUBKEY_LEN],
@@ -246,6 +481,137 @@ pub struct RoundLifecycleView {
     pub participants_count: u16,
 }
 
+/// The round's VRF payer and whether it has been reimbursed, read together
+/// since every payout path that reimburses needs both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundVrfMeta {
+    pub payer: [u8; PUBKEY_LEN],
+    pub reimbursed: bool,
+}
+
+/// Program-owned Anchor account with a fixed length and 8-byte discriminator.
+///
+/// Lets runtime validators and tests check the header and decode the body
+/// the same way whether they hold a raw slice or a live `AccountView`.
+pub trait AccountLayout: Sized {
+    const LEN: usize;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN];
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError>;
+
+    fn check_header(data: &[u8]) -> Result<(), LayoutError> {
+        if data.len() != Self::LEN || data[..ANCHOR_DISCRIMINATOR_LEN] != Self::DISCRIMINATOR {
+            return Err(LayoutError::InvalidAccountHeader);
+        }
+        Ok(())
+    }
+
+    fn from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
+        Self::check_header(data)?;
+        Self::parse_body(&data[ANCHOR_DISCRIMINATOR_LEN..Self::LEN])
+    }
+
+    fn check_account_view(account: &AccountView) -> Result<(), ProgramError> {
+        let data = account.try_borrow()?;
+        Self::check_header(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    fn from_account_view(account: &AccountView) -> Result<Self, ProgramError> {
+        let data = account.try_borrow()?;
+        Self::from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl AccountLayout for ConfigView {
+    const LEN: usize = CONFIG_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = CONFIG_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for RoundLifecycleView {
+    const LEN: usize = ROUND_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = ROUND_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for DegenConfigView {
+    const LEN: usize = DEGEN_CONFIG_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = DEGEN_CONFIG_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for DegenClaimView {
+    const LEN: usize = DEGEN_CLAIM_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = DEGEN_CLAIM_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for VrfQueueConfigView {
+    const LEN: usize = VRF_QUEUE_CONFIG_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = VRF_QUEUE_CONFIG_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for FeeSplitConfigView {
+    const LEN: usize = FEE_SPLIT_CONFIG_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = FEE_SPLIT_CONFIG_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+impl AccountLayout for ParticipantView {
+    const LEN: usize = PARTICIPANT_ACCOUNT_LEN;
+    const DISCRIMINATOR: [u8; ANCHOR_DISCRIMINATOR_LEN] = PARTICIPANT_DISCRIMINATOR;
+
+    fn parse_body(body: &[u8]) -> Result<Self, LayoutError> {
+        Self::read_body(body)
+    }
+}
+
+/// Program-owned account types, for tooling that sizes accounts (and their
+/// rent-exempt minimum) without depending on the view structs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountKind {
+    Config,
+    Round,
+    Participant,
+    DegenConfig,
+    DegenClaim,
+    VrfQueueConfig,
+    FeeSplitConfig,
+}
+
+/// Full on-chain size (discriminator included) of an account of `kind`; the
+/// length the init paths pass to `CreateAccount`.
+pub const fn account_len_for(kind: AccountKind) -> usize {
+    match kind {
+        AccountKind::Config => ConfigView::LEN,
+        AccountKind::Round => RoundLifecycleView::LEN,
+        AccountKind::Participant => ParticipantView::LEN,
+        AccountKind::DegenConfig => DegenConfigView::LEN,
+        AccountKind::DegenClaim => DegenClaimView::LEN,
+        AccountKind::VrfQueueConfig => VrfQueueConfigView::LEN,
+        AccountKind::FeeSplitConfig => FeeSplitConfigView::LEN,
+    }
+}
+
 impl TokenAccountCoreView {
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
         if data.len() < TOKEN_ACCOUNT_CORE_LEN {
@@ -259,6 +625,9 @@ impl TokenAccountCoreView {
 }
 
 impl TokenAccountWithAmountView {
+    /// Reads mint, owner and amount from the first 72 bytes only, so a full
+    /// 165-byte SPL account costs the same as the synthetic layout
+    /// (`benches/token_account_reads.rs`).
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
         if data.len() < TOKEN_ACCOUNT_WITH_AMOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -266,7 +635,7 @@ impl TokenAccountWithAmountView {
         let mut offset = 0usize;
         let mint = read_pubkey(data, &mut offset)?;
         let owner = read_pubkey(data, &mut offset)?;
-        let amount = read_u64(data, &mut offset)?;
+        let amount = Self::read_amount_at_offset(data, SPL_AMOUNT_OFFSET)?;
         Ok(Self {
             mint,
             owner,
@@ -274,16 +643,67 @@ impl TokenAccountWithAmountView {
         })
     }
 
+    /// `AccountState` byte of a full SPL token account, or
+    /// `TOKEN_ACCOUNT_STATE_UNKNOWN` for the shorter synthetic layout.
+    pub fn read_state_from_account_data(data: &[u8]) -> Result<u8, LayoutError> {
+        if data.len() < TOKEN_ACCOUNT_WITH_AMOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        if data.len() < SPL_TOKEN_ACCOUNT_LEN {
+            return Ok(TOKEN_ACCOUNT_STATE_UNKNOWN);
+        }
+        Ok(data[SPL_STATE_OFFSET])
+    }
+
+    /// Whether the account is frozen; the synthetic layout never is.
+    pub fn is_frozen_account_data(data: &[u8]) -> Result<bool, LayoutError> {
+        Ok(Self::read_state_from_account_data(data)? == TOKEN_ACCOUNT_STATE_FROZEN)
+    }
+
     pub fn write_amount_to_account_data(data: &mut [u8], amount: u64) -> Result<(), LayoutError> {
         if data.len() < TOKEN_ACCOUNT_WITH_AMOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
         }
-        data[64..72].copy_from_slice(&amount.to_le_bytes());
-        Ok(())
+        Self::write_amount_at_offset(data, SPL_AMOUNT_OFFSET, amount)
+    }
+
+    /// For token layouts whose `amount` field does not live at `SPL_AMOUNT_OFFSET`.
+    pub fn read_amount_at_offset(data: &[u8], amount_offset: usize) -> Result<u64, LayoutError> {
+        if amount_offset.checked_add(8).is_none() {
+            return Err(LayoutError::SliceTooShort);
+        }
+        read_u64_at(data, amount_offset)
+    }
+
+    pub fn write_amount_at_offset(data: &mut [u8], amount_offset: usize, amount: u64) -> Result<(), LayoutError> {
+        if amount_offset.checked_add(8).is_none() {
+            return Err(LayoutError::SliceTooShort);
+        }
+        write_u64_at(data, amount_offset, amount)
     }
 }
 
 impl RoundLifecycleView {
+    /// Rejects rounds that have not reached the config's participant and
+    /// ticket minimums; the bounds are inclusive.
+    pub fn check_lockable(&self, config: &ConfigView) -> Result<(), LayoutError> {
+        if self.participants_count < config.min_participants
+            || self.total_tickets < config.min_total_tickets
+        {
+            return Err(LayoutError::RoundNotEligible);
+        }
+        Ok(())
+    }
+
+    /// Rejects adding another participant once all `MAX_PARTICIPANTS` slots
+    /// are taken. Top-ups from existing participants don't need a slot.
+    pub fn require_room_for_participant(&self) -> Result<(), LayoutError> {
+        if self.participants_count as usize >= MAX_PARTICIPANTS {
+            return Err(LayoutError::RoundFull);
+        }
+        Ok(())
+    }
+
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
         if data.len() < ROUND_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -291,6 +711,13 @@ impl RoundLifecycleView {
         Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN])
     }
 
+    /// [`Self::read_from_account_data`] for a whole account: trailing bytes
+    /// are rejected with `WrongLength` instead of ignored.
+    pub fn read_from_account_data_exact(data: &[u8]) -> Result<Self, LayoutError> {
+        require_exact_len(data, ROUND_ACCOUNT_LEN)?;
+        Self::read_from_account_data(data)
+    }
+
     pub fn write_to_account_data(&self, data: &mut [u8]) -> Result<(), LayoutError> {
         if data.len() < ROUND_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -299,6 +726,41 @@ impl RoundLifecycleView {
         Ok(())
     }
 
+    /// Clears the whole round buffer, then writes the `Round` discriminator and
+    /// `new`'s header fields.
+    ///
+    /// Must be used when recycling a round PDA: `write_to_account_data` only
+    /// touches the header, so participant keys, Fenwick weights, randomness,
+    /// winner, VRF payer and degen mode from the previous round would survive.
+    pub fn zero_and_reinit_account_data(
+        data: &mut [u8],
+        new: &RoundLifecycleView,
+    ) -> Result<(), LayoutError> {
+        if data.len() < ROUND_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        data.fill(0);
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&ROUND_DISCRIMINATOR);
+        new.write_to_account_data(data)
+    }
+
+    /// True once `round_duration_sec` has elapsed since the first deposit.
+    /// A round without deposits (`first_deposit_ts == 0`) never expires.
+    pub fn is_expired(&self, config: &ConfigView, now_ts: i64) -> bool {
+        if self.first_deposit_ts == 0 {
+            return false;
+        }
+        now_ts >= self.first_deposit_ts.saturating_add(config.round_duration_i64())
+    }
+
+    pub fn require_expired(&self, config: &ConfigView, now_ts: i64) -> Result<(), LayoutError> {
+        if self.is_expired(config, now_ts) {
+            Ok(())
+        } else {
+            Err(LayoutError::RoundStillOpen)
+        }
+    }
+
     pub fn write_status_to_account_data(data: &mut [u8], status: u8) -> Result<(), LayoutError> {
         if data.len() < ROUND_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -317,6 +779,17 @@ impl RoundLifecycleView {
         read_pubkey_at(body, ROUND_VAULT_USDC_ATA_OFFSET)
     }
 
+    /// [`Self::read_vault_pubkey_from_account_data`] for transfer paths: an
+    /// all-zero vault means `start_round` never recorded one, and moving
+    /// funds against it would target the system program.
+    pub fn require_vault_set(data: &[u8]) -> Result<[u8; PUBKEY_LEN], LayoutError> {
+        let vault = Self::read_vault_pubkey_from_account_data(data)?;
+        if vault == [0u8; PUBKEY_LEN] {
+            return Err(LayoutError::VaultNotSet);
+        }
+        Ok(vault)
+    }
+
     pub fn write_vault_pubkey_to_account_data(
         data: &mut [u8],
         vault: &[u8; PUBKEY_LEN],
@@ -412,6 +885,12 @@ impl RoundLifecycleView {
         )
     }
 
+    /// 1-based index of the first leaf whose prefix sum reaches `target`.
+    /// Tickets are numbered from 1 in leaf order, so with participant A
+    /// holding `1..=a` and B holding `a + 1..=b`, target `a` resolves to A
+    /// and `a + 1` to B. Zero-weight leaves own no tickets and are never
+    /// picked. The descent only advances while the candidate prefix is
+    /// strictly below `target`, which is what makes the boundary land on A.
     pub fn bit_find_prefix_in_account_data(data: &[u8], target: u64) -> Result<usize, LayoutError> {
         if data.len() < ROUND_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -479,6 +958,26 @@ impl RoundLifecycleView {
         write_u8_at(body, ROUND_VRF_REIMBURSED_OFFSET, reimbursed)
     }
 
+    pub fn read_vrf_meta_from_account_data(data: &[u8]) -> Result<RoundVrfMeta, LayoutError> {
+        if data.len() < ROUND_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        let body = &data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN];
+        let payer = read_pubkey_at(body, ROUND_VRF_PAYER_OFFSET)?;
+        let mut offset = ROUND_VRF_REIMBURSED_OFFSET;
+        let reimbursed = read_bool(body, &mut offset)?;
+        Ok(RoundVrfMeta { payer, reimbursed })
+    }
+
+    pub fn write_vrf_meta_to_account_data(data: &mut [u8], meta: &RoundVrfMeta) -> Result<(), LayoutError> {
+        if data.len() < ROUND_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        let body = &mut data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN];
+        write_bytes_at(body, ROUND_VRF_PAYER_OFFSET, &meta.payer)?;
+        write_u8_at(body, ROUND_VRF_REIMBURSED_OFFSET, u8::from(meta.reimbursed))
+    }
+
     pub fn read_degen_mode_status_from_account_data(data: &[u8]) -> Result<u8, LayoutError> {
         if data.len() < ROUND_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -487,6 +986,15 @@ impl RoundLifecycleView {
         read_u8_at(body, ROUND_RESERVED_OFFSET)
     }
 
+    /// Fails with `InconsistentDegenState` unless the round's degen mode is
+    /// `expected`; callers map that to their own "wrong mode" error.
+    pub fn require_degen_mode(data: &[u8], expected: u8) -> Result<(), LayoutError> {
+        if Self::read_degen_mode_status_from_account_data(data)? != expected {
+            return Err(LayoutError::InconsistentDegenState);
+        }
+        Ok(())
+    }
+
     pub fn write_degen_mode_status_to_account_data(
         data: &mut [u8],
         status: u8,
@@ -498,6 +1006,27 @@ impl RoundLifecycleView {
         write_u8_at(body, ROUND_RESERVED_OFFSET, status)
     }
 
+    /// Checks that the degen mode byte agrees with the round status.
+    ///
+    /// Degen mode only advances on a `Settled` round and becomes `CLAIMED`
+    /// together with the round; every other status must carry `NONE`.
+    pub fn verify_degen_mode_consistency(data: &[u8]) -> Result<(), LayoutError> {
+        let status = Self::read_from_account_data(data)?.status;
+        let degen_mode = Self::read_degen_mode_status_from_account_data(data)?;
+        let legal = match status {
+            ROUND_STATUS_SETTLED => matches!(
+                degen_mode,
+                DEGEN_MODE_NONE | DEGEN_MODE_VRF_REQUESTED | DEGEN_MODE_VRF_READY | DEGEN_MODE_EXECUTING
+            ),
+            ROUND_STATUS_CLAIMED => matches!(degen_mode, DEGEN_MODE_NONE | DEGEN_MODE_CLAIMED),
+            _ => degen_mode == DEGEN_MODE_NONE,
+        };
+        if !legal {
+            return Err(LayoutError::InconsistentDegenState);
+        }
+        Ok(())
+    }
+
     pub fn bit_sub_in_account_data(
         data: &mut [u8],
         mut index: usize,
@@ -513,6 +1042,12 @@ impl RoundLifecycleView {
         let body = &mut data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN];
         let n = ROUND_FENWICK_NODE_COUNT - 1;
 
+        // Every node on the update path covers this leaf, so checking the leaf
+        // first means a short leaf fails before any node is rewritten.
+        if fenwick_leaf(body, index)? < delta {
+            return Err(LayoutError::MathOverflow);
+        }
+
         while index <= n {
             let node_offset = ROUND_BIT_OFFSET + (index * 8);
             let current = read_u64_at(body, node_offset)?;
@@ -539,6 +1074,12 @@ impl RoundLifecycleView {
         let body = &mut data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN];
         let n = ROUND_FENWICK_NODE_COUNT - 1;
 
+        // No node exceeds the full prefix sum, so if `prefix(n) + delta` fits
+        // the walk below cannot overflow halfway and leave a torn tree.
+        if delta > Self::bit_capacity_in_body(body)? {
+            return Err(LayoutError::MathOverflow);
+        }
+
         while index <= n {
             let node_offset = ROUND_BIT_OFFSET + (index * 8);
             let current = read_u64_at(body, node_offset)?;
@@ -550,6 +1091,161 @@ impl RoundLifecycleView {
         Ok(())
     }
 
+    /// Ticket weight of a single Fenwick leaf (1-based participant index).
+    pub fn bit_point_query_in_account_data(data: &[u8], index: usize) -> Result<u64, LayoutError> {
+        if data.len() < ROUND_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        if index == 0 || index > ROUND_FENWICK_NODE_COUNT - 1 {
+            return Err(LayoutError::SliceTooShort);
+        }
+        fenwick_leaf(&data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN], index)
+    }
+
+    /// Every participant of the round paired with their ticket weight, in
+    /// deposit order. Meant for off-chain settlement audits.
+    pub fn read_participants_with_weights_from_account_data(
+        data: &[u8],
+    ) -> Result<Vec<([u8; PUBKEY_LEN], u64)>, LayoutError> {
+        let round = Self::read_from_account_data(data)?;
+        (0..round.participants_count as usize)
+            .map(|index_zero_based| {
+                let participant = Self::read_participant_pubkey_from_account_data(data, index_zero_based)?;
+                let weight = Self::bit_point_query_in_account_data(data, index_zero_based + 1)?;
+                Ok((participant, weight))
+            })
+            .collect()
+    }
+
+    /// How much ticket weight can still be added to the Fenwick tree before
+    /// its full prefix sum would overflow `u64`.
+    pub fn bit_capacity_remaining(data: &[u8]) -> Result<u64, LayoutError> {
+        if data.len() < ROUND_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        Self::bit_capacity_in_body(&data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN])
+    }
+
+    fn bit_capacity_in_body(body: &[u8]) -> Result<u64, LayoutError> {
+        Ok(u64::MAX - fenwick_prefix(body, ROUND_FENWICK_NODE_COUNT - 1)?)
+    }
+
+    /// Credits `tickets_delta` to a participant's Fenwick leaf and to
+    /// `total_tickets` in one step so the two cannot drift apart.
+    ///
+    /// The total is checked before any node is touched; because every node is
+    /// a partial sum of the total, the node additions cannot overflow after it.
+    pub fn apply_deposit_to_account_data(
+        data: &mut [u8],
+        participant_index: usize,
+        tickets_delta: u64,
+    ) -> Result<(), LayoutError> {
+        let mut round = Self::read_from_account_data(data)?;
+        round.total_tickets = round
+            .total_tickets
+            .checked_add(tickets_delta)
+            .ok_or(LayoutError::MathOverflow)?;
+        Self::bit_add_in_account_data(data, participant_index, tickets_delta)?;
+        round.write_to_account_data(data)
+    }
+
+    /// [`Self::apply_deposit_to_account_data`] for a participant already
+    /// seated in the round: repeat deposits grow the existing leaf, and an
+    /// index past `participants_count` is rejected instead of crediting a
+    /// leaf `verify_fenwick_consistency` would flag.
+    pub fn increment_participant_tickets(
+        data: &mut [u8],
+        participant_index: usize,
+        tickets_delta: u64,
+    ) -> Result<(), LayoutError> {
+        let round = Self::read_from_account_data(data)?;
+        if participant_index == 0 || participant_index > round.participants_count as usize {
+            return Err(LayoutError::InconsistentFenwick);
+        }
+        Self::apply_deposit_to_account_data(data, participant_index, tickets_delta)
+    }
+
+    /// Checks that the Fenwick tree describes non-negative per-participant
+    /// weights that sum to `total_tickets`, with no weight past
+    /// `participants_count`. Intended for tests and devnet invariant checks.
+    pub fn verify_fenwick_consistency(data: &[u8]) -> Result<(), LayoutError> {
+        let round = Self::read_from_account_data(data)?;
+        let body = &data[ANCHOR_DISCRIMINATOR_LEN..ROUND_ACCOUNT_LEN];
+        let n = ROUND_FENWICK_NODE_COUNT - 1;
+        let participants = round.participants_count as usize;
+        if participants > n {
+            return Err(LayoutError::InconsistentFenwick);
+        }
+
+        let mut previous = 0u64;
+        let mut index = 1usize;
+        while index <= n {
+            let prefix = fenwick_prefix(body, index)?;
+            let leaf_is_negative = prefix < previous;
+            let weight_past_last_participant = index > participants && prefix != previous;
+            if leaf_is_negative || weight_past_last_participant {
+                return Err(LayoutError::InconsistentFenwick);
+            }
+            previous = prefix;
+            index += 1;
+        }
+
+        if previous != round.total_tickets {
+            return Err(LayoutError::InconsistentFenwick);
+        }
+        Ok(())
+    }
+
+    /// Post-settlement invariant: the participant owning the stored
+    /// `winning_ticket` in the Fenwick tree is the stored `winner`. Lets
+    /// indexers check a settled round without replaying the VRF reduction.
+    pub fn verify_winner_matches_ticket(data: &[u8]) -> Result<(), LayoutError> {
+        let round = Self::read_from_account_data(data)?;
+        let winning_ticket = Self::read_winning_ticket_from_account_data(data)?;
+        if winning_ticket == 0 || winning_ticket > round.total_tickets {
+            return Err(LayoutError::WinnerMismatch);
+        }
+        let winner_idx = Self::bit_find_prefix_in_account_data(data, winning_ticket)?;
+        if winner_idx > round.participants_count as usize {
+            return Err(LayoutError::WinnerMismatch);
+        }
+        let owner = Self::read_participant_pubkey_from_account_data(data, winner_idx - 1)?;
+        if owner != Self::read_winner_from_account_data(data)? {
+            return Err(LayoutError::WinnerMismatch);
+        }
+        Ok(())
+    }
+
+    /// Every round invariant in one pass, returning the first failure:
+    /// header, status enum, `participants_count` bound, Fenwick tree vs
+    /// `total_tickets`, degen mode vs status, and for settled or claimed
+    /// rounds the stored winner vs the winning ticket. Meant for audits and
+    /// devnet assertions.
+    pub fn verify_integrity(data: &[u8]) -> Result<(), LayoutError> {
+        Self::check_header(data)?;
+        let round = Self::read_from_account_data(data)?;
+        if !matches!(
+            round.status,
+            ROUND_STATUS_OPEN
+                | ROUND_STATUS_LOCKED
+                | ROUND_STATUS_VRF_REQUESTED
+                | ROUND_STATUS_SETTLED
+                | ROUND_STATUS_CLAIMED
+                | ROUND_STATUS_CANCELLED
+        ) {
+            return Err(LayoutError::InvalidRoundStatus);
+        }
+        if round.participants_count as usize > MAX_PARTICIPANTS {
+            return Err(LayoutError::RoundFull);
+        }
+        Self::verify_fenwick_consistency(data)?;
+        Self::verify_degen_mode_consistency(data)?;
+        if matches!(round.status, ROUND_STATUS_SETTLED | ROUND_STATUS_CLAIMED) {
+            Self::verify_winner_matches_ticket(data)?;
+        }
+        Ok(())
+    }
+
     pub fn write_bit_node_to_account_data(
         data: &mut [u8],
         index: usize,
@@ -618,6 +1314,49 @@ impl DegenConfigView {
         Ok(())
     }
 
+    /// Inclusive `MIN_FALLBACK_TIMEOUT_SEC..=MAX_FALLBACK_TIMEOUT_SEC` check.
+    pub fn validate_timeout(timeout: u32) -> Result<(), LayoutError> {
+        if !(MIN_FALLBACK_TIMEOUT_SEC..=MAX_FALLBACK_TIMEOUT_SEC).contains(&timeout) {
+            return Err(LayoutError::InvalidFallbackTimeout);
+        }
+        Ok(())
+    }
+
+    /// How many pool candidates `request_degen_vrf` offers the winner.
+    pub fn candidate_window(&self) -> u8 {
+        match self.reserved[DEGEN_CONFIG_CANDIDATE_WINDOW_INDEX] {
+            0 => DEGEN_CANDIDATE_WINDOW,
+            window => window,
+        }
+    }
+
+    pub fn set_candidate_window(&mut self, window: u8) {
+        self.reserved[DEGEN_CONFIG_CANDIDATE_WINDOW_INDEX] = window;
+    }
+
+    /// Inclusive `1..=pool_len` check: every rank in the window must map to a
+    /// distinct pool index.
+    pub fn validate_candidate_window(window: u8, pool_len: usize) -> Result<(), LayoutError> {
+        if window == 0 || usize::from(window) > pool_len {
+            return Err(LayoutError::InvalidCandidateWindow);
+        }
+        Ok(())
+    }
+
+    pub fn read_fallback_timeout_from_account_data(data: &[u8]) -> Result<u32, LayoutError> {
+        if data.len() < DEGEN_CONFIG_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        read_u32_at(data, ANCHOR_DISCRIMINATOR_LEN + DEGEN_CONFIG_FALLBACK_TIMEOUT_OFFSET)
+    }
+
+    pub fn write_fallback_timeout_to_account_data(data: &mut [u8], timeout: u32) -> Result<(), LayoutError> {
+        if data.len() < DEGEN_CONFIG_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        write_u32_at(data, ANCHOR_DISCRIMINATOR_LEN + DEGEN_CONFIG_FALLBACK_TIMEOUT_OFFSET, timeout)
+    }
+
     pub fn read_body(body: &[u8]) -> Result<Self, LayoutError> {
         if body.len() < DEGEN_CONFIG_BODY_LEN {
             return Err(LayoutError::SliceTooShort);
@@ -646,35 +1385,123 @@ impl DegenConfigView {
     }
 }
 
-impl DegenClaimView {
+impl VrfQueueConfigView {
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
-        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+        if data.len() < VRF_QUEUE_CONFIG_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
         }
-        Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN])
+        Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..VRF_QUEUE_CONFIG_ACCOUNT_LEN])
     }
 
     pub fn write_to_account_data(&self, data: &mut [u8]) -> Result<(), LayoutError> {
-        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+        if data.len() < VRF_QUEUE_CONFIG_ACCOUNT_LEN {
             return Err(LayoutError::SliceTooShort);
         }
-        self.write_body(&mut data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN]);
+        self.write_body(&mut data[ANCHOR_DISCRIMINATOR_LEN..VRF_QUEUE_CONFIG_ACCOUNT_LEN]);
         Ok(())
     }
 
     pub fn read_body(body: &[u8]) -> Result<Self, LayoutError> {
-        if body.len() < DEGEN_CLAIM_BODY_LEN {
+        if body.len() < VRF_QUEUE_CONFIG_BODY_LEN {
             return Err(LayoutError::SliceTooShort);
         }
 
-        Ok(Self {
-            round: read_pubkey_at(body, DEGEN_CLAIM_ROUND_OFFSET)?,
-            winner: read_pubkey_at(body, DEGEN_CLAIM_WINNER_OFFSET)?,
-            round_id: read_u64_at(body, DEGEN_CLAIM_ROUND_ID_OFFSET)?,
-            status: read_u8_at(body, DEGEN_CLAIM_STATUS_OFFSET)?,
-            bump: read_u8_at(body, DEGEN_CLAIM_BUMP_OFFSET)?,
-            selected_candidate_rank: read_u8_at(body, DEGEN_CLAIM_SELECTED_CANDIDATE_RANK_OFFSET)?,
-            fallback_reason: read_u8_at(body, DEGEN_CLAIM_FALLBACK_REASON_OFFSET)?,
+        let mut offset = 0usize;
+        let queue = read_pubkey(body, &mut offset)?;
+        let bump = read_u8(body, &mut offset)?;
+
+        Ok(Self { queue, bump })
+    }
+
+    pub fn write_body(&self, body: &mut [u8]) {
+        let mut offset = 0usize;
+        write_bytes(body, &mut offset, &self.queue);
+        write_u8(body, &mut offset, self.bump);
+    }
+}
+
+impl FeeSplitConfigView {
+    pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
+        if data.len() < FEE_SPLIT_CONFIG_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..FEE_SPLIT_CONFIG_ACCOUNT_LEN])
+    }
+
+    pub fn write_to_account_data(&self, data: &mut [u8]) -> Result<(), LayoutError> {
+        if data.len() < FEE_SPLIT_CONFIG_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        self.write_body(&mut data[ANCHOR_DISCRIMINATOR_LEN..FEE_SPLIT_CONFIG_ACCOUNT_LEN]);
+        Ok(())
+    }
+
+    pub fn read_body(body: &[u8]) -> Result<Self, LayoutError> {
+        if body.len() < FEE_SPLIT_CONFIG_BODY_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+
+        let mut offset = 0usize;
+        let recipient_usdc_ata = read_pubkey(body, &mut offset)?;
+        let fee_split_bps = read_u16(body, &mut offset)?;
+        let bump = read_u8(body, &mut offset)?;
+
+        Ok(Self { recipient_usdc_ata, fee_split_bps, bump })
+    }
+
+    pub fn write_body(&self, body: &mut [u8]) {
+        let mut offset = 0usize;
+        write_bytes(body, &mut offset, &self.recipient_usdc_ata);
+        write_u16(body, &mut offset, self.fee_split_bps);
+        write_u8(body, &mut offset, self.bump);
+    }
+}
+
+impl DegenClaimView {
+    pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
+        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        Self::read_body(&data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN])
+    }
+
+    pub fn write_to_account_data(&self, data: &mut [u8]) -> Result<(), LayoutError> {
+        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        self.write_body(&mut data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN]);
+        Ok(())
+    }
+
+    pub fn write_status_to_account_data(data: &mut [u8], status: u8) -> Result<(), LayoutError> {
+        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        let body = &mut data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN];
+        write_u8_at(body, DEGEN_CLAIM_STATUS_OFFSET, status)
+    }
+
+    pub fn write_claimed_at_to_account_data(data: &mut [u8], claimed_at: i64) -> Result<(), LayoutError> {
+        if data.len() < DEGEN_CLAIM_ACCOUNT_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+        let body = &mut data[ANCHOR_DISCRIMINATOR_LEN..DEGEN_CLAIM_ACCOUNT_LEN];
+        write_i64_at(body, DEGEN_CLAIM_CLAIMED_AT_OFFSET, claimed_at)
+    }
+
+    pub fn read_body(body: &[u8]) -> Result<Self, LayoutError> {
+        if body.len() < DEGEN_CLAIM_BODY_LEN {
+            return Err(LayoutError::SliceTooShort);
+        }
+
+        Ok(Self {
+            round: read_pubkey_at(body, DEGEN_CLAIM_ROUND_OFFSET)?,
+            winner: read_pubkey_at(body, DEGEN_CLAIM_WINNER_OFFSET)?,
+            round_id: read_u64_at(body, DEGEN_CLAIM_ROUND_ID_OFFSET)?,
+            status: read_u8_at(body, DEGEN_CLAIM_STATUS_OFFSET)?,
+            bump: read_u8_at(body, DEGEN_CLAIM_BUMP_OFFSET)?,
+            selected_candidate_rank: read_u8_at(body, DEGEN_CLAIM_SELECTED_CANDIDATE_RANK_OFFSET)?,
+            fallback_reason: read_u8_at(body, DEGEN_CLAIM_FALLBACK_REASON_OFFSET)?,
             token_index: read_u32_at(body, DEGEN_CLAIM_TOKEN_INDEX_OFFSET)?,
             pool_version: read_u32_at(body, DEGEN_CLAIM_POOL_VERSION_OFFSET)?,
             candidate_window: read_u8_at(body, DEGEN_CLAIM_CANDIDATE_WINDOW_OFFSET)?,
@@ -695,6 +1522,53 @@ impl DegenClaimView {
         })
     }
 
+    /// [`Self::read_body`] for bodies written by a newer program that grew
+    /// the struct: the known fields parse as usual and the number of bytes
+    /// past `DEGEN_CLAIM_BODY_LEN` is returned alongside them.
+    pub fn read_body_versioned(body: &[u8]) -> Result<(Self, usize), LayoutError> {
+        let view = Self::read_body(body)?;
+        Ok((view, body.len() - DEGEN_CLAIM_BODY_LEN))
+    }
+
+    /// When the current execution began, 0 if it was never recorded.
+    pub fn execution_started_at(&self) -> i64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.reserved[DEGEN_CLAIM_EXECUTION_STARTED_AT_RANGE]);
+        i64::from_le_bytes(bytes)
+    }
+
+    pub fn set_execution_started_at(&mut self, started_at: i64) {
+        self.reserved[DEGEN_CLAIM_EXECUTION_STARTED_AT_RANGE].copy_from_slice(&started_at.to_le_bytes());
+    }
+
+    /// Whether the fee and VRF reimbursement already left the vault.
+    pub fn fees_paid(&self) -> bool {
+        self.reserved[DEGEN_CLAIM_FEES_PAID_INDEX] != 0
+    }
+
+    pub fn set_fees_paid(&mut self, paid: bool) {
+        self.reserved[DEGEN_CLAIM_FEES_PAID_INDEX] = u8::from(paid);
+    }
+
+    /// Returns true when an executor began a swap (`EXECUTING`) but has not
+    /// finalized it within `stale_after_sec` of `execution_started_at`.
+    /// Claims that predate the field fall back to `requested_at`.
+    pub fn is_stale_executing(&self, now_ts: i64, stale_after_sec: i64) -> bool {
+        let started_at = match self.execution_started_at() {
+            0 => self.requested_at,
+            started_at => started_at,
+        };
+        self.status == DEGEN_CLAIM_STATUS_EXECUTING && now_ts.saturating_sub(started_at) > stale_after_sec
+    }
+
+    /// Rejects a fallback claim before the claim's `fallback_after_ts` deadline.
+    pub fn require_fallback_ready(&self, now_ts: i64) -> Result<(), LayoutError> {
+        if now_ts < self.fallback_after_ts {
+            return Err(LayoutError::FallbackNotReady);
+        }
+        Ok(())
+    }
+
     pub fn write_body(&self, body: &mut [u8]) {
         write_bytes_at(body, DEGEN_CLAIM_ROUND_OFFSET, &self.round).unwrap();
         write_bytes_at(body, DEGEN_CLAIM_WINNER_OFFSET, &self.winner).unwrap();
@@ -724,6 +1598,18 @@ impl DegenClaimView {
     }
 }
 
+/// A participant account only speaks for the round it was opened in; using
+/// it against another round PDA would misattribute its deposits.
+pub fn require_participant_belongs_to_round(
+    participant: &ParticipantView,
+    round_address: &[u8; PUBKEY_LEN],
+) -> Result<(), LayoutError> {
+    if participant.round != *round_address {
+        return Err(LayoutError::ParticipantRoundMismatch);
+    }
+    Ok(())
+}
+
 impl ParticipantView {
     pub fn read_from_account_data(data: &[u8]) -> Result<Self, LayoutError> {
         if data.len() < PARTICIPANT_ACCOUNT_LEN {
@@ -780,6 +1666,18 @@ impl ParticipantView {
     }
 }
 
+/// Too-short and oversized buffers fail differently: a longer account than
+/// the layout expects points at a layout version mismatch, not a truncation.
+fn require_exact_len(data: &[u8], len: usize) -> Result<(), LayoutError> {
+    if data.len() < len {
+        return Err(LayoutError::SliceTooShort);
+    }
+    if data.len() > len {
+        return Err(LayoutError::WrongLength);
+    }
+    Ok(())
+}
+
 fn read_pubkey(data: &[u8], offset: &mut usize) -> Result<[u8; PUBKEY_LEN], LayoutError> {
     if data.len() < *offset + PUBKEY_LEN {
         return Err(LayoutError::SliceTooShort);
@@ -928,6 +1826,25 @@ fn read_u32_at(data: &[u8], offset: usize) -> Result<u32, LayoutError> {
     ]))
 }
 
+/// Sum of Fenwick leaves `1..=index` read from a round body.
+fn fenwick_prefix(body: &[u8], index: usize) -> Result<u64, LayoutError> {
+    let mut prefix = 0u64;
+    let mut cursor = index;
+    while cursor > 0 {
+        let node = read_u64_at(body, ROUND_BIT_OFFSET + (cursor * 8))?;
+        prefix = prefix.checked_add(node).ok_or(LayoutError::MathOverflow)?;
+        cursor &= cursor - 1;
+    }
+    Ok(prefix)
+}
+
+/// Weight of Fenwick leaf `index` alone: `prefix(index) - prefix(index - 1)`.
+fn fenwick_leaf(body: &[u8], index: usize) -> Result<u64, LayoutError> {
+    fenwick_prefix(body, index)?
+        .checked_sub(fenwick_prefix(body, index - 1)?)
+        .ok_or(LayoutError::MathOverflow)
+}
+
 fn read_u64_at(data: &[u8], offset: usize) -> Result<u64, LayoutError> {
     if data.len() < offset + 8 {
         return Err(LayoutError::SliceTooShort);
@@ -1024,7 +1941,7 @@ fn write_i64_at(data: &mut [u8], offset: usize, value: i64) -> Result<(), Layout
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::anchor_compat::account_discriminator;
+    use crate::{anchor_compat::account_discriminator, winner_selection::linear_find_winner};
 
     #[test]
     fn config_lengths_match_live_program() {
@@ -1070,6 +1987,177 @@ mod tests {
         assert_eq!(&data[..ANCHOR_DISCRIMINATOR_LEN], &[7u8; ANCHOR_DISCRIMINATOR_LEN]);
     }
 
+    #[test]
+    fn config_vrf_reimbursement_flag_defaults_to_enabled() {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        let mut view = ConfigView::read_from_account_data(&data).unwrap();
+        assert!(view.vrf_reimbursement_enabled());
+
+        view.set_vrf_reimbursement_enabled(false);
+        view.write_to_account_data(&mut data).unwrap();
+        let parsed = ConfigView::read_from_account_data(&data).unwrap();
+        assert!(!parsed.vrf_reimbursement_enabled());
+        assert_eq!(parsed.reserved[1..], [0u8; 23]);
+
+        view.set_vrf_reimbursement_enabled(true);
+        assert_eq!(view.reserved, [0u8; 24]);
+    }
+
+    #[test]
+    fn config_last_round_id_round_trips_in_reserved() {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        let mut view = ConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(view.last_round_id(), 0);
+        view.require_next_round_id(1).unwrap();
+
+        view.set_vrf_reimbursement_enabled(false);
+        view.set_last_round_id(81);
+        view.write_to_account_data(&mut data).unwrap();
+        let parsed = ConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(parsed.last_round_id(), 81);
+        assert!(!parsed.vrf_reimbursement_enabled());
+
+        parsed.require_next_round_id(82).unwrap();
+        assert_eq!(parsed.require_next_round_id(81), Err(LayoutError::RoundIdOutOfSequence));
+        assert_eq!(parsed.require_next_round_id(80), Err(LayoutError::RoundIdOutOfSequence));
+        #[cfg(not(feature = "devnet"))]
+        assert_eq!(parsed.require_next_round_id(83), Err(LayoutError::RoundIdOutOfSequence));
+        #[cfg(feature = "devnet")]
+        parsed.require_next_round_id(83).unwrap();
+
+        view.set_last_round_id(u64::MAX);
+        assert_eq!(view.require_next_round_id(0), Err(LayoutError::RoundIdOutOfSequence));
+    }
+
+    #[test]
+    fn config_reserved_flags_use_their_own_bytes() {
+        let mut view = ConfigView::read_from_account_data(&[0u8; CONFIG_ACCOUNT_LEN]).unwrap();
+        assert!(!view.vrf_queue_overridden());
+        assert!(!view.fee_split_enabled());
+
+        view.mark_vrf_queue_overridden();
+        view.set_fee_split_enabled(true);
+        assert!(view.vrf_reimbursement_enabled());
+        assert_eq!(view.reserved[..3], [0, 1, 1]);
+        assert_eq!(view.reserved[3..], [0u8; 21]);
+
+        view.set_fee_split_enabled(false);
+        assert!(!view.fee_split_enabled());
+        assert!(view.vrf_queue_overridden());
+    }
+
+    #[test]
+    fn config_vrf_timeout_round_trips_in_reserved() {
+        let mut data = [0u8; CONFIG_ACCOUNT_LEN];
+        let mut view = ConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(view.vrf_timeout_sec(), 0);
+
+        view.set_last_round_id(u64::MAX);
+        view.set_vrf_timeout_sec(86_400);
+        view.write_to_account_data(&mut data).unwrap();
+        let parsed = ConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(parsed.vrf_timeout_sec(), 86_400);
+        assert_eq!(parsed.last_round_id(), u64::MAX);
+        assert_eq!(parsed.reserved[16..18], [0u8; 2]);
+        assert_eq!(parsed.reserved[22..], [0u8; 2]);
+    }
+
+    #[test]
+    fn exact_reads_reject_short_and_oversized_accounts() {
+        let config = vec![0u8; CONFIG_ACCOUNT_LEN + 1];
+        assert!(ConfigView::read_from_account_data_exact(&config[..CONFIG_ACCOUNT_LEN]).is_ok());
+        assert_eq!(
+            ConfigView::read_from_account_data_exact(&config[..CONFIG_ACCOUNT_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+        assert_eq!(ConfigView::read_from_account_data_exact(&config), Err(LayoutError::WrongLength));
+        assert!(ConfigView::read_from_account_data(&config).is_ok());
+
+        let round = vec![0u8; ROUND_ACCOUNT_LEN + 1];
+        assert!(RoundLifecycleView::read_from_account_data_exact(&round[..ROUND_ACCOUNT_LEN]).is_ok());
+        assert_eq!(
+            RoundLifecycleView::read_from_account_data_exact(&round[..ROUND_ACCOUNT_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+        assert_eq!(RoundLifecycleView::read_from_account_data_exact(&round), Err(LayoutError::WrongLength));
+        assert!(RoundLifecycleView::read_from_account_data(&round).is_ok());
+    }
+
+    #[test]
+    fn config_round_duration_i64_does_not_wrap() {
+        let mut view = ConfigView::read_from_account_data(&[0u8; CONFIG_ACCOUNT_LEN]).unwrap();
+        view.round_duration_sec = u32::MAX;
+        assert_eq!(view.round_duration_i64(), 4_294_967_295);
+
+        // Bit 31 set: a sign-extending `as i32` detour would go negative.
+        view.round_duration_sec = 0x8000_0000;
+        assert_eq!(view.round_duration_i64(), 2_147_483_648);
+    }
+
+    #[test]
+    fn config_validate_reports_each_invariant() {
+        let valid = ConfigView {
+            fee_bps: 10_000,
+            ticket_unit: 1,
+            round_duration_sec: 1,
+            min_participants: 1,
+            ..ConfigView::read_from_account_data(&[0u8; CONFIG_ACCOUNT_LEN]).unwrap()
+        };
+        assert_eq!(valid.validate(), Ok(()));
+
+        let cases = [
+            (ConfigView { fee_bps: 10_001, ..valid }, LayoutError::InvalidFeeBps),
+            (ConfigView { ticket_unit: 0, ..valid }, LayoutError::InvalidTicketUnit),
+            (ConfigView { round_duration_sec: 0, ..valid }, LayoutError::InvalidRoundDuration),
+            (ConfigView { min_participants: 0, ..valid }, LayoutError::InvalidMinParticipants),
+        ];
+        for (config, expected) in cases {
+            assert_eq!(config.validate(), Err(expected));
+        }
+
+        for (vrf_timeout_sec, expected) in [
+            (0, Ok(())),
+            (MIN_VRF_TIMEOUT_SEC, Ok(())),
+            (MAX_VRF_TIMEOUT_SEC, Ok(())),
+            (MIN_VRF_TIMEOUT_SEC - 1, Err(LayoutError::InvalidFallbackTimeout)),
+            (MAX_VRF_TIMEOUT_SEC + 1, Err(LayoutError::InvalidFallbackTimeout)),
+        ] {
+            let mut config = valid;
+            config.set_vrf_timeout_sec(vrf_timeout_sec);
+            assert_eq!(config.validate(), expected, "vrf_timeout_sec {vrf_timeout_sec}");
+        }
+    }
+
+    #[test]
+    fn vrf_queue_config_round_trip() {
+        let view = VrfQueueConfigView { queue: [6u8; 32], bump: 250 };
+
+        let mut data = [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&VrfQueueConfigView::DISCRIMINATOR);
+        view.write_to_account_data(&mut data).unwrap();
+
+        assert_eq!(VrfQueueConfigView::from_account_data(&data), Ok(view));
+        assert_eq!(
+            VrfQueueConfigView::read_from_account_data(&data[..VRF_QUEUE_CONFIG_ACCOUNT_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    #[test]
+    fn fee_split_config_round_trip() {
+        let view = FeeSplitConfigView { recipient_usdc_ata: [6u8; 32], fee_split_bps: 2_500, bump: 250 };
+
+        let mut data = [0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&FeeSplitConfigView::DISCRIMINATOR);
+        view.write_to_account_data(&mut data).unwrap();
+
+        assert_eq!(FeeSplitConfigView::from_account_data(&data), Ok(view));
+        assert_eq!(
+            FeeSplitConfigView::read_from_account_data(&data[..FEE_SPLIT_CONFIG_ACCOUNT_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
     #[test]
     fn degen_config_round_trip_preserves_anchor_layout() {
         let view = DegenConfigView {
@@ -1088,6 +2176,80 @@ mod tests {
         assert_eq!(&data[..ANCHOR_DISCRIMINATOR_LEN], &[5u8; ANCHOR_DISCRIMINATOR_LEN]);
     }
 
+    #[test]
+    fn degen_config_timeout_bounds_are_inclusive() {
+        assert!(DegenConfigView::validate_timeout(DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC).is_ok());
+        assert!(DegenConfigView::validate_timeout(MIN_FALLBACK_TIMEOUT_SEC).is_ok());
+        assert!(DegenConfigView::validate_timeout(MAX_FALLBACK_TIMEOUT_SEC).is_ok());
+        assert_eq!(
+            DegenConfigView::validate_timeout(0),
+            Err(LayoutError::InvalidFallbackTimeout)
+        );
+        assert_eq!(
+            DegenConfigView::validate_timeout(MIN_FALLBACK_TIMEOUT_SEC - 1),
+            Err(LayoutError::InvalidFallbackTimeout)
+        );
+        assert_eq!(
+            DegenConfigView::validate_timeout(MAX_FALLBACK_TIMEOUT_SEC + 1),
+            Err(LayoutError::InvalidFallbackTimeout)
+        );
+    }
+
+    #[test]
+    fn degen_config_candidate_window_defaults_and_round_trips() {
+        let mut view = DegenConfigView {
+            executor: [4u8; 32],
+            fallback_timeout_sec: 300,
+            bump: 201,
+            reserved: [0u8; 27],
+        };
+        assert_eq!(view.candidate_window(), DEGEN_CANDIDATE_WINDOW);
+
+        view.set_candidate_window(12);
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        view.write_to_account_data(&mut data).unwrap();
+        let parsed = DegenConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(parsed.candidate_window(), 12);
+        assert_eq!(parsed.fallback_timeout_sec, 300);
+        assert_eq!(parsed.reserved[1..], [0u8; 26]);
+    }
+
+    #[test]
+    fn degen_config_candidate_window_bounds_are_inclusive() {
+        assert!(DegenConfigView::validate_candidate_window(1, 40).is_ok());
+        assert!(DegenConfigView::validate_candidate_window(40, 40).is_ok());
+        assert_eq!(
+            DegenConfigView::validate_candidate_window(0, 40),
+            Err(LayoutError::InvalidCandidateWindow)
+        );
+        assert_eq!(
+            DegenConfigView::validate_candidate_window(41, 40),
+            Err(LayoutError::InvalidCandidateWindow)
+        );
+    }
+
+    #[test]
+    fn degen_config_fallback_timeout_accessor_touches_only_timeout() {
+        let view = DegenConfigView {
+            executor: [4u8; 32],
+            fallback_timeout_sec: 300,
+            bump: 201,
+            reserved: [8u8; 27],
+        };
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        view.write_to_account_data(&mut data).unwrap();
+
+        assert_eq!(DegenConfigView::read_fallback_timeout_from_account_data(&data).unwrap(), 300);
+        DegenConfigView::write_fallback_timeout_to_account_data(&mut data, 900).unwrap();
+
+        let parsed = DegenConfigView::read_from_account_data(&data).unwrap();
+        assert_eq!(parsed, DegenConfigView { fallback_timeout_sec: 900, ..view });
+        assert_eq!(
+            DegenConfigView::write_fallback_timeout_to_account_data(&mut data[..DEGEN_CONFIG_ACCOUNT_LEN - 1], 1),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
     #[test]
     fn token_account_core_reads_mint_and_owner() {
         let mut data = [0u8; TOKEN_ACCOUNT_CORE_LEN];
@@ -1112,6 +2274,66 @@ mod tests {
         assert_eq!(parsed.amount, 123_456);
     }
 
+    #[test]
+    fn token_amount_default_offset_matches_spl_layout() {
+        assert_eq!(SPL_AMOUNT_OFFSET, TOKEN_ACCOUNT_CORE_LEN);
+        assert_eq!(SPL_AMOUNT_OFFSET + 8, TOKEN_ACCOUNT_WITH_AMOUNT_LEN);
+
+        let mut data = [0u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, 987_654).unwrap();
+        assert_eq!(&data[64..72], &987_654u64.to_le_bytes());
+        assert_eq!(
+            TokenAccountWithAmountView::read_amount_at_offset(&data, SPL_AMOUNT_OFFSET).unwrap(),
+            TokenAccountWithAmountView::read_from_account_data(&data).unwrap().amount
+        );
+    }
+
+    #[test]
+    fn token_account_state_reads_full_spl_layout_only() {
+        let mut data = [0u8; SPL_TOKEN_ACCOUNT_LEN];
+        data[SPL_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_INITIALIZED;
+        assert_eq!(
+            TokenAccountWithAmountView::read_state_from_account_data(&data),
+            Ok(TOKEN_ACCOUNT_STATE_INITIALIZED)
+        );
+        assert_eq!(TokenAccountWithAmountView::is_frozen_account_data(&data), Ok(false));
+
+        data[SPL_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_FROZEN;
+        assert_eq!(
+            TokenAccountWithAmountView::read_state_from_account_data(&data),
+            Ok(TOKEN_ACCOUNT_STATE_FROZEN)
+        );
+        assert_eq!(TokenAccountWithAmountView::is_frozen_account_data(&data), Ok(true));
+
+        let synthetic = [2u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        assert_eq!(
+            TokenAccountWithAmountView::read_state_from_account_data(&synthetic),
+            Ok(TOKEN_ACCOUNT_STATE_UNKNOWN)
+        );
+        assert_eq!(TokenAccountWithAmountView::is_frozen_account_data(&synthetic), Ok(false));
+        assert_eq!(
+            TokenAccountWithAmountView::read_state_from_account_data(&data[..TOKEN_ACCOUNT_CORE_LEN]),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    #[test]
+    fn token_amount_custom_offset_round_trips() {
+        let mut data = [0u8; 120];
+        TokenAccountWithAmountView::write_amount_at_offset(&mut data, 100, 42_000).unwrap();
+
+        assert_eq!(TokenAccountWithAmountView::read_amount_at_offset(&data, 100).unwrap(), 42_000);
+        assert_eq!(&data[..100], &[0u8; 100]);
+        assert_eq!(
+            TokenAccountWithAmountView::read_amount_at_offset(&data, 113),
+            Err(LayoutError::SliceTooShort)
+        );
+        assert_eq!(
+            TokenAccountWithAmountView::write_amount_at_offset(&mut data, usize::MAX, 1),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
     #[test]
     fn round_lengths_match_live_program() {
         assert_eq!(ROUND_PARTICIPANTS_BYTES_LEN, 6_400);
@@ -1180,6 +2402,127 @@ mod tests {
         assert_eq!(&data[..ANCHOR_DISCRIMINATOR_LEN], &[7u8; ANCHOR_DISCRIMINATOR_LEN]);
     }
 
+    #[test]
+    fn degen_claim_versioned_read_reports_trailing_bytes() {
+        let view = sample_degen_claim(DEGEN_CLAIM_STATUS_VRF_READY, 100);
+        let mut body = vec![0xEEu8; DEGEN_CLAIM_BODY_LEN + 24];
+        view.write_body(&mut body[..DEGEN_CLAIM_BODY_LEN]);
+
+        assert_eq!(DegenClaimView::read_body_versioned(&body), Ok((view, 24)));
+        assert_eq!(DegenClaimView::read_body_versioned(&body[..DEGEN_CLAIM_BODY_LEN]), Ok((view, 0)));
+        assert_eq!(
+            DegenClaimView::read_body_versioned(&body[..DEGEN_CLAIM_BODY_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    fn sample_degen_claim(status: u8, requested_at: i64) -> DegenClaimView {
+        DegenClaimView {
+            round: [1u8; 32],
+            winner: [2u8; 32],
+            round_id: 81,
+            status,
+            bump: 201,
+            selected_candidate_rank: 0,
+            fallback_reason: DEGEN_FALLBACK_REASON_NONE,
+            token_index: 17,
+            pool_version: 1,
+            candidate_window: DEGEN_CANDIDATE_WINDOW,
+            padding0: [0u8; 7],
+            requested_at,
+            fulfilled_at: requested_at,
+            claimed_at: 0,
+            fallback_after_ts: requested_at + 300,
+            payout_raw: 975_000,
+            min_out_raw: 1,
+            receiver_pre_balance: 0,
+            token_mint: [3u8; 32],
+            executor: [4u8; 32],
+            receiver_token_ata: [5u8; 32],
+            randomness: [6u8; 32],
+            route_hash: [7u8; 32],
+            reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn degen_claim_targeted_writes_only_touch_their_field() {
+        let view = sample_degen_claim(DEGEN_CLAIM_STATUS_EXECUTING, 1_000);
+        let mut data = [0u8; DEGEN_CLAIM_ACCOUNT_LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&DEGEN_CLAIM_DISCRIMINATOR);
+        view.write_to_account_data(&mut data).unwrap();
+        let before = data;
+
+        DegenClaimView::write_status_to_account_data(&mut data, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED).unwrap();
+        DegenClaimView::write_claimed_at_to_account_data(&mut data, 1_234).unwrap();
+
+        let status_at = ANCHOR_DISCRIMINATOR_LEN + DEGEN_CLAIM_STATUS_OFFSET;
+        let claimed_at = ANCHOR_DISCRIMINATOR_LEN + DEGEN_CLAIM_CLAIMED_AT_OFFSET;
+        for (offset, (after, original)) in data.iter().zip(before.iter()).enumerate() {
+            if offset != status_at && !(claimed_at..claimed_at + 8).contains(&offset) {
+                assert_eq!(after, original, "byte {offset} changed");
+            }
+        }
+        assert_eq!(
+            DegenClaimView::read_from_account_data(&data).unwrap(),
+            DegenClaimView {
+                status: DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED,
+                claimed_at: 1_234,
+                ..view
+            }
+        );
+        assert_eq!(
+            DegenClaimView::write_claimed_at_to_account_data(&mut data[..DEGEN_CLAIM_ACCOUNT_LEN - 1], 1),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    #[test]
+    fn fresh_executing_claim_is_not_stale() {
+        let claim = sample_degen_claim(DEGEN_CLAIM_STATUS_EXECUTING, 1_000);
+        assert!(!claim.is_stale_executing(1_500, 600));
+        assert!(!claim.is_stale_executing(1_600, 600));
+    }
+
+    #[test]
+    fn executing_claim_past_threshold_is_stale() {
+        let claim = sample_degen_claim(DEGEN_CLAIM_STATUS_EXECUTING, 1_000);
+        assert!(claim.is_stale_executing(1_601, 600));
+
+        let ready = sample_degen_claim(DEGEN_CLAIM_STATUS_VRF_READY, 1_000);
+        assert!(!ready.is_stale_executing(1_601, 600));
+    }
+
+    #[test]
+    fn staleness_is_measured_from_execution_start() {
+        let mut claim = sample_degen_claim(DEGEN_CLAIM_STATUS_EXECUTING, 1_000);
+        claim.set_execution_started_at(5_000);
+        assert_eq!(claim.execution_started_at(), 5_000);
+        assert_eq!(claim.reserved[8..], [0u8; 24]);
+        assert!(!claim.is_stale_executing(5_600, 600));
+        assert!(claim.is_stale_executing(5_601, 600));
+    }
+
+    #[test]
+    fn degen_claim_fees_paid_round_trips_in_reserved() {
+        let mut claim = sample_degen_claim(DEGEN_CLAIM_STATUS_EXECUTING, 1_000);
+        assert!(!claim.fees_paid());
+        claim.set_fees_paid(true);
+        assert!(claim.fees_paid());
+        assert_eq!(claim.reserved[..8], [0u8; 8]);
+        claim.set_fees_paid(false);
+        assert_eq!(claim.reserved, [0u8; 32]);
+    }
+
+    #[test]
+    fn fallback_ready_from_deadline_onward() {
+        let mut claim = sample_degen_claim(DEGEN_CLAIM_STATUS_VRF_READY, 1_000);
+        claim.fallback_after_ts = 2_000;
+        assert_eq!(claim.require_fallback_ready(1_999), Err(LayoutError::FallbackNotReady));
+        assert_eq!(claim.require_fallback_ready(2_000), Ok(()));
+        assert_eq!(claim.require_fallback_ready(2_001), Ok(()));
+    }
+
     #[test]
     fn round_status_write_only_mutates_status_byte() {
         let view = RoundLifecycleView {
@@ -1207,6 +2550,79 @@ mod tests {
         assert_eq!(parsed.total_tickets, 55);
     }
 
+    #[test]
+    fn room_for_participant_stops_at_max_participants() {
+        let mut view = RoundLifecycleView {
+            round_id: 3,
+            status: ROUND_STATUS_OPEN,
+            bump: 8,
+            start_ts: 10,
+            end_ts: 0,
+            first_deposit_ts: 11,
+            total_usdc: 0,
+            total_tickets: 0,
+            participants_count: (MAX_PARTICIPANTS - 1) as u16,
+        };
+        assert_eq!(view.require_room_for_participant(), Ok(()));
+
+        view.participants_count = MAX_PARTICIPANTS as u16;
+        assert_eq!(view.require_room_for_participant(), Err(LayoutError::RoundFull));
+    }
+
+    #[test]
+    fn account_len_for_matches_account_constants() {
+        // Sizes of the Anchor accounts; a layout change must update both.
+        let expected = [
+            (AccountKind::Config, CONFIG_ACCOUNT_LEN, 162),
+            (AccountKind::Round, ROUND_ACCOUNT_LEN, 8_248),
+            (AccountKind::Participant, PARTICIPANT_ACCOUNT_LEN, 111),
+            (AccountKind::DegenConfig, DEGEN_CONFIG_ACCOUNT_LEN, 72),
+            (AccountKind::DegenClaim, DEGEN_CLAIM_ACCOUNT_LEN, 348),
+            // Pinocchio-only; no Anchor account to match.
+            (AccountKind::VrfQueueConfig, VRF_QUEUE_CONFIG_ACCOUNT_LEN, 41),
+            (AccountKind::FeeSplitConfig, FEE_SPLIT_CONFIG_ACCOUNT_LEN, 43),
+        ];
+        for (kind, constant, anchor_len) in expected {
+            assert_eq!(account_len_for(kind), constant, "{kind:?}");
+            assert_eq!(account_len_for(kind), anchor_len, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn linear_and_fenwick_winner_lookup_agree() {
+        for count in [1usize, 2, 3, 4, 5, 8, 9, 32, MAX_PARTICIPANTS] {
+            let weights: Vec<u64> = (0..count as u64).map(|i| (i * 37) % 5).collect();
+            let mut data = [0u8; ROUND_ACCOUNT_LEN];
+            data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&account_discriminator("Round"));
+            RoundLifecycleView {
+                round_id: 1,
+                status: ROUND_STATUS_OPEN,
+                bump: 1,
+                start_ts: 1,
+                end_ts: 0,
+                first_deposit_ts: 1,
+                total_usdc: 0,
+                total_tickets: 0,
+                participants_count: count as u16,
+            }
+            .write_to_account_data(&mut data)
+            .unwrap();
+            for (index_zero_based, weight) in weights.iter().enumerate() {
+                RoundLifecycleView::apply_deposit_to_account_data(&mut data, index_zero_based + 1, *weight).unwrap();
+            }
+
+            let total: u64 = weights.iter().sum();
+            for target in 1..=total {
+                let expected = linear_find_winner(&weights, target);
+                assert_eq!(
+                    RoundLifecycleView::bit_find_prefix_in_account_data(&data, target),
+                    Ok(expected),
+                    "count {count}, target {target}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn round_vault_accessor_reads_live_offset() {
         let mut data = [0u8; ROUND_ACCOUNT_LEN];
@@ -1229,6 +2645,477 @@ mod tests {
 
         let parsed = RoundLifecycleView::read_vault_pubkey_from_account_data(&data).unwrap();
         assert_eq!(parsed, [6u8; 32]);
+        assert_eq!(RoundLifecycleView::require_vault_set(&data), Ok([6u8; 32]));
+    }
+
+    #[test]
+    fn require_vault_set_rejects_unrecorded_vault() {
+        let mut data = vec![0u8; ROUND_ACCOUNT_LEN];
+        assert_eq!(RoundLifecycleView::require_vault_set(&data), Err(LayoutError::VaultNotSet));
+
+        RoundLifecycleView::write_vault_pubkey_to_account_data(&mut data, &[6u8; 32]).unwrap();
+        assert_eq!(RoundLifecycleView::require_vault_set(&data), Ok([6u8; 32]));
+        assert_eq!(
+            RoundLifecycleView::require_vault_set(&data[..ROUND_ACCOUNT_LEN - 1]),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    fn exercise_account_layout<T>(read_from_account_data: fn(&[u8]) -> Result<T, LayoutError>)
+    where
+        T: AccountLayout + core::fmt::Debug + PartialEq,
+    {
+        let mut data = vec![0u8; T::LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&T::DISCRIMINATOR);
+        data[ANCHOR_DISCRIMINATOR_LEN] = 1;
+        assert_eq!(T::check_header(&data), Ok(()));
+        assert_eq!(T::from_account_data(&data).unwrap(), read_from_account_data(&data).unwrap());
+
+        let mut wrong_discriminator = data.clone();
+        wrong_discriminator[0] ^= 0xff;
+        assert_eq!(T::from_account_data(&wrong_discriminator), Err(LayoutError::InvalidAccountHeader));
+
+        let mut too_long = data.clone();
+        too_long.push(0);
+        assert_eq!(T::from_account_data(&too_long), Err(LayoutError::InvalidAccountHeader));
+        assert_eq!(T::from_account_data(&data[..T::LEN - 1]), Err(LayoutError::InvalidAccountHeader));
+    }
+
+    #[test]
+    fn account_layout_validates_header_for_each_view() {
+        exercise_account_layout(ConfigView::read_from_account_data);
+        exercise_account_layout(RoundLifecycleView::read_from_account_data);
+        exercise_account_layout(DegenConfigView::read_from_account_data);
+        exercise_account_layout(DegenClaimView::read_from_account_data);
+        exercise_account_layout(ParticipantView::read_from_account_data);
+        exercise_account_layout(VrfQueueConfigView::read_from_account_data);
+        exercise_account_layout(FeeSplitConfigView::read_from_account_data);
+    }
+
+    #[test]
+    fn zero_and_reinit_clears_participants_and_fenwick() {
+        let mut data = [0xabu8; ROUND_ACCOUNT_LEN];
+        let fresh = RoundLifecycleView {
+            round_id: 82,
+            status: ROUND_STATUS_OPEN,
+            bump: 201,
+            start_ts: 1_000,
+            end_ts: 0,
+            first_deposit_ts: 0,
+            total_usdc: 0,
+            total_tickets: 0,
+            participants_count: 0,
+        };
+
+        RoundLifecycleView::zero_and_reinit_account_data(&mut data, &fresh).unwrap();
+
+        assert_eq!(RoundLifecycleView::from_account_data(&data).unwrap(), fresh);
+        let body = &data[ANCHOR_DISCRIMINATOR_LEN..];
+        let participants = &body[ROUND_PARTICIPANTS_OFFSET..ROUND_BIT_OFFSET];
+        let fenwick = &body[ROUND_BIT_OFFSET..ROUND_VRF_PAYER_OFFSET];
+        assert!(participants.iter().all(|byte| *byte == 0));
+        assert!(fenwick.iter().all(|byte| *byte == 0));
+        assert!(body[ROUND_VRF_PAYER_OFFSET..].iter().all(|byte| *byte == 0));
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(&data).unwrap(), [0u8; 32]);
+        assert_eq!(RoundLifecycleView::read_degen_mode_status_from_account_data(&data).unwrap(), DEGEN_MODE_NONE);
+    }
+
+    #[test]
+    fn zero_and_reinit_rejects_short_buffer() {
+        let mut data = [0xabu8; ROUND_ACCOUNT_LEN - 1];
+        let fresh = RoundLifecycleView::read_body(&[0u8; ROUND_BODY_LEN]).unwrap();
+        assert_eq!(
+            RoundLifecycleView::zero_and_reinit_account_data(&mut data, &fresh),
+            Err(LayoutError::SliceTooShort),
+        );
+        assert!(data.iter().all(|byte| *byte == 0xab));
+    }
+
+    fn empty_round_with_participants(participants_count: u16) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        RoundLifecycleView::zero_and_reinit_account_data(
+            &mut data,
+            &RoundLifecycleView {
+                round_id: 81,
+                status: ROUND_STATUS_OPEN,
+                bump: 201,
+                start_ts: 10,
+                end_ts: 0,
+                first_deposit_ts: 0,
+                total_usdc: 0,
+                total_tickets: 0,
+                participants_count,
+            },
+        )
+        .unwrap();
+        data
+    }
+
+    #[test]
+    fn apply_deposit_keeps_fenwick_and_total_in_sync() {
+        let mut data = empty_round_with_participants(2);
+
+        for (index, tickets) in [(1, 5), (2, 7), (1, 3), (2, 1), (1, 10)] {
+            RoundLifecycleView::apply_deposit_to_account_data(&mut data, index, tickets).unwrap();
+            RoundLifecycleView::verify_fenwick_consistency(&data).unwrap();
+        }
+
+        let round = RoundLifecycleView::read_from_account_data(&data).unwrap();
+        assert_eq!(round.total_tickets, 26);
+        // Participant 1 holds tickets 1..=18, participant 2 holds 19..=26.
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 18).unwrap(), 1);
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 19).unwrap(), 2);
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 26).unwrap(), 2);
+    }
+
+    #[test]
+    fn boundary_tickets_belong_to_the_earlier_participant() {
+        // One ticket each: ticket N is participant N's, across every subtree
+        // edge the descent crosses (8/9, 64/65, 128/129, ...).
+        let mut data = empty_round_with_participants(MAX_PARTICIPANTS as u16);
+        for index in 1..=MAX_PARTICIPANTS {
+            RoundLifecycleView::apply_deposit_to_account_data(&mut data, index, 1).unwrap();
+        }
+        for ticket in 1..=MAX_PARTICIPANTS as u64 {
+            assert_eq!(
+                RoundLifecycleView::bit_find_prefix_in_account_data(&data, ticket),
+                Ok(ticket as usize),
+                "ticket {ticket}"
+            );
+        }
+
+        // A holds 1..=4, B holds nothing, C holds 5..=7: the boundary after
+        // A's last ticket skips the empty leaf.
+        let mut data = empty_round_with_participants(3);
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 1, 4).unwrap();
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 3, 3).unwrap();
+        for (ticket, owner) in [(1, 1), (4, 1), (5, 3), (7, 3)] {
+            assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, ticket), Ok(owner));
+        }
+    }
+
+    #[test]
+    fn apply_deposit_overflow_leaves_round_untouched() {
+        let mut data = empty_round_with_participants(1);
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 1, u64::MAX - 1).unwrap();
+        let before = data;
+
+        assert_eq!(
+            RoundLifecycleView::apply_deposit_to_account_data(&mut data, 1, 2),
+            Err(LayoutError::MathOverflow),
+        );
+        assert_eq!(data, before);
+    }
+
+    #[test]
+    fn vrf_meta_round_trips_and_matches_field_readers() {
+        let mut data = empty_round_with_participants(1);
+        let meta = RoundVrfMeta { payer: [10u8; 32], reimbursed: true };
+
+        RoundLifecycleView::write_vrf_meta_to_account_data(&mut data, &meta).unwrap();
+
+        assert_eq!(RoundLifecycleView::read_vrf_meta_from_account_data(&data), Ok(meta));
+        assert_eq!(RoundLifecycleView::read_vrf_payer_from_account_data(&data), Ok([10u8; 32]));
+        assert_eq!(RoundLifecycleView::read_vrf_reimbursed_from_account_data(&data), Ok(1));
+    }
+
+    #[test]
+    fn vrf_meta_rejects_non_bool_reimbursed_byte() {
+        let mut data = empty_round_with_participants(1);
+        RoundLifecycleView::write_vrf_reimbursed_to_account_data(&mut data, 2).unwrap();
+
+        assert_eq!(
+            RoundLifecycleView::read_vrf_meta_from_account_data(&data),
+            Err(LayoutError::InvalidBool),
+        );
+    }
+
+    #[test]
+    fn participants_with_weights_match_deposits() {
+        let mut data = empty_round_with_participants(3);
+        let participants = [[11u8; 32], [22u8; 32], [33u8; 32]];
+        for (index, participant) in participants.iter().enumerate() {
+            RoundLifecycleView::write_participant_pubkey_to_account_data(&mut data, index, participant).unwrap();
+        }
+        for (index, tickets) in [(1, 5), (2, 7), (3, 2), (1, 3)] {
+            RoundLifecycleView::apply_deposit_to_account_data(&mut data, index, tickets).unwrap();
+        }
+
+        let snapshot = RoundLifecycleView::read_participants_with_weights_from_account_data(&data).unwrap();
+        assert_eq!(snapshot, vec![([11u8; 32], 8), ([22u8; 32], 7), ([33u8; 32], 2)]);
+
+        let total_tickets = RoundLifecycleView::read_from_account_data(&data).unwrap().total_tickets;
+        assert_eq!(snapshot.iter().map(|(_, weight)| weight).sum::<u64>(), total_tickets);
+        assert_eq!(RoundLifecycleView::bit_point_query_in_account_data(&data, 4), Ok(0));
+        assert_eq!(
+            RoundLifecycleView::bit_point_query_in_account_data(&data, 0),
+            Err(LayoutError::SliceTooShort),
+        );
+    }
+
+    #[test]
+    fn bit_capacity_tracks_total_weight() {
+        let mut data = empty_round_with_participants(2);
+        assert_eq!(RoundLifecycleView::bit_capacity_remaining(&data), Ok(u64::MAX));
+
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 1, 5).unwrap();
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 2, 7).unwrap();
+        assert_eq!(RoundLifecycleView::bit_capacity_remaining(&data), Ok(u64::MAX - 12));
+    }
+
+    #[test]
+    fn bit_add_near_u64_max_fails_without_partial_writes() {
+        let mut data = empty_round_with_participants(3);
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 1, u64::MAX - 10).unwrap();
+        assert_eq!(RoundLifecycleView::bit_capacity_remaining(&data), Ok(10));
+
+        // Leaf 3's path starts at node 3 (empty) and reaches node 4, which
+        // already covers leaf 1; the overflow must be caught before node 3.
+        let before = data;
+        assert_eq!(
+            RoundLifecycleView::bit_add_in_account_data(&mut data, 3, 11),
+            Err(LayoutError::MathOverflow),
+        );
+        assert_eq!(data, before);
+
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 3, 10).unwrap();
+        assert_eq!(RoundLifecycleView::bit_capacity_remaining(&data), Ok(0));
+        assert_eq!(
+            RoundLifecycleView::bit_add_in_account_data(&mut data, 2, 1),
+            Err(LayoutError::MathOverflow),
+        );
+    }
+
+    #[test]
+    fn bit_sub_rejects_more_than_leaf_weight() {
+        let mut data = empty_round_with_participants(2);
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 1, 5).unwrap();
+        RoundLifecycleView::bit_add_in_account_data(&mut data, 2, 7).unwrap();
+
+        // Node 2 holds 12, so a per-node check alone would let leaf 2 go
+        // "negative" by borrowing leaf 1's weight.
+        let before = data;
+        assert_eq!(
+            RoundLifecycleView::bit_sub_in_account_data(&mut data, 2, 8),
+            Err(LayoutError::MathOverflow),
+        );
+        assert_eq!(data, before);
+
+        RoundLifecycleView::bit_sub_in_account_data(&mut data, 2, 7).unwrap();
+        assert_eq!(RoundLifecycleView::bit_capacity_remaining(&data), Ok(u64::MAX - 5));
+    }
+
+    #[test]
+    fn fenwick_consistency_rejects_drifted_total_and_stray_weight() {
+        let mut drifted = empty_round_with_participants(1);
+        RoundLifecycleView::bit_add_in_account_data(&mut drifted, 1, 4).unwrap();
+        assert_eq!(
+            RoundLifecycleView::verify_fenwick_consistency(&drifted),
+            Err(LayoutError::InconsistentFenwick),
+        );
+
+        let mut stray = empty_round_with_participants(1);
+        RoundLifecycleView::apply_deposit_to_account_data(&mut stray, 2, 4).unwrap();
+        assert_eq!(
+            RoundLifecycleView::verify_fenwick_consistency(&stray),
+            Err(LayoutError::InconsistentFenwick),
+        );
+    }
+
+    #[test]
+    fn repeat_deposit_grows_existing_leaf_and_shifts_winner() {
+        let mut data = empty_round_with_participants(2);
+        RoundLifecycleView::increment_participant_tickets(&mut data, 1, 10).unwrap();
+        RoundLifecycleView::increment_participant_tickets(&mut data, 2, 10).unwrap();
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 15).unwrap(), 2);
+
+        RoundLifecycleView::increment_participant_tickets(&mut data, 1, 30).unwrap();
+
+        assert_eq!(RoundLifecycleView::bit_point_query_in_account_data(&data, 1).unwrap(), 40);
+        assert_eq!(RoundLifecycleView::bit_point_query_in_account_data(&data, 2).unwrap(), 10);
+        assert_eq!(RoundLifecycleView::read_from_account_data(&data).unwrap().total_tickets, 50);
+        assert_eq!(RoundLifecycleView::verify_fenwick_consistency(&data), Ok(()));
+        // Participant 1 now owns tickets 1..=40, participant 2 owns 41..=50.
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 15).unwrap(), 1);
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 40).unwrap(), 1);
+        assert_eq!(RoundLifecycleView::bit_find_prefix_in_account_data(&data, 41).unwrap(), 2);
+
+        let before = data;
+        for index in [0, 3] {
+            assert_eq!(
+                RoundLifecycleView::increment_participant_tickets(&mut data, index, 1),
+                Err(LayoutError::InconsistentFenwick)
+            );
+        }
+        assert_eq!(data, before);
+    }
+
+    fn settled_round_with_winner(winning_ticket: u64, winner: [u8; PUBKEY_LEN]) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = empty_round_with_participants(2);
+        RoundLifecycleView::write_participant_pubkey_to_account_data(&mut data, 0, &[11u8; 32]).unwrap();
+        RoundLifecycleView::write_participant_pubkey_to_account_data(&mut data, 1, &[22u8; 32]).unwrap();
+        // Participant 1 holds tickets 1..=18, participant 2 holds 19..=26.
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 1, 18).unwrap();
+        RoundLifecycleView::apply_deposit_to_account_data(&mut data, 2, 8).unwrap();
+        RoundLifecycleView::write_winning_ticket_to_account_data(&mut data, winning_ticket).unwrap();
+        RoundLifecycleView::write_winner_to_account_data(&mut data, &winner).unwrap();
+        data
+    }
+
+    #[test]
+    fn participant_must_reference_the_given_round() {
+        let participant = ParticipantView {
+            round: [8u8; 32],
+            user: [4u8; 32],
+            index: 1,
+            bump: 7,
+            tickets_total: 1,
+            usdc_total: 10_000,
+            deposits_count: 1,
+            reserved: [0u8; 16],
+        };
+        assert_eq!(require_participant_belongs_to_round(&participant, &[8u8; 32]), Ok(()));
+        assert_eq!(
+            require_participant_belongs_to_round(&participant, &[9u8; 32]),
+            Err(LayoutError::ParticipantRoundMismatch)
+        );
+    }
+
+    fn settled_round_for_integrity() -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = settled_round_with_winner(19, [22u8; 32]);
+        RoundLifecycleView::write_status_to_account_data(&mut data, ROUND_STATUS_SETTLED).unwrap();
+        data
+    }
+
+    #[test]
+    fn integrity_check_accepts_consistent_rounds() {
+        assert_eq!(RoundLifecycleView::verify_integrity(&settled_round_for_integrity()), Ok(()));
+        // Open rounds have no winner yet, so only the structural checks run.
+        assert_eq!(RoundLifecycleView::verify_integrity(&settled_round_with_winner(0, [0u8; 32])), Ok(()));
+    }
+
+    #[test]
+    fn integrity_check_reports_first_broken_invariant() {
+        let mut bad_header = settled_round_for_integrity();
+        bad_header[0] ^= 1;
+        assert_eq!(RoundLifecycleView::verify_integrity(&bad_header), Err(LayoutError::InvalidAccountHeader));
+
+        let mut bad_status = settled_round_for_integrity();
+        RoundLifecycleView::write_status_to_account_data(&mut bad_status, ROUND_STATUS_CANCELLED + 1).unwrap();
+        assert_eq!(RoundLifecycleView::verify_integrity(&bad_status), Err(LayoutError::InvalidRoundStatus));
+
+        let mut too_many = settled_round_for_integrity();
+        let mut round = RoundLifecycleView::read_from_account_data(&too_many).unwrap();
+        round.participants_count = MAX_PARTICIPANTS as u16 + 1;
+        round.write_to_account_data(&mut too_many).unwrap();
+        assert_eq!(RoundLifecycleView::verify_integrity(&too_many), Err(LayoutError::RoundFull));
+
+        let mut drifted_total = settled_round_for_integrity();
+        let mut round = RoundLifecycleView::read_from_account_data(&drifted_total).unwrap();
+        round.total_tickets += 1;
+        round.write_to_account_data(&mut drifted_total).unwrap();
+        assert_eq!(RoundLifecycleView::verify_integrity(&drifted_total), Err(LayoutError::InconsistentFenwick));
+
+        let mut stray_degen = settled_round_with_winner(19, [22u8; 32]);
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut stray_degen, DEGEN_MODE_VRF_REQUESTED)
+            .unwrap();
+        assert_eq!(RoundLifecycleView::verify_integrity(&stray_degen), Err(LayoutError::InconsistentDegenState));
+
+        let mut wrong_winner = settled_round_with_winner(19, [11u8; 32]);
+        RoundLifecycleView::write_status_to_account_data(&mut wrong_winner, ROUND_STATUS_SETTLED).unwrap();
+        assert_eq!(RoundLifecycleView::verify_integrity(&wrong_winner), Err(LayoutError::WinnerMismatch));
+    }
+
+    #[test]
+    fn winner_matches_ticket_for_settled_round() {
+        for (ticket, winner) in [(1, [11u8; 32]), (18, [11u8; 32]), (19, [22u8; 32]), (26, [22u8; 32])] {
+            let data = settled_round_with_winner(ticket, winner);
+            assert_eq!(RoundLifecycleView::verify_winner_matches_ticket(&data), Ok(()));
+        }
+    }
+
+    #[test]
+    fn winner_mismatch_detects_tampered_winner_and_ticket() {
+        let tampered_winner = settled_round_with_winner(19, [11u8; 32]);
+        assert_eq!(
+            RoundLifecycleView::verify_winner_matches_ticket(&tampered_winner),
+            Err(LayoutError::WinnerMismatch),
+        );
+
+        for ticket in [0, 27] {
+            let out_of_range = settled_round_with_winner(ticket, [22u8; 32]);
+            assert_eq!(
+                RoundLifecycleView::verify_winner_matches_ticket(&out_of_range),
+                Err(LayoutError::WinnerMismatch),
+            );
+        }
+    }
+
+    fn round_with_degen_mode(status: u8, degen_mode: u8) -> [u8; ROUND_ACCOUNT_LEN] {
+        let mut data = [0u8; ROUND_ACCOUNT_LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&account_discriminator("Round"));
+        RoundLifecycleView::write_status_to_account_data(&mut data, status).unwrap();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut data, degen_mode).unwrap();
+        data
+    }
+
+    #[test]
+    fn require_degen_mode_matches_exact_mode() {
+        let data = round_with_degen_mode(ROUND_STATUS_SETTLED, DEGEN_MODE_VRF_READY);
+        assert_eq!(RoundLifecycleView::require_degen_mode(&data, DEGEN_MODE_VRF_READY), Ok(()));
+        for other in [DEGEN_MODE_NONE, DEGEN_MODE_EXECUTING, DEGEN_MODE_CLAIMED] {
+            assert_eq!(
+                RoundLifecycleView::require_degen_mode(&data, other),
+                Err(LayoutError::InconsistentDegenState)
+            );
+        }
+        assert_eq!(
+            RoundLifecycleView::require_degen_mode(&data[..ROUND_ACCOUNT_LEN - 1], DEGEN_MODE_VRF_READY),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+
+    #[test]
+    fn degen_mode_consistency_accepts_lifecycle_pairs() {
+        let legal = [
+            (ROUND_STATUS_OPEN, DEGEN_MODE_NONE),
+            (ROUND_STATUS_SETTLED, DEGEN_MODE_VRF_REQUESTED),
+            (ROUND_STATUS_SETTLED, DEGEN_MODE_VRF_READY),
+            // begin_degen_execution
+            (ROUND_STATUS_SETTLED, DEGEN_MODE_EXECUTING),
+            // finalize_degen_success / claim_degen_fallback
+            (ROUND_STATUS_CLAIMED, DEGEN_MODE_CLAIMED),
+            // plain claim
+            (ROUND_STATUS_CLAIMED, DEGEN_MODE_NONE),
+            (ROUND_STATUS_CANCELLED, DEGEN_MODE_NONE),
+        ];
+        for (status, degen_mode) in legal {
+            let data = round_with_degen_mode(status, degen_mode);
+            assert_eq!(
+                RoundLifecycleView::verify_degen_mode_consistency(&data),
+                Ok(()),
+                "status {status} degen_mode {degen_mode}",
+            );
+        }
+    }
+
+    #[test]
+    fn degen_mode_consistency_rejects_manufactured_pairs() {
+        let illegal = [
+            (ROUND_STATUS_CLAIMED, DEGEN_MODE_EXECUTING),
+            (ROUND_STATUS_CLAIMED, DEGEN_MODE_VRF_READY),
+            (ROUND_STATUS_SETTLED, DEGEN_MODE_CLAIMED),
+            (ROUND_STATUS_OPEN, DEGEN_MODE_VRF_REQUESTED),
+            (ROUND_STATUS_CANCELLED, DEGEN_MODE_EXECUTING),
+        ];
+        for (status, degen_mode) in illegal {
+            let data = round_with_degen_mode(status, degen_mode);
+            assert_eq!(
+                RoundLifecycleView::verify_degen_mode_consistency(&data),
+                Err(LayoutError::InconsistentDegenState),
+                "status {status} degen_mode {degen_mode}",
+            );
+        }
     }
 
     #[test]
@@ -1293,4 +3180,158 @@ mod tests {
         let parsed = ParticipantView::read_from_account_data(&data).unwrap();
         assert_eq!(parsed, view);
     }
+
+    /// Deterministic xorshift64* so layout fuzz failures reproduce from the seed.
+    struct FuzzRng(u64);
+
+    impl FuzzRng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u16(&mut self) -> u16 {
+            self.next_u64() as u16
+        }
+
+        fn next_u8(&mut self) -> u8 {
+            self.next_u64() as u8
+        }
+
+        fn next_i64(&mut self) -> i64 {
+            self.next_u64() as i64
+        }
+
+        fn next_bool(&mut self) -> bool {
+            self.next_u64() & 1 == 1
+        }
+
+        fn next_bytes<const N: usize>(&mut self) -> [u8; N] {
+            let mut out = [0u8; N];
+            for byte in out.iter_mut() {
+                *byte = self.next_u8();
+            }
+            out
+        }
+    }
+
+    const FUZZ_ITERATIONS: usize = 256;
+    const FUZZ_GUARD_BYTE: u8 = 0xA5;
+    const FUZZ_TAIL_LEN: usize = 16;
+
+    /// Writes `value` into an account buffer padded with guard bytes past the
+    /// declared length, then checks the round trip and that neither the
+    /// discriminator nor anything beyond `T::LEN` was touched.
+    fn assert_layout_round_trip<T: AccountLayout + PartialEq + core::fmt::Debug>(
+        value: &T,
+        write: impl Fn(&T, &mut [u8]) -> Result<(), LayoutError>,
+    ) {
+        let mut data = vec![FUZZ_GUARD_BYTE; T::LEN + FUZZ_TAIL_LEN];
+        data[..ANCHOR_DISCRIMINATOR_LEN].copy_from_slice(&T::DISCRIMINATOR);
+
+        write(value, &mut data).unwrap();
+
+        assert_eq!(&data[..ANCHOR_DISCRIMINATOR_LEN], &T::DISCRIMINATOR);
+        assert!(data[T::LEN..].iter().all(|byte| *byte == FUZZ_GUARD_BYTE));
+        assert_eq!(&T::from_account_data(&data[..T::LEN]).unwrap(), value);
+    }
+
+    #[test]
+    fn fuzz_config_round_trip_stays_within_body() {
+        let mut rng = FuzzRng(0x0c0f_f1e5_eed0_0001);
+        for _ in 0..FUZZ_ITERATIONS {
+            let view = ConfigView {
+                admin: rng.next_bytes(),
+                usdc_mint: rng.next_bytes(),
+                treasury_usdc_ata: rng.next_bytes(),
+                fee_bps: rng.next_u16(),
+                ticket_unit: rng.next_u64(),
+                round_duration_sec: rng.next_u32(),
+                min_participants: rng.next_u16(),
+                min_total_tickets: rng.next_u64(),
+                paused: rng.next_bool(),
+                bump: rng.next_u8(),
+                max_deposit_per_user: rng.next_u64(),
+                reserved: rng.next_bytes(),
+            };
+            assert_layout_round_trip(&view, ConfigView::write_to_account_data);
+        }
+    }
+
+    #[test]
+    fn fuzz_round_header_round_trip_stays_within_body() {
+        let mut rng = FuzzRng(0x0c0f_f1e5_eed0_0002);
+        for _ in 0..FUZZ_ITERATIONS {
+            let view = RoundLifecycleView {
+                round_id: rng.next_u64(),
+                status: rng.next_u8(),
+                bump: rng.next_u8(),
+                start_ts: rng.next_i64(),
+                end_ts: rng.next_i64(),
+                first_deposit_ts: rng.next_i64(),
+                total_usdc: rng.next_u64(),
+                total_tickets: rng.next_u64(),
+                participants_count: rng.next_u16(),
+            };
+            assert_layout_round_trip(&view, RoundLifecycleView::write_to_account_data);
+        }
+    }
+
+    #[test]
+    fn fuzz_degen_claim_round_trip_stays_within_body() {
+        let mut rng = FuzzRng(0x0c0f_f1e5_eed0_0003);
+        for _ in 0..FUZZ_ITERATIONS {
+            let view = DegenClaimView {
+                round: rng.next_bytes(),
+                winner: rng.next_bytes(),
+                round_id: rng.next_u64(),
+                status: rng.next_u8(),
+                bump: rng.next_u8(),
+                selected_candidate_rank: rng.next_u8(),
+                fallback_reason: rng.next_u8(),
+                token_index: rng.next_u32(),
+                pool_version: rng.next_u32(),
+                candidate_window: rng.next_u8(),
+                padding0: rng.next_bytes(),
+                requested_at: rng.next_i64(),
+                fulfilled_at: rng.next_i64(),
+                claimed_at: rng.next_i64(),
+                fallback_after_ts: rng.next_i64(),
+                payout_raw: rng.next_u64(),
+                min_out_raw: rng.next_u64(),
+                receiver_pre_balance: rng.next_u64(),
+                token_mint: rng.next_bytes(),
+                executor: rng.next_bytes(),
+                receiver_token_ata: rng.next_bytes(),
+                randomness: rng.next_bytes(),
+                route_hash: rng.next_bytes(),
+                reserved: rng.next_bytes(),
+            };
+            assert_layout_round_trip(&view, DegenClaimView::write_to_account_data);
+        }
+    }
+
+    #[test]
+    fn fuzz_participant_round_trip_stays_within_body() {
+        let mut rng = FuzzRng(0x0c0f_f1e5_eed0_0004);
+        for _ in 0..FUZZ_ITERATIONS {
+            let view = ParticipantView {
+                round: rng.next_bytes(),
+                user: rng.next_bytes(),
+                index: rng.next_u16(),
+                bump: rng.next_u8(),
+                tickets_total: rng.next_u64(),
+                usdc_total: rng.next_u64(),
+                deposits_count: rng.next_u32(),
+                reserved: rng.next_bytes(),
+            };
+            assert_layout_round_trip(&view, ParticipantView::write_to_account_data);
+        }
+    }
 }
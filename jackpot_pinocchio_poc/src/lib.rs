@@ -3,8 +3,12 @@
 pub mod anchor_compat;
 pub mod degen_pool_compat;
 pub mod errors;
+pub mod events;
 pub mod handlers;
 pub mod instruction_layouts;
 pub mod legacy_layouts;
+pub mod pdas;
 pub mod processors;
+pub mod randomness_source;
 pub mod runtime;
+pub mod winner_selection;
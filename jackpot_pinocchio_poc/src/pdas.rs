@@ -0,0 +1,503 @@
+//! Seeds and PDA derivations shared by the runtime modules.
+//!
+//! `derive_*` runs the bump search and is meant for accounts whose bump is not
+//! known yet (init paths, or checks that do not trust the stored bump).
+//! `create_*` re-derives from a bump already stored in the account.
+
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+
+use crate::{
+    instruction_layouts::RoundId,
+    legacy_layouts::{AccountLayout, FeeSplitConfigView, ParticipantView, VrfQueueConfigView, PUBKEY_LEN},
+};
+
+pub const SEED_CFG: &[u8] = b"cfg";
+pub const SEED_ROUND: &[u8] = b"round";
+pub const SEED_PARTICIPANT: &[u8] = b"p";
+pub const SEED_DEGEN_CFG: &[u8] = b"degen_cfg";
+pub const SEED_DEGEN_CLAIM: &[u8] = b"degen_claim";
+pub const SEED_IDENTITY: &[u8] = b"identity";
+pub const SEED_VRF_QUEUE: &[u8] = b"vrf_queue";
+pub const SEED_FEE_SPLIT: &[u8] = b"fee_split";
+
+pub fn derive_config_pda(program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_CFG], program_id)
+}
+
+pub fn derive_degen_config_pda(program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_DEGEN_CFG], program_id)
+}
+
+pub fn derive_vrf_queue_config_pda(program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_VRF_QUEUE], program_id)
+}
+
+pub fn derive_fee_split_config_pda(program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_FEE_SPLIT], program_id)
+}
+
+/// Reads the `set_fee_split` PDA, checking its address, owner and bump.
+pub fn require_fee_split_config(account: &AccountView, program_id: &Address) -> Result<FeeSplitConfigView, ProgramError> {
+    let (expected_address, expected_bump) = derive_fee_split_config_pda(program_id);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !account.owned_by(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let fee_split_config = FeeSplitConfigView::from_account_view(account)?;
+    if fee_split_config.bump != expected_bump {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(fee_split_config)
+}
+
+/// Oracle queue a VRF request must name. `queue_config`, when passed, must
+/// be the `set_vrf_queue` PDA; its stored queue wins once the account
+/// exists. Without it (omitted, or not created yet) the build-time
+/// `default` applies, so clients built for the Anchor account list keep
+/// working against the default queue. Once `set_vrf_queue` has run
+/// (`overridden`), the PDA is required and there is no fallback.
+pub fn expected_oracle_queue(
+    queue_config: Option<&AccountView>,
+    program_id: &Address,
+    default: Address,
+    overridden: bool,
+) -> Result<Address, ProgramError> {
+    let Some(account) = queue_config else {
+        if overridden {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        return Ok(default);
+    };
+    let (expected_address, expected_bump) = derive_vrf_queue_config_pda(program_id);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !account.owned_by(program_id) {
+        if overridden {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        return Ok(default);
+    }
+    let queue_config = VrfQueueConfigView::from_account_view(account)?;
+    if queue_config.bump != expected_bump {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(Address::new_from_array(queue_config.queue))
+}
+
+/// Signer PDA the VRF program expects as `program_identity`.
+pub fn derive_program_identity_pda(program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_IDENTITY], program_id)
+}
+
+pub fn derive_round_pda(program_id: &Address, round_id: RoundId) -> (Address, u8) {
+    Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id)
+}
+
+pub fn create_round_pda(program_id: &Address, round_id: RoundId, bump: u8) -> Result<Address, ProgramError> {
+    Address::create_program_address(&[SEED_ROUND, &round_id.to_le_bytes(), &[bump]], program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)
+}
+
+pub fn derive_participant_pda(program_id: &Address, round: &Address, user: &Address) -> (Address, u8) {
+    Address::find_program_address(&[SEED_PARTICIPANT, round.as_ref(), user.as_ref()], program_id)
+}
+
+/// Returns the canonical bump when `provided` is the one participant PDA of
+/// `(round, user)`. Seeds pin a user to a single participant per round, so
+/// `max_deposit_per_user` holds as long as no other address is accepted:
+/// a look-alike (another user's PDA, or the same seeds under a
+/// non-canonical bump) is rejected with `InvalidSeeds`.
+pub fn require_single_participant_pda(
+    program_id: &Address,
+    round: &Address,
+    user: &Address,
+    provided: &Address,
+) -> Result<u8, ProgramError> {
+    let (expected_address, bump) = derive_participant_pda(program_id, round, user);
+    if provided != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(bump)
+}
+
+/// Checks that `account` is the program-owned participant PDA for
+/// `(round, user)`, carries a `Participant` discriminator and length, and
+/// stores the canonical bump.
+pub fn require_participant_pda(
+    account: &AccountView,
+    program_id: &Address,
+    round: &Address,
+    user: &Address,
+) -> ProgramResult {
+    if !account.owned_by(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_address, expected_bump) = derive_participant_pda(program_id, round, user);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let participant = ParticipantView::from_account_view(account)?;
+    if participant.bump != expected_bump {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+pub fn derive_degen_claim_pda(
+    program_id: &Address,
+    round_id: RoundId,
+    winner: &[u8; PUBKEY_LEN],
+) -> (Address, u8) {
+    Address::find_program_address(&[SEED_DEGEN_CLAIM, &round_id.to_le_bytes(), winner], program_id)
+}
+
+pub fn create_degen_claim_pda(
+    program_id: &Address,
+    round_id: RoundId,
+    winner: &[u8; PUBKEY_LEN],
+    bump: u8,
+) -> Result<Address, ProgramError> {
+    Address::create_program_address(&[SEED_DEGEN_CLAIM, &round_id.to_le_bytes(), winner, &[bump]], program_id)
+        .map_err(|_| ProgramError::InvalidSeeds)
+}
+
+/// Associated token account of `round` for `mint`: the vault `start_round`
+/// creates and records on the round.
+pub fn derive_vault_ata(
+    round: &Address,
+    token_program: &Address,
+    mint: &Address,
+    ata_program: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(&[round.as_ref(), token_program.as_ref(), mint.as_ref()], ata_program)
+}
+
+/// Rejects any `vault` other than the canonical ATA of `(round, mint)` under
+/// `token_program` with `InvalidSeeds`.
+pub fn require_vault_ata(
+    vault: &Address,
+    round: &Address,
+    token_program: &Address,
+    mint: &Address,
+    ata_program: &Address,
+) -> ProgramResult {
+    let (expected_address, _) = derive_vault_ata(round, token_program, mint, ata_program);
+    if vault != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use pinocchio::account::{NOT_BORROWED, RuntimeAccount};
+
+    use super::*;
+    use crate::{anchor_compat::account_discriminator, legacy_layouts::PARTICIPANT_ACCOUNT_LEN};
+
+    const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
+
+    struct TestAccount {
+        backing: Vec<u64>,
+    }
+
+    impl TestAccount {
+        fn new(address: Address, owner: Address, data: &[u8]) -> Self {
+            let words = (size_of::<RuntimeAccount>() + data.len()).div_ceil(size_of::<u64>());
+            let mut backing = vec![0u64; words];
+            let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
+            unsafe {
+                (*raw).borrow_state = NOT_BORROWED;
+                (*raw).address = address;
+                (*raw).owner = owner;
+                (*raw).data_len = data.len() as u64;
+                let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
+                core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
+            }
+            Self { backing }
+        }
+
+        fn view(&mut self) -> AccountView {
+            unsafe { AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
+        }
+    }
+
+    fn vrf_queue_config_data(queue: [u8; 32], bump: u8) -> Vec<u8> {
+        let mut data = vec![0u8; VrfQueueConfigView::LEN];
+        data[..8].copy_from_slice(&account_discriminator("VrfQueueConfig"));
+        VrfQueueConfigView { queue, bump }.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn fee_split_config_data(view: FeeSplitConfigView) -> Vec<u8> {
+        let mut data = vec![0u8; FeeSplitConfigView::LEN];
+        data[..8].copy_from_slice(&account_discriminator("FeeSplitConfig"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn participant_data(bump: u8) -> Vec<u8> {
+        let mut data = vec![0u8; PARTICIPANT_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("Participant"));
+        ParticipantView {
+            round: [3u8; 32],
+            user: [4u8; 32],
+            index: 1,
+            bump,
+            tickets_total: 100,
+            usdc_total: 1_000_000,
+            deposits_count: 1,
+            reserved: [0u8; 16],
+        }
+        .write_to_account_data(&mut data)
+        .unwrap();
+        data
+    }
+
+    #[test]
+    fn singleton_pdas_match_inline_derivations() {
+        assert_eq!(derive_config_pda(&PROGRAM_ID), Address::find_program_address(&[b"cfg"], &PROGRAM_ID));
+        assert_eq!(
+            derive_degen_config_pda(&PROGRAM_ID),
+            Address::find_program_address(&[b"degen_cfg"], &PROGRAM_ID)
+        );
+        assert_eq!(
+            derive_program_identity_pda(&PROGRAM_ID),
+            Address::find_program_address(&[b"identity"], &PROGRAM_ID)
+        );
+        assert_eq!(
+            derive_vrf_queue_config_pda(&PROGRAM_ID),
+            Address::find_program_address(&[b"vrf_queue"], &PROGRAM_ID)
+        );
+        assert_eq!(
+            derive_fee_split_config_pda(&PROGRAM_ID),
+            Address::find_program_address(&[b"fee_split"], &PROGRAM_ID)
+        );
+    }
+
+    #[test]
+    fn require_fee_split_config_checks_address_owner_and_bump() {
+        let (pda, bump) = derive_fee_split_config_pda(&PROGRAM_ID);
+        let stored = FeeSplitConfigView { recipient_usdc_ata: [6u8; 32], fee_split_bps: 2_500, bump };
+
+        let mut valid = TestAccount::new(pda, PROGRAM_ID, &fee_split_config_data(stored));
+        assert_eq!(require_fee_split_config(&valid.view(), &PROGRAM_ID), Ok(stored));
+
+        let mut look_alike = TestAccount::new(Address::new_from_array([5u8; 32]), PROGRAM_ID, &fee_split_config_data(stored));
+        assert_eq!(require_fee_split_config(&look_alike.view(), &PROGRAM_ID), Err(ProgramError::InvalidSeeds));
+
+        let mut not_created = TestAccount::new(pda, Address::new_from_array([0u8; 32]), &[]);
+        assert_eq!(
+            require_fee_split_config(&not_created.view(), &PROGRAM_ID),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+
+        let wrong_bump = FeeSplitConfigView { bump: bump.wrapping_sub(1), ..stored };
+        let mut wrong_bump = TestAccount::new(pda, PROGRAM_ID, &fee_split_config_data(wrong_bump));
+        assert_eq!(require_fee_split_config(&wrong_bump.view(), &PROGRAM_ID), Err(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    fn expected_oracle_queue_prefers_stored_queue_over_default() {
+        let default = Address::new_from_array([1u8; 32]);
+        let (pda, bump) = derive_vrf_queue_config_pda(&PROGRAM_ID);
+
+        assert_eq!(expected_oracle_queue(None, &PROGRAM_ID, default, false), Ok(default));
+
+        let mut not_created = TestAccount::new(pda, Address::new_from_array([0u8; 32]), &[]);
+        assert_eq!(expected_oracle_queue(Some(&not_created.view()), &PROGRAM_ID, default, false), Ok(default));
+
+        let mut stored = TestAccount::new(pda, PROGRAM_ID, &vrf_queue_config_data([6u8; 32], bump));
+        assert_eq!(
+            expected_oracle_queue(Some(&stored.view()), &PROGRAM_ID, default, false),
+            Ok(Address::new_from_array([6u8; 32]))
+        );
+
+        let mut look_alike = TestAccount::new(
+            Address::new_from_array([5u8; 32]),
+            PROGRAM_ID,
+            &vrf_queue_config_data([6u8; 32], bump),
+        );
+        assert_eq!(
+            expected_oracle_queue(Some(&look_alike.view()), &PROGRAM_ID, default, false),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        let mut wrong_bump = TestAccount::new(pda, PROGRAM_ID, &vrf_queue_config_data([6u8; 32], bump.wrapping_sub(1)));
+        assert_eq!(
+            expected_oracle_queue(Some(&wrong_bump.view()), &PROGRAM_ID, default, false),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn expected_oracle_queue_requires_pda_once_overridden() {
+        let default = Address::new_from_array([1u8; 32]);
+        let (pda, bump) = derive_vrf_queue_config_pda(&PROGRAM_ID);
+
+        assert_eq!(
+            expected_oracle_queue(None, &PROGRAM_ID, default, true),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        let mut not_created = TestAccount::new(pda, Address::new_from_array([0u8; 32]), &[]);
+        assert_eq!(
+            expected_oracle_queue(Some(&not_created.view()), &PROGRAM_ID, default, true),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+
+        let mut stored = TestAccount::new(pda, PROGRAM_ID, &vrf_queue_config_data([6u8; 32], bump));
+        assert_eq!(
+            expected_oracle_queue(Some(&stored.view()), &PROGRAM_ID, default, true),
+            Ok(Address::new_from_array([6u8; 32]))
+        );
+    }
+
+    #[test]
+    fn round_pda_matches_inline_derivation() {
+        let inline = Address::find_program_address(&[b"round", &81u64.to_le_bytes()], &PROGRAM_ID);
+        assert_eq!(derive_round_pda(&PROGRAM_ID, RoundId(81)), inline);
+        assert_eq!(create_round_pda(&PROGRAM_ID, RoundId(81), inline.1).unwrap(), inline.0);
+    }
+
+    #[test]
+    fn first_round_pda_matches_inline_derivation() {
+        let inline = Address::find_program_address(&[b"round", &1u64.to_le_bytes()], &PROGRAM_ID);
+        assert_eq!(derive_round_pda(&PROGRAM_ID, RoundId::MIN), inline);
+        assert_eq!(create_round_pda(&PROGRAM_ID, RoundId::MIN, inline.1).unwrap(), inline.0);
+    }
+
+    #[test]
+    fn participant_pda_matches_inline_derivation() {
+        let round = Address::new_from_array([3u8; 32]);
+        let user = Address::new_from_array([4u8; 32]);
+        let inline = Address::find_program_address(&[b"p", round.as_ref(), user.as_ref()], &PROGRAM_ID);
+        assert_eq!(derive_participant_pda(&PROGRAM_ID, &round, &user), inline);
+    }
+
+    #[test]
+    fn require_single_participant_pda_accepts_only_the_canonical_address() {
+        let round = Address::new_from_array([3u8; 32]);
+        let user = Address::new_from_array([4u8; 32]);
+        let (pda, bump) = derive_participant_pda(&PROGRAM_ID, &round, &user);
+        assert_eq!(require_single_participant_pda(&PROGRAM_ID, &round, &user, &pda), Ok(bump));
+
+        let non_canonical = (0..bump)
+            .rev()
+            .find_map(|b| {
+                Address::create_program_address(&[b"p", round.as_ref(), user.as_ref(), &[b]], &PROGRAM_ID).ok()
+            })
+            .unwrap();
+        assert_eq!(
+            require_single_participant_pda(&PROGRAM_ID, &round, &user, &non_canonical),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        let (other_users_pda, _) = derive_participant_pda(&PROGRAM_ID, &round, &Address::new_from_array([5u8; 32]));
+        assert_eq!(
+            require_single_participant_pda(&PROGRAM_ID, &round, &user, &other_users_pda),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn require_participant_pda_checks_owner_address_header_and_bump() {
+        let round = Address::new_from_array([3u8; 32]);
+        let user = Address::new_from_array([4u8; 32]);
+        let (pda, bump) = derive_participant_pda(&PROGRAM_ID, &round, &user);
+
+        let mut valid = TestAccount::new(pda, PROGRAM_ID, &participant_data(bump));
+        require_participant_pda(&valid.view(), &PROGRAM_ID, &round, &user).unwrap();
+
+        let other_user = Address::new_from_array([5u8; 32]);
+        assert_eq!(
+            require_participant_pda(&valid.view(), &PROGRAM_ID, &round, &other_user),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        let mut foreign = TestAccount::new(pda, Address::new_from_array([8u8; 32]), &participant_data(bump));
+        assert_eq!(
+            require_participant_pda(&foreign.view(), &PROGRAM_ID, &round, &user),
+            Err(ProgramError::IncorrectProgramId)
+        );
+
+        let mut wrong_discriminator = participant_data(bump);
+        wrong_discriminator[..8].copy_from_slice(&account_discriminator("Round"));
+        let mut wrong_header = TestAccount::new(pda, PROGRAM_ID, &wrong_discriminator);
+        assert_eq!(
+            require_participant_pda(&wrong_header.view(), &PROGRAM_ID, &round, &user),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut truncated = TestAccount::new(pda, PROGRAM_ID, &participant_data(bump)[..PARTICIPANT_ACCOUNT_LEN - 1]);
+        assert_eq!(
+            require_participant_pda(&truncated.view(), &PROGRAM_ID, &round, &user),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut wrong_bump = TestAccount::new(pda, PROGRAM_ID, &participant_data(bump.wrapping_sub(1)));
+        assert_eq!(
+            require_participant_pda(&wrong_bump.view(), &PROGRAM_ID, &round, &user),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn degen_claim_pda_matches_inline_derivation() {
+        let winner = [9u8; 32];
+        let inline = Address::find_program_address(
+            &[b"degen_claim", &81u64.to_le_bytes(), &winner],
+            &PROGRAM_ID,
+        );
+        assert_eq!(derive_degen_claim_pda(&PROGRAM_ID, RoundId(81), &winner), inline);
+        assert_eq!(create_degen_claim_pda(&PROGRAM_ID, RoundId(81), &winner, inline.1).unwrap(), inline.0);
+        assert_ne!(create_degen_claim_pda(&PROGRAM_ID, RoundId(82), &winner, inline.1).ok(), Some(inline.0));
+    }
+
+    #[test]
+    fn vault_ata_matches_inline_derivation() {
+        let round = Address::new_from_array([3u8; 32]);
+        let token_program = Address::new_from_array([6u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let ata_program = Address::new_from_array([8u8; 32]);
+        let inline = Address::find_program_address(
+            &[round.as_ref(), token_program.as_ref(), mint.as_ref()],
+            &ata_program,
+        );
+        assert_eq!(derive_vault_ata(&round, &token_program, &mint, &ata_program), inline);
+        require_vault_ata(&inline.0, &round, &token_program, &mint, &ata_program).unwrap();
+    }
+
+    #[test]
+    fn require_vault_ata_rejects_non_canonical_vault() {
+        let round = Address::new_from_array([3u8; 32]);
+        let token_program = Address::new_from_array([6u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let ata_program = Address::new_from_array([8u8; 32]);
+        let (_, bump) = derive_vault_ata(&round, &token_program, &mint, &ata_program);
+
+        let non_canonical = (0..bump)
+            .rev()
+            .find_map(|b| {
+                Address::create_program_address(
+                    &[round.as_ref(), token_program.as_ref(), mint.as_ref(), &[b]],
+                    &ata_program,
+                )
+                .ok()
+            })
+            .unwrap();
+        let (other_mint_vault, _) =
+            derive_vault_ata(&round, &token_program, &Address::new_from_array([4u8; 32]), &ata_program);
+        for vault in [non_canonical, other_mint_vault, round] {
+            assert_eq!(
+                require_vault_ata(&vault, &round, &token_program, &mint, &ata_program),
+                Err(ProgramError::InvalidSeeds)
+            );
+        }
+    }
+}
@@ -12,6 +12,10 @@ pub struct AdminConfigProcessor<'a> {
     pub config_bump: Option<u8>,
     pub degen_config_account_data: Option<&'a mut [u8]>,
     pub degen_config_bump: Option<u8>,
+    pub vrf_queue_config_account_data: Option<&'a mut [u8]>,
+    pub vrf_queue_config_bump: Option<u8>,
+    pub fee_split_config_account_data: Option<&'a mut [u8]>,
+    pub fee_split_config_bump: Option<u8>,
     pub new_treasury_ata_pubkey: Option<[u8; PUBKEY_LEN]>,
     pub new_treasury_token_account_data: Option<&'a [u8]>,
     pub expected_owner_pubkey: Option<[u8; PUBKEY_LEN]>,
@@ -54,6 +58,56 @@ impl<'a> AdminConfigProcessor<'a> {
             );
         }
 
+        if discriminator == instruction_discriminator("update_degen_timeout") {
+            let degen_config_account_data = self
+                .degen_config_account_data
+                .as_deref_mut()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            return handlers::update_degen_timeout::process_anchor_bytes(
+                self.admin_pubkey,
+                self.config_account_data,
+                degen_config_account_data,
+                ix_data,
+            );
+        }
+
+        if discriminator == instruction_discriminator("set_vrf_queue") {
+            let vrf_queue_config_account_data = self
+                .vrf_queue_config_account_data
+                .as_deref_mut()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let vrf_queue_config_bump = self
+                .vrf_queue_config_bump
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            return handlers::set_vrf_queue::process_anchor_bytes(
+                self.admin_pubkey,
+                self.config_account_data,
+                vrf_queue_config_account_data,
+                vrf_queue_config_bump,
+                ix_data,
+            );
+        }
+
+        if discriminator == instruction_discriminator("set_fee_split") {
+            let fee_split_config_account_data = self
+                .fee_split_config_account_data
+                .as_deref_mut()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let fee_split_config_bump = self
+                .fee_split_config_bump
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            return handlers::set_fee_split::process_anchor_bytes(
+                self.admin_pubkey,
+                self.config_account_data,
+                fee_split_config_account_data,
+                fee_split_config_bump,
+                ix_data,
+            );
+        }
+
         if discriminator == instruction_discriminator("update_config") {
             return handlers::update_config::process_anchor_bytes(
                 self.admin_pubkey,
@@ -101,8 +155,9 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            ConfigView, DegenConfigView, TokenAccountCoreView, CONFIG_ACCOUNT_LEN,
-            DEGEN_CONFIG_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
+            ConfigView, DegenConfigView, FeeSplitConfigView, TokenAccountCoreView, VrfQueueConfigView,
+            CONFIG_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN, FEE_SPLIT_CONFIG_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
+            VRF_QUEUE_CONFIG_ACCOUNT_LEN,
         },
     };
 
@@ -145,6 +200,10 @@ mod tests {
             config_bump: None,
             degen_config_account_data: Some(&mut degen_data),
             degen_config_bump: Some(201),
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
             new_treasury_ata_pubkey: None,
             new_treasury_token_account_data: None,
             expected_owner_pubkey: None,
@@ -157,6 +216,140 @@ mod tests {
         assert_eq!(parsed.fallback_timeout_sec, 300);
     }
 
+    #[test]
+    fn routes_update_degen_timeout_preserving_executor() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut degen_data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        degen_data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
+        DegenConfigView {
+            executor: [9u8; 32],
+            fallback_timeout_sec: 300,
+            bump: 201,
+            reserved: [0u8; 27],
+        }
+        .write_to_account_data(&mut degen_data)
+        .unwrap();
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+        ix.extend_from_slice(&900u32.to_le_bytes());
+
+        let mut processor = AdminConfigProcessor {
+            admin_pubkey: admin,
+            config_account_data: &mut config_data,
+            config_bump: None,
+            degen_config_account_data: Some(&mut degen_data),
+            degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
+            new_treasury_ata_pubkey: None,
+            new_treasury_token_account_data: None,
+            expected_owner_pubkey: None,
+        };
+
+        processor.process(&ix).unwrap();
+
+        let parsed = DegenConfigView::read_from_account_data(&degen_data).unwrap();
+        assert_eq!(parsed.executor, [9u8; 32]);
+        assert_eq!(parsed.bump, 201);
+        assert_eq!(parsed.fallback_timeout_sec, 900);
+    }
+
+    #[test]
+    fn update_degen_timeout_requires_degen_config_account() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+        ix.extend_from_slice(&900u32.to_le_bytes());
+
+        let mut processor = AdminConfigProcessor {
+            admin_pubkey: admin,
+            config_account_data: &mut config_data,
+            config_bump: None,
+            degen_config_account_data: None,
+            degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
+            new_treasury_ata_pubkey: None,
+            new_treasury_token_account_data: None,
+            expected_owner_pubkey: None,
+        };
+
+        assert_eq!(processor.process(&ix), Err(ProgramError::NotEnoughAccountKeys));
+    }
+
+    #[test]
+    fn routes_set_vrf_queue() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut queue_data = [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_vrf_queue"));
+        ix.extend_from_slice(&[6u8; 32]);
+
+        let mut processor = AdminConfigProcessor {
+            admin_pubkey: admin,
+            config_account_data: &mut config_data,
+            config_bump: None,
+            degen_config_account_data: None,
+            degen_config_bump: None,
+            vrf_queue_config_account_data: Some(&mut queue_data),
+            vrf_queue_config_bump: Some(250),
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
+            new_treasury_ata_pubkey: None,
+            new_treasury_token_account_data: None,
+            expected_owner_pubkey: None,
+        };
+
+        processor.process(&ix).unwrap();
+
+        let parsed = VrfQueueConfigView::read_from_account_data(&queue_data).unwrap();
+        assert_eq!(parsed, VrfQueueConfigView { queue: [6u8; 32], bump: 250 });
+        assert!(ConfigView::read_from_account_data(&config_data).unwrap().vrf_queue_overridden());
+    }
+
+    #[test]
+    fn routes_set_fee_split() {
+        let admin = [7u8; 32];
+        let mut config_data = sample_config(admin);
+        let mut split_data = [0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_fee_split"));
+        ix.extend_from_slice(&[6u8; 32]);
+        ix.extend_from_slice(&2_500u16.to_le_bytes());
+
+        let mut processor = AdminConfigProcessor {
+            admin_pubkey: admin,
+            config_account_data: &mut config_data,
+            config_bump: None,
+            degen_config_account_data: None,
+            degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: Some(&mut split_data),
+            fee_split_config_bump: Some(249),
+            new_treasury_ata_pubkey: None,
+            new_treasury_token_account_data: None,
+            expected_owner_pubkey: None,
+        };
+
+        processor.process(&ix).unwrap();
+
+        let parsed = FeeSplitConfigView::read_from_account_data(&split_data).unwrap();
+        assert_eq!(parsed, FeeSplitConfigView { recipient_usdc_ata: [6u8; 32], fee_split_bps: 2_500, bump: 249 });
+        assert!(ConfigView::read_from_account_data(&config_data).unwrap().fee_split_enabled());
+    }
+
     #[test]
     fn routes_update_config() {
         let admin = [7u8; 32];
@@ -179,6 +372,10 @@ mod tests {
             config_bump: None,
             degen_config_account_data: None,
             degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
             new_treasury_ata_pubkey: None,
             new_treasury_token_account_data: None,
             expected_owner_pubkey: None,
@@ -205,6 +402,10 @@ mod tests {
             config_bump: None,
             degen_config_account_data: None,
             degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
             new_treasury_ata_pubkey: None,
             new_treasury_token_account_data: None,
             expected_owner_pubkey: None,
@@ -240,6 +441,10 @@ mod tests {
             config_bump: None,
             degen_config_account_data: None,
             degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
             new_treasury_ata_pubkey: Some(new_treasury_pubkey),
             new_treasury_token_account_data: Some(&token_account),
             expected_owner_pubkey: Some(expected_owner),
@@ -273,6 +478,10 @@ mod tests {
             config_bump: Some(254),
             degen_config_account_data: None,
             degen_config_bump: None,
+            vrf_queue_config_account_data: None,
+            vrf_queue_config_bump: None,
+            fee_split_config_account_data: None,
+            fee_split_config_bump: None,
             new_treasury_ata_pubkey: None,
             new_treasury_token_account_data: None,
             expected_owner_pubkey: None,
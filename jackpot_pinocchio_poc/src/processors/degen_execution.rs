@@ -12,10 +12,12 @@ pub enum DegenExecutionEffect {
     Fallback(ClaimAmountsCompat),
     ClaimDegen(ClaimAmountsCompat),
     Finalize,
+    RecoverStuckExecution,
 }
 
 pub struct DegenExecutionProcessor<'a> {
     pub executor_pubkey: Option<[u8; PUBKEY_LEN]>,
+    pub admin_pubkey: Option<[u8; PUBKEY_LEN]>,
     pub winner_pubkey: Option<[u8; PUBKEY_LEN]>,
     pub round_pubkey: [u8; PUBKEY_LEN],
     pub vault_pubkey: Option<[u8; PUBKEY_LEN]>,
@@ -135,6 +137,23 @@ impl<'a> DegenExecutionProcessor<'a> {
             return Ok(DegenExecutionEffect::Finalize);
         }
 
+        if discriminator == instruction_discriminator("recover_stuck_execution") {
+            handlers::recover_stuck_execution::process_anchor_bytes(
+                self.admin_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
+                self.round_pubkey,
+                self.vault_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
+                self.receiver_token_ata_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
+                self.now_ts,
+                self.config_account_data.ok_or(ProgramError::InvalidInstructionData)?,
+                self.round_account_data,
+                self.degen_claim_account_data,
+                self.vault_account_data.ok_or(ProgramError::InvalidInstructionData)?,
+                self.receiver_token_ata_data.ok_or(ProgramError::InvalidInstructionData)?,
+                ix_data,
+            )?;
+            return Ok(DegenExecutionEffect::RecoverStuckExecution);
+        }
+
         Err(ProgramError::InvalidInstructionData)
     }
 }
@@ -144,6 +163,7 @@ mod tests {
     use super::*;
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
+        instruction_layouts::{encode_begin_degen_execution, RoundId},
         legacy_layouts::{
             ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, TokenAccountWithAmountView,
             CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN,
@@ -267,6 +287,7 @@ mod tests {
 
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: None,
+            admin_pubkey: None,
             winner_pubkey: Some([9u8; 32]),
             round_pubkey: [8u8; 32],
             vault_pubkey: Some([8u8; 32]),
@@ -303,22 +324,17 @@ mod tests {
         let degen_config = sample_degen_config();
         let mut round = sample_round(DEGEN_MODE_VRF_READY);
         let token_index = 42u32;
-        let token_mint = [11u8; 32];
+        let token_mint = crate::degen_pool_compat::degen_token_mint_by_index(token_index).unwrap();
         let mut degen_claim = sample_degen_claim(DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
         let vault = token_account([2u8; 32], [8u8; 32], 1_000_000);
         let executor_ata = token_account([2u8; 32], [5u8; 32], 0);
         let treasury_ata = token_account([2u8; 32], [7u8; 32], 0);
         let receiver_ata = token_account(token_mint, [9u8; 32], 500);
-        let mut ix = Vec::new();
-        ix.extend_from_slice(&instruction_discriminator("begin_degen_execution"));
-        ix.extend_from_slice(&81u64.to_le_bytes());
-        ix.push(0);
-        ix.extend_from_slice(&token_index.to_le_bytes());
-        ix.extend_from_slice(&777u64.to_le_bytes());
-        ix.extend_from_slice(&[33u8; 32]);
+        let ix = encode_begin_degen_execution(RoundId(81), 0, token_index, 777, [33u8; 32]);
 
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: Some([5u8; 32]),
+            admin_pubkey: None,
             winner_pubkey: None,
             round_pubkey: [8u8; 32],
             vault_pubkey: Some([8u8; 32]),
@@ -362,6 +378,7 @@ mod tests {
 
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: Some([5u8; 32]),
+            admin_pubkey: None,
             winner_pubkey: None,
             round_pubkey: [8u8; 32],
             vault_pubkey: None,
@@ -31,6 +31,7 @@ impl<'a> DegenVrfProcessor<'a> {
                 self.now_ts,
                 self.round_account_data,
                 self.degen_claim_account_data,
+                self.degen_config_account_data,
                 ix_data,
             );
         }
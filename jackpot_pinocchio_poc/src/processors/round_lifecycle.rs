@@ -8,13 +8,8 @@ use crate::{
 
 pub struct RoundLifecycleProcessor<'a> {
     pub caller_pubkey: [u8; PUBKEY_LEN],
-    pub round_pubkey: Option<[u8; PUBKEY_LEN]>,
-    pub round_bump: Option<u8>,
-    pub vault_pubkey: Option<[u8; PUBKEY_LEN]>,
-    pub usdc_mint_pubkey: Option<[u8; PUBKEY_LEN]>,
     pub config_account_data: &'a [u8],
     pub round_account_data: &'a mut [u8],
-    pub vault_account_data: Option<&'a [u8]>,
     pub current_unix_timestamp: i64,
 }
 
@@ -43,16 +38,39 @@ impl<'a> RoundLifecycleProcessor<'a> {
             );
         }
 
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// `start_round` is split out because it is the only round lifecycle
+/// instruction that writes the config (it advances `last_round_id`).
+pub struct StartRoundProcessor<'a> {
+    pub round_pubkey: [u8; PUBKEY_LEN],
+    pub round_bump: u8,
+    pub vault_pubkey: [u8; PUBKEY_LEN],
+    pub usdc_mint_pubkey: [u8; PUBKEY_LEN],
+    pub config_account_data: &'a mut [u8],
+    pub round_account_data: &'a mut [u8],
+    pub vault_account_data: &'a [u8],
+    pub current_unix_timestamp: i64,
+}
+
+impl<'a> StartRoundProcessor<'a> {
+    pub fn process(&mut self, ix_data: &[u8]) -> Result<(), ProgramError> {
+        let discriminator = ix_data
+            .get(..8)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
         if discriminator == instruction_discriminator("start_round") {
             return handlers::start_round::process_anchor_bytes(
-                self.round_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
-                self.vault_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
-                self.usdc_mint_pubkey.ok_or(ProgramError::InvalidInstructionData)?,
-                self.round_bump.ok_or(ProgramError::InvalidInstructionData)?,
+                self.round_pubkey,
+                self.vault_pubkey,
+                self.usdc_mint_pubkey,
+                self.round_bump,
                 self.current_unix_timestamp,
                 self.config_account_data,
                 self.round_account_data,
-                self.vault_account_data.ok_or(ProgramError::InvalidInstructionData)?,
+                self.vault_account_data,
                 ix_data,
             );
         }
@@ -124,14 +142,9 @@ mod tests {
 
         let mut processor = RoundLifecycleProcessor {
             caller_pubkey: [9u8; 32],
-            round_pubkey: None,
-            round_bump: None,
-            vault_pubkey: None,
-            usdc_mint_pubkey: None,
             config_account_data: &config_data,
             round_account_data: &mut round_data,
-            vault_account_data: None,
-            current_unix_timestamp: 130,
+            current_unix_timestamp: 145,
         };
 
         processor.process(&ix).unwrap();
@@ -152,13 +165,8 @@ mod tests {
 
         let mut processor = RoundLifecycleProcessor {
             caller_pubkey: admin,
-            round_pubkey: None,
-            round_bump: None,
-            vault_pubkey: None,
-            usdc_mint_pubkey: None,
             config_account_data: &config_data,
             round_account_data: &mut round_data,
-            vault_account_data: None,
             current_unix_timestamp: 10,
         };
 
@@ -173,7 +181,10 @@ mod tests {
         let round_pubkey = [8u8; 32];
         let vault_pubkey = [9u8; 32];
         let usdc_mint = [2u8; 32];
-        let config_data = sample_config([7u8; 32]);
+        let mut config_data = sample_config([7u8; 32]);
+        let mut config = ConfigView::read_from_account_data(&config_data).unwrap();
+        config.set_last_round_id(80);
+        config.write_to_account_data(&mut config_data).unwrap();
         let mut round_data = [0u8; ROUND_ACCOUNT_LEN];
         let mut vault_data = [0u8; crate::legacy_layouts::TOKEN_ACCOUNT_CORE_LEN];
         vault_data[..32].copy_from_slice(&usdc_mint);
@@ -183,15 +194,14 @@ mod tests {
         ix.extend_from_slice(&instruction_discriminator("start_round"));
         ix.extend_from_slice(&81u64.to_le_bytes());
 
-        let mut processor = RoundLifecycleProcessor {
-            caller_pubkey: [9u8; 32],
-            round_pubkey: Some(round_pubkey),
-            round_bump: Some(201),
-            vault_pubkey: Some(vault_pubkey),
-            usdc_mint_pubkey: Some(usdc_mint),
-            config_account_data: &config_data,
+        let mut processor = StartRoundProcessor {
+            round_pubkey,
+            round_bump: 201,
+            vault_pubkey,
+            usdc_mint_pubkey: usdc_mint,
+            config_account_data: &mut config_data,
             round_account_data: &mut round_data,
-            vault_account_data: Some(&vault_data),
+            vault_account_data: &vault_data,
             current_unix_timestamp: 130,
         };
 
@@ -205,5 +215,6 @@ mod tests {
             RoundLifecycleView::read_vault_pubkey_from_account_data(&round_data).unwrap(),
             vault_pubkey,
         );
+        assert_eq!(ConfigView::read_from_account_data(&config_data).unwrap().last_round_id(), 81);
     }
 }
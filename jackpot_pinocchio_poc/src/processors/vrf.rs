@@ -10,6 +10,9 @@ pub struct VrfProcessor<'a> {
     pub payer_pubkey: [u8; PUBKEY_LEN],
     pub config_account_data: &'a [u8],
     pub round_account_data: &'a mut [u8],
+    /// Only `settle_with_slot_hash` reads these two.
+    pub slot_hashes_account_data: Option<&'a [u8]>,
+    pub current_unix_timestamp: i64,
 }
 
 impl<'a> VrfProcessor<'a> {
@@ -35,6 +38,30 @@ impl<'a> VrfProcessor<'a> {
             );
         }
 
+        if discriminator == instruction_discriminator("settle_with_slot_hash") {
+            let slot_hashes_account_data = self
+                .slot_hashes_account_data
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            return handlers::settle_with_slot_hash::process_anchor_bytes(
+                self.payer_pubkey,
+                self.current_unix_timestamp,
+                self.config_account_data,
+                self.round_account_data,
+                slot_hashes_account_data,
+                ix_data,
+            );
+        }
+
+        #[cfg(feature = "devnet")]
+        if discriminator == instruction_discriminator("mock_settle") {
+            return handlers::mock_settle::process_anchor_bytes(
+                self.payer_pubkey,
+                self.config_account_data,
+                self.round_account_data,
+                ix_data,
+            );
+        }
+
         Err(ProgramError::InvalidInstructionData)
     }
 }
@@ -112,6 +139,8 @@ mod tests {
             payer_pubkey: [9u8; 32],
             config_account_data: &config,
             round_account_data: &mut round_data,
+            slot_hashes_account_data: None,
+            current_unix_timestamp: 0,
         };
 
         processor.process(&ix).unwrap();
@@ -132,6 +161,8 @@ mod tests {
             payer_pubkey: [0u8; 32],
             config_account_data: &config,
             round_account_data: &mut round_data,
+            slot_hashes_account_data: None,
+            current_unix_timestamp: 0,
         };
 
         processor.process(&ix).unwrap();
@@ -0,0 +1,125 @@
+//! Where settlement randomness comes from.
+//!
+//! Rounds normally settle from the Switchboard VRF bytes `vrf_callback`
+//! stores on the round. If the oracle stays silent past the config's VRF
+//! timeout, the admin can settle from the SlotHashes sysvar instead
+//! (`settle_with_slot_hash`). Both sources yield 32 bytes that go through the
+//! same `compute_winning_ticket` reduction.
+
+use crate::legacy_layouts::LayoutError;
+
+/// `u64` entry count that prefixes the SlotHashes sysvar data.
+const SLOT_HASHES_LEN_PREFIX: usize = 8;
+/// Each SlotHashes entry is `(slot: u64, hash: [u8; 32])`, newest first.
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+pub trait RandomnessSource {
+    /// 32 bytes to reduce to a winning ticket, or `RandomnessUnavailable`
+    /// when the source has nothing to offer yet.
+    fn randomness(&self) -> Result<[u8; 32], LayoutError>;
+}
+
+/// VRF output as stored on the round. All zeroes means the oracle has not
+/// delivered yet.
+pub struct VrfRandomness(pub [u8; 32]);
+
+impl RandomnessSource for VrfRandomness {
+    fn randomness(&self) -> Result<[u8; 32], LayoutError> {
+        if self.0 == [0u8; 32] {
+            return Err(LayoutError::RandomnessUnavailable);
+        }
+        Ok(self.0)
+    }
+}
+
+/// Most recent slot hash from the SlotHashes sysvar, with the round id
+/// folded into the first 8 bytes so rounds settled in the same slot draw
+/// independently.
+///
+/// Weaker than VRF: whoever submits the transaction picks the slot, so this
+/// only exists as a liveness fallback behind the admin and the VRF timeout.
+pub struct SlotHashRandomness<'a> {
+    pub sysvar_data: &'a [u8],
+    pub round_id: u64,
+}
+
+impl RandomnessSource for SlotHashRandomness<'_> {
+    fn randomness(&self) -> Result<[u8; 32], LayoutError> {
+        let len_prefix = self
+            .sysvar_data
+            .get(..SLOT_HASHES_LEN_PREFIX)
+            .ok_or(LayoutError::SliceTooShort)?;
+        if len_prefix == [0u8; SLOT_HASHES_LEN_PREFIX] {
+            return Err(LayoutError::RandomnessUnavailable);
+        }
+        let hash = self
+            .sysvar_data
+            .get(SLOT_HASHES_LEN_PREFIX + 8..SLOT_HASHES_LEN_PREFIX + SLOT_HASH_ENTRY_LEN)
+            .ok_or(LayoutError::SliceTooShort)?;
+
+        let mut randomness = [0u8; 32];
+        randomness.copy_from_slice(hash);
+        for (byte, round_byte) in randomness.iter_mut().zip(self.round_id.to_le_bytes()) {
+            *byte ^= round_byte;
+        }
+        Ok(randomness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::winner_selection::compute_winning_ticket;
+
+    fn slot_hashes(entries: &[(u64, [u8; 32])]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        data
+    }
+
+    #[test]
+    fn vrf_randomness_rejects_undelivered_bytes() {
+        assert_eq!(VrfRandomness([0u8; 32]).randomness(), Err(LayoutError::RandomnessUnavailable));
+        assert_eq!(VrfRandomness([7u8; 32]).randomness(), Ok([7u8; 32]));
+    }
+
+    #[test]
+    fn slot_hash_reduction_is_deterministic_and_uses_newest_entry() {
+        let data = slot_hashes(&[(1_001, [0xAB; 32]), (1_000, [0x11; 32])]);
+        let source = SlotHashRandomness { sysvar_data: &data, round_id: 81 };
+
+        let randomness = source.randomness().unwrap();
+        assert_eq!(randomness, source.randomness().unwrap());
+        assert_eq!(randomness[0], 0xAB ^ 81);
+        assert_eq!(randomness[1..], [0xAB; 31]);
+        assert_eq!(
+            compute_winning_ticket(&randomness, 200),
+            compute_winning_ticket(&source.randomness().unwrap(), 200)
+        );
+
+        let other_round = SlotHashRandomness { sysvar_data: &data, round_id: 82 };
+        assert_ne!(other_round.randomness().unwrap(), randomness);
+    }
+
+    #[test]
+    fn slot_hash_rejects_empty_or_truncated_sysvar() {
+        let empty = slot_hashes(&[]);
+        assert_eq!(
+            SlotHashRandomness { sysvar_data: &empty, round_id: 81 }.randomness(),
+            Err(LayoutError::RandomnessUnavailable)
+        );
+
+        let data = slot_hashes(&[(1_001, [0xAB; 32])]);
+        assert_eq!(
+            SlotHashRandomness { sysvar_data: &data[..data.len() - 1], round_id: 81 }.randomness(),
+            Err(LayoutError::SliceTooShort)
+        );
+        assert_eq!(
+            SlotHashRandomness { sysvar_data: &data[..4], round_id: 81 }.randomness(),
+            Err(LayoutError::SliceTooShort)
+        );
+    }
+}
@@ -8,9 +8,18 @@ use pinocchio::cpi::{Seed, Signer};
 use pinocchio_system::create_account_with_minimum_balance_signed;
 
 use crate::{
-    anchor_compat::account_discriminator,
-    anchor_compat::instruction_discriminator,
-    legacy_layouts::{CONFIG_ACCOUNT_LEN, ConfigView, DEGEN_CONFIG_ACCOUNT_LEN, DegenConfigView},
+    anchor_compat::{
+        AccountInitState, DEGEN_CONFIG_DISCRIMINATOR, FEE_SPLIT_CONFIG_DISCRIMINATOR,
+        VRF_QUEUE_CONFIG_DISCRIMINATOR, account_init_state, instruction_discriminator, is_uninitialized,
+    },
+    legacy_layouts::{
+        AccountLayout, CONFIG_ACCOUNT_LEN, ConfigView, DEGEN_CONFIG_ACCOUNT_LEN, DegenConfigView,
+        FEE_SPLIT_CONFIG_ACCOUNT_LEN, FeeSplitConfigView, VRF_QUEUE_CONFIG_ACCOUNT_LEN, VrfQueueConfigView,
+    },
+    pdas::{
+        SEED_CFG, SEED_DEGEN_CFG, SEED_FEE_SPLIT, SEED_VRF_QUEUE, derive_config_pda, derive_degen_config_pda,
+        derive_fee_split_config_pda, derive_vrf_queue_config_pda,
+    },
     processors::admin_config::AdminConfigProcessor,
 };
 
@@ -19,8 +28,6 @@ const SPL_TOKEN_PROGRAM_ID: Address = Address::new_from_array([
     6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133,
     237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
 ]);
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_DEGEN_CFG: &[u8] = b"degen_cfg";
 
 pub fn process_instruction(
     program_id: &Address,
@@ -40,6 +47,15 @@ pub fn process_instruction(
     if discriminator == instruction_discriminator("update_config") {
         return process_update_config(program_id, accounts, instruction_data);
     }
+    if discriminator == instruction_discriminator("update_degen_timeout") {
+        return process_update_degen_timeout(program_id, accounts, instruction_data);
+    }
+    if discriminator == instruction_discriminator("set_vrf_queue") {
+        return process_set_vrf_queue(program_id, accounts, instruction_data);
+    }
+    if discriminator == instruction_discriminator("set_fee_split") {
+        return process_set_fee_split(program_id, accounts, instruction_data);
+    }
     if discriminator == instruction_discriminator("transfer_admin") {
         return process_transfer_admin(program_id, accounts, instruction_data);
     }
@@ -76,6 +92,10 @@ fn process_upsert_degen_config(
         config_bump: None,
         degen_config_account_data: Some(&mut degen_config_data[..]),
         degen_config_bump: Some(degen_config_bump),
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
         new_treasury_ata_pubkey: None,
         new_treasury_token_account_data: None,
         expected_owner_pubkey: None,
@@ -107,6 +127,10 @@ fn process_init_config(
         config_bump: Some(config_bump),
         degen_config_account_data: None,
         degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
         new_treasury_ata_pubkey: None,
         new_treasury_token_account_data: None,
         expected_owner_pubkey: None,
@@ -136,6 +160,121 @@ fn process_update_config(
         config_bump: None,
         degen_config_account_data: None,
         degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
+        new_treasury_ata_pubkey: None,
+        new_treasury_token_account_data: None,
+        expected_owner_pubkey: None,
+    }
+    .process(instruction_data)
+}
+
+fn process_update_degen_timeout(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, degen_config, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(admin)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(degen_config)?;
+    require_existing_degen_config_pda(degen_config, program_id)?;
+
+    let admin_pubkey = admin.address().to_bytes();
+    let mut config_data = config.try_borrow_mut()?;
+    let mut degen_config_data = degen_config.try_borrow_mut()?;
+
+    AdminConfigProcessor {
+        admin_pubkey,
+        config_account_data: &mut config_data[..],
+        config_bump: None,
+        degen_config_account_data: Some(&mut degen_config_data[..]),
+        degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
+        new_treasury_ata_pubkey: None,
+        new_treasury_token_account_data: None,
+        expected_owner_pubkey: None,
+    }
+    .process(instruction_data)
+}
+
+fn process_set_vrf_queue(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, vrf_queue_config, system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(admin)?;
+    require_writable(admin)?;
+    require_writable(config)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(vrf_queue_config)?;
+    let vrf_queue_config_bump =
+        prepare_vrf_queue_config_pda_init_if_needed(vrf_queue_config, admin, system_program, program_id)?;
+
+    let admin_pubkey = admin.address().to_bytes();
+    let mut config_data = config.try_borrow_mut()?;
+    let mut vrf_queue_config_data = vrf_queue_config.try_borrow_mut()?;
+
+    AdminConfigProcessor {
+        admin_pubkey,
+        config_account_data: &mut config_data[..],
+        config_bump: None,
+        degen_config_account_data: None,
+        degen_config_bump: None,
+        vrf_queue_config_account_data: Some(&mut vrf_queue_config_data[..]),
+        vrf_queue_config_bump: Some(vrf_queue_config_bump),
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
+        new_treasury_ata_pubkey: None,
+        new_treasury_token_account_data: None,
+        expected_owner_pubkey: None,
+    }
+    .process(instruction_data)
+}
+
+fn process_set_fee_split(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, fee_split_config, system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(admin)?;
+    require_writable(admin)?;
+    require_writable(config)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(fee_split_config)?;
+    let fee_split_config_bump =
+        prepare_fee_split_config_pda_init_if_needed(fee_split_config, admin, system_program, program_id)?;
+
+    let admin_pubkey = admin.address().to_bytes();
+    let mut config_data = config.try_borrow_mut()?;
+    let mut fee_split_config_data = fee_split_config.try_borrow_mut()?;
+
+    AdminConfigProcessor {
+        admin_pubkey,
+        config_account_data: &mut config_data[..],
+        config_bump: None,
+        degen_config_account_data: None,
+        degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: Some(&mut fee_split_config_data[..]),
+        fee_split_config_bump: Some(fee_split_config_bump),
         new_treasury_ata_pubkey: None,
         new_treasury_token_account_data: None,
         expected_owner_pubkey: None,
@@ -165,6 +304,10 @@ fn process_transfer_admin(
         config_bump: None,
         degen_config_account_data: None,
         degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
         new_treasury_ata_pubkey: None,
         new_treasury_token_account_data: None,
         expected_owner_pubkey: None,
@@ -198,6 +341,10 @@ fn process_set_treasury_usdc_ata(
         config_bump: None,
         degen_config_account_data: None,
         degen_config_bump: None,
+        vrf_queue_config_account_data: None,
+        vrf_queue_config_bump: None,
+        fee_split_config_account_data: None,
+        fee_split_config_bump: None,
         new_treasury_ata_pubkey: Some(new_treasury_ata_pubkey),
         new_treasury_token_account_data: Some(&new_treasury_token_account_data[..]),
         expected_owner_pubkey: Some(expected_owner_pubkey),
@@ -240,17 +387,12 @@ fn require_address(account: &AccountView, address: &Address) -> ProgramResult {
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
 
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -258,6 +400,22 @@ fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<Con
     Ok(config)
 }
 
+fn require_existing_degen_config_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
+    require_owned_by(account, program_id)?;
+
+    let (expected_address, expected_bump) = derive_degen_config_pda(program_id);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let degen = DegenConfigView::from_account_view(account)?;
+    if degen.bump != expected_bump {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
 fn prepare_degen_config_pda_init_if_needed(
     account: &AccountView,
     payer: &AccountView,
@@ -266,7 +424,7 @@ fn prepare_degen_config_pda_init_if_needed(
 ) -> Result<u8, ProgramError> {
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
 
-    let (expected_address, bump) = Address::find_program_address(&[SEED_DEGEN_CFG], program_id);
+    let (expected_address, bump) = derive_degen_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -281,23 +439,92 @@ fn prepare_degen_config_pda_init_if_needed(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let has_discriminator = data.get(..8) == Some(&account_discriminator("DegenConfig"));
-    let is_zeroed = data.iter().all(|byte| *byte == 0);
+    match account_init_state(&data, &DEGEN_CONFIG_DISCRIMINATOR) {
+        Some(AccountInitState::Initialized) => {
+            let degen =
+                DegenConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            if degen.bump != bump {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            Ok(bump)
+        }
+        Some(AccountInitState::Uninitialized) => Ok(bump),
+        None => Err(ProgramError::AccountAlreadyInitialized),
+    }
+}
+
+fn prepare_vrf_queue_config_pda_init_if_needed(
+    account: &AccountView,
+    payer: &AccountView,
+    system_program: &AccountView,
+    program_id: &Address,
+) -> Result<u8, ProgramError> {
+    require_address(system_program, &SYSTEM_PROGRAM_ID)?;
+
+    let (expected_address, bump) = derive_vrf_queue_config_pda(program_id);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if !account.owned_by(program_id) {
+        require_owned_by(account, &SYSTEM_PROGRAM_ID)?;
+        create_vrf_queue_config_pda_account(account, payer, program_id, bump)?;
+    }
 
-    if has_discriminator {
-        let degen =
-            DegenConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-        if degen.bump != bump {
-            return Err(ProgramError::InvalidSeeds);
+    let data = account.try_borrow()?;
+    if data.len() != VRF_QUEUE_CONFIG_ACCOUNT_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match account_init_state(&data, &VRF_QUEUE_CONFIG_DISCRIMINATOR) {
+        Some(AccountInitState::Initialized) => {
+            let queue_config =
+                VrfQueueConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            if queue_config.bump != bump {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            Ok(bump)
         }
-        return Ok(bump);
+        Some(AccountInitState::Uninitialized) => Ok(bump),
+        None => Err(ProgramError::AccountAlreadyInitialized),
+    }
+}
+
+fn prepare_fee_split_config_pda_init_if_needed(
+    account: &AccountView,
+    payer: &AccountView,
+    system_program: &AccountView,
+    program_id: &Address,
+) -> Result<u8, ProgramError> {
+    require_address(system_program, &SYSTEM_PROGRAM_ID)?;
+
+    let (expected_address, bump) = derive_fee_split_config_pda(program_id);
+    if account.address() != &expected_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if !account.owned_by(program_id) {
+        require_owned_by(account, &SYSTEM_PROGRAM_ID)?;
+        create_fee_split_config_pda_account(account, payer, program_id, bump)?;
     }
 
-    if is_zeroed {
-        return Ok(bump);
+    let data = account.try_borrow()?;
+    if data.len() != FEE_SPLIT_CONFIG_ACCOUNT_LEN {
+        return Err(ProgramError::InvalidAccountData);
     }
 
-    Err(ProgramError::AccountAlreadyInitialized)
+    match account_init_state(&data, &FEE_SPLIT_CONFIG_DISCRIMINATOR) {
+        Some(AccountInitState::Initialized) => {
+            let fee_split_config =
+                FeeSplitConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            if fee_split_config.bump != bump {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            Ok(bump)
+        }
+        Some(AccountInitState::Uninitialized) => Ok(bump),
+        None => Err(ProgramError::AccountAlreadyInitialized),
+    }
 }
 
 fn prepare_config_pda_init_if_needed(
@@ -308,7 +535,7 @@ fn prepare_config_pda_init_if_needed(
 ) -> Result<u8, ProgramError> {
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
 
-    let (expected_address, bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -319,7 +546,7 @@ fn prepare_config_pda_init_if_needed(
     }
 
     let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.iter().any(|byte| *byte != 0) {
+    if data.len() != CONFIG_ACCOUNT_LEN || !is_uninitialized(&data) {
         return Err(ProgramError::AccountAlreadyInitialized);
     }
 
@@ -360,6 +587,74 @@ fn create_degen_config_pda_account(
     Ok(())
 }
 
+#[cfg(not(test))]
+fn create_vrf_queue_config_pda_account(
+    account: &AccountView,
+    payer: &AccountView,
+    program_id: &Address,
+    bump: u8,
+) -> ProgramResult {
+    let bump_seed = [bump];
+    let seeds = [Seed::from(SEED_VRF_QUEUE), Seed::from(&bump_seed)];
+    let signer = Signer::from(&seeds);
+    create_account_with_minimum_balance_signed(
+        account,
+        VRF_QUEUE_CONFIG_ACCOUNT_LEN,
+        program_id,
+        payer,
+        None,
+        &[signer],
+    )
+}
+
+#[cfg(test)]
+fn create_vrf_queue_config_pda_account(
+    account: &AccountView,
+    _payer: &AccountView,
+    program_id: &Address,
+    _bump: u8,
+) -> ProgramResult {
+    unsafe {
+        account.assign(program_id);
+        account.resize_unchecked(VRF_QUEUE_CONFIG_ACCOUNT_LEN)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(test))]
+fn create_fee_split_config_pda_account(
+    account: &AccountView,
+    payer: &AccountView,
+    program_id: &Address,
+    bump: u8,
+) -> ProgramResult {
+    let bump_seed = [bump];
+    let seeds = [Seed::from(SEED_FEE_SPLIT), Seed::from(&bump_seed)];
+    let signer = Signer::from(&seeds);
+    create_account_with_minimum_balance_signed(
+        account,
+        FEE_SPLIT_CONFIG_ACCOUNT_LEN,
+        program_id,
+        payer,
+        None,
+        &[signer],
+    )
+}
+
+#[cfg(test)]
+fn create_fee_split_config_pda_account(
+    account: &AccountView,
+    _payer: &AccountView,
+    program_id: &Address,
+    _bump: u8,
+) -> ProgramResult {
+    unsafe {
+        account.assign(program_id);
+        account.resize_unchecked(FEE_SPLIT_CONFIG_ACCOUNT_LEN)?;
+    }
+    Ok(())
+}
+
 #[cfg(not(test))]
 fn create_config_pda_account(
     account: &AccountView,
@@ -396,16 +691,14 @@ fn create_config_pda_account(
 
 #[cfg(test)]
 mod tests {
-    use core::mem::size_of;
-
-    use pinocchio::account::{NOT_BORROWED, RuntimeAccount};
-
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
+        errors::JackpotCompatError,
         legacy_layouts::{
             CONFIG_ACCOUNT_LEN, ConfigView, DEGEN_CONFIG_ACCOUNT_LEN, DegenConfigView,
             TOKEN_ACCOUNT_CORE_LEN,
         },
+        runtime::test_support::TestAccount,
     };
 
     use super::*;
@@ -414,69 +707,7 @@ mod tests {
         43, 187, 24, 179, 245, 85, 238, 77, 204, 252, 3, 113, 231, 169, 27, 207, 165, 14, 251,
         108, 242, 117, 20, 87, 30, 9, 66, 30, 58, 230, 228, 54,
     ]);
-
-    struct TestAccount {
-        backing: Vec<u64>,
-    }
-
-    impl TestAccount {
-        fn new(
-            address: [u8; 32],
-            owner: Address,
-            is_signer: bool,
-            is_writable: bool,
-            data: &[u8],
-        ) -> Self {
-            Self::new_with_capacity(address, owner, is_signer, is_writable, data, data.len())
-        }
-
-        fn new_with_capacity(
-            address: [u8; 32],
-            owner: Address,
-            is_signer: bool,
-            is_writable: bool,
-            data: &[u8],
-            capacity: usize,
-        ) -> Self {
-            let bytes = size_of::<RuntimeAccount>() + capacity;
-            let words = bytes.div_ceil(size_of::<u64>());
-            let mut backing = vec![0u64; words.max(1)];
-            let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
-
-            unsafe {
-                (*raw).borrow_state = NOT_BORROWED;
-                (*raw).is_signer = u8::from(is_signer);
-                (*raw).is_writable = u8::from(is_writable);
-                (*raw).executable = 0;
-                (*raw).resize_delta = 0;
-                (*raw).address = Address::from(address);
-                (*raw).owner = owner;
-                (*raw).lamports = 1_000_000_000;
-                (*raw).data_len = data.len() as u64;
-
-                if !data.is_empty() {
-                    let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
-                    core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
-                }
-            }
-
-            Self { backing }
-        }
-
-        fn view(&mut self) -> AccountView {
-            unsafe { AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
-        }
-
-        fn data(&self) -> &[u8] {
-            let raw = self.backing.as_ptr() as *const RuntimeAccount;
-            unsafe {
-                core::slice::from_raw_parts(
-                    (raw as *const u8).add(size_of::<RuntimeAccount>()),
-                    (*raw).data_len as usize,
-                )
-            }
-        }
-    }
+    const LAMPORTS: u64 = 1_000_000_000;
 
     fn sample_config(admin: [u8; 32]) -> [u8; CONFIG_ACCOUNT_LEN] {
         let (_config_pda, config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
@@ -504,10 +735,10 @@ mod tests {
     #[test]
     fn entrypoint_routes_update_config() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &sample_config(admin));
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("update_config"));
@@ -531,19 +762,20 @@ mod tests {
     fn entrypoint_routes_init_config() {
         let payer = [6u8; 32];
         let admin = [7u8; 32];
-        let mut payer_acc = TestAccount::new(payer, SYSTEM_PROGRAM_ID, true, true, &[]);
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, false, &[]);
+        let mut payer_acc = TestAccount::new(payer, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, false, LAMPORTS, &[]);
         let (config_pda, config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut config_acc = TestAccount::new_with_capacity(
             config_pda.to_bytes(),
             SYSTEM_PROGRAM_ID,
             false,
             true,
+            LAMPORTS,
             &[],
             CONFIG_ACCOUNT_LEN,
         );
         let mut system_program_acc =
-            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, &[]);
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("init_config"));
@@ -577,10 +809,10 @@ mod tests {
     #[test]
     fn entrypoint_routes_transfer_admin() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &sample_config(admin));
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("transfer_admin"));
@@ -596,10 +828,10 @@ mod tests {
     #[test]
     fn entrypoint_routes_set_treasury() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &sample_config(admin));
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
 
         let mut token_account_data = [0u8; TOKEN_ACCOUNT_CORE_LEN];
         token_account_data[..32].copy_from_slice(&[2u8; 32]);
@@ -610,9 +842,10 @@ mod tests {
             SPL_TOKEN_PROGRAM_ID,
             false,
             false,
+            LAMPORTS,
             &token_account_data,
         );
-        let mut expected_owner_acc = TestAccount::new([5u8; 32], SYSTEM_PROGRAM_ID, false, false, &[]);
+        let mut expected_owner_acc = TestAccount::new([5u8; 32], SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("set_treasury_usdc_ata"));
@@ -629,25 +862,102 @@ mod tests {
         assert_eq!(parsed.treasury_usdc_ata, [4u8; 32]);
     }
 
+    #[test]
+    fn entrypoint_set_treasury_rejects_ata_not_owned_by_expected_owner() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+
+        let mut token_account_data = [0u8; TOKEN_ACCOUNT_CORE_LEN];
+        token_account_data[..32].copy_from_slice(&[2u8; 32]);
+        token_account_data[32..64].copy_from_slice(&[6u8; 32]);
+
+        let mut new_treasury_acc = TestAccount::new(
+            [4u8; 32],
+            SPL_TOKEN_PROGRAM_ID,
+            false,
+            false,
+            LAMPORTS,
+            &token_account_data,
+        );
+        let mut expected_owner_acc = TestAccount::new([5u8; 32], SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_treasury_usdc_ata"));
+
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            new_treasury_acc.view(),
+            expected_owner_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+
+        let parsed = ConfigView::read_from_account_data(config_acc.data()).unwrap();
+        assert_eq!(parsed.treasury_usdc_ata, [3u8; 32]);
+    }
+
+    #[test]
+    fn entrypoint_set_treasury_rejects_ata_with_wrong_mint() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+
+        let mut token_account_data = [0u8; TOKEN_ACCOUNT_CORE_LEN];
+        token_account_data[..32].copy_from_slice(&[8u8; 32]);
+        token_account_data[32..64].copy_from_slice(&[5u8; 32]);
+
+        let mut new_treasury_acc = TestAccount::new(
+            [4u8; 32],
+            SPL_TOKEN_PROGRAM_ID,
+            false,
+            false,
+            LAMPORTS,
+            &token_account_data,
+        );
+        let mut expected_owner_acc = TestAccount::new([5u8; 32], SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_treasury_usdc_ata"));
+
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            new_treasury_acc.view(),
+            expected_owner_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+
+        let parsed = ConfigView::read_from_account_data(config_acc.data()).unwrap();
+        assert_eq!(parsed.treasury_usdc_ata, [3u8; 32]);
+    }
+
     #[test]
     fn entrypoint_routes_upsert_degen_config() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let (degen_pda, _degen_bump) =
             Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
         let config_bytes = sample_config(admin);
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_bytes);
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &config_bytes);
         let mut degen_acc = TestAccount::new(
             degen_pda.to_bytes(),
             PROGRAM_ID,
             false,
             true,
+            LAMPORTS,
             &[0u8; DEGEN_CONFIG_ACCOUNT_LEN],
         );
         let mut system_program_acc =
-            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, &[]);
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
@@ -671,28 +981,29 @@ mod tests {
     #[test]
     fn entrypoint_routes_upsert_degen_config_init_if_needed() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let (degen_pda, degen_bump) =
             Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
         let config_bytes = sample_config(admin);
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_bytes);
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &config_bytes);
         let mut degen_acc = TestAccount::new_with_capacity(
             degen_pda.to_bytes(),
             SYSTEM_PROGRAM_ID,
             false,
             true,
+            LAMPORTS,
             &[],
             DEGEN_CONFIG_ACCOUNT_LEN,
         );
         let mut system_program_acc =
-            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, &[]);
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
         ix.extend_from_slice(&[10u8; 32]);
-        ix.extend_from_slice(&45u32.to_le_bytes());
+        ix.extend_from_slice(&900u32.to_le_bytes());
 
         let accounts = [
             admin_acc.view(),
@@ -704,16 +1015,290 @@ mod tests {
 
         let parsed = DegenConfigView::read_from_account_data(degen_acc.data()).unwrap();
         assert_eq!(parsed.executor, [10u8; 32]);
-        assert_eq!(parsed.fallback_timeout_sec, 45);
+        assert_eq!(parsed.fallback_timeout_sec, 900);
         assert_eq!(parsed.bump, degen_bump);
     }
 
+    fn set_vrf_queue_ix(queue: [u8; 32]) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_vrf_queue"));
+        ix.extend_from_slice(&queue);
+        ix
+    }
+
+    #[test]
+    fn entrypoint_routes_set_vrf_queue_init_if_needed_then_overwrites() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (queue_pda, queue_bump) = Address::find_program_address(&[SEED_VRF_QUEUE], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+        let mut queue_acc = TestAccount::new_with_capacity(
+            queue_pda.to_bytes(),
+            SYSTEM_PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[],
+            VRF_QUEUE_CONFIG_ACCOUNT_LEN,
+        );
+        let mut system_program_acc =
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            queue_acc.view(),
+            system_program_acc.view(),
+        ];
+        process_instruction(&PROGRAM_ID, &accounts, &set_vrf_queue_ix([6u8; 32])).unwrap();
+        assert_eq!(
+            VrfQueueConfigView::from_account_data(queue_acc.data()),
+            Ok(VrfQueueConfigView { queue: [6u8; 32], bump: queue_bump })
+        );
+        assert!(ConfigView::read_from_account_data(config_acc.data()).unwrap().vrf_queue_overridden());
+
+        process_instruction(&PROGRAM_ID, &accounts, &set_vrf_queue_ix([8u8; 32])).unwrap();
+        assert_eq!(VrfQueueConfigView::from_account_data(queue_acc.data()).unwrap().queue, [8u8; 32]);
+    }
+
+    #[test]
+    fn entrypoint_set_vrf_queue_rejects_non_admin_and_wrong_pda() {
+        let admin = [7u8; 32];
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (queue_pda, _queue_bump) = Address::find_program_address(&[SEED_VRF_QUEUE], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+        let mut system_program_acc =
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let mut intruder_acc = TestAccount::new([8u8; 32], SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let mut queue_acc = TestAccount::new(
+            queue_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN],
+        );
+        let accounts = [
+            intruder_acc.view(),
+            config_acc.view(),
+            queue_acc.view(),
+            system_program_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &set_vrf_queue_ix([6u8; 32])).unwrap_err();
+        assert_eq!(err, JackpotCompatError::Unauthorized.into());
+        assert_eq!(queue_acc.data(), &[0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN]);
+        assert_eq!(config_acc.data(), &sample_config(admin));
+
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let mut look_alike_acc = TestAccount::new(
+            [9u8; 32],
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN],
+        );
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            look_alike_acc.view(),
+            system_program_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &set_vrf_queue_ix([6u8; 32])).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidSeeds);
+    }
+
+    fn set_fee_split_ix(recipient_usdc_ata: [u8; 32], fee_split_bps: u16) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("set_fee_split"));
+        ix.extend_from_slice(&recipient_usdc_ata);
+        ix.extend_from_slice(&fee_split_bps.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn entrypoint_routes_set_fee_split_init_if_needed_then_disables() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (split_pda, split_bump) = Address::find_program_address(&[SEED_FEE_SPLIT], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+        let mut split_acc = TestAccount::new_with_capacity(
+            split_pda.to_bytes(),
+            SYSTEM_PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[],
+            FEE_SPLIT_CONFIG_ACCOUNT_LEN,
+        );
+        let mut system_program_acc =
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            split_acc.view(),
+            system_program_acc.view(),
+        ];
+        process_instruction(&PROGRAM_ID, &accounts, &set_fee_split_ix([6u8; 32], 2_500)).unwrap();
+        assert_eq!(
+            FeeSplitConfigView::from_account_data(split_acc.data()),
+            Ok(FeeSplitConfigView { recipient_usdc_ata: [6u8; 32], fee_split_bps: 2_500, bump: split_bump })
+        );
+        assert!(ConfigView::read_from_account_data(config_acc.data()).unwrap().fee_split_enabled());
+
+        process_instruction(&PROGRAM_ID, &accounts, &set_fee_split_ix([6u8; 32], 0)).unwrap();
+        assert_eq!(FeeSplitConfigView::from_account_data(split_acc.data()).unwrap().fee_split_bps, 0);
+        assert!(!ConfigView::read_from_account_data(config_acc.data()).unwrap().fee_split_enabled());
+    }
+
+    #[test]
+    fn entrypoint_set_fee_split_rejects_wrong_pda_and_read_only_config() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (split_pda, _split_bump) = Address::find_program_address(&[SEED_FEE_SPLIT], &PROGRAM_ID);
+        let mut system_program_acc =
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+
+        let mut read_only_config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &sample_config(admin));
+        let mut split_acc = TestAccount::new(
+            split_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN],
+        );
+        let accounts = [
+            admin_acc.view(),
+            read_only_config_acc.view(),
+            split_acc.view(),
+            system_program_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &set_fee_split_ix([6u8; 32], 2_500)).unwrap_err();
+        assert_eq!(err, ProgramError::Immutable);
+
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
+        let mut look_alike_acc = TestAccount::new(
+            [9u8; 32],
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN],
+        );
+        let accounts = [
+            admin_acc.view(),
+            config_acc.view(),
+            look_alike_acc.view(),
+            system_program_acc.view(),
+        ];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &set_fee_split_ix([6u8; 32], 2_500)).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidSeeds);
+        assert_eq!(config_acc.data(), &sample_config(admin));
+    }
+
+    fn sample_degen_config(executor: [u8; 32], fallback_timeout_sec: u32) -> [u8; DEGEN_CONFIG_ACCOUNT_LEN] {
+        let (_degen_pda, degen_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+        let view = DegenConfigView {
+            executor,
+            fallback_timeout_sec,
+            bump: degen_bump,
+            reserved: [0u8; 27],
+        };
+
+        let mut data = [0u8; DEGEN_CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("DegenConfig"));
+        view.write_to_account_data(&mut data).unwrap();
+        data
+    }
+
+    fn update_degen_timeout_ix(timeout: u32) -> Vec<u8> {
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("update_degen_timeout"));
+        ix.extend_from_slice(&timeout.to_le_bytes());
+        ix
+    }
+
+    #[test]
+    fn entrypoint_routes_update_degen_timeout() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, false, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (degen_pda, _degen_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &sample_config(admin));
+        let mut degen_acc = TestAccount::new(
+            degen_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &sample_degen_config([10u8; 32], 300),
+        );
+
+        let accounts = [admin_acc.view(), config_acc.view(), degen_acc.view()];
+        process_instruction(&PROGRAM_ID, &accounts, &update_degen_timeout_ix(600)).unwrap();
+
+        let parsed = DegenConfigView::read_from_account_data(degen_acc.data()).unwrap();
+        assert_eq!(parsed.executor, [10u8; 32]);
+        assert_eq!(parsed.fallback_timeout_sec, 600);
+    }
+
+    #[test]
+    fn entrypoint_update_degen_timeout_requires_admin_signer() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (degen_pda, _degen_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &sample_config(admin));
+        let degen_bytes = sample_degen_config([10u8; 32], 300);
+        let mut degen_acc = TestAccount::new(degen_pda.to_bytes(), PROGRAM_ID, false, true, LAMPORTS, &degen_bytes);
+
+        let accounts = [admin_acc.view(), config_acc.view(), degen_acc.view()];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &update_degen_timeout_ix(600)).unwrap_err();
+        assert_eq!(err, ProgramError::MissingRequiredSignature);
+        assert_eq!(degen_acc.data(), degen_bytes.as_slice());
+    }
+
+    #[test]
+    fn entrypoint_update_degen_timeout_rejects_uninitialized_degen_config() {
+        let admin = [7u8; 32];
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, false, LAMPORTS, &[]);
+        let (config_pda, _config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let (degen_pda, _degen_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+        let mut config_acc =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &sample_config(admin));
+        let mut degen_acc = TestAccount::new(
+            degen_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            true,
+            LAMPORTS,
+            &[0u8; DEGEN_CONFIG_ACCOUNT_LEN],
+        );
+
+        let accounts = [admin_acc.view(), config_acc.view(), degen_acc.view()];
+        let err = process_instruction(&PROGRAM_ID, &accounts, &update_degen_timeout_ix(600)).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidAccountData);
+    }
+
     #[test]
     fn entrypoint_rejects_wrong_config_pda() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let mut config_acc =
-            TestAccount::new([8u8; 32], PROGRAM_ID, false, true, &sample_config(admin));
+            TestAccount::new([8u8; 32], PROGRAM_ID, false, true, LAMPORTS, &sample_config(admin));
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("transfer_admin"));
@@ -727,23 +1312,24 @@ mod tests {
     #[test]
     fn entrypoint_rejects_wrong_degen_pda() {
         let admin = [7u8; 32];
-        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, &[]);
+        let mut admin_acc = TestAccount::new(admin, SYSTEM_PROGRAM_ID, true, true, LAMPORTS, &[]);
         let (config_pda, config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut config_bytes = sample_config(admin);
         let mut parsed = ConfigView::read_from_account_data(&config_bytes).unwrap();
         parsed.bump = config_bump;
         parsed.write_to_account_data(&mut config_bytes).unwrap();
         let mut config_acc =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_bytes);
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, LAMPORTS, &config_bytes);
         let mut degen_acc = TestAccount::new(
             [9u8; 32],
             PROGRAM_ID,
             false,
             true,
+            LAMPORTS,
             &[0u8; DEGEN_CONFIG_ACCOUNT_LEN],
         );
         let mut system_program_acc =
-            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, &[]);
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, LAMPORTS, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
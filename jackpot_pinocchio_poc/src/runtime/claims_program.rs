@@ -7,21 +7,22 @@ use pinocchio::{
 use pinocchio::cpi::{Seed, Signer};
 #[cfg(not(test))]
 use pinocchio_token::instructions::Transfer as TokenTransfer;
+#[cfg(not(test))]
+use crate::pdas::SEED_ROUND;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::instruction_discriminator,
+    errors::JackpotCompatError,
+    instruction_layouts::RoundId,
     handlers::degen_common::ClaimAmountsCompat,
-    legacy_layouts::{CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ConfigView},
+    legacy_layouts::{AccountLayout, ConfigView, RoundLifecycleView},
+    pdas::{derive_config_pda, derive_round_pda},
     processors::claims::ClaimProcessor,
 };
 
 #[cfg(test)]
-use crate::{errors::JackpotCompatError, legacy_layouts::TokenAccountWithAmountView};
-#[cfg(not(test))]
-use crate::legacy_layouts::RoundLifecycleView;
+use crate::legacy_layouts::TokenAccountWithAmountView;
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
 
 pub fn process_instruction(
     program_id: &Address,
@@ -67,13 +68,14 @@ fn process_claim(
     require_writable(winner_usdc_ata)?;
     require_writable(treasury_usdc_ata)?;
     let config_view = require_config_pda(config, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "claim")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "claim")?;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(winner_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
     if let Some(vrf_payer_usdc_ata) = vrf_payer_usdc_ata {
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
@@ -150,13 +152,14 @@ fn process_auto_claim(
     require_writable(winner_usdc_ata)?;
     require_writable(treasury_usdc_ata)?;
     let config_view = require_config_pda(config, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "auto_claim")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "auto_claim")?;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(winner_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
     if let Some(vrf_payer_usdc_ata) = vrf_payer_usdc_ata {
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
@@ -220,7 +223,7 @@ fn transfer_claim_amounts(
 ) -> ProgramResult {
     let round_data = round.try_borrow()?;
     let round_view =
-        RoundLifecycleView::read_from_account_data(&round_data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RoundLifecycleView::read_from_account_data_exact(&round_data).map_err(|_| ProgramError::InvalidAccountData)?;
     let round_bump = round_view.bump;
     let round_id = round_view.round_id;
     drop(round_data);
@@ -339,32 +342,24 @@ fn require_owned_by(account: &AccountView, owner: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
     Ok(config)
 }
 
-fn require_round_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: u64) -> ProgramResult {
+fn require_round_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: RoundId) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let (expected_address, _) = Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn require_token_program(account: &AccountView) -> ProgramResult {
@@ -375,6 +370,27 @@ fn require_token_program(account: &AccountView) -> ProgramResult {
     }
 }
 
+/// Ties the vault to the ATA recorded on the round at `start_round`, so a
+/// different round-owned USDC account cannot be substituted.
+fn require_vault_matches_round(vault: &AccountView, round: &AccountView) -> ProgramResult {
+    let round_data = round.try_borrow()?;
+    let expected = RoundLifecycleView::read_vault_pubkey_from_account_data(&round_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if vault.address().to_bytes() != expected {
+        return Err(JackpotCompatError::InvalidVault.into());
+    }
+    Ok(())
+}
+
+/// Fees must land in the treasury ATA recorded on `config`; any other
+/// account of the right mint would otherwise be accepted as the fee sink.
+fn require_treasury_matches_config(treasury: &AccountView, config: &ConfigView) -> ProgramResult {
+    if treasury.address().to_bytes() != config.treasury_usdc_ata {
+        return Err(JackpotCompatError::InvalidTreasury.into());
+    }
+    Ok(())
+}
+
 fn require_token_account_owned_by_program(account: &AccountView, token_program: &AccountView) -> ProgramResult {
     require_owned_by(account, token_program.address())
 }
@@ -390,18 +406,18 @@ mod tests {
 
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
+        errors::JackpotCompatError,
         legacy_layouts::{
             ConfigView, RoundLifecycleView, TokenAccountWithAmountView, CONFIG_ACCOUNT_LEN,
             ROUND_ACCOUNT_LEN, ROUND_STATUS_CLAIMED, ROUND_STATUS_SETTLED,
             TOKEN_ACCOUNT_WITH_AMOUNT_LEN, DEGEN_MODE_NONE,
         },
+        pdas::{SEED_CFG, SEED_ROUND},
     };
 
     use super::process_instruction;
 
     const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
-    const SEED_CFG: &[u8] = b"cfg";
-    const SEED_ROUND: &[u8] = b"round";
 
     struct TestAccount {
         backing: Vec<u64>,
@@ -700,4 +716,87 @@ mod tests {
             200_300
         );
     }
+
+    #[test]
+    fn claim_rejects_vault_not_recorded_on_round() {
+        let winner = Address::new_from_array([9u8; 32]);
+        let token_program = pinocchio_token::ID;
+        let usdc_mint = Address::new_from_array([2u8; 32]);
+        let recorded_vault = Address::new_from_array([8u8; 32]);
+        let other_vault = Address::new_from_array([66u8; 32]);
+        let winner_ata = Address::new_from_array([12u8; 32]);
+        let treasury_ata = Address::new_from_array([3u8; 32]);
+        let vrf_payer_ata = Address::new_from_array([13u8; 32]);
+
+        let (config_pda, config_data) = sample_config(usdc_mint, treasury_ata);
+        let (round_pda, round_data) = sample_round(83, recorded_vault, winner);
+
+        let mut winner_account =
+            TestAccount::new(winner.to_bytes(), Address::new_from_array([0u8; 32]), true, true, 1_000_000, &[]);
+        let mut config_account =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account =
+            TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        // Owned by the round PDA, so only the recorded-vault check can catch it.
+        let mut vault_account = TestAccount::new(
+            other_vault.to_bytes(),
+            token_program,
+            false,
+            true,
+            1_000_000,
+            &token_account(usdc_mint, round_pda, 1_000_000),
+        );
+        let mut winner_ata_account = TestAccount::new(
+            winner_ata.to_bytes(),
+            token_program,
+            false,
+            true,
+            1_000_000,
+            &token_account(usdc_mint, winner, 100),
+        );
+        let mut treasury_ata_account = TestAccount::new(
+            treasury_ata.to_bytes(),
+            token_program,
+            false,
+            true,
+            1_000_000,
+            &token_account(usdc_mint, Address::new_from_array([1u8; 32]), 200),
+        );
+        let mut vrf_payer_ata_account = TestAccount::new(
+            vrf_payer_ata.to_bytes(),
+            token_program,
+            false,
+            true,
+            1_000_000,
+            &token_account(usdc_mint, Address::new_from_array([11u8; 32]), 300),
+        );
+        let mut token_program_account =
+            TestAccount::new(token_program.to_bytes(), Address::new_from_array([0u8; 32]), false, false, 1_000_000, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim"));
+        ix.extend_from_slice(&83u64.to_le_bytes());
+
+        let accounts = [
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            vault_account.view(),
+            winner_ata_account.view(),
+            treasury_ata_account.view(),
+            vrf_payer_ata_account.view(),
+            token_program_account.view(),
+        ];
+
+        let err = process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVault.into());
+        assert_eq!(
+            RoundLifecycleView::read_from_account_data(round_account.data()).unwrap().status,
+            ROUND_STATUS_SETTLED
+        );
+        assert_eq!(
+            TokenAccountWithAmountView::read_from_account_data(vault_account.data()).unwrap().amount,
+            1_000_000
+        );
+    }
 }
@@ -9,25 +9,28 @@ use pinocchio::cpi::{Seed, Signer};
 use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
-    legacy_layouts::{
-        ConfigView, DegenClaimView, DegenConfigView, CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN,
-        DEGEN_CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+    anchor_compat::instruction_discriminator,
+    errors::JackpotCompatError,
+    handlers::degen_common::{map_layout_err, split_fee},
+    degen_pool_compat::{degen_token_mint_by_index, is_valid_token_index},
+    events::emit_degen_claimed,
+    instruction_layouts::{BeginDegenExecutionArgsCompat, RoundId},
+    legacy_layouts::{AccountLayout, ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView},
+    pdas::{
+        SEED_ROUND, create_degen_claim_pda, derive_config_pda, derive_degen_config_pda, derive_round_pda,
+        require_fee_split_config,
     },
     processors::degen_execution::{DegenExecutionEffect, DegenExecutionProcessor},
+    runtime::token_programs::{
+        owning_token_program, read_token_core, require_not_frozen, require_token_program_any,
+    },
 };
 
 #[cfg(test)]
-use crate::{errors::JackpotCompatError, legacy_layouts::TokenAccountWithAmountView};
+use crate::runtime::token_simulation::simulate_token_transfers;
 #[cfg(test)]
 use solana_address::address;
-#[cfg(not(test))]
-use crate::legacy_layouts::RoundLifecycleView;
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
-const SEED_DEGEN_CLAIM: &[u8] = b"degen_claim";
-const SEED_DEGEN_CFG: &[u8] = b"degen_cfg";
 #[cfg(test)]
 const SYSTEM_PROGRAM_ID: Address = address!("11111111111111111111111111111111");
 
@@ -55,6 +58,9 @@ pub fn process_instruction(
     if discriminator == instruction_discriminator("finalize_degen_success") {
         return process_finalize_degen_success(program_id, accounts, instruction_data);
     }
+    if discriminator == instruction_discriminator("recover_stuck_execution") {
+        return process_recover_stuck_execution(program_id, accounts, instruction_data);
+    }
 
     Err(ProgramError::InvalidInstructionData)
 }
@@ -64,6 +70,7 @@ fn process_begin_degen_execution(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    let (accounts, fee_split_accounts) = split_off_fee_split_accounts(accounts, 13);
     let (
         executor,
         config,
@@ -119,12 +126,16 @@ fn process_begin_degen_execution(
         _ => return Err(ProgramError::NotEnoughAccountKeys),
     };
 
+    let begin_args = parse_begin_degen_execution_args(instruction_data)?;
+    if !is_valid_token_index(begin_args.token_index) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let round_id = begin_args.round_id;
+
     require_signer(executor)?;
     require_writable(executor)?;
-    let _config = require_config_pda(config, program_id)?;
-    require_existing_degen_config_pda(degen_config, program_id)?;
+    let config_view = require_paired_configs(config, degen_config, program_id)?;
     require_writable(round)?;
-    let round_id = parse_round_id_from_begin_ix(instruction_data)?;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_writable(degen_claim)?;
     require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
@@ -134,10 +145,15 @@ fn process_begin_degen_execution(
     require_writable(receiver_token_ata)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(executor_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
-    require_token_account_owned_by_program(receiver_token_ata, token_program)?;
-    require_mint_owned_by_program(selected_token_mint, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
+    let fee_split = require_fee_split_accounts(fee_split_accounts, &config_view, token_program, program_id)?;
+    // `token_program` is the classic one the USDC transfers CPI into; the
+    // receiver is only read here, so its mint may live under Token-2022.
+    let receiver_token_program = owning_token_program(receiver_token_ata)?;
+    require_owned_by(selected_token_mint, receiver_token_program)?;
     if let Some(vrf_payer_authority) = vrf_payer_authority {
         require_writable(vrf_payer_authority)?;
     }
@@ -145,6 +161,15 @@ fn process_begin_degen_execution(
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
     }
+    require_distinct_token_accounts(&[
+        Some(vault),
+        Some(executor_usdc_ata),
+        Some(treasury_usdc_ata),
+        Some(receiver_token_ata),
+        vrf_payer_usdc_ata,
+        fee_split.map(|(fee_recipient_usdc_ata, _)| fee_recipient_usdc_ata),
+    ])?;
+    require_transfer_destinations_not_frozen(&[Some(executor_usdc_ata), Some(treasury_usdc_ata), vrf_payer_usdc_ata])?;
 
     let (begin_amounts, round_shadow, degen_claim_shadow) = {
         let config_data = config.try_borrow()?;
@@ -164,6 +189,7 @@ fn process_begin_degen_execution(
 
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: Some(executor.address().to_bytes()),
+            admin_pubkey: None,
             winner_pubkey: None,
             round_pubkey: round.address().to_bytes(),
             vault_pubkey: Some(vault.address().to_bytes()),
@@ -195,6 +221,7 @@ fn process_begin_degen_execution(
         executor_usdc_ata,
         treasury_usdc_ata,
         vrf_payer_usdc_ata,
+        fee_split,
         round,
         begin_amounts.vrf_reimburse,
         begin_amounts.payout,
@@ -217,6 +244,7 @@ fn process_claim_degen_fallback(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    let (accounts, fee_split_accounts) = split_off_fee_split_accounts(accounts, 10);
     let (winner, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, vrf_payer_authority, vrf_payer_usdc_ata, token_program) =
         match accounts {
             [winner, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, token_program] => {
@@ -237,20 +265,23 @@ fn process_claim_degen_fallback(
     require_writable(vault)?;
     require_writable(winner_usdc_ata)?;
     require_writable(treasury_usdc_ata)?;
-    let _config = require_config_pda(config, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_u8_ix(instruction_data, "claim_degen_fallback")
-        .map_err(|_| ProgramError::InvalidInstructionData)?
-        .0;
+    let config_view = require_config_pda(config, program_id)?;
+    let round_id =
+        crate::instruction_layouts::parse_round_id_u8_ix(instruction_data, "claim_degen_fallback")?.0;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(winner_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
+    let fee_split = require_fee_split_accounts(fee_split_accounts, &config_view, token_program, program_id)?;
     if let Some(vrf_payer_usdc_ata) = vrf_payer_usdc_ata {
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
     }
+    require_transfer_destinations_not_frozen(&[Some(winner_usdc_ata), Some(treasury_usdc_ata), vrf_payer_usdc_ata])?;
 
     let (amounts, round_shadow, degen_claim_shadow) = {
         let config_data = config.try_borrow()?;
@@ -267,6 +298,7 @@ fn process_claim_degen_fallback(
         };
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: None,
+            admin_pubkey: None,
             winner_pubkey: Some(winner.address().to_bytes()),
             round_pubkey: round.address().to_bytes(),
             vault_pubkey: Some(vault.address().to_bytes()),
@@ -298,6 +330,7 @@ fn process_claim_degen_fallback(
         winner_usdc_ata,
         treasury_usdc_ata,
         vrf_payer_usdc_ata,
+        fee_split,
         round,
         amounts.vrf_reimburse,
         amounts.payout,
@@ -323,6 +356,7 @@ fn process_auto_claim_degen_fallback(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    let (accounts, fee_split_accounts) = split_off_fee_split_accounts(accounts, 10);
     let (payer, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, vrf_payer_authority, vrf_payer_usdc_ata, token_program) =
         match accounts {
             [payer, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, token_program] => {
@@ -342,20 +376,23 @@ fn process_auto_claim_degen_fallback(
     require_writable(vault)?;
     require_writable(winner_usdc_ata)?;
     require_writable(treasury_usdc_ata)?;
-    let _config = require_config_pda(config, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_u8_ix(instruction_data, "auto_claim_degen_fallback")
-        .map_err(|_| ProgramError::InvalidInstructionData)?
-        .0;
+    let config_view = require_config_pda(config, program_id)?;
+    let round_id =
+        crate::instruction_layouts::parse_round_id_u8_ix(instruction_data, "auto_claim_degen_fallback")?.0;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(winner_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
+    let fee_split = require_fee_split_accounts(fee_split_accounts, &config_view, token_program, program_id)?;
     if let Some(vrf_payer_usdc_ata) = vrf_payer_usdc_ata {
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
     }
+    require_transfer_destinations_not_frozen(&[Some(winner_usdc_ata), Some(treasury_usdc_ata), vrf_payer_usdc_ata])?;
 
     // Read winner from round data (no signature required — anyone can trigger)
     let winner_pubkey = {
@@ -379,6 +416,7 @@ fn process_auto_claim_degen_fallback(
         };
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: None,
+            admin_pubkey: None,
             winner_pubkey: Some(winner_pubkey),
             round_pubkey: round.address().to_bytes(),
             vault_pubkey: Some(vault.address().to_bytes()),
@@ -410,6 +448,7 @@ fn process_auto_claim_degen_fallback(
         winner_usdc_ata,
         treasury_usdc_ata,
         vrf_payer_usdc_ata,
+        fee_split,
         round,
         amounts.vrf_reimburse,
         amounts.payout,
@@ -434,6 +473,7 @@ fn process_claim_degen(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    let (accounts, fee_split_accounts) = split_off_fee_split_accounts(accounts, 10);
     let (winner, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, vrf_payer_authority, vrf_payer_usdc_ata, token_program) =
         match accounts {
             [winner, config, round, degen_claim, vault, winner_usdc_ata, treasury_usdc_ata, token_program] => {
@@ -454,20 +494,23 @@ fn process_claim_degen(
     require_writable(vault)?;
     require_writable(winner_usdc_ata)?;
     require_writable(treasury_usdc_ata)?;
-    let _config = require_config_pda(config, program_id)?;
-    let round_id = crate::instruction_layouts::ClaimDegenArgsCompat::parse(instruction_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?
-        .round_id;
+    let config_view = require_config_pda(config, program_id)?;
+    let round_id =
+        crate::instruction_layouts::ClaimDegenArgsCompat::parse(instruction_data)?.round_id;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
+    require_vault_matches_round(vault, round)?;
     require_token_account_owned_by_program(winner_usdc_ata, token_program)?;
     require_token_account_owned_by_program(treasury_usdc_ata, token_program)?;
+    require_treasury_matches_config(treasury_usdc_ata, &config_view)?;
+    let fee_split = require_fee_split_accounts(fee_split_accounts, &config_view, token_program, program_id)?;
     if let Some(vrf_payer_usdc_ata) = vrf_payer_usdc_ata {
         require_writable(vrf_payer_usdc_ata)?;
         require_token_account_owned_by_program(vrf_payer_usdc_ata, token_program)?;
     }
+    require_transfer_destinations_not_frozen(&[Some(winner_usdc_ata), Some(treasury_usdc_ata), vrf_payer_usdc_ata])?;
 
     let (amounts, round_shadow, degen_claim_shadow) = {
         let config_data = config.try_borrow()?;
@@ -484,6 +527,7 @@ fn process_claim_degen(
         };
         let mut processor = DegenExecutionProcessor {
             executor_pubkey: None,
+            admin_pubkey: None,
             winner_pubkey: Some(winner.address().to_bytes()),
             round_pubkey: round.address().to_bytes(),
             vault_pubkey: Some(vault.address().to_bytes()),
@@ -516,6 +560,7 @@ fn process_claim_degen(
         winner_usdc_ata,
         treasury_usdc_ata,
         vrf_payer_usdc_ata,
+        fee_split,
         round,
         amounts.vrf_reimburse,
         amounts.payout,
@@ -530,6 +575,11 @@ fn process_claim_degen(
         let mut degen_claim_data = degen_claim.try_borrow_mut()?;
         degen_claim_data.copy_from_slice(&degen_claim_shadow);
     }
+
+    let claim = DegenClaimView::read_from_account_data(&degen_claim_shadow)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let token_mint = degen_token_mint_by_index(claim.token_index).ok_or(ProgramError::InvalidAccountData)?;
+    emit_degen_claimed(claim.round_id, &token_mint, amounts.payout);
     Ok(())
 }
 
@@ -548,15 +598,15 @@ fn process_finalize_degen_success(
     require_writable(executor)?;
     require_existing_degen_config_pda(degen_config, program_id)?;
     require_writable(round)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "finalize_degen_success")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "finalize_degen_success")?;
     require_round_pda_for_round_id(round, program_id, round_id)?;
     require_writable(degen_claim)?;
     require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
     require_writable(executor_usdc_ata)?;
     require_writable(receiver_token_ata)?;
-    require_token_program(token_program)?;
-    require_token_account_owned_by_program(executor_usdc_ata, token_program)?;
+    // No CPI here: the receiver may hold a Token-2022 mint, USDC stays classic.
+    require_token_program_any(token_program)?;
+    require_owned_by(executor_usdc_ata, &pinocchio_token::ID)?;
     require_token_account_owned_by_program(receiver_token_ata, token_program)?;
 
     let degen_config_data = degen_config.try_borrow()?;
@@ -566,6 +616,7 @@ fn process_finalize_degen_success(
     let receiver_token_ata_data = receiver_token_ata.try_borrow()?;
     let mut processor = DegenExecutionProcessor {
         executor_pubkey: Some(executor.address().to_bytes()),
+        admin_pubkey: None,
         winner_pubkey: None,
         round_pubkey: round.address().to_bytes(),
         vault_pubkey: None,
@@ -586,7 +637,68 @@ fn process_finalize_degen_success(
         vrf_payer_usdc_ata_data: None,
     };
     match processor.process(instruction_data)? {
-        DegenExecutionEffect::Finalize => Ok(()),
+        DegenExecutionEffect::Finalize => {}
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    let claim = DegenClaimView::read_from_account_data(&degen_claim_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    emit_degen_claimed(claim.round_id, &claim.token_mint, claim.payout_raw);
+    Ok(())
+}
+
+/// recover_stuck_execution — admin reverts a stale `EXECUTING` claim to `VRF_READY`.
+/// No tokens move here; the vault must already hold the claim's payout and
+/// the receiver token account must show the swap never landed.
+/// Accounts: [admin(signer), config, round, degen_claim, vault, receiver_token_ata]
+fn process_recover_stuck_execution(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, round, degen_claim, vault, receiver_token_ata, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(admin)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(round)?;
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "recover_stuck_execution")?;
+    require_round_pda_for_round_id(round, program_id, round_id)?;
+    require_writable(degen_claim)?;
+    require_existing_degen_claim_pda_for_round_id(degen_claim, program_id, round_id)?;
+    require_owned_by(vault, &pinocchio_token::ID)?;
+    require_owned_by(receiver_token_ata, &pinocchio_token::ID)?;
+
+    let config_data = config.try_borrow()?;
+    let mut round_data = round.try_borrow_mut()?;
+    let mut degen_claim_data = degen_claim.try_borrow_mut()?;
+    let vault_data = vault.try_borrow()?;
+    let receiver_token_ata_data = receiver_token_ata.try_borrow()?;
+    let mut processor = DegenExecutionProcessor {
+        executor_pubkey: None,
+        admin_pubkey: Some(admin.address().to_bytes()),
+        winner_pubkey: None,
+        round_pubkey: round.address().to_bytes(),
+        vault_pubkey: Some(vault.address().to_bytes()),
+        treasury_usdc_ata_pubkey: None,
+        selected_token_mint_pubkey: None,
+        receiver_token_ata_pubkey: Some(receiver_token_ata.address().to_bytes()),
+        vrf_payer_authority_pubkey: None,
+        now_ts: clock_unix_timestamp(),
+        config_account_data: Some(&config_data),
+        degen_config_account_data: None,
+        round_account_data: &mut round_data[..],
+        degen_claim_account_data: &mut degen_claim_data[..],
+        vault_account_data: Some(&vault_data),
+        executor_usdc_ata_data: None,
+        winner_usdc_ata_data: None,
+        treasury_usdc_ata_data: None,
+        receiver_token_ata_data: Some(&receiver_token_ata_data),
+        vrf_payer_usdc_ata_data: None,
+    };
+    match processor.process(instruction_data)? {
+        DegenExecutionEffect::RecoverStuckExecution => Ok(()),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -597,13 +709,14 @@ fn transfer_begin_amounts(
     executor_usdc_ata: &AccountView,
     treasury_usdc_ata: &AccountView,
     vrf_payer_usdc_ata: Option<&AccountView>,
+    fee_split: Option<(&AccountView, u16)>,
     round: &AccountView,
     vrf_reimburse: u64,
     payout: u64,
     fee: u64,
 ) -> ProgramResult {
     let round_data = round.try_borrow()?;
-    let round_view = RoundLifecycleView::read_from_account_data(&round_data)
+    let round_view = RoundLifecycleView::read_from_account_data_exact(&round_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round_bump = round_view.bump;
     let round_id = round_view.round_id;
@@ -624,8 +737,13 @@ fn transfer_begin_amounts(
     }
     TokenTransfer { from: vault, to: executor_usdc_ata, authority: round, amount: payout }
         .invoke_signed(&[Signer::from(&signer_seeds)])?;
-    if fee > 0 {
-        TokenTransfer { from: vault, to: treasury_usdc_ata, authority: round, amount: fee }
+    let (treasury_fee, fee_recipient) = route_fee(fee, fee_split)?;
+    if treasury_fee > 0 {
+        TokenTransfer { from: vault, to: treasury_usdc_ata, authority: round, amount: treasury_fee }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+    }
+    if let Some((fee_recipient_usdc_ata, recipient_fee)) = fee_recipient.filter(|(_, amount)| *amount > 0) {
+        TokenTransfer { from: vault, to: fee_recipient_usdc_ata, authority: round, amount: recipient_fee }
             .invoke_signed(&[Signer::from(&signer_seeds)])?;
     }
     Ok(())
@@ -637,67 +755,31 @@ fn transfer_begin_amounts(
     executor_usdc_ata: &AccountView,
     treasury_usdc_ata: &AccountView,
     vrf_payer_usdc_ata: Option<&AccountView>,
+    fee_split: Option<(&AccountView, u16)>,
     _round: &AccountView,
     vrf_reimburse: u64,
     payout: u64,
     fee: u64,
 ) -> ProgramResult {
-    let vault_amount = TokenAccountWithAmountView::read_from_account_data(&vault.try_borrow()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?
-        .amount;
-    let executor_amount = TokenAccountWithAmountView::read_from_account_data(&executor_usdc_ata.try_borrow()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?
-        .amount;
-    let treasury_amount = TokenAccountWithAmountView::read_from_account_data(&treasury_usdc_ata.try_borrow()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?
-        .amount;
-    let vrf_payer_amount = match vrf_payer_usdc_ata {
-        Some(account) => TokenAccountWithAmountView::read_from_account_data(&account.try_borrow()?)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .amount,
-        None => 0,
-    };
-
     let total = vrf_reimburse
         .checked_add(payout)
         .and_then(|v| v.checked_add(fee))
         .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    let next_vault = vault_amount
-        .checked_sub(total)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    let next_executor = executor_amount
-        .checked_add(payout)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    let next_treasury = treasury_amount
-        .checked_add(fee)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-
-    {
-        let mut data = vault.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_vault)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-    }
-    {
-        let mut data = executor_usdc_ata.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_executor)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-    }
-    {
-        let mut data = treasury_usdc_ata.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_treasury)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+    let (treasury_fee, fee_recipient) = route_fee(fee, fee_split)?;
+    let mut transfers = vec![
+        (vault, -i128::from(total)),
+        (executor_usdc_ata, i128::from(payout)),
+        (treasury_usdc_ata, i128::from(treasury_fee)),
+    ];
+    if let Some((fee_recipient_usdc_ata, recipient_fee)) = fee_recipient {
+        transfers.push((fee_recipient_usdc_ata, i128::from(recipient_fee)));
     }
     if vrf_reimburse > 0 {
-        let vrf_payer_usdc_ata = vrf_payer_usdc_ata.ok_or::<ProgramError>(JackpotCompatError::InvalidVrfPayerAta.into())?;
-        let next_vrf_payer = vrf_payer_amount
-            .checked_add(vrf_reimburse)
-            .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-        let mut data = vrf_payer_usdc_ata.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_vrf_payer)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let vrf_payer_usdc_ata =
+            vrf_payer_usdc_ata.ok_or::<ProgramError>(JackpotCompatError::InvalidVrfPayerAta.into())?;
+        transfers.push((vrf_payer_usdc_ata, i128::from(vrf_reimburse)));
     }
-
-    Ok(())
+    simulate_token_transfers(&transfers)
 }
 
 #[cfg(not(test))]
@@ -706,13 +788,14 @@ fn transfer_fallback_amounts(
     winner_usdc_ata: &AccountView,
     treasury_usdc_ata: &AccountView,
     vrf_payer_usdc_ata: Option<&AccountView>,
+    fee_split: Option<(&AccountView, u16)>,
     round: &AccountView,
     vrf_reimburse: u64,
     payout: u64,
     fee: u64,
 ) -> ProgramResult {
     let round_data = round.try_borrow()?;
-    let round_view = RoundLifecycleView::read_from_account_data(&round_data)
+    let round_view = RoundLifecycleView::read_from_account_data_exact(&round_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let round_bump = round_view.bump;
     let round_id = round_view.round_id;
@@ -732,8 +815,13 @@ fn transfer_fallback_amounts(
     }
     TokenTransfer { from: vault, to: winner_usdc_ata, authority: round, amount: payout }
         .invoke_signed(&[Signer::from(&signer_seeds)])?;
-    if fee > 0 {
-        TokenTransfer { from: vault, to: treasury_usdc_ata, authority: round, amount: fee }
+    let (treasury_fee, fee_recipient) = route_fee(fee, fee_split)?;
+    if treasury_fee > 0 {
+        TokenTransfer { from: vault, to: treasury_usdc_ata, authority: round, amount: treasury_fee }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+    }
+    if let Some((fee_recipient_usdc_ata, recipient_fee)) = fee_recipient.filter(|(_, amount)| *amount > 0) {
+        TokenTransfer { from: vault, to: fee_recipient_usdc_ata, authority: round, amount: recipient_fee }
             .invoke_signed(&[Signer::from(&signer_seeds)])?;
     }
 
@@ -746,101 +834,88 @@ fn transfer_fallback_amounts(
     winner_usdc_ata: &AccountView,
     treasury_usdc_ata: &AccountView,
     vrf_payer_usdc_ata: Option<&AccountView>,
+    fee_split: Option<(&AccountView, u16)>,
     _round: &AccountView,
     vrf_reimburse: u64,
     payout: u64,
     fee: u64,
 ) -> ProgramResult {
-    let vault_amount = {
-        let data = vault.try_borrow()?;
-        TokenAccountWithAmountView::read_from_account_data(&data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .amount
-    };
-    let winner_amount = {
-        let data = winner_usdc_ata.try_borrow()?;
-        TokenAccountWithAmountView::read_from_account_data(&data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .amount
-    };
-    let treasury_amount = {
-        let data = treasury_usdc_ata.try_borrow()?;
-        TokenAccountWithAmountView::read_from_account_data(&data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-            .amount
-    };
-
     let total = vrf_reimburse
         .checked_add(payout)
         .and_then(|v| v.checked_add(fee))
         .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    let next_vault = vault_amount
-        .checked_sub(total)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-    let next_treasury = treasury_amount
-        .checked_add(fee)
-        .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
+    let (treasury_fee, fee_recipient) = route_fee(fee, fee_split)?;
+    let mut transfers = vec![
+        (vault, -i128::from(total)),
+        (winner_usdc_ata, i128::from(payout)),
+        (treasury_usdc_ata, i128::from(treasury_fee)),
+    ];
+    if let Some((fee_recipient_usdc_ata, recipient_fee)) = fee_recipient {
+        transfers.push((fee_recipient_usdc_ata, i128::from(recipient_fee)));
+    }
+    if vrf_reimburse > 0 {
+        let vrf_payer_usdc_ata =
+            vrf_payer_usdc_ata.ok_or::<ProgramError>(JackpotCompatError::InvalidVrfPayerAta.into())?;
+        transfers.push((vrf_payer_usdc_ata, i128::from(vrf_reimburse)));
+    }
+    simulate_token_transfers(&transfers)
+}
 
-    // Detect if winner_usdc_ata and vrf_payer_usdc_ata are the same account.
-    // In the real CPI path, sequential invoke_signed calls are naturally cumulative
-    // (SPL token reads current balance each time). In this test simulation we
-    // read all balances upfront, so writing to the same account twice with stale
-    // values would lose the first write.  We merge the amounts when overlapping.
-    let winner_is_vrf_payer = vrf_reimburse > 0
-        && vrf_payer_usdc_ata
-            .map(|a| a.address() == winner_usdc_ata.address())
-            .unwrap_or(false);
+/// Fee leg owed to the `set_fee_split` recipient ATA.
+type FeeRecipientLeg<'a> = (&'a AccountView, u64);
 
-    {
-        let mut data = vault.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_vault)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-    }
-
-    if winner_is_vrf_payer {
-        // Combined write: payout + vrf_reimburse to the same account
-        let combined = winner_amount
-            .checked_add(payout)
-            .and_then(|v| v.checked_add(vrf_reimburse))
-            .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-        let mut data = winner_usdc_ata.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, combined)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-    } else {
-        // Separate writes for winner and vrf_payer
-        let next_winner = winner_amount
-            .checked_add(payout)
-            .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-        {
-            let mut data = winner_usdc_ata.try_borrow_mut()?;
-            TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_winner)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-        }
-        if vrf_reimburse > 0 {
-            let vrf_payer_usdc_ata =
-                vrf_payer_usdc_ata.ok_or::<ProgramError>(JackpotCompatError::InvalidVrfPayerAta.into())?;
-            let vrf_payer_amount = {
-                let data = vrf_payer_usdc_ata.try_borrow()?;
-                TokenAccountWithAmountView::read_from_account_data(&data)
-                    .map_err(|_| ProgramError::InvalidAccountData)?
-                    .amount
-            };
-            let next_vrf_payer = vrf_payer_amount
-                .checked_add(vrf_reimburse)
-                .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
-            let mut data = vrf_payer_usdc_ata.try_borrow_mut()?;
-            TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_vrf_payer)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-        }
-    }
+/// Splits `fee` between the treasury and the `set_fee_split` recipient, when
+/// the claim carries one.
+fn route_fee(
+    fee: u64,
+    fee_split: Option<(&AccountView, u16)>,
+) -> Result<(u64, Option<FeeRecipientLeg<'_>>), ProgramError> {
+    let Some((fee_recipient_usdc_ata, fee_split_bps)) = fee_split else {
+        return Ok((fee, None));
+    };
+    let split = split_fee(fee, fee_split_bps)?;
+    Ok((split.treasury, Some((fee_recipient_usdc_ata, split.second_recipient))))
+}
 
-    {
-        let mut data = treasury_usdc_ata.try_borrow_mut()?;
-        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next_treasury)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+/// Takes the `[fee_split_config, fee_recipient_usdc_ata]` pair off the end of
+/// a degen claim's accounts. The pair is only recognised after the full list
+/// (VRF payer slots included, program_id standing in for `None`), so the
+/// shorter layouts keep their meaning.
+fn split_off_fee_split_accounts(
+    accounts: &[AccountView],
+    full_len: usize,
+) -> (&[AccountView], Option<(&AccountView, &AccountView)>) {
+    if accounts.len() != full_len + 2 {
+        return (accounts, None);
     }
+    let (accounts, fee_split_accounts) = accounts.split_at(full_len);
+    (accounts, Some((&fee_split_accounts[0], &fee_split_accounts[1])))
+}
 
-    Ok(())
+/// Once `set_fee_split` has enabled the split, the claim must pass its PDA
+/// and the recipient ATA it names; leaving them out would send the whole fee
+/// to the treasury.
+fn require_fee_split_accounts<'a>(
+    fee_split_accounts: Option<(&'a AccountView, &'a AccountView)>,
+    config: &ConfigView,
+    token_program: &AccountView,
+    program_id: &Address,
+) -> Result<Option<(&'a AccountView, u16)>, ProgramError> {
+    if !config.fee_split_enabled() {
+        return Ok(None);
+    }
+    let (fee_split_config, fee_recipient_usdc_ata) = fee_split_accounts.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let fee_split = require_fee_split_config(fee_split_config, program_id)?;
+    if fee_recipient_usdc_ata.address().to_bytes() != fee_split.recipient_usdc_ata {
+        return Err(JackpotCompatError::InvalidTreasury.into());
+    }
+    require_writable(fee_recipient_usdc_ata)?;
+    require_token_account_owned_by_program(fee_recipient_usdc_ata, token_program)?;
+    if read_token_core(fee_recipient_usdc_ata)?.mint != config.usdc_mint {
+        return Err(JackpotCompatError::InvalidTreasury.into());
+    }
+    require_not_frozen(fee_recipient_usdc_ata)?;
+    Ok(Some((fee_recipient_usdc_ata, fee_split.fee_split_bps)))
 }
 
 fn require_signer(account: &AccountView) -> ProgramResult {
@@ -857,15 +932,11 @@ fn require_owned_by(account: &AccountView, owner: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -874,68 +945,96 @@ fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<Con
 
 fn require_existing_degen_config_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], program_id);
+    let (expected_address, expected_bump) = derive_degen_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != DEGEN_CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("DegenConfig")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let cfg = DegenConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let cfg = DegenConfigView::from_account_view(account)?;
     if cfg.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
     Ok(())
 }
 
-fn parse_round_id_from_begin_ix(instruction_data: &[u8]) -> Result<u64, ProgramError> {
-    crate::instruction_layouts::BeginDegenExecutionArgsCompat::parse(instruction_data)
-        .map(|args| args.round_id)
-        .map_err(|_| ProgramError::InvalidInstructionData)
+/// Validates `config` and `degen_config` together: both must be owned by and
+/// derived from the same `program_id`, so neither can be swapped in from
+/// another deployment of the program.
+fn require_paired_configs(
+    config: &AccountView,
+    degen_config: &AccountView,
+    program_id: &Address,
+) -> Result<ConfigView, ProgramError> {
+    let config_view = require_config_pda(config, program_id)?;
+    require_existing_degen_config_pda(degen_config, program_id)?;
+    Ok(config_view)
 }
 
-fn require_round_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: u64) -> ProgramResult {
+fn parse_begin_degen_execution_args(instruction_data: &[u8]) -> Result<BeginDegenExecutionArgsCompat, ProgramError> {
+    BeginDegenExecutionArgsCompat::parse(instruction_data).map_err(ProgramError::from)
+}
+
+fn require_round_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: RoundId) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let (expected_address, _) = Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
-fn require_existing_degen_claim_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: u64) -> ProgramResult {
+fn require_existing_degen_claim_pda_for_round_id(account: &AccountView, program_id: &Address, round_id: RoundId) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let data = account.try_borrow()?;
-    if data.len() != DEGEN_CLAIM_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("DegenClaim")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let claim = DegenClaimView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    let expected = Address::create_program_address(
-        &[SEED_DEGEN_CLAIM, &round_id.to_le_bytes(), &claim.winner, &[claim.bump]],
-        program_id,
-    )
-    .map_err(|_| ProgramError::InvalidSeeds)?;
+    let claim = DegenClaimView::from_account_view(account)?;
+    let expected = create_degen_claim_pda(program_id, round_id, &claim.winner, claim.bump)?;
     if account.address() != &expected {
         return Err(ProgramError::InvalidSeeds);
     }
     Ok(())
 }
 
+/// Rejects one token account passed in two writable slots. Balances are
+/// read before the transfers run, so an aliased account would be validated
+/// (e.g. the executor ATA's zero balance) as if it were two accounts.
+fn require_distinct_token_accounts(accounts: &[Option<&AccountView>]) -> ProgramResult {
+    for (index, account) in accounts.iter().enumerate() {
+        let Some(account) = account else { continue };
+        if accounts[index + 1..].iter().flatten().any(|other| other.address() == account.address()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    Ok(())
+}
+
 fn require_token_program(account: &AccountView) -> ProgramResult {
     if account.address() == &pinocchio_token::ID { Ok(()) } else { Err(ProgramError::IncorrectProgramId) }
 }
 
+/// Ties the vault to the ATA recorded on the round at `start_round`, so a
+/// different round-owned USDC account cannot be substituted.
+fn require_vault_matches_round(vault: &AccountView, round: &AccountView) -> ProgramResult {
+    let round_data = round.try_borrow()?;
+    let expected = RoundLifecycleView::require_vault_set(&round_data).map_err(map_layout_err)?;
+    if vault.address().to_bytes() != expected {
+        return Err(JackpotCompatError::InvalidVault.into());
+    }
+    Ok(())
+}
+
+/// Fees must land in the treasury ATA recorded on `config`; any other
+/// account of the right mint would otherwise be accepted as the fee sink.
+fn require_treasury_matches_config(treasury: &AccountView, config: &ConfigView) -> ProgramResult {
+    if treasury.address().to_bytes() != config.treasury_usdc_ata {
+        return Err(JackpotCompatError::InvalidTreasury.into());
+    }
+    Ok(())
+}
+
 fn require_token_account_owned_by_program(account: &AccountView, token_program: &AccountView) -> ProgramResult {
     require_owned_by(account, token_program.address())
 }
 
-fn require_mint_owned_by_program(account: &AccountView, token_program: &AccountView) -> ProgramResult {
-    require_owned_by(account, token_program.address())
+fn require_transfer_destinations_not_frozen(destinations: &[Option<&AccountView>]) -> ProgramResult {
+    destinations.iter().flatten().try_for_each(|account| require_not_frozen(account))
 }
 
 #[cfg(not(test))]
@@ -953,20 +1052,21 @@ fn clock_unix_timestamp() -> i64 {
 
 #[cfg(test)]
 mod tests {
-    use core::mem::size_of;
-
-    use pinocchio::account::{NOT_BORROWED, RuntimeAccount};
-
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
+        instruction_layouts::encode_begin_degen_execution,
         legacy_layouts::{
-            ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, TokenAccountWithAmountView,
-            CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+            ConfigView, DegenClaimView, DegenConfigView, FeeSplitConfigView, RoundLifecycleView,
+            TokenAccountWithAmountView, CONFIG_ACCOUNT_LEN, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN,
+            FEE_SPLIT_CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
             DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED,
             DEGEN_CLAIM_STATUS_EXECUTING, DEGEN_CLAIM_STATUS_VRF_READY, DEGEN_MODE_EXECUTING,
             DEGEN_MODE_VRF_READY, ROUND_STATUS_CLAIMED, ROUND_STATUS_SETTLED,
-            TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+            SPL_STATE_OFFSET, SPL_TOKEN_ACCOUNT_LEN, TOKEN_ACCOUNT_STATE_FROZEN,
+            TOKEN_ACCOUNT_STATE_INITIALIZED, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
         },
+        pdas::{SEED_CFG, SEED_DEGEN_CFG, SEED_DEGEN_CLAIM, SEED_FEE_SPLIT},
+        runtime::test_support::TestAccount,
     };
 
     use super::*;
@@ -976,57 +1076,6 @@ mod tests {
         108, 242, 117, 20, 87, 30, 9, 66, 30, 58, 230, 228, 54,
     ]);
 
-    struct TestAccount {
-        backing: Vec<u64>,
-    }
-
-    impl TestAccount {
-        fn new(
-            address: [u8; 32],
-            owner: Address,
-            is_signer: bool,
-            is_writable: bool,
-            lamports: u64,
-            data: &[u8],
-        ) -> Self {
-            let bytes = size_of::<RuntimeAccount>() + data.len();
-            let words = bytes.div_ceil(size_of::<u64>());
-            let mut backing = vec![0u64; words.max(1)];
-            let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
-
-            unsafe {
-                (*raw).borrow_state = NOT_BORROWED;
-                (*raw).is_signer = u8::from(is_signer);
-                (*raw).is_writable = u8::from(is_writable);
-                (*raw).executable = 0;
-                (*raw).resize_delta = 0;
-                (*raw).address = Address::from(address);
-                (*raw).owner = owner;
-                (*raw).lamports = lamports;
-                (*raw).data_len = data.len() as u64;
-
-                let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
-                core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
-            }
-
-            Self { backing }
-        }
-
-        fn view(&mut self) -> pinocchio::AccountView {
-            unsafe { pinocchio::AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
-        }
-
-        fn data(&self) -> &[u8] {
-            let raw = self.backing.as_ptr() as *const RuntimeAccount;
-            unsafe {
-                core::slice::from_raw_parts(
-                    (raw as *const u8).add(size_of::<RuntimeAccount>()),
-                    (*raw).data_len as usize,
-                )
-            }
-        }
-    }
-
     fn sample_config() -> (Address, Vec<u8>) {
         let (config_pda, config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut data = vec![0u8; CONFIG_ACCOUNT_LEN];
@@ -1050,6 +1099,25 @@ mod tests {
         (config_pda, data)
     }
 
+    /// `sample_config` with the fee split switched on by `set_fee_split`.
+    fn sample_config_with_fee_split() -> (Address, Vec<u8>) {
+        let (config_pda, mut data) = sample_config();
+        let mut config = ConfigView::read_from_account_data(&data).unwrap();
+        config.set_fee_split_enabled(true);
+        config.write_to_account_data(&mut data).unwrap();
+        (config_pda, data)
+    }
+
+    fn sample_fee_split_config(recipient_usdc_ata: [u8; 32], fee_split_bps: u16) -> (Address, Vec<u8>) {
+        let (fee_split_pda, bump) = Address::find_program_address(&[SEED_FEE_SPLIT], &PROGRAM_ID);
+        let mut data = vec![0u8; FEE_SPLIT_CONFIG_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&account_discriminator("FeeSplitConfig"));
+        FeeSplitConfigView { recipient_usdc_ata, fee_split_bps, bump }
+            .write_to_account_data(&mut data)
+            .unwrap();
+        (fee_split_pda, data)
+    }
+
     fn sample_degen_config() -> (Address, Vec<u8>) {
         let (degen_config_pda, degen_config_bump) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
         let mut data = vec![0u8; DEGEN_CONFIG_ACCOUNT_LEN];
@@ -1134,6 +1202,52 @@ mod tests {
         data
     }
 
+    #[test]
+    fn paired_configs_reject_degen_config_from_other_program() {
+        const OTHER_PROGRAM_ID: Address = Address::new_from_array([44u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (degen_config_pda, degen_config_data) = sample_degen_config();
+        let (foreign_degen_config_pda, foreign_bump) =
+            Address::find_program_address(&[SEED_DEGEN_CFG], &OTHER_PROGRAM_ID);
+        let mut foreign_degen_config_data = degen_config_data.clone();
+        DegenConfigView {
+            bump: foreign_bump,
+            ..DegenConfigView::read_from_account_data(&degen_config_data).unwrap()
+        }
+        .write_to_account_data(&mut foreign_degen_config_data)
+        .unwrap();
+
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut degen_config_account = TestAccount::new(degen_config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &degen_config_data);
+        let mut foreign_owned = TestAccount::new(
+            foreign_degen_config_pda.to_bytes(),
+            OTHER_PROGRAM_ID,
+            false,
+            false,
+            1_000_000,
+            &foreign_degen_config_data,
+        );
+        let mut foreign_address = TestAccount::new(
+            foreign_degen_config_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            false,
+            1_000_000,
+            &foreign_degen_config_data,
+        );
+
+        let config_view = config_account.view();
+        require_paired_configs(&config_view, &degen_config_account.view(), &PROGRAM_ID).unwrap();
+        assert_eq!(
+            require_paired_configs(&config_view, &foreign_owned.view(), &PROGRAM_ID).unwrap_err(),
+            ProgramError::IncorrectProgramId
+        );
+        assert_eq!(
+            require_paired_configs(&config_view, &foreign_address.view(), &PROGRAM_ID).unwrap_err(),
+            ProgramError::InvalidSeeds
+        );
+    }
+
     #[test]
     fn claim_degen_fallback_runtime_transfers_and_marks_claimed() {
         let winner = Address::new_from_array([9u8; 32]);
@@ -1183,6 +1297,201 @@ mod tests {
         assert_eq!(updated_claim.status, DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK);
     }
 
+    /// Runs `claim_degen_fallback` with the winner ATA in the full 165-byte
+    /// SPL layout and the given `AccountState`.
+    fn run_fallback_with_winner_ata_state(state: u8) -> (ProgramResult, Vec<u8>, Vec<u8>, Vec<u8>) {
+        let winner = Address::new_from_array([9u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+        let vault_data = token_account([2u8; 32], round_pda.to_bytes(), 1_000_000);
+        let mut winner_usdc_ata_data = vec![0u8; SPL_TOKEN_ACCOUNT_LEN];
+        winner_usdc_ata_data[..TOKEN_ACCOUNT_WITH_AMOUNT_LEN].copy_from_slice(&token_account([2u8; 32], winner.to_bytes(), 0));
+        winner_usdc_ata_data[SPL_STATE_OFFSET] = state;
+        let treasury_data = token_account([2u8; 32], [7u8; 32], 0);
+
+        let mut winner_account = TestAccount::new(winner.to_bytes(), SYSTEM_PROGRAM_ID, true, false, 1_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut vault_account = TestAccount::new(round_pda.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &vault_data);
+        let mut winner_usdc_ata_account = TestAccount::new([13u8; 32], pinocchio_token::ID, false, true, 1_000_000, &winner_usdc_ata_data);
+        let mut treasury_account = TestAccount::new([3u8; 32], pinocchio_token::ID, false, true, 1_000_000, &treasury_data);
+        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(3);
+
+        let accounts = [
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            vault_account.view(),
+            winner_usdc_ata_account.view(),
+            treasury_account.view(),
+            token_program.view(),
+        ];
+
+        let result = process_instruction(&PROGRAM_ID, &accounts, &ix);
+        (
+            result,
+            round_account.data().to_vec(),
+            vault_account.data().to_vec(),
+            winner_usdc_ata_account.data().to_vec(),
+        )
+    }
+
+    #[test]
+    fn claim_degen_fallback_rejects_frozen_winner_ata() {
+        let (result, round_data, vault_data, _) = run_fallback_with_winner_ata_state(TOKEN_ACCOUNT_STATE_FROZEN);
+
+        assert_eq!(result.unwrap_err(), JackpotCompatError::TokenAccountFrozen.into());
+        assert_eq!(RoundLifecycleView::read_from_account_data(&round_data).unwrap().status, ROUND_STATUS_SETTLED);
+        assert_eq!(TokenAccountWithAmountView::read_from_account_data(&vault_data).unwrap().amount, 1_000_000);
+    }
+
+    #[test]
+    fn claim_degen_fallback_accepts_initialized_full_spl_winner_ata() {
+        let (result, round_data, _, winner_data) = run_fallback_with_winner_ata_state(TOKEN_ACCOUNT_STATE_INITIALIZED);
+
+        result.unwrap();
+        assert_eq!(RoundLifecycleView::read_from_account_data(&round_data).unwrap().status, ROUND_STATUS_CLAIMED);
+        assert_eq!(TokenAccountWithAmountView::read_from_account_data(&winner_data).unwrap().amount, 997_500);
+    }
+
+    #[test]
+    fn claim_degen_fallback_rejects_treasury_not_in_config() {
+        let winner = Address::new_from_array([9u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+        let vault_data = token_account([2u8; 32], round_pda.to_bytes(), 1_000_000);
+        let winner_usdc_ata_data = token_account([2u8; 32], winner.to_bytes(), 0);
+        // Same mint as the real treasury, but owned by the caller.
+        let spoofed_treasury_data = token_account([2u8; 32], winner.to_bytes(), 0);
+
+        let mut winner_account = TestAccount::new(winner.to_bytes(), SYSTEM_PROGRAM_ID, true, false, 1_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut vault_account = TestAccount::new(round_pda.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &vault_data);
+        let mut winner_usdc_ata_account = TestAccount::new([13u8; 32], pinocchio_token::ID, false, true, 1_000_000, &winner_usdc_ata_data);
+        let mut treasury_account = TestAccount::new([14u8; 32], pinocchio_token::ID, false, true, 1_000_000, &spoofed_treasury_data);
+        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(3);
+
+        let accounts = [
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            vault_account.view(),
+            winner_usdc_ata_account.view(),
+            treasury_account.view(),
+            token_program.view(),
+        ];
+
+        let err = process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidTreasury.into());
+        let untouched_vault = TokenAccountWithAmountView::read_from_account_data(vault_account.data()).unwrap();
+        let untouched_treasury = TokenAccountWithAmountView::read_from_account_data(treasury_account.data()).unwrap();
+        assert_eq!(untouched_vault.amount, 1_000_000);
+        assert_eq!(untouched_treasury.amount, 0);
+    }
+
+    /// Runs `claim_degen_fallback` against a config with the fee split enabled,
+    /// in the 10-account layout (no VRF payer) plus the fee split pair when
+    /// `pass_fee_split` is set. Returns the vault, winner, treasury and
+    /// recipient balances afterwards.
+    fn run_fallback_with_fee_split(
+        fee_split_bps: u16,
+        stored_recipient: [u8; 32],
+        pass_fee_split: bool,
+    ) -> (ProgramResult, [u64; 4]) {
+        let winner = Address::new_from_array([9u8; 32]);
+        let (config_pda, config_data) = sample_config_with_fee_split();
+        let (fee_split_pda, fee_split_data) = sample_fee_split_config(stored_recipient, fee_split_bps);
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+
+        let mut winner_account = TestAccount::new(winner.to_bytes(), SYSTEM_PROGRAM_ID, true, false, 1_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut vault_account = TestAccount::new(round_pda.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], round_pda.to_bytes(), 1_000_000));
+        let mut winner_usdc_ata_account = TestAccount::new([13u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], winner.to_bytes(), 0));
+        let mut treasury_account = TestAccount::new([3u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [7u8; 32], 0));
+        let mut no_vrf_authority = TestAccount::new(PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 1_000_000, &[]);
+        let mut no_vrf_ata = TestAccount::new(PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 1_000_000, &[]);
+        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+        let mut fee_split_account = TestAccount::new(fee_split_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &fee_split_data);
+        let mut recipient_account = TestAccount::new([17u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [18u8; 32], 0));
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(3);
+
+        let mut accounts = vec![
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            vault_account.view(),
+            winner_usdc_ata_account.view(),
+            treasury_account.view(),
+            no_vrf_authority.view(),
+            no_vrf_ata.view(),
+            token_program.view(),
+        ];
+        if pass_fee_split {
+            accounts.push(fee_split_account.view());
+            accounts.push(recipient_account.view());
+        }
+
+        let result = process_instruction(&PROGRAM_ID, &accounts, &ix);
+        let amount = |account: &TestAccount| TokenAccountWithAmountView::read_from_account_data(account.data()).unwrap().amount;
+        (
+            result,
+            [
+                amount(&vault_account),
+                amount(&winner_usdc_ata_account),
+                amount(&treasury_account),
+                amount(&recipient_account),
+            ],
+        )
+    }
+
+    #[test]
+    fn claim_degen_fallback_splits_fee_with_second_recipient() {
+        // fee = 2_500; 2_500 * 3_333 / 10_000 = 833 (rounded down), treasury keeps 1_667.
+        let (result, balances) = run_fallback_with_fee_split(3_333, [17u8; 32], true);
+        result.unwrap();
+        assert_eq!(balances, [0, 997_500, 1_667, 833]);
+
+        let (result, balances) = run_fallback_with_fee_split(10_000, [17u8; 32], true);
+        result.unwrap();
+        assert_eq!(balances, [0, 997_500, 0, 2_500]);
+    }
+
+    #[test]
+    fn claim_degen_fallback_requires_fee_split_accounts_once_enabled() {
+        let (result, balances) = run_fallback_with_fee_split(3_333, [17u8; 32], false);
+        assert_eq!(result, Err(ProgramError::NotEnoughAccountKeys));
+        assert_eq!(balances, [1_000_000, 0, 0, 0]);
+
+        let (result, balances) = run_fallback_with_fee_split(3_333, [19u8; 32], true);
+        assert_eq!(result, Err(JackpotCompatError::InvalidTreasury.into()));
+        assert_eq!(balances, [1_000_000, 0, 0, 0]);
+    }
+
     /// Regression test: when the winner is also the VRF payer, both payout and
     /// vrf_reimburse must land in the same ATA without the second write
     /// clobbering the first.
@@ -1301,6 +1610,51 @@ mod tests {
         assert_eq!(updated_claim.status, DEGEN_CLAIM_STATUS_CLAIMED_FALLBACK);
     }
 
+    #[test]
+    fn auto_claim_degen_fallback_rejects_vault_not_recorded_on_round() {
+        let winner = Address::new_from_array([9u8; 32]);
+        let payer = Address::new_from_array([42u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+        // Round-owned USDC account, but not the one stored on the round.
+        let vault_data = token_account([2u8; 32], round_pda.to_bytes(), 1_000_000);
+        let winner_usdc_ata_data = token_account([2u8; 32], winner.to_bytes(), 0);
+        let treasury_data = token_account([2u8; 32], [7u8; 32], 0);
+
+        let mut payer_account = TestAccount::new(payer.to_bytes(), SYSTEM_PROGRAM_ID, true, false, 1_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut vault_account = TestAccount::new([66u8; 32], pinocchio_token::ID, false, true, 1_000_000, &vault_data);
+        let mut winner_usdc_ata_account = TestAccount::new([13u8; 32], pinocchio_token::ID, false, true, 1_000_000, &winner_usdc_ata_data);
+        let mut treasury_account = TestAccount::new([3u8; 32], pinocchio_token::ID, false, true, 1_000_000, &treasury_data);
+        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("auto_claim_degen_fallback"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        ix.push(3);
+
+        let accounts = [
+            payer_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            vault_account.view(),
+            winner_usdc_ata_account.view(),
+            treasury_account.view(),
+            token_program.view(),
+        ];
+
+        let err = process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::InvalidVault.into());
+        let untouched_vault = TokenAccountWithAmountView::read_from_account_data(vault_account.data()).unwrap();
+        assert_eq!(untouched_vault.amount, 1_000_000);
+        let untouched_round = RoundLifecycleView::read_from_account_data(round_account.data()).unwrap();
+        assert_ne!(untouched_round.status, ROUND_STATUS_CLAIMED);
+    }
+
     #[test]
     fn begin_degen_execution_runtime_transfers_to_executor_and_marks_executing() {
         let executor = Address::new_from_array([5u8; 32]);
@@ -1309,7 +1663,7 @@ mod tests {
         let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
         let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
         let token_index = 42u32;
-        let token_mint = [11u8; 32];
+        let token_mint = degen_token_mint_by_index(token_index).unwrap();
         let vault_data = token_account([2u8; 32], round_pda.to_bytes(), 1_000_000);
         let executor_usdc_ata_data = token_account([2u8; 32], executor.to_bytes(), 0);
         let treasury_data = token_account([2u8; 32], [7u8; 32], 0);
@@ -1327,13 +1681,7 @@ mod tests {
         let mut receiver_account = TestAccount::new([12u8; 32], pinocchio_token::ID, false, true, 1_000_000, &receiver_data);
         let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
 
-        let mut ix = Vec::new();
-        ix.extend_from_slice(&instruction_discriminator("begin_degen_execution"));
-        ix.extend_from_slice(&81u64.to_le_bytes());
-        ix.push(0);
-        ix.extend_from_slice(&token_index.to_le_bytes());
-        ix.extend_from_slice(&777u64.to_le_bytes());
-        ix.extend_from_slice(&[33u8; 32]);
+        let ix = encode_begin_degen_execution(RoundId(81), 0, token_index, 777, [33u8; 32]);
 
         let accounts = [
             executor_account.view(),
@@ -1369,7 +1717,211 @@ mod tests {
     }
 
     #[test]
-    fn finalize_degen_success_runtime_marks_claimed_swapped() {
+    fn begin_degen_execution_rejects_out_of_range_token_index() {
+        let executor = Address::new_from_array([5u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (degen_config_pda, degen_config_data) = sample_degen_config();
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+        let token_index = crate::degen_pool_compat::degen_pool_len() as u32;
+        let token_mint = [11u8; 32];
+        let vault_data = token_account([2u8; 32], round_pda.to_bytes(), 1_000_000);
+        let executor_usdc_ata_data = token_account([2u8; 32], executor.to_bytes(), 0);
+        let treasury_data = token_account([2u8; 32], [7u8; 32], 0);
+        let receiver_data = token_account(token_mint, [9u8; 32], 500);
+
+        let mut executor_account = TestAccount::new(executor.to_bytes(), SYSTEM_PROGRAM_ID, true, true, 1_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut degen_config_account = TestAccount::new(degen_config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &degen_config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut vault_account = TestAccount::new(round_pda.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &vault_data);
+        let mut executor_usdc_ata_account = TestAccount::new([14u8; 32], pinocchio_token::ID, false, true, 1_000_000, &executor_usdc_ata_data);
+        let mut treasury_account = TestAccount::new([3u8; 32], pinocchio_token::ID, false, true, 1_000_000, &treasury_data);
+        let mut selected_mint_account = TestAccount::new(token_mint, pinocchio_token::ID, false, false, 1_000_000, &[]);
+        let mut receiver_account = TestAccount::new([12u8; 32], pinocchio_token::ID, false, true, 1_000_000, &receiver_data);
+        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+
+        let ix = encode_begin_degen_execution(RoundId(81), 0, token_index, 777, [33u8; 32]);
+
+        let accounts = [
+            executor_account.view(),
+            config_account.view(),
+            degen_config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            vault_account.view(),
+            executor_usdc_ata_account.view(),
+            treasury_account.view(),
+            selected_mint_account.view(),
+            receiver_account.view(),
+            token_program.view(),
+        ];
+
+        assert_eq!(process_instruction(&PROGRAM_ID, &accounts, &ix), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(round_account.data(), round_data.as_slice());
+        assert_eq!(degen_claim_account.data(), degen_claim_data.as_slice());
+    }
+
+    /// `begin_degen_execution` accounts in the 13-account order (VRF payer
+    /// accounts included), carrying exactly the signer/writable flags the
+    /// instruction requires.
+    fn begin_degen_execution_accounts() -> [TestAccount; 13] {
+        let executor = [5u8; 32];
+        let (config_pda, config_data) = sample_config();
+        let (degen_config_pda, degen_config_data) = sample_degen_config();
+        let (round_pda, round_data) = sample_round(DEGEN_MODE_VRF_READY);
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim(round_pda, DEGEN_CLAIM_STATUS_VRF_READY, [0u8; 32], [0u8; 32]);
+        let token_mint = degen_token_mint_by_index(42).unwrap();
+
+        [
+            TestAccount::new(executor, SYSTEM_PROGRAM_ID, true, true, 1_000_000, &[]),
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data),
+            TestAccount::new(degen_config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &degen_config_data),
+            TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data),
+            TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data),
+            TestAccount::new(round_pda.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], round_pda.to_bytes(), 1_000_000)),
+            TestAccount::new([14u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], executor, 0)),
+            TestAccount::new([3u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [7u8; 32], 0)),
+            TestAccount::new([15u8; 32], SYSTEM_PROGRAM_ID, false, true, 1_000_000, &[]),
+            TestAccount::new([16u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [15u8; 32], 0)),
+            TestAccount::new(token_mint, pinocchio_token::ID, false, false, 1_000_000, &[]),
+            TestAccount::new([12u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account(token_mint, [9u8; 32], 500)),
+            TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]),
+        ]
+    }
+
+    fn run_begin_degen_execution(accounts: &mut [TestAccount]) -> ProgramResult {
+        let views: Vec<_> = accounts.iter_mut().map(TestAccount::view).collect();
+        let ix = encode_begin_degen_execution(RoundId(81), 0, 42, 777, [33u8; 32]);
+        process_instruction(&PROGRAM_ID, &views, &ix)
+    }
+
+    #[test]
+    fn begin_degen_execution_account_meta_contract() {
+        run_begin_degen_execution(&mut begin_degen_execution_accounts()).unwrap();
+
+        // (account, position, drop signer, drop writable, expected)
+        let cases = [
+            ("executor", 0, true, false, ProgramError::MissingRequiredSignature),
+            ("executor", 0, false, true, ProgramError::Immutable),
+            ("round", 3, false, true, ProgramError::Immutable),
+            ("degen_claim", 4, false, true, ProgramError::Immutable),
+            ("vault", 5, false, true, ProgramError::Immutable),
+            ("executor_usdc_ata", 6, false, true, ProgramError::Immutable),
+            ("treasury_usdc_ata", 7, false, true, ProgramError::Immutable),
+            ("vrf_payer_authority", 8, false, true, ProgramError::Immutable),
+            ("vrf_payer_usdc_ata", 9, false, true, ProgramError::Immutable),
+            ("receiver_token_ata", 11, false, true, ProgramError::Immutable),
+        ];
+        for (name, position, drop_signer, drop_writable, expected) in cases {
+            let mut accounts = begin_degen_execution_accounts();
+            if drop_signer {
+                accounts[position].set_signer(false);
+            }
+            if drop_writable {
+                accounts[position].set_writable(false);
+            }
+            assert_eq!(run_begin_degen_execution(&mut accounts), Err(expected), "{name}");
+        }
+    }
+
+    #[test]
+    fn begin_degen_execution_rejects_same_account_in_two_writable_slots() {
+        // (account, position, position of the account it aliases)
+        let cases = [
+            ("receiver_token_ata", 11, 6),
+            ("vrf_payer_usdc_ata", 9, 11),
+        ];
+        for (name, position, aliased) in cases {
+            let mut accounts = begin_degen_execution_accounts();
+            let address = *accounts[aliased].view().address();
+            let data = accounts[position].data().to_vec();
+            accounts[position] = TestAccount::new(address.to_bytes(), pinocchio_token::ID, false, true, 1_000_000, &data);
+            assert_eq!(run_begin_degen_execution(&mut accounts), Err(ProgramError::InvalidArgument), "{name}");
+        }
+    }
+
+    #[test]
+    fn begin_degen_execution_splits_fee_with_second_recipient() {
+        let (config_pda, config_data) = sample_config_with_fee_split();
+        let (fee_split_pda, fee_split_data) = sample_fee_split_config([17u8; 32], 10_000);
+        let mut accounts = Vec::from(begin_degen_execution_accounts());
+        accounts[1] = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        assert_eq!(run_begin_degen_execution(&mut accounts), Err(ProgramError::NotEnoughAccountKeys));
+
+        accounts.push(TestAccount::new(fee_split_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &fee_split_data));
+        accounts.push(TestAccount::new([17u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [18u8; 32], 0)));
+        run_begin_degen_execution(&mut accounts).unwrap();
+
+        let amount = |account: &TestAccount| TokenAccountWithAmountView::read_from_account_data(account.data()).unwrap().amount;
+        assert_eq!(amount(&accounts[5]), 0);
+        assert_eq!(amount(&accounts[6]), 997_500);
+        assert_eq!(amount(&accounts[7]), 0);
+        assert_eq!(amount(&accounts[14]), 2_500);
+    }
+
+    /// begin → (swap) → finalize for a token whose mint and receiver ATA live
+    /// under Token-2022, while the USDC legs stay on the classic program.
+    #[test]
+    fn degen_execution_runs_end_to_end_with_token_2022_receiver() {
+        use crate::runtime::token_programs::TOKEN_2022_PROGRAM_ID;
+
+        let token_mint = degen_token_mint_by_index(42).unwrap();
+        let mut accounts = begin_degen_execution_accounts();
+        accounts[10] = TestAccount::new(token_mint, TOKEN_2022_PROGRAM_ID, false, false, 1_000_000, &[]);
+        accounts[11] = TestAccount::new([12u8; 32], TOKEN_2022_PROGRAM_ID, false, true, 1_000_000, &token_account(token_mint, [9u8; 32], 500));
+        run_begin_degen_execution(&mut accounts).unwrap();
+        let claim = DegenClaimView::read_from_account_data(accounts[4].data()).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_EXECUTING);
+        assert_eq!(claim.receiver_pre_balance, 500);
+
+        // The swap spends the executor's USDC and lands min_out in the receiver.
+        let (degen_config_pda, degen_config_data) = sample_degen_config();
+        let mut executor_account = TestAccount::new([5u8; 32], SYSTEM_PROGRAM_ID, true, true, 1_000_000, &[]);
+        let mut degen_config_account = TestAccount::new(degen_config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &degen_config_data);
+        let round_data = accounts[3].data().to_vec();
+        let degen_claim_data = accounts[4].data().to_vec();
+        let mut round_account = TestAccount::new(accounts[3].view().address().to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(accounts[4].view().address().to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut executor_usdc_ata_account = TestAccount::new([14u8; 32], pinocchio_token::ID, false, true, 1_000_000, &token_account([2u8; 32], [5u8; 32], 0));
+        let mut receiver_account = TestAccount::new([12u8; 32], TOKEN_2022_PROGRAM_ID, false, true, 1_000_000, &token_account(token_mint, [9u8; 32], 1_277));
+        let mut token_program = TestAccount::new(TOKEN_2022_PROGRAM_ID.to_bytes(), TOKEN_2022_PROGRAM_ID, false, false, 1_000_000, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("finalize_degen_success"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        let finalize_accounts = [
+            executor_account.view(),
+            degen_config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            executor_usdc_ata_account.view(),
+            receiver_account.view(),
+            token_program.view(),
+        ];
+        process_instruction(&PROGRAM_ID, &finalize_accounts, &ix).unwrap();
+
+        let claim = DegenClaimView::read_from_account_data(degen_claim_account.data()).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED);
+        assert_eq!(RoundLifecycleView::read_from_account_data(round_account.data()).unwrap().status, ROUND_STATUS_CLAIMED);
+    }
+
+    #[test]
+    fn begin_degen_execution_rejects_receiver_and_mint_under_different_token_programs() {
+        use crate::runtime::token_programs::TOKEN_2022_PROGRAM_ID;
+
+        let token_mint = degen_token_mint_by_index(42).unwrap();
+        let mut accounts = begin_degen_execution_accounts();
+        accounts[11] = TestAccount::new([12u8; 32], TOKEN_2022_PROGRAM_ID, false, true, 1_000_000, &token_account(token_mint, [9u8; 32], 500));
+        assert_eq!(run_begin_degen_execution(&mut accounts), Err(ProgramError::IncorrectProgramId));
+
+        let mut accounts = begin_degen_execution_accounts();
+        accounts[11] = TestAccount::new([12u8; 32], Address::new_from_array([77u8; 32]), false, true, 1_000_000, &token_account(token_mint, [9u8; 32], 500));
+        assert_eq!(run_begin_degen_execution(&mut accounts), Err(ProgramError::IncorrectProgramId));
+    }
+
+    fn run_finalize_with_token_program(token_program_id: Address) -> (ProgramResult, Vec<u8>, Vec<u8>) {
         let executor = Address::new_from_array([5u8; 32]);
         let (_config_pda, _config_data) = sample_config();
         let (degen_config_pda, degen_config_data) = sample_degen_config();
@@ -1384,8 +1936,8 @@ mod tests {
         let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
         let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
         let mut executor_usdc_ata_account = TestAccount::new([14u8; 32], pinocchio_token::ID, false, true, 1_000_000, &executor_usdc_ata_data);
-        let mut receiver_account = TestAccount::new([12u8; 32], pinocchio_token::ID, false, true, 1_000_000, &receiver_data);
-        let mut token_program = TestAccount::new(pinocchio_token::ID.to_bytes(), pinocchio_token::ID, false, false, 1_000_000, &[]);
+        let mut receiver_account = TestAccount::new([12u8; 32], token_program_id, false, true, 1_000_000, &receiver_data);
+        let mut token_program = TestAccount::new(token_program_id.to_bytes(), token_program_id, false, false, 1_000_000, &[]);
 
         let mut ix = Vec::new();
         ix.extend_from_slice(&instruction_discriminator("finalize_degen_success"));
@@ -1401,13 +1953,37 @@ mod tests {
             token_program.view(),
         ];
 
-        process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap();
+        let result = process_instruction(&PROGRAM_ID, &accounts, &ix);
+        (result, round_account.data().to_vec(), degen_claim_account.data().to_vec())
+    }
 
-        let updated_round = RoundLifecycleView::read_from_account_data(round_account.data()).unwrap();
+    #[test]
+    fn finalize_degen_success_runtime_marks_claimed_swapped() {
+        let (result, round_data, degen_claim_data) = run_finalize_with_token_program(pinocchio_token::ID);
+        result.unwrap();
+
+        let updated_round = RoundLifecycleView::read_from_account_data(&round_data).unwrap();
         assert_eq!(updated_round.status, ROUND_STATUS_CLAIMED);
-        assert_eq!(RoundLifecycleView::read_degen_mode_status_from_account_data(round_account.data()).unwrap(), 4);
-        let claim = DegenClaimView::read_from_account_data(degen_claim_account.data()).unwrap();
+        assert_eq!(RoundLifecycleView::read_degen_mode_status_from_account_data(&round_data).unwrap(), 4);
+        let claim = DegenClaimView::read_from_account_data(&degen_claim_data).unwrap();
         assert_eq!(claim.status, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED);
         assert_eq!(claim.claimed_at, 1_001);
     }
+
+    #[test]
+    fn finalize_degen_success_accepts_token_2022_receiver() {
+        let (result, _round_data, degen_claim_data) =
+            run_finalize_with_token_program(crate::runtime::token_programs::TOKEN_2022_PROGRAM_ID);
+        result.unwrap();
+
+        let claim = DegenClaimView::read_from_account_data(&degen_claim_data).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED);
+    }
+
+    #[test]
+    fn finalize_degen_success_rejects_unrelated_token_program() {
+        let (result, _round_data, _degen_claim_data) =
+            run_finalize_with_token_program(Address::new_from_array([77u8; 32]));
+        assert_eq!(result, Err(ProgramError::IncorrectProgramId));
+    }
 }
@@ -1,6 +1,5 @@
 extern crate alloc;
 
-#[cfg(not(test))]
 use alloc::vec::Vec;
 
 use pinocchio::{
@@ -11,17 +10,23 @@ use pinocchio::{
 use pinocchio::{
     cpi::{Seed, Signer, invoke_signed},
     instruction::{InstructionAccount, InstructionView},
-    sysvars::Sysvar,
+    sysvars::{Sysvar, rent::Rent},
 };
 #[cfg(not(test))]
 use pinocchio_system::create_account_with_minimum_balance_signed;
+use sha2::{Digest, Sha256};
 use solana_address::address;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::{AccountInitState, DEGEN_CLAIM_DISCRIMINATOR, account_init_state, instruction_discriminator},
+    instruction_layouts::RoundId,
     legacy_layouts::{
-        ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
-        DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+        AccountLayout, ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, DEGEN_CLAIM_ACCOUNT_LEN,
+    },
+    pdas::{
+        SEED_DEGEN_CLAIM, SEED_IDENTITY, create_degen_claim_pda, create_round_pda, derive_config_pda,
+        derive_degen_claim_pda, derive_degen_config_pda, derive_program_identity_pda, derive_round_pda,
+        expected_oracle_queue,
     },
     processors::degen_vrf::DegenVrfProcessor,
 };
@@ -31,11 +36,6 @@ mod degen_vrf_constants {
 }
 use degen_vrf_constants::{DEFAULT_QUEUE, VRF_PROGRAM_ID, VRF_PROGRAM_IDENTITY};
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
-const SEED_DEGEN_CLAIM: &[u8] = b"degen_claim";
-const SEED_DEGEN_CFG: &[u8] = b"degen_cfg";
-const SEED_IDENTITY: &[u8] = b"identity";
 const SYSTEM_PROGRAM_ID: Address = address!("11111111111111111111111111111111");
 const SLOT_HASHES_SYSVAR_ID: Address = address!("SysvarS1otHashes111111111111111111111111111");
 
@@ -63,13 +63,20 @@ fn process_request_degen_vrf(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let [winner, config, round, degen_claim, program_identity, oracle_queue, vrf_program, slot_hashes, system_program, ..] = accounts else {
+    let [winner, config, round, degen_claim, program_identity, oracle_queue, vrf_program, slot_hashes, system_program, rest @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // Optional trailing degen config: supplies the candidate window. Clients
+    // built for the Anchor account list omit it and get the default window.
+    let degen_config = rest.first();
+    // Optional `set_vrf_queue` PDA after it, required once the queue has
+    // been set; without it the request must name the build-time
+    // `DEFAULT_QUEUE`.
+    let vrf_queue_config = rest.get(1);
 
     require_signer(winner)?;
     require_writable(winner)?;
-    let _config = require_config_pda(config, program_id)?;
+    let config_view = require_config_pda(config, program_id)?;
     require_writable(round)?;
     require_round_pda(round, program_id, instruction_data, "request_degen_vrf")?;
     require_writable(degen_claim)?;
@@ -82,10 +89,17 @@ fn process_request_degen_vrf(
     )?;
     require_program_identity_pda(program_identity, program_id)?;
     require_writable(oracle_queue)?;
-    require_address(oracle_queue, &DEFAULT_QUEUE)?;
+    let expected_queue =
+        expected_oracle_queue(vrf_queue_config, program_id, DEFAULT_QUEUE, config_view.vrf_queue_overridden())?;
+    require_address(oracle_queue, &expected_queue)?;
     require_address(vrf_program, &VRF_PROGRAM_ID)?;
     require_address(slot_hashes, &SLOT_HASHES_SYSVAR_ID)?;
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
+    if let Some(degen_config) = degen_config {
+        require_degen_config_pda(degen_config, program_id)?;
+    }
+    let degen_config_data = degen_config.map(AccountView::try_borrow).transpose()?;
+    let degen_config_account_data = degen_config_data.as_deref().filter(|data| !data.is_empty());
 
     {
         let round_data = round.try_borrow()?;
@@ -99,7 +113,7 @@ fn process_request_degen_vrf(
             config_account_data: &[],
             round_account_data: &mut round_shadow,
             degen_claim_account_data: &mut degen_claim_shadow,
-            degen_config_account_data: None,
+            degen_config_account_data,
         };
         processor.process(instruction_data)?;
     }
@@ -116,7 +130,7 @@ fn process_request_degen_vrf(
         config_account_data: &[],
         round_account_data: &mut round_data[..],
         degen_claim_account_data: &mut degen_claim_data[..],
-        degen_config_account_data: None,
+        degen_config_account_data,
     };
     processor.process(instruction_data)
 }
@@ -173,48 +187,12 @@ fn invoke_degen_vrf_request(
     slot_hashes: &AccountView,
     system_program: &AccountView,
 ) -> ProgramResult {
-    let round_data = round.try_borrow()?;
-    let round_view = RoundLifecycleView::read_from_account_data(&round_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
-    drop(round_data);
-
-    let round_id_le = round_view.round_id.to_le_bytes();
-    let (_, identity_bump) = Address::find_program_address(&[SEED_IDENTITY], program_id);
+    let ix_data = degen_vrf_request_ix_data(program_id, winner, config, round, degen_claim)?;
+    let (_, identity_bump) = derive_program_identity_pda(program_id);
     let identity_bump_slice = [identity_bump];
     let signer_seeds: [Seed<'_>; 2] = [Seed::from(SEED_IDENTITY), Seed::from(&identity_bump_slice)];
     let signer = Signer::from(&signer_seeds);
 
-    let callback_accounts = [
-        SerializableAccountMetaCompat {
-            pubkey: config.address().to_bytes(),
-            is_signer: false,
-            is_writable: false,
-        },
-        SerializableAccountMetaCompat {
-            pubkey: round.address().to_bytes(),
-            is_signer: false,
-            is_writable: true,
-        },
-        SerializableAccountMetaCompat {
-            pubkey: degen_claim.address().to_bytes(),
-            is_signer: false,
-            is_writable: true,
-        },
-        SerializableAccountMetaCompat {
-            pubkey: Address::find_program_address(&[SEED_DEGEN_CFG], program_id).0.to_bytes(),
-            is_signer: false,
-            is_writable: false,
-        },
-    ];
-    let callback_discriminator = instruction_discriminator("degen_vrf_callback");
-    let ix_data = build_request_randomness_ix_data(
-        program_id.clone(),
-        winner.address().to_bytes(),
-        round_id_le,
-        &callback_discriminator,
-        &callback_accounts,
-    );
-
     let instruction_accounts: [InstructionAccount; 5] = [
         InstructionAccount::writable_signer(winner.address()),
         InstructionAccount::readonly_signer(program_identity.address()),
@@ -236,41 +214,107 @@ fn invoke_degen_vrf_request(
     )
 }
 
+#[cfg(test)]
+std::thread_local! {
+    /// Instruction data the last `invoke_degen_vrf_request` would have sent.
+    static RECORDED_VRF_REQUEST: core::cell::RefCell<Option<Vec<u8>>> = const { core::cell::RefCell::new(None) };
+}
+
 #[cfg(test)]
 fn invoke_degen_vrf_request(
-    _program_id: &Address,
-    _winner: &AccountView,
-    _config: &AccountView,
-    _round: &AccountView,
-    _degen_claim: &AccountView,
+    program_id: &Address,
+    winner: &AccountView,
+    config: &AccountView,
+    round: &AccountView,
+    degen_claim: &AccountView,
     _program_identity: &AccountView,
     _oracle_queue: &AccountView,
     _vrf_program: &AccountView,
     _slot_hashes: &AccountView,
     _system_program: &AccountView,
 ) -> ProgramResult {
+    let ix_data = degen_vrf_request_ix_data(program_id, winner, config, round, degen_claim)?;
+    RECORDED_VRF_REQUEST.with(|recorded| *recorded.borrow_mut() = Some(ix_data));
     Ok(())
 }
 
-#[cfg(not(test))]
-#[derive(Clone, Copy)]
-struct SerializableAccountMetaCompat {
+/// Switchboard `request_randomness` payload for this round's winner, with
+/// `degen_vrf_callback` as the callback.
+fn degen_vrf_request_ix_data(
+    program_id: &Address,
+    winner: &AccountView,
+    config: &AccountView,
+    round: &AccountView,
+    degen_claim: &AccountView,
+) -> Result<Vec<u8>, ProgramError> {
+    let round_data = round.try_borrow()?;
+    let round_view = RoundLifecycleView::read_from_account_data_exact(&round_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    drop(round_data);
+
+    let callback_accounts = degen_vrf_callback_accounts(
+        config.address(),
+        round.address(),
+        degen_claim.address(),
+        &derive_degen_config_pda(program_id).0,
+    );
+    let callback_discriminator = instruction_discriminator("degen_vrf_callback");
+    Ok(build_request_randomness_ix_data(
+        program_id,
+        winner.address().to_bytes(),
+        round_view.round_id.to_le_bytes(),
+        &callback_discriminator,
+        &callback_accounts,
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerializableAccountMetaCompat {
     pub pubkey: [u8; 32],
     pub is_signer: bool,
     pub is_writable: bool,
 }
 
-#[cfg(not(test))]
-fn build_request_randomness_ix_data(
-    callback_program_id: Address,
+/// Accounts the oracle forwards to `degen_vrf_callback`, after its own
+/// `vrf_program_identity` signer. Order and flags must match the
+/// destructuring in `process_degen_vrf_callback`.
+pub fn degen_vrf_callback_accounts(
+    config: &Address,
+    round: &Address,
+    degen_claim: &Address,
+    degen_config: &Address,
+) -> [SerializableAccountMetaCompat; 4] {
+    [
+        SerializableAccountMetaCompat { pubkey: config.to_bytes(), is_signer: false, is_writable: false },
+        SerializableAccountMetaCompat { pubkey: round.to_bytes(), is_signer: false, is_writable: true },
+        SerializableAccountMetaCompat { pubkey: degen_claim.to_bytes(), is_signer: false, is_writable: true },
+        SerializableAccountMetaCompat { pubkey: degen_config.to_bytes(), is_signer: false, is_writable: false },
+    ]
+}
+
+/// Caller seed for a degen VRF request: `sha256(round_id_le || winner)`.
+///
+/// Anchor packs `round_id_le || winner[..24]`, so two winners sharing a
+/// 24-byte prefix would collide; hashing keeps the full winner key.
+pub fn derive_caller_seed(round_id: u64, winner: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(round_id.to_le_bytes());
+    hasher.update(winner);
+    hasher.finalize().into()
+}
+
+/// Serializes the Switchboard `request_randomness` instruction data sent by
+/// `request_degen_vrf`: discriminator, caller seed, callback program id,
+/// length-prefixed callback discriminator, length-prefixed callback account
+/// metas and a trailing zero `u32`.
+pub fn build_request_randomness_ix_data(
+    callback_program_id: &Address,
     winner_pubkey: [u8; 32],
     round_id_le: [u8; 8],
     callback_discriminator: &[u8; 8],
     callback_accounts: &[SerializableAccountMetaCompat],
 ) -> Vec<u8> {
-    let mut caller_seed = [0u8; 32];
-    caller_seed[..8].copy_from_slice(&round_id_le);
-    caller_seed[8..].copy_from_slice(&winner_pubkey[..24]);
+    let caller_seed = derive_caller_seed(u64::from_le_bytes(round_id_le), &winner_pubkey);
 
     let mut data = Vec::with_capacity(8 + 32 + 32 + 4 + 8 + 4 + (callback_accounts.len() * 34) + 4);
     data.extend_from_slice(&[3, 0, 0, 0, 0, 0, 0, 0]);
@@ -288,6 +332,75 @@ fn build_request_randomness_ix_data(
     data
 }
 
+/// Decoded form of [`build_request_randomness_ix_data`], for pinning the
+/// Switchboard wire format in tests.
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedVrfRequest {
+    discriminator: [u8; 8],
+    caller_seed: [u8; 32],
+    callback_program_id: [u8; 32],
+    callback_discriminator: Vec<u8>,
+    callback_accounts: Vec<SerializableAccountMetaCompat>,
+    trailing: u32,
+}
+
+#[cfg(test)]
+fn parse_request_randomness_ix_data(data: &[u8]) -> Result<ParsedVrfRequest, ProgramError> {
+    fn take<'a>(data: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], ProgramError> {
+        let end = offset.checked_add(len).ok_or(ProgramError::InvalidInstructionData)?;
+        let bytes = data.get(*offset..end).ok_or(ProgramError::InvalidInstructionData)?;
+        *offset = end;
+        Ok(bytes)
+    }
+    fn take_u32(data: &[u8], offset: &mut usize) -> Result<u32, ProgramError> {
+        let bytes = take(data, offset, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+    fn take_bool(data: &[u8], offset: &mut usize) -> Result<bool, ProgramError> {
+        match take(data, offset, 1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    let mut offset = 0usize;
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(take(data, &mut offset, 8)?);
+    let mut caller_seed = [0u8; 32];
+    caller_seed.copy_from_slice(take(data, &mut offset, 32)?);
+    let mut callback_program_id = [0u8; 32];
+    callback_program_id.copy_from_slice(take(data, &mut offset, 32)?);
+
+    let discriminator_len = take_u32(data, &mut offset)? as usize;
+    let callback_discriminator = take(data, &mut offset, discriminator_len)?.to_vec();
+
+    let accounts_len = take_u32(data, &mut offset)? as usize;
+    let mut callback_accounts = Vec::new();
+    for _ in 0..accounts_len {
+        let mut pubkey = [0u8; 32];
+        pubkey.copy_from_slice(take(data, &mut offset, 32)?);
+        let is_signer = take_bool(data, &mut offset)?;
+        let is_writable = take_bool(data, &mut offset)?;
+        callback_accounts.push(SerializableAccountMetaCompat { pubkey, is_signer, is_writable });
+    }
+
+    let trailing = take_u32(data, &mut offset)?;
+    if offset != data.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(ParsedVrfRequest {
+        discriminator,
+        caller_seed,
+        callback_program_id,
+        callback_discriminator,
+        callback_accounts,
+        trailing,
+    })
+}
+
 #[cfg(test)]
 fn clock_unix_timestamp() -> i64 {
     1_700_000_000
@@ -334,17 +447,12 @@ fn require_address(account: &AccountView, expected: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -358,31 +466,18 @@ fn require_round_pda(
     ix_name: &str,
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected_address, _) = Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)?;
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn require_round_self_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let round = RoundLifecycleView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    let expected = Address::create_program_address(
-        &[SEED_ROUND, &round.round_id.to_le_bytes(), &[round.bump]],
-        program_id,
-    )
-    .map_err(|_| ProgramError::InvalidSeeds)?;
+    let round = RoundLifecycleView::from_account_view(account)?;
+    let expected = create_round_pda(program_id, RoundId(round.round_id), round.bump)?;
     if account.address() != &expected {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -392,7 +487,7 @@ fn require_round_self_pda(account: &AccountView, program_id: &Address) -> Progra
 fn require_program_identity_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
     // Identity PDA is not a signer at outer instruction level — it signs only
     // during invoke_signed CPI to the VRF program.  Validate address only.
-    require_address(account, &Address::find_program_address(&[SEED_IDENTITY], program_id).0)
+    require_address(account, &derive_program_identity_pda(program_id).0)
 }
 
 fn prepare_degen_claim_pda_init_if_needed(
@@ -402,12 +497,8 @@ fn prepare_degen_claim_pda_init_if_needed(
     program_id: &Address,
     instruction_data: &[u8],
 ) -> Result<u8, ProgramError> {
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "request_degen_vrf")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected, bump) = Address::find_program_address(
-        &[SEED_DEGEN_CLAIM, &round_id.to_le_bytes(), payer.address().as_ref()],
-        program_id,
-    );
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "request_degen_vrf")?;
+    let (expected, bump) = derive_degen_claim_pda(program_id, round_id, &payer.address().to_bytes());
     if account.address() != &expected {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -415,6 +506,7 @@ fn prepare_degen_claim_pda_init_if_needed(
     if !account.owned_by(program_id) {
         require_address(system_program, &SYSTEM_PROGRAM_ID)?;
         require_owned_by(account, &SYSTEM_PROGRAM_ID)?;
+        require_can_fund_degen_claim(payer, account)?;
         create_degen_claim_pda_account(account, payer, program_id, round_id, bump)?;
     }
 
@@ -422,26 +514,48 @@ fn prepare_degen_claim_pda_init_if_needed(
     if data.len() != DEGEN_CLAIM_ACCOUNT_LEN {
         return Err(ProgramError::InvalidAccountData);
     }
-    let is_zeroed = data.iter().all(|byte| *byte == 0);
-    if !is_zeroed && data.get(..8) != Some(&account_discriminator("DegenClaim")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    if !is_zeroed {
-        let claim =
-            DegenClaimView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-        if claim.bump != bump {
-            return Err(ProgramError::InvalidSeeds);
+    match account_init_state(&data, &DEGEN_CLAIM_DISCRIMINATOR) {
+        Some(AccountInitState::Uninitialized) => {}
+        Some(AccountInitState::Initialized) => {
+            let claim =
+                DegenClaimView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            if claim.bump != bump {
+                return Err(ProgramError::InvalidSeeds);
+            }
         }
+        None => return Err(ProgramError::InvalidAccountData),
     }
     Ok(bump)
 }
 
+/// The system program only tops the PDA up to rent exemption, so the winner
+/// must cover whatever the account does not already hold. Failing here gives
+/// `InsufficientFunds` instead of an opaque error from inside the CPI.
+fn require_can_fund_degen_claim(payer: &AccountView, degen_claim: &AccountView) -> ProgramResult {
+    let required = degen_claim_rent_exempt_lamports()?.saturating_sub(degen_claim.lamports());
+    if payer.lamports() < required {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+#[cfg(not(test))]
+fn degen_claim_rent_exempt_lamports() -> Result<u64, ProgramError> {
+    Rent::get()?.try_minimum_balance(DEGEN_CLAIM_ACCOUNT_LEN)
+}
+
+#[cfg(test)]
+fn degen_claim_rent_exempt_lamports() -> Result<u64, ProgramError> {
+    use pinocchio::sysvars::rent::{ACCOUNT_STORAGE_OVERHEAD, DEFAULT_LAMPORTS_PER_BYTE};
+    Ok((ACCOUNT_STORAGE_OVERHEAD + DEGEN_CLAIM_ACCOUNT_LEN as u64) * DEFAULT_LAMPORTS_PER_BYTE)
+}
+
 #[cfg(not(test))]
 fn create_degen_claim_pda_account(
     account: &AccountView,
     payer: &AccountView,
     program_id: &Address,
-    round_id: u64,
+    round_id: RoundId,
     bump: u8,
 ) -> ProgramResult {
     let round_id_le = round_id.to_le_bytes();
@@ -468,7 +582,7 @@ fn create_degen_claim_pda_account(
     account: &AccountView,
     _payer: &AccountView,
     program_id: &Address,
-    _round_id: u64,
+    _round_id: RoundId,
     _bump: u8,
 ) -> ProgramResult {
     unsafe {
@@ -485,138 +599,64 @@ fn require_existing_degen_claim_pda(
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
     let round_data = round.try_borrow()?;
-    let round_view = RoundLifecycleView::read_from_account_data(&round_data)
+    let round_view = RoundLifecycleView::read_from_account_data_exact(&round_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     let winner = RoundLifecycleView::read_winner_from_account_data(&round_data)
         .map_err(|_| ProgramError::InvalidAccountData)?;
     drop(round_data);
 
-    let data = account.try_borrow()?;
-    if data.len() != DEGEN_CLAIM_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("DegenClaim")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let claim = DegenClaimView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    let expected = Address::create_program_address(
-        &[SEED_DEGEN_CLAIM, &round_view.round_id.to_le_bytes(), &winner, &[claim.bump]],
-        program_id,
-    )
-    .map_err(|_| ProgramError::InvalidSeeds)?;
+    let claim = DegenClaimView::from_account_view(account)?;
+    let expected = create_degen_claim_pda(program_id, RoundId(round_view.round_id), &winner, claim.bump)?;
     if account.address() != &expected {
         return Err(ProgramError::InvalidSeeds);
     }
     Ok(())
 }
 
+/// The degen config is optional for the callback: a never-initialized PDA
+/// (empty and still system-owned) makes it fall back to
+/// `DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC`. Anything else must be a valid
+/// program-owned `DegenConfig`.
 fn require_degen_config_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
-    let expected = Address::find_program_address(&[SEED_DEGEN_CFG], program_id).0;
+    let expected = derive_degen_config_pda(program_id).0;
     if account.address() != &expected {
         return Err(ProgramError::InvalidSeeds);
     }
     let data = account.try_borrow()?;
     if data.is_empty() {
-        return Ok(());
+        return require_owned_by(account, &SYSTEM_PROGRAM_ID);
     }
     require_owned_by(account, program_id)?;
-    if data.len() != DEGEN_CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("DegenConfig")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let _cfg = DegenConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let _cfg = DegenConfigView::from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use core::mem::size_of;
-
-    use pinocchio::{
-        Address,
-        account::{NOT_BORROWED, RuntimeAccount},
-        error::ProgramError,
-    };
+    use pinocchio::{Address, error::ProgramError};
 
     use crate::{
         anchor_compat::account_discriminator,
         legacy_layouts::{
-            ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, CONFIG_ACCOUNT_LEN,
-            DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CLAIM_STATUS_VRF_READY,
+            ConfigView, DegenClaimView, DegenConfigView, RoundLifecycleView, VrfQueueConfigView, CONFIG_ACCOUNT_LEN,
+            DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC, DEGEN_CLAIM_ACCOUNT_LEN, DEGEN_CLAIM_STATUS_VRF_READY,
             DEGEN_CLAIM_STATUS_VRF_REQUESTED, DEGEN_CANDIDATE_WINDOW, DEGEN_CONFIG_ACCOUNT_LEN,
-            ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED,
+            ROUND_ACCOUNT_LEN, ROUND_STATUS_SETTLED, VRF_QUEUE_CONFIG_ACCOUNT_LEN,
         },
+        pdas::{SEED_CFG, SEED_DEGEN_CFG, SEED_ROUND, SEED_VRF_QUEUE},
+        runtime::test_support::TestAccount,
     };
 
     use super::{
-        process_instruction, instruction_discriminator, DEFAULT_QUEUE, SEED_CFG, SEED_DEGEN_CLAIM,
-        SEED_DEGEN_CFG, SEED_IDENTITY, SEED_ROUND, SLOT_HASHES_SYSVAR_ID, SYSTEM_PROGRAM_ID,
-        VRF_PROGRAM_ID, VRF_PROGRAM_IDENTITY,
+        RECORDED_VRF_REQUEST, build_request_randomness_ix_data, degen_claim_rent_exempt_lamports, degen_vrf_callback_accounts,
+        derive_caller_seed,
+        parse_request_randomness_ix_data, prepare_degen_claim_pda_init_if_needed,
+        process_instruction, instruction_discriminator, SerializableAccountMetaCompat, DEFAULT_QUEUE,
+        SEED_DEGEN_CLAIM, SEED_IDENTITY, SLOT_HASHES_SYSVAR_ID, SYSTEM_PROGRAM_ID, VRF_PROGRAM_ID, VRF_PROGRAM_IDENTITY,
     };
 
     const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
 
-    struct TestAccount {
-        backing: Vec<u64>,
-    }
-
-    impl TestAccount {
-        fn new(
-            address: [u8; 32],
-            owner: Address,
-            is_signer: bool,
-            is_writable: bool,
-            lamports: u64,
-            data: &[u8],
-        ) -> Self {
-            Self::new_with_capacity(address, owner, is_signer, is_writable, lamports, data, data.len())
-        }
-
-        fn new_with_capacity(
-            address: [u8; 32],
-            owner: Address,
-            is_signer: bool,
-            is_writable: bool,
-            lamports: u64,
-            data: &[u8],
-            capacity: usize,
-        ) -> Self {
-            let bytes = size_of::<RuntimeAccount>() + capacity;
-            let words = bytes.div_ceil(size_of::<u64>());
-            let mut backing = vec![0u64; words.max(1)];
-            let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
-
-            unsafe {
-                (*raw).borrow_state = NOT_BORROWED;
-                (*raw).is_signer = u8::from(is_signer);
-                (*raw).is_writable = u8::from(is_writable);
-                (*raw).executable = 0;
-                (*raw).resize_delta = 0;
-                (*raw).address = Address::from(address);
-                (*raw).owner = owner;
-                (*raw).lamports = lamports;
-                (*raw).data_len = data.len() as u64;
-
-                if !data.is_empty() {
-                    let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
-                    core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
-                }
-            }
-
-            Self { backing }
-        }
-
-        fn view(&mut self) -> pinocchio::AccountView {
-            unsafe { pinocchio::AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
-        }
-
-        fn data(&self) -> &[u8] {
-            let raw = self.backing.as_ptr() as *const RuntimeAccount;
-            unsafe {
-                core::slice::from_raw_parts(
-                    (raw as *const u8).add(size_of::<RuntimeAccount>()),
-                    (*raw).data_len as usize,
-                )
-            }
-        }
-    }
-
     fn sample_config() -> (Address, Vec<u8>) {
         let (config_pda, config_bump) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
         let mut data = vec![0u8; CONFIG_ACCOUNT_LEN];
@@ -799,6 +839,80 @@ mod tests {
         assert_eq!(claim.status, DEGEN_CLAIM_STATUS_VRF_REQUESTED);
     }
 
+    /// Runs `request_degen_vrf` naming `queue` as the oracle queue after
+    /// `set_vrf_queue` stored `rotated`. `trailing` picks how many of the
+    /// optional degen config and `set_vrf_queue` PDA accounts are passed.
+    fn request_degen_vrf_after_override(queue: Address, rotated: Address, trailing: usize) -> Result<(), ProgramError> {
+        let (config_pda, mut config_data) = sample_config();
+        let mut config_view = ConfigView::read_from_account_data(&config_data).unwrap();
+        config_view.mark_vrf_queue_overridden();
+        config_view.write_to_account_data(&mut config_data).unwrap();
+        let (round_pda, round_data) = sample_round();
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim();
+        let (degen_cfg_pda, degen_cfg_data) = sample_degen_config();
+        let (program_identity_pda, _) = Address::find_program_address(&[SEED_IDENTITY], &PROGRAM_ID);
+        let (queue_config_pda, queue_config_bump) = Address::find_program_address(&[SEED_VRF_QUEUE], &PROGRAM_ID);
+        let mut queue_config_data = vec![0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+        queue_config_data[..8].copy_from_slice(&account_discriminator("VrfQueueConfig"));
+        VrfQueueConfigView { queue: rotated.to_bytes(), bump: queue_config_bump }
+            .write_to_account_data(&mut queue_config_data)
+            .unwrap();
+
+        let mut winner_account = TestAccount::new([9u8; 32], Address::new_from_array([0u8; 32]), true, true, 1_000_000_000, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut identity_account = TestAccount::new(program_identity_pda.to_bytes(), PROGRAM_ID, true, false, 0, &[]);
+        let mut queue_account = TestAccount::new(queue.to_bytes(), Address::new_from_array([0u8; 32]), false, true, 0, &[]);
+        let mut vrf_program_account = TestAccount::new(VRF_PROGRAM_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, 0, &[]);
+        let mut slot_hashes_account = TestAccount::new(SLOT_HASHES_SYSVAR_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, 0, &[]);
+        let mut system_program_account = TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, 0, &[]);
+        let mut degen_cfg_account = TestAccount::new(degen_cfg_pda.to_bytes(), PROGRAM_ID, false, false, 0, &degen_cfg_data);
+        let mut queue_config_account =
+            TestAccount::new(queue_config_pda.to_bytes(), PROGRAM_ID, false, false, 0, &queue_config_data);
+
+        let views = [
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            identity_account.view(),
+            queue_account.view(),
+            vrf_program_account.view(),
+            slot_hashes_account.view(),
+            system_program_account.view(),
+            degen_cfg_account.view(),
+            queue_config_account.view(),
+        ];
+        let ix = crate::instruction_layouts::encode_round_id_ix("request_degen_vrf", crate::instruction_layouts::RoundId(81));
+
+        process_instruction(&PROGRAM_ID, &views[..9 + trailing], &ix)
+    }
+
+    #[test]
+    fn request_degen_vrf_runtime_checks_queue_against_stored_override() {
+        let rotated = Address::new_from_array([6u8; 32]);
+        assert_eq!(
+            request_degen_vrf_after_override(DEFAULT_QUEUE, rotated, 2),
+            Err(ProgramError::InvalidArgument)
+        );
+        request_degen_vrf_after_override(rotated, rotated, 2).unwrap();
+    }
+
+    #[test]
+    fn request_degen_vrf_runtime_rejects_omitted_queue_config_after_override() {
+        let rotated = Address::new_from_array([6u8; 32]);
+        for trailing in [0, 1] {
+            for queue in [DEFAULT_QUEUE, rotated] {
+                assert_eq!(
+                    request_degen_vrf_after_override(queue, rotated, trailing),
+                    Err(ProgramError::NotEnoughAccountKeys),
+                    "{trailing} {queue:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn request_degen_vrf_runtime_init_if_needed_creates_claim() {
         let winner = Address::new_from_array([9u8; 32]);
@@ -855,6 +969,199 @@ mod tests {
         assert_eq!(claim.round_id, round_id);
     }
 
+    fn run_request_degen_vrf_init(winner_lamports: u64, degen_claim_lamports: u64) -> Result<(), ProgramError> {
+        let winner = Address::new_from_array([9u8; 32]);
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, round_data) = sample_round();
+        let (degen_claim_pda, _) = Address::find_program_address(
+            &[SEED_DEGEN_CLAIM, &81u64.to_le_bytes(), winner.as_ref()],
+            &PROGRAM_ID,
+        );
+        let (program_identity_pda, _) = Address::find_program_address(&[SEED_IDENTITY], &PROGRAM_ID);
+
+        let mut winner_account = TestAccount::new(winner.to_bytes(), SYSTEM_PROGRAM_ID, true, true, winner_lamports, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new_with_capacity(
+            degen_claim_pda.to_bytes(),
+            SYSTEM_PROGRAM_ID,
+            false,
+            true,
+            degen_claim_lamports,
+            &[],
+            DEGEN_CLAIM_ACCOUNT_LEN,
+        );
+        let mut identity_account = TestAccount::new(program_identity_pda.to_bytes(), PROGRAM_ID, true, false, 0, &[]);
+        let mut queue_account = TestAccount::new(DEFAULT_QUEUE.to_bytes(), SYSTEM_PROGRAM_ID, false, true, 0, &[]);
+        let mut vrf_program_account = TestAccount::new(VRF_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]);
+        let mut slot_hashes_account = TestAccount::new(SLOT_HASHES_SYSVAR_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]);
+        let mut system_program_account = TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]);
+
+        let views = [
+            winner_account.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            identity_account.view(),
+            queue_account.view(),
+            vrf_program_account.view(),
+            slot_hashes_account.view(),
+            system_program_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("request_degen_vrf"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        process_instruction(&PROGRAM_ID, &views, &ix)
+    }
+
+    #[test]
+    fn request_degen_vrf_records_switchboard_request() {
+        RECORDED_VRF_REQUEST.with(|recorded| recorded.borrow_mut().take());
+        let rent = degen_claim_rent_exempt_lamports().unwrap();
+        run_request_degen_vrf_init(rent, 0).unwrap();
+
+        let ix_data = RECORDED_VRF_REQUEST.with(|recorded| recorded.borrow_mut().take()).unwrap();
+        let parsed = parse_request_randomness_ix_data(&ix_data).unwrap();
+
+        let winner = [9u8; 32];
+        let (config_pda, _) = sample_config();
+        let (round_pda, _) = sample_round();
+        let (degen_claim_pda, _) = Address::find_program_address(
+            &[SEED_DEGEN_CLAIM, &81u64.to_le_bytes(), &winner],
+            &PROGRAM_ID,
+        );
+        let (degen_cfg_pda, _) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+
+        assert_eq!(parsed.caller_seed, derive_caller_seed(81, &winner));
+        assert_eq!(parsed.callback_program_id, PROGRAM_ID.to_bytes());
+        assert_eq!(parsed.callback_discriminator, instruction_discriminator("degen_vrf_callback"));
+        assert_eq!(
+            parsed.callback_accounts,
+            degen_vrf_callback_accounts(&config_pda, &round_pda, &degen_claim_pda, &degen_cfg_pda)
+        );
+    }
+
+    #[test]
+    fn request_degen_vrf_requires_winner_to_fund_claim_rent() {
+        let rent = degen_claim_rent_exempt_lamports().unwrap();
+
+        assert_eq!(run_request_degen_vrf_init(rent - 1, 0), Err(ProgramError::InsufficientFunds));
+        run_request_degen_vrf_init(rent, 0).unwrap();
+        // Lamports already sitting on the PDA count towards rent.
+        run_request_degen_vrf_init(1, rent - 1).unwrap();
+        assert_eq!(run_request_degen_vrf_init(0, rent - 1), Err(ProgramError::InsufficientFunds));
+    }
+
+    /// `request_degen_vrf` accounts in instruction order, carrying exactly the
+    /// signer/writable flags the instruction requires.
+    fn request_degen_vrf_accounts() -> [TestAccount; 9] {
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, round_data) = sample_round();
+        let (degen_claim_pda, degen_claim_data) = sample_degen_claim();
+        let (program_identity_pda, _) = Address::find_program_address(&[SEED_IDENTITY], &PROGRAM_ID);
+
+        [
+            TestAccount::new([9u8; 32], SYSTEM_PROGRAM_ID, true, true, 1_000_000_000, &[]),
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data),
+            TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data),
+            TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data),
+            TestAccount::new(program_identity_pda.to_bytes(), PROGRAM_ID, false, false, 0, &[]),
+            TestAccount::new(DEFAULT_QUEUE.to_bytes(), SYSTEM_PROGRAM_ID, false, true, 0, &[]),
+            TestAccount::new(VRF_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]),
+            TestAccount::new(SLOT_HASHES_SYSVAR_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]),
+            TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]),
+        ]
+    }
+
+    fn run_request_degen_vrf(accounts: &mut [TestAccount]) -> Result<(), ProgramError> {
+        let views: Vec<_> = accounts.iter_mut().map(TestAccount::view).collect();
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("request_degen_vrf"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        process_instruction(&PROGRAM_ID, &views, &ix)
+    }
+
+    #[test]
+    fn request_degen_vrf_account_meta_contract() {
+        run_request_degen_vrf(&mut request_degen_vrf_accounts()).unwrap();
+
+        // (account, position, drop signer, drop writable, expected)
+        let cases = [
+            ("winner", 0, true, false, ProgramError::MissingRequiredSignature),
+            ("winner", 0, false, true, ProgramError::Immutable),
+            ("round", 2, false, true, ProgramError::Immutable),
+            ("degen_claim", 3, false, true, ProgramError::Immutable),
+            ("oracle_queue", 5, false, true, ProgramError::Immutable),
+        ];
+        for (name, position, drop_signer, drop_writable, expected) in cases {
+            let mut accounts = request_degen_vrf_accounts();
+            if drop_signer {
+                accounts[position].set_signer(false);
+            }
+            if drop_writable {
+                accounts[position].set_writable(false);
+            }
+            assert_eq!(run_request_degen_vrf(&mut accounts), Err(expected), "{name}");
+        }
+    }
+
+    #[test]
+    fn request_degen_vrf_takes_candidate_window_from_trailing_degen_config() {
+        let (degen_cfg_pda, degen_cfg_data) = sample_degen_config();
+        let mut degen_cfg = DegenConfigView::read_from_account_data(&degen_cfg_data).unwrap();
+        degen_cfg.set_candidate_window(12);
+        let mut degen_cfg_data = degen_cfg_data.clone();
+        degen_cfg.write_to_account_data(&mut degen_cfg_data).unwrap();
+
+        let mut accounts: Vec<TestAccount> = request_degen_vrf_accounts().into();
+        accounts.push(TestAccount::new(degen_cfg_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &degen_cfg_data));
+        run_request_degen_vrf(&mut accounts).unwrap();
+        let claim = DegenClaimView::read_from_account_data(accounts[3].data()).unwrap();
+        assert_eq!(claim.candidate_window, 12);
+
+        let mut accounts: Vec<TestAccount> = request_degen_vrf_accounts().into();
+        accounts.push(TestAccount::new([6u8; 32], PROGRAM_ID, false, false, 1_000_000, &degen_cfg_data));
+        assert_eq!(run_request_degen_vrf(&mut accounts), Err(ProgramError::InvalidSeeds));
+    }
+
+    fn prepare_existing_degen_claim(data: &[u8]) -> Result<u8, ProgramError> {
+        let (degen_claim_pda, _) = sample_degen_claim();
+        let mut winner_account = TestAccount::new([9u8; 32], SYSTEM_PROGRAM_ID, true, true, 1_000_000_000, &[]);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, data);
+        let mut system_program_account = TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), SYSTEM_PROGRAM_ID, false, false, 0, &[]);
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("request_degen_vrf"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        prepare_degen_claim_pda_init_if_needed(
+            &degen_claim_account.view(),
+            &winner_account.view(),
+            &system_program_account.view(),
+            &PROGRAM_ID,
+            &ix,
+        )
+    }
+
+    #[test]
+    fn prepare_degen_claim_accepts_only_zeroed_or_initialized_data() {
+        let (_, initialized) = sample_degen_claim();
+        let bump = DegenClaimView::read_from_account_data(&initialized).unwrap().bump;
+
+        assert_eq!(prepare_existing_degen_claim(&[0u8; DEGEN_CLAIM_ACCOUNT_LEN]), Ok(bump));
+        assert_eq!(prepare_existing_degen_claim(&initialized), Ok(bump));
+
+        // Zeroed discriminator over a body that was partly written.
+        let mut corrupted = [0u8; DEGEN_CLAIM_ACCOUNT_LEN];
+        corrupted[72] = 81;
+        assert_eq!(prepare_existing_degen_claim(&corrupted), Err(ProgramError::InvalidAccountData));
+
+        let mut corrupted = initialized.clone();
+        corrupted[0] ^= 0xff;
+        assert_eq!(prepare_existing_degen_claim(&corrupted), Err(ProgramError::InvalidAccountData));
+    }
+
     #[test]
     fn degen_vrf_callback_runtime_sets_ready_state() {
         let (config_pda, config_data) = sample_config();
@@ -888,6 +1195,93 @@ mod tests {
         assert_eq!(claim.fallback_after_ts, 1_700_000_450);
     }
 
+    fn run_degen_vrf_callback(degen_cfg_owner: Address, degen_cfg_data: &[u8]) -> Result<DegenClaimView, ProgramError> {
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, mut round_data) = sample_round();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round_data, 1).unwrap();
+        let (degen_claim_pda, degen_claim_data) = ready_degen_claim();
+        let (degen_cfg_pda, _) = Address::find_program_address(&[SEED_DEGEN_CFG], &PROGRAM_ID);
+
+        let mut vrf_identity = TestAccount::new(VRF_PROGRAM_IDENTITY.to_bytes(), Address::new_from_array([0u8; 32]), true, false, 0, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, 1_000_000, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &round_data);
+        let mut degen_claim_account = TestAccount::new(degen_claim_pda.to_bytes(), PROGRAM_ID, false, true, 1_000_000, &degen_claim_data);
+        let mut degen_cfg_account = TestAccount::new(degen_cfg_pda.to_bytes(), degen_cfg_owner, false, false, 0, degen_cfg_data);
+
+        let views = [
+            vrf_identity.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            degen_cfg_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("degen_vrf_callback"));
+        ix.extend_from_slice(&[7u8; 32]);
+
+        process_instruction(&PROGRAM_ID, &views, &ix)?;
+        Ok(DegenClaimView::read_from_account_data(degen_claim_account.data()).unwrap())
+    }
+
+    #[test]
+    fn degen_vrf_callback_uses_default_timeout_without_degen_config() {
+        let claim = run_degen_vrf_callback(SYSTEM_PROGRAM_ID, &[]).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_VRF_READY);
+        assert_eq!(claim.fallback_after_ts, 1_700_000_000 + i64::from(DEFAULT_DEGEN_FALLBACK_TIMEOUT_SEC));
+
+        let (_, degen_cfg_data) = sample_degen_config();
+        let claim = run_degen_vrf_callback(PROGRAM_ID, &degen_cfg_data).unwrap();
+        assert_eq!(claim.fallback_after_ts, 1_700_000_450);
+    }
+
+    #[test]
+    fn degen_vrf_callback_rejects_empty_degen_config_not_owned_by_system_program() {
+        let err = run_degen_vrf_callback(Address::new_from_array([5u8; 32]), &[]).unwrap_err();
+        assert_eq!(err, ProgramError::IncorrectProgramId);
+    }
+
+    #[test]
+    fn degen_vrf_callback_accounts_match_callback_handler() {
+        let (config_pda, config_data) = sample_config();
+        let (round_pda, mut round_data) = sample_round();
+        RoundLifecycleView::write_degen_mode_status_to_account_data(&mut round_data, 1).unwrap();
+        let (degen_claim_pda, degen_claim_data) = ready_degen_claim();
+        let (degen_cfg_pda, degen_cfg_data) = sample_degen_config();
+
+        let metas = degen_vrf_callback_accounts(&config_pda, &round_pda, &degen_claim_pda, &degen_cfg_pda);
+        let expected = [config_pda, round_pda, degen_claim_pda, degen_cfg_pda].map(|address| address.to_bytes());
+        assert_eq!(metas.map(|meta| meta.pubkey), expected);
+        assert!(metas.iter().all(|meta| !meta.is_signer));
+
+        // Replay the callback with exactly the flags the request advertises.
+        let account_for = |meta: &SerializableAccountMetaCompat, data: &[u8]| {
+            TestAccount::new(meta.pubkey, PROGRAM_ID, meta.is_signer, meta.is_writable, 1_000_000, data)
+        };
+        let mut vrf_identity = TestAccount::new(VRF_PROGRAM_IDENTITY.to_bytes(), Address::new_from_array([0u8; 32]), true, false, 0, &[]);
+        let mut config_account = account_for(&metas[0], &config_data);
+        let mut round_account = account_for(&metas[1], &round_data);
+        let mut degen_claim_account = account_for(&metas[2], &degen_claim_data);
+        let mut degen_cfg_account = account_for(&metas[3], &degen_cfg_data);
+
+        let views = [
+            vrf_identity.view(),
+            config_account.view(),
+            round_account.view(),
+            degen_claim_account.view(),
+            degen_cfg_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("degen_vrf_callback"));
+        ix.extend_from_slice(&[7u8; 32]);
+
+        process_instruction(&PROGRAM_ID, &views, &ix).unwrap();
+
+        let claim = DegenClaimView::read_from_account_data(degen_claim_account.data()).unwrap();
+        assert_eq!(claim.status, DEGEN_CLAIM_STATUS_VRF_READY);
+    }
+
     #[test]
     fn rejects_wrong_degen_claim_pda() {
         let winner = Address::new_from_array([9u8; 32]);
@@ -925,4 +1319,90 @@ mod tests {
         let err = process_instruction(&PROGRAM_ID, &views, &ix).unwrap_err();
         assert_eq!(err, ProgramError::InvalidSeeds);
     }
+
+    #[test]
+    fn request_randomness_ix_data_matches_switchboard_layout() {
+        let round_id = 81u64;
+        let winner = [9u8; 32];
+        let callback_accounts = [
+            SerializableAccountMetaCompat { pubkey: [1u8; 32], is_signer: false, is_writable: false },
+            SerializableAccountMetaCompat { pubkey: [2u8; 32], is_signer: false, is_writable: true },
+            SerializableAccountMetaCompat { pubkey: [3u8; 32], is_signer: false, is_writable: true },
+            SerializableAccountMetaCompat { pubkey: [4u8; 32], is_signer: false, is_writable: false },
+        ];
+        let callback_discriminator = instruction_discriminator("degen_vrf_callback");
+
+        let data = build_request_randomness_ix_data(
+            &PROGRAM_ID,
+            winner,
+            round_id.to_le_bytes(),
+            &callback_discriminator,
+            &callback_accounts,
+        );
+
+        assert_eq!(data.len(), 8 + 32 + 32 + 4 + 8 + 4 + (4 * 34) + 4);
+        assert_eq!(&data[..8], &[3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&data[8..40], &derive_caller_seed(round_id, &winner));
+        assert_eq!(&data[40..72], PROGRAM_ID.as_array());
+        assert_eq!(&data[72..76], &8u32.to_le_bytes());
+        assert_eq!(&data[76..84], &callback_discriminator);
+        assert_eq!(&data[84..88], &4u32.to_le_bytes());
+        let mut offset = 88;
+        for meta in &callback_accounts {
+            assert_eq!(&data[offset..offset + 32], &meta.pubkey);
+            assert_eq!(data[offset + 32], u8::from(meta.is_signer));
+            assert_eq!(data[offset + 33], u8::from(meta.is_writable));
+            offset += 34;
+        }
+        assert_eq!(&data[offset..], &0u32.to_le_bytes());
+    }
+
+    #[test]
+    fn request_randomness_ix_data_round_trips_through_parser() {
+        let winner = [9u8; 32];
+        let callback_accounts = [
+            SerializableAccountMetaCompat { pubkey: [1u8; 32], is_signer: false, is_writable: false },
+            SerializableAccountMetaCompat { pubkey: [2u8; 32], is_signer: false, is_writable: true },
+            SerializableAccountMetaCompat { pubkey: [3u8; 32], is_signer: true, is_writable: true },
+        ];
+        let callback_discriminator = instruction_discriminator("degen_vrf_callback");
+
+        let data = build_request_randomness_ix_data(
+            &PROGRAM_ID,
+            winner,
+            81u64.to_le_bytes(),
+            &callback_discriminator,
+            &callback_accounts,
+        );
+        let parsed = parse_request_randomness_ix_data(&data).unwrap();
+
+        assert_eq!(parsed.discriminator, [3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(parsed.caller_seed, derive_caller_seed(81, &winner));
+        assert_eq!(parsed.callback_program_id, PROGRAM_ID.to_bytes());
+        assert_eq!(parsed.callback_discriminator, callback_discriminator);
+        assert_eq!(parsed.callback_accounts, callback_accounts);
+        assert_eq!(parsed.trailing, 0);
+
+        assert!(parse_request_randomness_ix_data(&data[..data.len() - 1]).is_err());
+        let mut extended = data.clone();
+        extended.push(0);
+        assert!(parse_request_randomness_ix_data(&extended).is_err());
+    }
+
+    #[test]
+    fn caller_seed_hashes_full_winner_key() {
+        let mut winner_a = [9u8; 32];
+        let mut winner_b = [9u8; 32];
+        winner_a[31] = 1;
+        winner_b[31] = 2;
+        assert_eq!(winner_a[..24], winner_b[..24]);
+
+        assert_ne!(derive_caller_seed(81, &winner_a), derive_caller_seed(81, &winner_b));
+        assert_ne!(derive_caller_seed(81, &winner_a), derive_caller_seed(82, &winner_a));
+
+        let mut preimage = 81u64.to_le_bytes().to_vec();
+        preimage.extend_from_slice(&winner_a);
+        let expected: [u8; 32] = <sha2::Sha256 as sha2::Digest>::digest(&preimage).into();
+        assert_eq!(derive_caller_seed(81, &winner_a), expected);
+    }
 }
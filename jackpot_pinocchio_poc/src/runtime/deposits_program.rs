@@ -22,17 +22,19 @@ use crate::{
 use solana_address::address;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::{
+        AccountInitState, PARTICIPANT_DISCRIMINATOR, account_init_state, instruction_discriminator,
+    },
+    instruction_layouts::RoundId,
+    handlers::degen_common::map_layout_err,
     legacy_layouts::{
-        CONFIG_ACCOUNT_LEN, PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
-        ConfigView, ParticipantView,
+        AccountLayout, PARTICIPANT_ACCOUNT_LEN, ConfigView, ParticipantView, RoundLifecycleView,
     },
+    pdas::{SEED_PARTICIPANT, derive_config_pda, derive_round_pda, require_single_participant_pda},
     processors::deposits::DepositProcessor,
+    runtime::token_programs::read_token_core,
 };
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
-const SEED_PARTICIPANT: &[u8] = b"p";
 const SYSTEM_PROGRAM_ID: Address = address!("11111111111111111111111111111111");
 
 #[cfg(test)]
@@ -65,11 +67,11 @@ fn process_deposit_any(
 
     require_signer(user)?;
     require_writable(user)?;
-    let _config = require_config_pda(config, program_id)?;
+    let config_view = require_config_pda(config, program_id)?;
+    config_view.require_not_paused().map_err(map_layout_err)?;
     require_writable(round)?;
-    let round_id = crate::instruction_layouts::DepositAnyArgsCompat::parse(instruction_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?
-        .round_id;
+    let round_id =
+        crate::instruction_layouts::DepositAnyArgsCompat::parse(instruction_data)?.round_id;
     require_round_pda(round, program_id, round_id)?;
     require_writable(participant)?;
     require_writable(user_usdc_ata)?;
@@ -207,33 +209,24 @@ fn require_address(account: &AccountView, address: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
     Ok(config)
 }
 
-fn require_round_pda(account: &AccountView, program_id: &Address, round_id: u64) -> ProgramResult {
+fn require_round_pda(account: &AccountView, program_id: &Address, round_id: RoundId) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let (expected_address, _) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn prepare_participant_pda_init_if_needed(
@@ -243,11 +236,7 @@ fn prepare_participant_pda_init_if_needed(
     system_program: &AccountView,
     program_id: &Address,
 ) -> Result<u8, ProgramError> {
-    let (expected_address, bump) =
-        Address::find_program_address(&[SEED_PARTICIPANT, round.address().as_ref(), user.address().as_ref()], program_id);
-    if account.address() != &expected_address {
-        return Err(ProgramError::InvalidSeeds);
-    }
+    let bump = require_single_participant_pda(program_id, round.address(), user.address(), account.address())?;
 
     if !account.owned_by(program_id) {
         require_address(system_program, &SYSTEM_PROGRAM_ID)?;
@@ -259,15 +248,16 @@ fn prepare_participant_pda_init_if_needed(
     if data.len() != PARTICIPANT_ACCOUNT_LEN {
         return Err(ProgramError::InvalidAccountData);
     }
-    let is_zeroed = data.iter().all(|byte| *byte == 0);
-    if !is_zeroed && data.get(..8) != Some(&account_discriminator("Participant")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    if !is_zeroed {
-        let participant = ParticipantView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-        if participant.bump != bump {
-            return Err(ProgramError::InvalidSeeds);
+    match account_init_state(&data, &PARTICIPANT_DISCRIMINATOR) {
+        Some(AccountInitState::Uninitialized) => {}
+        Some(AccountInitState::Initialized) => {
+            let participant =
+                ParticipantView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+            if participant.bump != bump {
+                return Err(ProgramError::InvalidSeeds);
+            }
         }
+        None => return Err(ProgramError::InvalidAccountData),
     }
     Ok(bump)
 }
@@ -323,12 +313,7 @@ fn require_token_program(account: &AccountView) -> ProgramResult {
 
 fn require_token_account_owned_by_program(account: &AccountView, token_program: &AccountView) -> ProgramResult {
     require_owned_by(account, token_program.address())?;
-    let data = account.try_borrow()?;
-    if data.len() < TOKEN_ACCOUNT_CORE_LEN {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    crate::legacy_layouts::TokenAccountCoreView::read_from_account_data(&data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    read_token_core(account)?;
     Ok(())
 }
 
@@ -346,6 +331,7 @@ mod tests {
             CONFIG_ACCOUNT_LEN, PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ROUND_STATUS_OPEN,
             TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
         },
+        pdas::{SEED_CFG, SEED_ROUND},
     };
 
     use super::*;
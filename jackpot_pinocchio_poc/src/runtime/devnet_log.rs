@@ -0,0 +1,76 @@
+//! Devnet-only dispatch logging for integration debugging.
+//!
+//! The top-level router logs which instruction it is about to dispatch and
+//! the compute units left at that point, or a catch-all line for unknown
+//! discriminators. Release builds don't compile this module.
+
+use crate::anchor_compat::{ANCHOR_DISCRIMINATOR_LEN, instruction_discriminator};
+
+/// Every instruction the runtime routers dispatch in a devnet build.
+const INSTRUCTION_NAMES: [&str; 30] = [
+    "upsert_degen_config", "init_config", "update_config",
+    "transfer_admin", "set_treasury_usdc_ata",
+    "lock_round", "start_round", "admin_force_cancel",
+    "deposit_any", "cancel_round", "claim_refund",
+    "claim", "auto_claim", "close_participant", "close_round",
+    "request_vrf", "vrf_callback", "settle_with_slot_hash",
+    "request_degen_vrf", "degen_vrf_callback",
+    "begin_degen_execution", "claim_degen_fallback",
+    "auto_claim_degen_fallback",
+    "claim_degen", "finalize_degen_success",
+    "recover_stuck_execution", "update_degen_timeout",
+    "set_vrf_queue", "set_fee_split", "mock_settle",
+];
+
+/// Instruction name for the discriminator leading `instruction_data`.
+pub(crate) fn instruction_name(instruction_data: &[u8]) -> Option<&'static str> {
+    let discriminator = instruction_data.get(..ANCHOR_DISCRIMINATOR_LEN)?;
+    INSTRUCTION_NAMES
+        .into_iter()
+        .find(|name| instruction_discriminator(name) == discriminator)
+}
+
+pub(crate) fn log_dispatch(instruction_data: &[u8]) {
+    match instruction_name(instruction_data) {
+        Some(name) => {
+            log("dispatch:");
+            log(name);
+        }
+        None => log("dispatch: unmatched discriminator"),
+    }
+    log_compute_units();
+}
+
+/// Syscalls only exist on the SBF target; host builds compile these to
+/// no-ops, as in `events`.
+#[cfg(any(target_os = "solana", target_arch = "bpf"))]
+fn log(message: &str) {
+    unsafe { pinocchio::syscalls::sol_log_(message.as_ptr(), message.len() as u64) };
+}
+
+#[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+fn log(_message: &str) {}
+
+#[cfg(any(target_os = "solana", target_arch = "bpf"))]
+fn log_compute_units() {
+    unsafe { pinocchio::syscalls::sol_log_compute_units_() };
+}
+
+#[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+fn log_compute_units() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_every_dispatched_instruction_name() {
+        for name in INSTRUCTION_NAMES {
+            let mut ix = instruction_discriminator(name).to_vec();
+            ix.extend_from_slice(&81u64.to_le_bytes());
+            assert_eq!(instruction_name(&ix), Some(name));
+        }
+        assert_eq!(instruction_name(&instruction_discriminator("unknown_ix")), None);
+        assert_eq!(instruction_name(&[1, 2, 3]), None);
+    }
+}
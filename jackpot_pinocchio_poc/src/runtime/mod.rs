@@ -5,6 +5,13 @@ pub mod refunds_program;
 pub mod claims_program;
 pub mod round_lifecycle_program;
 pub mod terminal_cleanup_program;
+pub mod token_programs;
 pub mod vrf_program;
 pub mod degen_vrf_program;
 pub mod degen_execution_program;
+#[cfg(feature = "devnet")]
+mod devnet_log;
+#[cfg(test)]
+pub(crate) mod token_simulation;
+#[cfg(test)]
+pub(crate) mod test_support;
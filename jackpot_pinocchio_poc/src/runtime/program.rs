@@ -18,6 +18,9 @@ pub fn process_instruction(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    #[cfg(feature = "devnet")]
+    super::devnet_log::log_dispatch(instruction_data);
+
     match admin_config_program::process_instruction(program_id, accounts, instruction_data) {
         Ok(()) => Ok(()),
         Err(ProgramError::InvalidInstructionData) => {
@@ -88,16 +91,15 @@ mod tests {
         },
     };
 
-    use crate::anchor_compat::instruction_discriminator;
+    use crate::{
+        anchor_compat::instruction_discriminator,
+        pdas::{SEED_CFG, SEED_PARTICIPANT, SEED_ROUND},
+    };
 
     use super::process_instruction;
 
     const PROGRAM_ID: Address = Address::new_from_array([7u8; 32]);
 
-    const SEED_ROUND: &[u8] = b"round";
-    const SEED_PARTICIPANT: &[u8] = b"p";
-    const SEED_CFG: &[u8] = b"cfg";
-
     struct TestAccount {
         backing: Vec<u64>,
     }
@@ -241,8 +243,9 @@ mod tests {
         assert_eq!(err, ProgramError::InvalidInstructionData);
     }
 
-    #[test]
-    fn routes_terminal_cleanup_slice() {
+    /// Routes `close_participant` for round 81 and returns the user's and
+    /// the participant's resulting lamports and the participant's data length.
+    fn close_participant_via_router() -> (u64, u64, usize) {
         let payer = Address::new_from_array([9u8; 32]);
         let user = Address::new_from_array([5u8; 32]);
         let (round_pda, round_data) = sample_round(81);
@@ -288,9 +291,25 @@ mod tests {
 
         process_instruction(&PROGRAM_ID, &views, &ix).unwrap();
 
-        assert_eq!(user_account.lamports(), 722_000);
-        assert_eq!(participant_account.lamports(), 0);
-        assert_eq!(participant_account.data_len(), 0);
+        (user_account.lamports(), participant_account.lamports(), participant_account.data_len())
+    }
+
+    #[test]
+    fn routes_terminal_cleanup_slice() {
+        assert_eq!(close_participant_via_router(), (722_000, 0, 0));
+    }
+
+    #[cfg(feature = "devnet")]
+    #[test]
+    fn devnet_dispatch_logging_keeps_routing() {
+        let mut ix = instruction_discriminator("close_participant").to_vec();
+        ix.extend_from_slice(&81u64.to_le_bytes());
+        assert_eq!(super::super::devnet_log::instruction_name(&ix), Some("close_participant"));
+        assert_eq!(close_participant_via_router(), (722_000, 0, 0));
+
+        let unknown = instruction_discriminator("unknown_ix");
+        assert_eq!(super::super::devnet_log::instruction_name(&unknown), None);
+        assert_eq!(process_instruction(&PROGRAM_ID, &[], &unknown).unwrap_err(), ProgramError::InvalidInstructionData);
     }
 
     #[test]
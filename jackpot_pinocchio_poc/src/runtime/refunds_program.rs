@@ -9,22 +9,18 @@ use pinocchio::cpi::{Seed, Signer};
 use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
-    legacy_layouts::{CONFIG_ACCOUNT_LEN, PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ConfigView, ParticipantView},
+    anchor_compat::instruction_discriminator,
+    legacy_layouts::{AccountLayout, ConfigView, RoundLifecycleView},
+    pdas::{SEED_ROUND, derive_config_pda, derive_round_pda, require_participant_pda},
     processors::refunds::RefundProcessor,
 };
 
-#[cfg(not(test))]
-use crate::legacy_layouts::RoundLifecycleView;
 #[cfg(test)]
 use crate::{
     errors::JackpotCompatError,
     legacy_layouts::TokenAccountWithAmountView,
 };
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
-const SEED_PARTICIPANT: &[u8] = b"p";
 
 pub fn process_instruction(
     program_id: &Address,
@@ -61,7 +57,7 @@ fn process_cancel_round(
     require_writable(user_usdc_ata)?;
     let config_view = require_config_pda(config, program_id)?;
     require_round_pda(round, program_id, instruction_data, "cancel_round")?;
-    require_participant_pda(participant, user, round, program_id)?;
+    require_participant_pda(participant, program_id, round.address(), user.address())?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
     require_token_account_owned_by_program(user_usdc_ata, token_program)?;
@@ -104,7 +100,7 @@ fn process_claim_refund(
     require_writable(user_usdc_ata)?;
     let config_view = require_config_pda(config, program_id)?;
     require_round_pda(round, program_id, instruction_data, "claim_refund")?;
-    require_participant_pda(participant, user, round, program_id)?;
+    require_participant_pda(participant, program_id, round.address(), user.address())?;
     require_token_program(token_program)?;
     require_token_account_owned_by_program(vault, token_program)?;
     require_token_account_owned_by_program(user_usdc_ata, token_program)?;
@@ -222,15 +218,11 @@ fn require_owned_by(account: &AccountView, owner: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -244,43 +236,12 @@ fn require_round_pda(
     ix_name: &str,
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected_address, _) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)?;
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
-}
-
-fn require_participant_pda(
-    account: &AccountView,
-    user: &AccountView,
-    round: &AccountView,
-    program_id: &Address,
-) -> ProgramResult {
-    require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(
-        &[SEED_PARTICIPANT, round.address().as_ref(), user.address().as_ref()],
-        program_id,
-    );
-    if account.address() != &expected_address {
-        return Err(ProgramError::InvalidSeeds);
-    }
-    let data = account.try_borrow()?;
-    if data.len() != PARTICIPANT_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Participant")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let participant = ParticipantView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    if participant.bump != expected_bump {
-        return Err(ProgramError::InvalidSeeds);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn require_token_program(account: &AccountView) -> ProgramResult {
@@ -304,6 +265,7 @@ mod tests {
             CONFIG_ACCOUNT_LEN, PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
             ROUND_STATUS_CANCELLED, ROUND_STATUS_OPEN, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
         },
+        pdas::{SEED_CFG, SEED_PARTICIPANT},
     };
 
     use super::*;
@@ -524,4 +486,86 @@ mod tests {
         let user_ata_view = TokenAccountWithAmountView::read_from_account_data(user_ata_account.data()).unwrap();
         assert_eq!(user_ata_view.amount, 1_000_000);
     }
+
+    #[test]
+    fn claim_refund_rejects_second_refund() {
+        let user = Address::new_from_array([5u8; 32]);
+        let usdc_mint = Address::new_from_array([9u8; 32]);
+        let vault = Address::new_from_array([8u8; 32]);
+        let (config_pda, config_data) = sample_config(usdc_mint);
+        let (round_pda, round_data) = sample_round(81, ROUND_STATUS_CANCELLED, vault, 1_000_000);
+        let (participant_pda, participant_data) = sample_participant(round_pda, user, 1_000_000);
+
+        let mut user_account = TestAccount::new(user.to_bytes(), Address::default(), true, false, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, false, &round_data);
+        let mut participant_account = TestAccount::new(participant_pda.to_bytes(), PROGRAM_ID, false, true, &participant_data);
+        let mut vault_account = TestAccount::new(vault.to_bytes(), pinocchio_token::ID, false, true, &token_account(usdc_mint, round_pda, 2_000_000));
+        let mut user_ata_account = TestAccount::new(Address::new_from_array([6u8; 32]).to_bytes(), pinocchio_token::ID, false, true, &token_account(usdc_mint, user, 0));
+        let mut token_program_account = TestAccount::new(pinocchio_token::ID.to_bytes(), Address::default(), false, false, &[]);
+
+        let views = [
+            user_account.view(),
+            config_account.view(),
+            round_account.view(),
+            participant_account.view(),
+            vault_account.view(),
+            user_ata_account.view(),
+            token_program_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_refund"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        process_instruction(&PROGRAM_ID, &views, &ix).unwrap();
+        let err = process_instruction(&PROGRAM_ID, &views, &ix).unwrap_err();
+        assert_eq!(err, JackpotCompatError::NoDepositToRefund.into());
+
+        let vault_view = TokenAccountWithAmountView::read_from_account_data(vault_account.data()).unwrap();
+        assert_eq!(vault_view.amount, 1_000_000);
+        let user_ata_view = TokenAccountWithAmountView::read_from_account_data(user_ata_account.data()).unwrap();
+        assert_eq!(user_ata_view.amount, 1_000_000);
+    }
+
+    #[test]
+    fn claim_refund_rejects_participant_of_another_signer() {
+        let owner = Address::new_from_array([5u8; 32]);
+        let attacker = Address::new_from_array([4u8; 32]);
+        let usdc_mint = Address::new_from_array([9u8; 32]);
+        let vault = Address::new_from_array([8u8; 32]);
+        let (config_pda, config_data) = sample_config(usdc_mint);
+        let (round_pda, round_data) = sample_round(81, ROUND_STATUS_CANCELLED, vault, 1_000_000);
+        let (participant_pda, participant_data) = sample_participant(round_pda, owner, 1_000_000);
+
+        let mut attacker_account = TestAccount::new(attacker.to_bytes(), Address::default(), true, false, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, false, &round_data);
+        let mut participant_account = TestAccount::new(participant_pda.to_bytes(), PROGRAM_ID, false, true, &participant_data);
+        let mut vault_account = TestAccount::new(vault.to_bytes(), pinocchio_token::ID, false, true, &token_account(usdc_mint, round_pda, 1_000_000));
+        let mut attacker_ata_account = TestAccount::new(Address::new_from_array([6u8; 32]).to_bytes(), pinocchio_token::ID, false, true, &token_account(usdc_mint, attacker, 0));
+        let mut token_program_account = TestAccount::new(pinocchio_token::ID.to_bytes(), Address::default(), false, false, &[]);
+
+        let views = [
+            attacker_account.view(),
+            config_account.view(),
+            round_account.view(),
+            participant_account.view(),
+            vault_account.view(),
+            attacker_ata_account.view(),
+            token_program_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("claim_refund"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        let err = process_instruction(&PROGRAM_ID, &views, &ix).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidSeeds);
+
+        let participant_view = ParticipantView::read_from_account_data(participant_account.data()).unwrap();
+        assert_eq!(participant_view.usdc_total, 1_000_000);
+        let vault_view = TokenAccountWithAmountView::read_from_account_data(vault_account.data()).unwrap();
+        assert_eq!(vault_view.amount, 1_000_000);
+    }
 }
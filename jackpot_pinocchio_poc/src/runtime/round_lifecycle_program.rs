@@ -13,18 +13,20 @@ use pinocchio_system::create_account_with_minimum_balance_signed;
 use core::sync::atomic::{AtomicI64, Ordering};
 #[cfg(not(test))]
 use pinocchio::sysvars::{Sysvar, clock::Clock};
+#[cfg(test)]
+use crate::legacy_layouts::TOKEN_ACCOUNT_CORE_LEN;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::instruction_discriminator,
+    instruction_layouts::RoundId,
     legacy_layouts::{
-        CONFIG_ACCOUNT_LEN, ConfigView, ROUND_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN,
-        TokenAccountCoreView,
+        AccountLayout, ConfigView, ROUND_ACCOUNT_LEN, RoundLifecycleView,
     },
-    processors::round_lifecycle::RoundLifecycleProcessor,
+    pdas::{SEED_ROUND, derive_config_pda, derive_round_pda, require_vault_ata},
+    processors::round_lifecycle::{RoundLifecycleProcessor, StartRoundProcessor},
+    runtime::token_programs::read_token_core,
 };
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
 const SYSTEM_PROGRAM_ID: Address = solana_address::address!("11111111111111111111111111111111");
 
 #[cfg(test)]
@@ -73,13 +75,8 @@ fn process_lock_round(
 
     RoundLifecycleProcessor {
         caller_pubkey,
-        round_pubkey: None,
-        round_bump: None,
-        vault_pubkey: None,
-        usdc_mint_pubkey: None,
         config_account_data: &config_data,
         round_account_data: &mut round_data[..],
-        vault_account_data: None,
         current_unix_timestamp,
     }
     .process(instruction_data)
@@ -108,9 +105,11 @@ fn process_start_round(
 
     require_signer(payer)?;
     let config_view = require_config_pda(config, program_id)?;
+    // `start_round` advances the config's `last_round_id`, so unlike the
+    // Anchor `StartRound` context the config meta must be writable.
+    require_writable(config)?;
     require_writable(round)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "start_round")
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, "start_round")?;
     let round_bump = prepare_round_pda_for_start(round, payer, system_program, program_id, round_id)?;
     require_writable(vault_usdc_ata)?;
     require_associated_token_program(associated_token_program)?;
@@ -129,20 +128,18 @@ fn process_start_round(
     )?;
     require_token_account_owned_by_program(vault_usdc_ata, token_program)?;
 
-    let caller_pubkey = payer.address().to_bytes();
-    let config_data = config.try_borrow()?;
+    let mut config_data = config.try_borrow_mut()?;
     let vault_data = vault_usdc_ata.try_borrow()?;
     let mut round_data = round.try_borrow_mut()?;
 
-    RoundLifecycleProcessor {
-        caller_pubkey,
-        round_pubkey: Some(round.address().to_bytes()),
-        round_bump: Some(round_bump),
-        vault_pubkey: Some(vault_usdc_ata.address().to_bytes()),
-        usdc_mint_pubkey: Some(usdc_mint.address().to_bytes()),
-        config_account_data: &config_data,
+    StartRoundProcessor {
+        round_pubkey: round.address().to_bytes(),
+        round_bump,
+        vault_pubkey: vault_usdc_ata.address().to_bytes(),
+        usdc_mint_pubkey: usdc_mint.address().to_bytes(),
+        config_account_data: &mut config_data[..],
         round_account_data: &mut round_data[..],
-        vault_account_data: Some(&vault_data),
+        vault_account_data: &vault_data,
         current_unix_timestamp: current_unix_timestamp()?,
     }
     .process(instruction_data)
@@ -168,13 +165,8 @@ fn process_admin_force_cancel(
 
     RoundLifecycleProcessor {
         caller_pubkey,
-        round_pubkey: None,
-        round_bump: None,
-        vault_pubkey: None,
-        usdc_mint_pubkey: None,
         config_account_data: &config_data,
         round_account_data: &mut round_data[..],
-        vault_account_data: None,
         current_unix_timestamp: 0,
     }
     .process(instruction_data)
@@ -227,17 +219,12 @@ fn require_address(account: &AccountView, address: &Address) -> ProgramResult {
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
 
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -253,20 +240,13 @@ fn require_round_pda(
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
 
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected_address, _) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)?;
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn prepare_round_pda_for_start(
@@ -274,12 +254,11 @@ fn prepare_round_pda_for_start(
     payer: &AccountView,
     system_program: &AccountView,
     program_id: &Address,
-    round_id: u64,
+    round_id: RoundId,
 ) -> Result<u8, ProgramError> {
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
 
-    let (expected_address, bump) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let (expected_address, bump) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -309,7 +288,13 @@ fn prepare_vault_ata_for_start(
     require_associated_token_program(associated_token_program)?;
     require_token_program(token_program)?;
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
-    require_associated_token_address(vault_usdc_ata, round, usdc_mint, token_program)?;
+    require_vault_ata(
+        vault_usdc_ata.address(),
+        round.address(),
+        token_program.address(),
+        usdc_mint.address(),
+        associated_token_program.address(),
+    )?;
 
     if !vault_usdc_ata.owned_by(token_program.address()) {
         require_owned_by(vault_usdc_ata, &SYSTEM_PROGRAM_ID)?;
@@ -324,11 +309,7 @@ fn prepare_vault_ata_for_start(
         )?;
     }
 
-    let vault = {
-        let data = vault_usdc_ata.try_borrow()?;
-        TokenAccountCoreView::read_from_account_data(&data)
-            .map_err(|_| ProgramError::InvalidAccountData)?
-    };
+    let vault = read_token_core(vault_usdc_ata)?;
     if vault.mint != usdc_mint.address().to_bytes() || vault.owner != round.address().to_bytes() {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -341,7 +322,7 @@ fn create_round_pda_account(
     account: &AccountView,
     payer: &AccountView,
     program_id: &Address,
-    round_id: u64,
+    round_id: RoundId,
     bump: u8,
 ) -> ProgramResult {
     let round_id_bytes = round_id.to_le_bytes();
@@ -367,7 +348,7 @@ fn create_round_pda_account(
     account: &AccountView,
     _payer: &AccountView,
     program_id: &Address,
-    _round_id: u64,
+    _round_id: RoundId,
     _bump: u8,
 ) -> ProgramResult {
     unsafe {
@@ -434,30 +415,9 @@ fn require_associated_token_program(account: &AccountView) -> ProgramResult {
     }
 }
 
-fn require_associated_token_address(
-    account: &AccountView,
-    wallet: &AccountView,
-    mint: &AccountView,
-    token_program: &AccountView,
-) -> ProgramResult {
-    let (expected_address, _) = Address::find_program_address(
-        &[wallet.address().as_ref(), token_program.address().as_ref(), mint.address().as_ref()],
-        &pinocchio_associated_token_account::ID,
-    );
-    if account.address() == &expected_address {
-        Ok(())
-    } else {
-        Err(ProgramError::InvalidSeeds)
-    }
-}
-
 fn require_token_account_owned_by_program(account: &AccountView, token_program: &AccountView) -> ProgramResult {
     require_owned_by(account, token_program.address())?;
-    let data = account.try_borrow()?;
-    if data.len() < TOKEN_ACCOUNT_CORE_LEN {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    TokenAccountCoreView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    read_token_core(account)?;
     Ok(())
 }
 
@@ -479,6 +439,7 @@ mod tests {
             TOKEN_ACCOUNT_CORE_LEN, ROUND_STATUS_CANCELLED, ROUND_STATUS_LOCKED,
             ROUND_STATUS_OPEN,
         },
+        pdas::SEED_CFG,
     };
 
     use super::*;
@@ -597,7 +558,7 @@ mod tests {
     #[test]
     fn entrypoint_routes_lock_round() {
         let _guard = TEST_GUARD.lock().unwrap();
-        TEST_UNIX_TIMESTAMP.store(130, Ordering::Relaxed);
+        TEST_UNIX_TIMESTAMP.store(145, Ordering::Relaxed);
 
         let caller = Address::new_from_array([9u8; 32]);
         let admin = Address::new_from_array([7u8; 32]);
@@ -641,8 +602,12 @@ mod tests {
 
         let mut payer_account =
             TestAccount::new(payer.to_bytes(), Address::new_from_array([0u8; 32]), true, true, &[]);
+        let mut config_data = sample_config(payer);
+        let mut config_view = ConfigView::read_from_account_data(&config_data).unwrap();
+        config_view.set_last_round_id(round_id - 1);
+        config_view.write_to_account_data(&mut config_data).unwrap();
         let mut config_account =
-            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &sample_config(payer));
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &config_data);
         let mut round_account = TestAccount::new_with_capacity(
             round_pda.to_bytes(),
             system_program,
@@ -699,6 +664,150 @@ mod tests {
             RoundLifecycleView::read_vault_pubkey_from_account_data(round_account.data()).unwrap(),
             vault_ata.to_bytes(),
         );
+        assert_eq!(ConfigView::read_from_account_data(config_account.data()).unwrap().last_round_id(), round_id);
+    }
+
+    #[test]
+    fn entrypoint_rejects_start_round_when_paused() {
+        let _guard = TEST_GUARD.lock().unwrap();
+        TEST_UNIX_TIMESTAMP.store(777, Ordering::Relaxed);
+
+        let payer = Address::new_from_array([9u8; 32]);
+        let usdc_mint = Address::new_from_array([2u8; 32]);
+        let (config_pda, _) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let round_id = 81u64;
+        let (round_pda, _) =
+            Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], &PROGRAM_ID);
+        let token_program = pinocchio_token::ID;
+        let associated_token_program = pinocchio_associated_token_account::ID;
+        let (vault_ata, _) = Address::find_program_address(
+            &[round_pda.as_ref(), token_program.as_ref(), usdc_mint.as_ref()],
+            &associated_token_program,
+        );
+        let system_program = SYSTEM_PROGRAM_ID;
+
+        let mut config_data = sample_config(payer);
+        let paused = ConfigView {
+            paused: true,
+            ..ConfigView::read_from_account_data(&config_data).unwrap()
+        };
+        paused.write_to_account_data(&mut config_data).unwrap();
+
+        let mut payer_account =
+            TestAccount::new(payer.to_bytes(), Address::new_from_array([0u8; 32]), true, true, &[]);
+        let mut config_account =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &config_data);
+        let mut round_account = TestAccount::new_with_capacity(
+            round_pda.to_bytes(),
+            system_program,
+            false,
+            true,
+            &[],
+            ROUND_ACCOUNT_LEN,
+        );
+        let mut vault_account = TestAccount::new_with_capacity(
+            vault_ata.to_bytes(),
+            system_program,
+            false,
+            true,
+            &[],
+            TOKEN_ACCOUNT_CORE_LEN,
+        );
+        let mut mint_account =
+            TestAccount::new(usdc_mint.to_bytes(), token_program, false, false, &[]);
+        let mut associated_token_program_account = TestAccount::new(
+            associated_token_program.to_bytes(),
+            Address::new_from_array([0u8; 32]),
+            false,
+            false,
+            &[],
+        );
+        let mut token_program_account =
+            TestAccount::new(token_program.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+        let mut system_program_account =
+            TestAccount::new(system_program.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+
+        let views = [
+            payer_account.view(),
+            config_account.view(),
+            round_account.view(),
+            vault_account.view(),
+            mint_account.view(),
+            associated_token_program_account.view(),
+            token_program_account.view(),
+            system_program_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("start_round"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+
+        let err = process_instruction(&PROGRAM_ID, &views, &ix).unwrap_err();
+        assert_eq!(err, crate::errors::JackpotCompatError::Paused.into());
+    }
+
+    #[test]
+    fn entrypoint_rejects_start_round_zero() {
+        let _guard = TEST_GUARD.lock().unwrap();
+        TEST_UNIX_TIMESTAMP.store(777, Ordering::Relaxed);
+
+        let payer = Address::new_from_array([9u8; 32]);
+        let usdc_mint = Address::new_from_array([2u8; 32]);
+        let (config_pda, _) = Address::find_program_address(&[SEED_CFG], &PROGRAM_ID);
+        let round_id = 0u64;
+        let (round_pda, _) =
+            Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], &PROGRAM_ID);
+        let token_program = pinocchio_token::ID;
+        let associated_token_program = pinocchio_associated_token_account::ID;
+        let (vault_ata, _) = Address::find_program_address(
+            &[round_pda.as_ref(), token_program.as_ref(), usdc_mint.as_ref()],
+            &associated_token_program,
+        );
+        let system_program = SYSTEM_PROGRAM_ID;
+        let zero = Address::new_from_array([0u8; 32]);
+
+        let mut payer_account = TestAccount::new(payer.to_bytes(), zero, true, true, &[]);
+        let mut config_account =
+            TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, true, &sample_config(payer));
+        let mut round_account = TestAccount::new_with_capacity(
+            round_pda.to_bytes(),
+            system_program,
+            false,
+            true,
+            &[],
+            ROUND_ACCOUNT_LEN,
+        );
+        let mut vault_account = TestAccount::new_with_capacity(
+            vault_ata.to_bytes(),
+            system_program,
+            false,
+            true,
+            &[],
+            TOKEN_ACCOUNT_CORE_LEN,
+        );
+        let mut mint_account = TestAccount::new(usdc_mint.to_bytes(), token_program, false, false, &[]);
+        let mut associated_token_program_account =
+            TestAccount::new(associated_token_program.to_bytes(), zero, false, false, &[]);
+        let mut token_program_account = TestAccount::new(token_program.to_bytes(), zero, false, false, &[]);
+        let mut system_program_account = TestAccount::new(system_program.to_bytes(), zero, false, false, &[]);
+
+        let views = [
+            payer_account.view(),
+            config_account.view(),
+            round_account.view(),
+            vault_account.view(),
+            mint_account.view(),
+            associated_token_program_account.view(),
+            token_program_account.view(),
+            system_program_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("start_round"));
+        ix.extend_from_slice(&round_id.to_le_bytes());
+
+        let err = process_instruction(&PROGRAM_ID, &views, &ix).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
     }
 
     #[test]
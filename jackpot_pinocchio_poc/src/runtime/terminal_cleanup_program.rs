@@ -11,17 +11,13 @@ use pinocchio_token::instructions::CloseAccount as TokenCloseAccount;
 use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::instruction_discriminator,
     errors::JackpotCompatError,
-    legacy_layouts::{
-        PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ParticipantView, RoundLifecycleView,
-        TokenAccountWithAmountView,
-    },
+    legacy_layouts::{AccountLayout, RoundLifecycleView, TokenAccountWithAmountView},
+    pdas::{SEED_ROUND, derive_round_pda, require_participant_pda},
     processors::terminal_cleanup::TerminalCleanupProcessor,
 };
 
-const SEED_ROUND: &[u8] = b"round";
-const SEED_PARTICIPANT: &[u8] = b"p";
 
 pub fn process_instruction(
     program_id: &Address,
@@ -55,7 +51,7 @@ fn process_close_participant(
     require_writable(payer)?;
     require_writable(user)?;
     require_round_pda(round, program_id, instruction_data, "close_participant")?;
-    require_participant_pda(participant, user, round, program_id)?;
+    require_participant_pda(participant, program_id, round.address(), user.address())?;
     require_writable(participant)?;
 
     {
@@ -112,7 +108,7 @@ fn process_close_round(
             vault_account_data: Some(&vault_data),
         };
         processor.process(instruction_data)?;
-        RoundLifecycleView::read_from_account_data(&round_data)
+        RoundLifecycleView::read_from_account_data_exact(&round_data)
             .map_err(|_| ProgramError::InvalidAccountData)?
     };
 
@@ -306,52 +302,13 @@ fn require_round_pda(
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
 
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected_address, _) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)?;
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    Ok(())
-}
-
-fn require_participant_pda(
-    account: &AccountView,
-    user: &AccountView,
-    round: &AccountView,
-    program_id: &Address,
-) -> ProgramResult {
-    require_owned_by(account, program_id)?;
-
-    let (expected_address, expected_bump) = Address::find_program_address(
-        &[SEED_PARTICIPANT, round.address().as_ref(), user.address().as_ref()],
-        program_id,
-    );
-    if account.address() != &expected_address {
-        return Err(ProgramError::InvalidSeeds);
-    }
-
-    let data = account.try_borrow()?;
-    if data.len() != PARTICIPANT_ACCOUNT_LEN
-        || data.get(..8) != Some(&account_discriminator("Participant"))
-    {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let participant =
-        ParticipantView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    if participant.bump != expected_bump {
-        return Err(ProgramError::InvalidSeeds);
-    }
-
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn require_vault_token_account(
@@ -399,6 +356,7 @@ mod tests {
             ParticipantView, RoundLifecycleView, PARTICIPANT_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
             ROUND_STATUS_CANCELLED, ROUND_STATUS_CLAIMED, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
         },
+        pdas::SEED_PARTICIPANT,
     };
 
     use super::*;
@@ -584,6 +542,64 @@ mod tests {
         assert_eq!(participant_account.data_len(), 0);
     }
 
+    #[test]
+    fn entrypoint_closes_refunded_participant_in_cancelled_round() {
+        let payer = Address::new_from_array([9u8; 32]);
+        let user = Address::new_from_array([5u8; 32]);
+        let (round_pda, round_data) = sample_round(81, ROUND_STATUS_CANCELLED);
+        let (participant_pda, participant_data) = sample_participant(round_pda, user, 0, 0);
+
+        let mut payer_account = TestAccount::new(
+            payer.to_bytes(),
+            Address::new_from_array([0u8; 32]),
+            true,
+            true,
+            1_000_000_000,
+            &[],
+        );
+        let mut user_account = TestAccount::new(
+            user.to_bytes(),
+            Address::new_from_array([0u8; 32]),
+            false,
+            true,
+            500_000,
+            &[],
+        );
+        let mut round_account = TestAccount::new(
+            round_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            false,
+            1_000_000,
+            &round_data,
+        );
+        let mut participant_account = TestAccount::new(
+            participant_pda.to_bytes(),
+            PROGRAM_ID,
+            false,
+            true,
+            222_000,
+            &participant_data,
+        );
+
+        let views = [
+            payer_account.view(),
+            user_account.view(),
+            round_account.view(),
+            participant_account.view(),
+        ];
+
+        let mut ix = Vec::new();
+        ix.extend_from_slice(&instruction_discriminator("close_participant"));
+        ix.extend_from_slice(&81u64.to_le_bytes());
+
+        process_instruction(&PROGRAM_ID, &views, &ix).unwrap();
+
+        assert_eq!(user_account.lamports(), 722_000);
+        assert_eq!(participant_account.lamports(), 0);
+        assert_eq!(participant_account.data_len(), 0);
+    }
+
     #[test]
     fn entrypoint_rejects_nonempty_cancelled_participant() {
         let payer = Address::new_from_array([9u8; 32]);
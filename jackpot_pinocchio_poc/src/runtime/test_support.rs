@@ -0,0 +1,125 @@
+//! Test-only account fixture shared by the runtime modules.
+//!
+//! Lays out a `RuntimeAccount` header followed by its data in a `u64`-aligned
+//! buffer, the way the loader hands accounts to the entrypoint, so runtime
+//! functions can be driven with real `AccountView`s.
+
+use core::mem::size_of;
+
+use pinocchio::{
+    AccountView, Address,
+    account::{NOT_BORROWED, RuntimeAccount},
+};
+
+pub(crate) struct TestAccount {
+    backing: Vec<u64>,
+}
+
+impl TestAccount {
+    pub(crate) fn new(
+        address: [u8; 32],
+        owner: Address,
+        is_signer: bool,
+        is_writable: bool,
+        lamports: u64,
+        data: &[u8],
+    ) -> Self {
+        Self::new_with_capacity(address, owner, is_signer, is_writable, lamports, data, data.len())
+    }
+
+    /// Like [`TestAccount::new`], but reserves `capacity` data bytes so the
+    /// account can be resized up to that length (e.g. by a `CreateAccount`
+    /// stand-in).
+    pub(crate) fn new_with_capacity(
+        address: [u8; 32],
+        owner: Address,
+        is_signer: bool,
+        is_writable: bool,
+        lamports: u64,
+        data: &[u8],
+        capacity: usize,
+    ) -> Self {
+        let bytes = size_of::<RuntimeAccount>() + capacity.max(data.len());
+        let words = bytes.div_ceil(size_of::<u64>());
+        let mut backing = vec![0u64; words.max(1)];
+        let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
+
+        unsafe {
+            (*raw).borrow_state = NOT_BORROWED;
+            (*raw).is_signer = u8::from(is_signer);
+            (*raw).is_writable = u8::from(is_writable);
+            (*raw).executable = 0;
+            (*raw).resize_delta = 0;
+            (*raw).address = Address::from(address);
+            (*raw).owner = owner;
+            (*raw).lamports = lamports;
+            (*raw).data_len = data.len() as u64;
+
+            if !data.is_empty() {
+                let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
+                core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
+            }
+        }
+
+        Self { backing }
+    }
+
+    pub(crate) fn view(&mut self) -> AccountView {
+        unsafe { AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
+    }
+
+    /// Overrides the signer flag, e.g. to drop a required signature from an
+    /// otherwise valid fixture.
+    pub(crate) fn set_signer(&mut self, is_signer: bool) {
+        let raw = self.backing.as_mut_ptr() as *mut RuntimeAccount;
+        unsafe { (*raw).is_signer = u8::from(is_signer) };
+    }
+
+    /// Overrides the writable flag, e.g. to pass a required-writable account
+    /// as read-only.
+    pub(crate) fn set_writable(&mut self, is_writable: bool) {
+        let raw = self.backing.as_mut_ptr() as *mut RuntimeAccount;
+        unsafe { (*raw).is_writable = u8::from(is_writable) };
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        let raw = self.backing.as_ptr() as *const RuntimeAccount;
+        unsafe {
+            core::slice::from_raw_parts(
+                (raw as *const u8).add(size_of::<RuntimeAccount>()),
+                (*raw).data_len as usize,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_exposes_header_and_data() {
+        let owner = Address::from([9u8; 32]);
+        let mut account = TestAccount::new_with_capacity([3u8; 32], owner, true, false, 42, &[1, 2, 3], 16);
+        let view = account.view();
+
+        assert_eq!(view.address(), &Address::from([3u8; 32]));
+        assert!(view.owned_by(&Address::from([9u8; 32])));
+        assert!(view.is_signer());
+        assert!(!view.is_writable());
+        assert_eq!(view.lamports(), 42);
+        assert_eq!(account.data(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn setters_flip_signer_and_writable_flags() {
+        let mut account = TestAccount::new([3u8; 32], Address::from([9u8; 32]), true, false, 42, &[1, 2, 3]);
+        account.set_signer(false);
+        account.set_writable(true);
+        let view = account.view();
+
+        assert!(!view.is_signer());
+        assert!(view.is_writable());
+        assert_eq!(account.data(), &[1, 2, 3]);
+    }
+}
@@ -0,0 +1,131 @@
+//! Token program ids accepted by the runtime.
+//!
+//! Every USDC path (vault, treasury, winner, executor and VRF-payer ATAs)
+//! moves funds through `pinocchio_token::instructions::Transfer`, which is
+//! bound to the classic SPL Token program, so those callers keep the strict
+//! `require_token_program` check. Token-2022 is only safe where the runtime
+//! reads a token account without a CPI and relies on the first 72 bytes
+//! (mint, owner, amount), which Token-2022 lays out identically before its
+//! extensions: today that is the swap receiver and its mint in
+//! `begin_degen_execution` and `finalize_degen_success`.
+//!
+//! Also holds token account reads and state checks shared by the runtime
+//! validators and transfer paths.
+
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+use solana_address::address;
+
+use crate::{
+    errors::JackpotCompatError,
+    legacy_layouts::{TokenAccountCoreView, TokenAccountWithAmountView},
+};
+
+pub const TOKEN_2022_PROGRAM_ID: Address = address!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+pub fn is_supported_token_program(program_id: &Address) -> bool {
+    program_id == &pinocchio_token::ID || program_id == &TOKEN_2022_PROGRAM_ID
+}
+
+/// Accepts either the classic SPL Token program or Token-2022.
+pub fn require_token_program_any(account: &AccountView) -> ProgramResult {
+    if is_supported_token_program(account.address()) {
+        Ok(())
+    } else {
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+/// Supported token program owning `account` (a token account or a mint).
+pub fn owning_token_program(account: &AccountView) -> Result<&'static Address, ProgramError> {
+    [&pinocchio_token::ID, &TOKEN_2022_PROGRAM_ID]
+        .into_iter()
+        .find(|program_id| account.owned_by(program_id))
+        .ok_or(ProgramError::IncorrectProgramId)
+}
+
+/// Mint and owner of a token account, for validators that only compare
+/// those. Accounts shorter than `TOKEN_ACCOUNT_CORE_LEN` are
+/// `InvalidAccountData`.
+pub fn read_token_core(account: &AccountView) -> Result<TokenAccountCoreView, ProgramError> {
+    let data = account.try_borrow()?;
+    TokenAccountCoreView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Rejects a frozen transfer destination up front, so the token CPI cannot
+/// fail after the instruction has already written its own state. Accounts
+/// shorter than the full SPL layout carry no state byte and pass.
+pub fn require_not_frozen(account: &AccountView) -> ProgramResult {
+    let data = account.try_borrow()?;
+    let frozen = TokenAccountWithAmountView::is_frozen_account_data(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if frozen {
+        return Err(JackpotCompatError::TokenAccountFrozen.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        legacy_layouts::{
+            SPL_STATE_OFFSET, SPL_TOKEN_ACCOUNT_LEN, TOKEN_ACCOUNT_CORE_LEN, TOKEN_ACCOUNT_STATE_FROZEN,
+            TOKEN_ACCOUNT_STATE_INITIALIZED, TOKEN_ACCOUNT_WITH_AMOUNT_LEN,
+        },
+        runtime::test_support::TestAccount,
+    };
+
+    fn spl_token_account(state: u8) -> TestAccount {
+        let mut data = [0u8; SPL_TOKEN_ACCOUNT_LEN];
+        data[SPL_STATE_OFFSET] = state;
+        TestAccount::new([5u8; 32], pinocchio_token::ID, false, true, 1, &data)
+    }
+
+    #[test]
+    fn require_not_frozen_checks_full_spl_accounts() {
+        require_not_frozen(&spl_token_account(TOKEN_ACCOUNT_STATE_INITIALIZED).view()).unwrap();
+        assert_eq!(
+            require_not_frozen(&spl_token_account(TOKEN_ACCOUNT_STATE_FROZEN).view()).unwrap_err(),
+            JackpotCompatError::TokenAccountFrozen.into()
+        );
+    }
+
+    #[test]
+    fn require_not_frozen_skips_synthetic_layout() {
+        let data = [2u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        let mut synthetic = TestAccount::new([5u8; 32], pinocchio_token::ID, false, true, 1, &data);
+        require_not_frozen(&synthetic.view()).unwrap();
+
+        let mut short = TestAccount::new([5u8; 32], pinocchio_token::ID, false, true, 1, &data[..32]);
+        assert_eq!(require_not_frozen(&short.view()).unwrap_err(), ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn read_token_core_returns_mint_and_owner() {
+        let mut data = [0u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+        data[..32].copy_from_slice(&[2u8; 32]);
+        data[32..64].copy_from_slice(&[3u8; 32]);
+        let mut account = TestAccount::new([5u8; 32], pinocchio_token::ID, false, false, 1, &data);
+        assert_eq!(
+            read_token_core(&account.view()),
+            Ok(TokenAccountCoreView { mint: [2u8; 32], owner: [3u8; 32] })
+        );
+
+        let mut short =
+            TestAccount::new([5u8; 32], pinocchio_token::ID, false, false, 1, &data[..TOKEN_ACCOUNT_CORE_LEN - 1]);
+        assert_eq!(read_token_core(&short.view()), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn accepts_classic_and_token_2022_program_ids() {
+        assert!(is_supported_token_program(&pinocchio_token::ID));
+        assert!(is_supported_token_program(&TOKEN_2022_PROGRAM_ID));
+    }
+
+    #[test]
+    fn rejects_unrelated_program_id() {
+        let system_program = address!("11111111111111111111111111111111");
+        assert!(!is_supported_token_program(&system_program));
+        assert!(!is_supported_token_program(&Address::new_from_array([7u8; 32])));
+    }
+}
@@ -0,0 +1,147 @@
+//! Test-only stand-in for the SPL token CPIs issued by the runtimes.
+//!
+//! Real transfers are applied one CPI at a time, so two legs landing on the
+//! same token account (e.g. a winner who is also the VRF payer) add up
+//! naturally. Reading every balance up front and writing them back would lose
+//! one of those legs; this helper merges deltas per address first.
+
+use pinocchio::{AccountView, ProgramResult, error::ProgramError};
+
+use crate::{errors::JackpotCompatError, legacy_layouts::TokenAccountWithAmountView};
+
+/// Applies signed amount deltas to SPL token accounts. Deltas on the same
+/// address are summed; nothing is written unless every resulting balance
+/// fits in `u64`, otherwise `MathOverflow` is returned.
+pub(crate) fn simulate_token_transfers(transfers: &[(&AccountView, i128)]) -> ProgramResult {
+    let mut next_amounts: Vec<(&AccountView, u64)> = Vec::with_capacity(transfers.len());
+
+    for (index, (account, _)) in transfers.iter().enumerate() {
+        let already_merged = transfers[..index]
+            .iter()
+            .any(|(earlier, _)| earlier.address() == account.address());
+        if already_merged {
+            continue;
+        }
+
+        let delta = transfers[index..]
+            .iter()
+            .filter(|(other, _)| other.address() == account.address())
+            .try_fold(0i128, |sum, (_, delta)| sum.checked_add(*delta))
+            .ok_or::<ProgramError>(JackpotCompatError::MathOverflow.into())?;
+        let current = {
+            let data = account.try_borrow()?;
+            TokenAccountWithAmountView::read_from_account_data(&data)
+                .map_err(|_| ProgramError::InvalidAccountData)?
+                .amount
+        };
+        let next = u64::try_from(i128::from(current) + delta)
+            .map_err(|_| ProgramError::from(JackpotCompatError::MathOverflow))?;
+        next_amounts.push((account, next));
+    }
+
+    for (account, next) in next_amounts {
+        let mut data = account.try_borrow_mut()?;
+        TokenAccountWithAmountView::write_amount_to_account_data(&mut data, next)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use pinocchio::{
+        Address,
+        account::{NOT_BORROWED, RuntimeAccount},
+    };
+
+    use super::*;
+    use crate::legacy_layouts::TOKEN_ACCOUNT_WITH_AMOUNT_LEN;
+
+    struct TokenAccount {
+        backing: Vec<u64>,
+    }
+
+    impl TokenAccount {
+        fn new(address: u8, amount: u64) -> Self {
+            let mut data = [0u8; TOKEN_ACCOUNT_WITH_AMOUNT_LEN];
+            TokenAccountWithAmountView::write_amount_to_account_data(&mut data, amount).unwrap();
+
+            let words = (size_of::<RuntimeAccount>() + data.len()).div_ceil(size_of::<u64>());
+            let mut backing = vec![0u64; words];
+            let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
+            unsafe {
+                (*raw).borrow_state = NOT_BORROWED;
+                (*raw).is_writable = 1;
+                (*raw).address = Address::new_from_array([address; 32]);
+                (*raw).owner = pinocchio_token::ID;
+                (*raw).data_len = data.len() as u64;
+                let data_ptr = (raw as *mut u8).add(size_of::<RuntimeAccount>());
+                core::ptr::copy_nonoverlapping(data.as_ptr(), data_ptr, data.len());
+            }
+            Self { backing }
+        }
+
+        fn view(&mut self) -> AccountView {
+            unsafe { AccountView::new_unchecked(self.backing.as_mut_ptr() as *mut RuntimeAccount) }
+        }
+
+        fn amount(&mut self) -> u64 {
+            let view = self.view();
+            let data = view.try_borrow().unwrap();
+            TokenAccountWithAmountView::read_from_account_data(&data).unwrap().amount
+        }
+    }
+
+    #[test]
+    fn applies_independent_deltas() {
+        let mut vault = TokenAccount::new(1, 1_000);
+        let mut winner = TokenAccount::new(2, 10);
+        let mut treasury = TokenAccount::new(3, 0);
+
+        simulate_token_transfers(&[
+            (&vault.view(), -1_000),
+            (&winner.view(), 990),
+            (&treasury.view(), 10),
+        ])
+        .unwrap();
+
+        assert_eq!(vault.amount(), 0);
+        assert_eq!(winner.amount(), 1_000);
+        assert_eq!(treasury.amount(), 10);
+    }
+
+    #[test]
+    fn merges_deltas_on_aliased_accounts() {
+        let mut vault = TokenAccount::new(1, 1_000);
+        let mut winner = TokenAccount::new(2, 5);
+        // A second handle to the same address, as when winner == VRF payer.
+        let mut winner_as_vrf_payer = TokenAccount::new(2, 5);
+
+        simulate_token_transfers(&[
+            (&vault.view(), -1_000),
+            (&winner.view(), 800),
+            (&winner_as_vrf_payer.view(), 200),
+        ])
+        .unwrap();
+
+        assert_eq!(vault.amount(), 0);
+        assert_eq!(winner.amount(), 1_005);
+    }
+
+    #[test]
+    fn rejects_underflow_and_overflow_without_writing() {
+        let mut vault = TokenAccount::new(1, 100);
+        let mut winner = TokenAccount::new(2, 0);
+
+        let err = simulate_token_transfers(&[(&winner.view(), 101), (&vault.view(), -101)]).unwrap_err();
+        assert_eq!(err, JackpotCompatError::MathOverflow.into());
+        assert_eq!((vault.amount(), winner.amount()), (100, 0));
+
+        let mut full = TokenAccount::new(3, u64::MAX);
+        let err = simulate_token_transfers(&[(&vault.view(), -1), (&full.view(), 1)]).unwrap_err();
+        assert_eq!(err, JackpotCompatError::MathOverflow.into());
+        assert_eq!((vault.amount(), full.amount()), (100, u64::MAX));
+    }
+}
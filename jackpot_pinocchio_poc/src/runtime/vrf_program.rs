@@ -11,26 +11,39 @@ use pinocchio::{
     cpi::{Seed, Signer, invoke_signed},
     instruction::{InstructionAccount, InstructionView},
 };
+#[cfg(test)]
+use core::sync::atomic::{AtomicI64, Ordering};
+#[cfg(not(test))]
+use pinocchio::sysvars::{Sysvar, clock::Clock};
 use solana_address::address;
 
 use crate::{
-    anchor_compat::{account_discriminator, instruction_discriminator},
+    anchor_compat::instruction_discriminator,
     errors::JackpotCompatError,
-    legacy_layouts::{CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN, ConfigView, RoundLifecycleView},
+    events::emit_round_settled,
+    instruction_layouts::RoundId,
+    legacy_layouts::{AccountLayout, ConfigView, RoundLifecycleView},
+    pdas::{
+        SEED_IDENTITY, create_round_pda, derive_config_pda, derive_program_identity_pda, derive_round_pda,
+        expected_oracle_queue,
+    },
     processors::vrf::VrfProcessor,
 };
 
 mod vrf_constants {
     include!(concat!(env!("OUT_DIR"), "/vrf_constants.rs"));
 }
-use vrf_constants::{DEFAULT_QUEUE, VRF_PROGRAM_ID, VRF_PROGRAM_IDENTITY};
+use vrf_constants::{DEFAULT_QUEUE, VRF_PROGRAM_ID};
+/// Signer the VRF program uses for `vrf_callback`; off-chain harnesses need
+/// it to drive settlement.
+pub use vrf_constants::VRF_PROGRAM_IDENTITY;
 
-const SEED_CFG: &[u8] = b"cfg";
-const SEED_ROUND: &[u8] = b"round";
-const SEED_IDENTITY: &[u8] = b"identity";
 const SYSTEM_PROGRAM_ID: Address = address!("11111111111111111111111111111111");
 const SLOT_HASHES_SYSVAR_ID: Address = address!("SysvarS1otHashes111111111111111111111111111");
 
+#[cfg(test)]
+static TEST_UNIX_TIMESTAMP: AtomicI64 = AtomicI64::new(0);
+
 pub fn process_instruction(
     program_id: &Address,
     accounts: &[AccountView],
@@ -46,6 +59,13 @@ pub fn process_instruction(
     if discriminator == instruction_discriminator("vrf_callback") {
         return process_vrf_callback(program_id, accounts, instruction_data);
     }
+    if discriminator == instruction_discriminator("settle_with_slot_hash") {
+        return process_settle_with_slot_hash(program_id, accounts, instruction_data);
+    }
+    #[cfg(feature = "devnet")]
+    if discriminator == instruction_discriminator("mock_settle") {
+        return process_mock_settle(program_id, accounts, instruction_data);
+    }
 
     Err(ProgramError::InvalidInstructionData)
 }
@@ -55,20 +75,26 @@ fn process_request_vrf(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let [payer, config, round, program_identity, oracle_queue, vrf_program, slot_hashes, system_program, ..] =
+    let [payer, config, round, program_identity, oracle_queue, vrf_program, slot_hashes, system_program, rest @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
+    // Optional trailing `set_vrf_queue` PDA, required once the queue has
+    // been set; without it the request must name the build-time
+    // `DEFAULT_QUEUE`.
+    let vrf_queue_config = rest.first();
 
     require_signer(payer)?;
     require_writable(payer)?;
-    let _config = require_config_pda(config, program_id)?;
+    let config_view = require_config_pda(config, program_id)?;
     require_writable(round)?;
     require_round_pda(round, program_id, instruction_data, "request_vrf")?;
     require_program_identity_pda(program_identity, program_id)?;
     require_writable(oracle_queue)?;
-    require_address(oracle_queue, &DEFAULT_QUEUE)?;
+    let expected_queue =
+        expected_oracle_queue(vrf_queue_config, program_id, DEFAULT_QUEUE, config_view.vrf_queue_overridden())?;
+    require_address(oracle_queue, &expected_queue)?;
     require_address(vrf_program, &VRF_PROGRAM_ID)?;
     require_address(slot_hashes, &SLOT_HASHES_SYSVAR_ID)?;
     require_address(system_program, &SYSTEM_PROGRAM_ID)?;
@@ -81,6 +107,8 @@ fn process_request_vrf(
             payer_pubkey: payer.address().to_bytes(),
             config_account_data: &config_data,
             round_account_data: &mut round_shadow,
+            slot_hashes_account_data: None,
+            current_unix_timestamp: 0,
         };
         processor.process(instruction_data)?;
     }
@@ -93,6 +121,8 @@ fn process_request_vrf(
         payer_pubkey: payer.address().to_bytes(),
         config_account_data: &config_data,
         round_account_data: &mut round_data[..],
+        slot_hashes_account_data: None,
+        current_unix_timestamp: 0,
     };
     processor.process(instruction_data)
 }
@@ -118,8 +148,102 @@ fn process_vrf_callback(
         payer_pubkey: [0u8; 32],
         config_account_data: &config_data,
         round_account_data: &mut round_data[..],
+        slot_hashes_account_data: None,
+        current_unix_timestamp: 0,
     };
-    processor.process(instruction_data)
+    processor.process(instruction_data)?;
+
+    emit_settled_round(&round_data)
+}
+
+/// Admin-only slot-hash settlement once the config's VRF timeout has passed.
+/// Accounts: [admin(signer), config, round(writable), slot_hashes]
+fn process_settle_with_slot_hash(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, round, slot_hashes, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(admin)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(round)?;
+    require_round_pda(round, program_id, instruction_data, "settle_with_slot_hash")?;
+    require_address(slot_hashes, &SLOT_HASHES_SYSVAR_ID)?;
+
+    let config_data = config.try_borrow()?;
+    let slot_hashes_data = slot_hashes.try_borrow()?;
+    let mut round_data = round.try_borrow_mut()?;
+    let mut processor = VrfProcessor {
+        payer_pubkey: admin.address().to_bytes(),
+        config_account_data: &config_data,
+        round_account_data: &mut round_data[..],
+        slot_hashes_account_data: Some(&slot_hashes_data),
+        current_unix_timestamp: current_unix_timestamp()?,
+    };
+    processor.process(instruction_data)?;
+
+    emit_settled_round(&round_data)
+}
+
+#[cfg(not(test))]
+fn current_unix_timestamp() -> Result<i64, ProgramError> {
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+#[cfg(test)]
+fn current_unix_timestamp() -> Result<i64, ProgramError> {
+    Ok(TEST_UNIX_TIMESTAMP.load(Ordering::Relaxed))
+}
+
+/// Admin-only VRF bypass for devnet parity testing.
+/// Accounts: [admin(signer), config, round(writable)]
+#[cfg(feature = "devnet")]
+fn process_mock_settle(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [admin, config, round, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let args = crate::instruction_layouts::MockSettleArgsCompat::parse(instruction_data)?;
+    require_signer(admin)?;
+    let _config = require_config_pda(config, program_id)?;
+    require_writable(round)?;
+    require_owned_by(round, program_id)?;
+    let (expected_round, _) = derive_round_pda(program_id, args.round_id);
+    if round.address() != &expected_round {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    RoundLifecycleView::check_account_view(round)?;
+
+    let config_data = config.try_borrow()?;
+    let mut round_data = round.try_borrow_mut()?;
+    let mut processor = VrfProcessor {
+        payer_pubkey: admin.address().to_bytes(),
+        config_account_data: &config_data,
+        round_account_data: &mut round_data[..],
+        slot_hashes_account_data: None,
+        current_unix_timestamp: 0,
+    };
+    processor.process(instruction_data)?;
+
+    emit_settled_round(&round_data)
+}
+
+fn emit_settled_round(round_data: &[u8]) -> ProgramResult {
+    let round_view =
+        RoundLifecycleView::read_from_account_data(round_data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let winner = RoundLifecycleView::read_winner_from_account_data(round_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let winning_ticket = RoundLifecycleView::read_winning_ticket_from_account_data(round_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    emit_round_settled(round_view.round_id, &winner, winning_ticket);
+    Ok(())
 }
 
 #[cfg(not(test))]
@@ -136,11 +260,11 @@ fn invoke_vrf_request(
 ) -> ProgramResult {
     let round_data = round.try_borrow()?;
     let round_view =
-        RoundLifecycleView::read_from_account_data(&round_data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RoundLifecycleView::read_from_account_data_exact(&round_data).map_err(|_| ProgramError::InvalidAccountData)?;
     drop(round_data);
 
     let round_id_le = round_view.round_id.to_le_bytes();
-    let (_, identity_bump) = Address::find_program_address(&[SEED_IDENTITY], program_id);
+    let (_, identity_bump) = derive_program_identity_pda(program_id);
     let identity_bump_slice = [identity_bump];
     let signer_seeds: [Seed<'_>; 2] = [Seed::from(SEED_IDENTITY), Seed::from(&identity_bump_slice)];
     let signer = Signer::from(&signer_seeds);
@@ -269,17 +393,12 @@ fn require_address(account: &AccountView, expected: &Address) -> ProgramResult {
 
 fn require_config_pda(account: &AccountView, program_id: &Address) -> Result<ConfigView, ProgramError> {
     require_owned_by(account, program_id)?;
-    let (expected_address, expected_bump) = Address::find_program_address(&[SEED_CFG], program_id);
+    let (expected_address, expected_bump) = derive_config_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != CONFIG_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Config")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    let config = ConfigView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+    let config = ConfigView::from_account_view(account)?;
     if config.bump != expected_bump {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -293,34 +412,19 @@ fn require_round_pda(
     ix_name: &str,
 ) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
-    let (expected_address, _) =
-        Address::find_program_address(&[SEED_ROUND, &round_id.to_le_bytes()], program_id);
+    let round_id = crate::instruction_layouts::parse_round_id_ix(instruction_data, ix_name)?;
+    let (expected_address, _) = derive_round_pda(program_id, round_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    Ok(())
+    RoundLifecycleView::check_account_view(account)
 }
 
 fn require_round_self_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
     require_owned_by(account, program_id)?;
-    let data = account.try_borrow()?;
-    if data.len() != ROUND_ACCOUNT_LEN || data.get(..8) != Some(&account_discriminator("Round")) {
-        return Err(ProgramError::InvalidAccountData);
-    }
-    let round =
-        RoundLifecycleView::read_from_account_data(&data).map_err(|_| ProgramError::InvalidAccountData)?;
-    let expected = Address::create_program_address(
-        &[SEED_ROUND, &round.round_id.to_le_bytes(), &[round.bump]],
-        program_id,
-    )
-    .map_err(|_| ProgramError::InvalidSeeds)?;
+    let round = RoundLifecycleView::from_account_view(account)?;
+    let expected = create_round_pda(program_id, RoundId(round.round_id), round.bump)?;
     if account.address() != &expected {
         return Err(JackpotCompatError::Unauthorized.into());
     }
@@ -328,7 +432,7 @@ fn require_round_self_pda(account: &AccountView, program_id: &Address) -> Progra
 }
 
 fn require_program_identity_pda(account: &AccountView, program_id: &Address) -> ProgramResult {
-    let (expected_address, _) = Address::find_program_address(&[SEED_IDENTITY], program_id);
+    let (expected_address, _) = derive_program_identity_pda(program_id);
     if account.address() != &expected_address {
         return Err(ProgramError::InvalidSeeds);
     }
@@ -344,9 +448,10 @@ mod tests {
     use crate::{
         anchor_compat::{account_discriminator, instruction_discriminator},
         legacy_layouts::{
-            ConfigView, RoundLifecycleView, CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
-            ROUND_STATUS_LOCKED, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED,
+            ConfigView, RoundLifecycleView, VrfQueueConfigView, CONFIG_ACCOUNT_LEN, ROUND_ACCOUNT_LEN,
+            ROUND_STATUS_LOCKED, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED, VRF_QUEUE_CONFIG_ACCOUNT_LEN,
         },
+        pdas::{SEED_CFG, SEED_ROUND, SEED_VRF_QUEUE},
     };
 
     use super::*;
@@ -498,6 +603,126 @@ mod tests {
         assert_eq!(vrf_payer, payer.to_bytes());
     }
 
+    /// Runs `request_vrf` naming `queue` as the oracle queue. `stored_queue`
+    /// means `set_vrf_queue` has run (PDA stored, config marked);
+    /// `pass_queue_config` appends that PDA to the accounts.
+    fn request_vrf_with_queue(
+        queue: Address,
+        stored_queue: Option<[u8; 32]>,
+        pass_queue_config: bool,
+    ) -> ProgramResult {
+        let (config_pda, mut config_data) = sample_config();
+        if stored_queue.is_some() {
+            let mut config_view = ConfigView::read_from_account_data(&config_data).unwrap();
+            config_view.mark_vrf_queue_overridden();
+            config_view.write_to_account_data(&mut config_data).unwrap();
+        }
+        let (round_pda, round_data) = sample_round(ROUND_STATUS_LOCKED);
+        let (identity_pda, _) = Address::find_program_address(&[SEED_IDENTITY], &PROGRAM_ID);
+        let (queue_config_pda, queue_config_bump) = Address::find_program_address(&[SEED_VRF_QUEUE], &PROGRAM_ID);
+        let mut queue_config_data = [0u8; VRF_QUEUE_CONFIG_ACCOUNT_LEN];
+        queue_config_data[..8].copy_from_slice(&account_discriminator("VrfQueueConfig"));
+        VrfQueueConfigView { queue: stored_queue.unwrap_or_default(), bump: queue_config_bump }
+            .write_to_account_data(&mut queue_config_data)
+            .unwrap();
+
+        let mut payer_account = TestAccount::new([9u8; 32], Address::new_from_array([0u8; 32]), true, true, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, &round_data);
+        let mut identity_account = TestAccount::new(identity_pda.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+        let mut queue_account = TestAccount::new(queue.to_bytes(), Address::new_from_array([0u8; 32]), false, true, &[]);
+        let mut vrf_program = TestAccount::new(VRF_PROGRAM_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+        let mut slot_hashes = TestAccount::new(SLOT_HASHES_SYSVAR_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+        let mut system_program = TestAccount::new(SYSTEM_PROGRAM_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &[]);
+        let mut queue_config_account =
+            TestAccount::new(queue_config_pda.to_bytes(), PROGRAM_ID, false, false, &queue_config_data);
+
+        let mut accounts = vec![
+            payer_account.view(),
+            config_account.view(),
+            round_account.view(),
+            identity_account.view(),
+            queue_account.view(),
+            vrf_program.view(),
+            slot_hashes.view(),
+            system_program.view(),
+        ];
+        if pass_queue_config {
+            accounts.push(queue_config_account.view());
+        }
+
+        let ix = crate::instruction_layouts::encode_round_id_ix("request_vrf", RoundId(81));
+        process_instruction(&PROGRAM_ID, &accounts, &ix)
+    }
+
+    #[test]
+    fn request_vrf_runtime_checks_queue_against_stored_override() {
+        let rotated = Address::new_from_array([6u8; 32]);
+        request_vrf_with_queue(rotated, Some(rotated.to_bytes()), true).unwrap();
+        assert_eq!(
+            request_vrf_with_queue(DEFAULT_QUEUE, Some(rotated.to_bytes()), true),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn request_vrf_runtime_rejects_omitted_queue_config_after_override() {
+        let rotated = Address::new_from_array([6u8; 32]);
+        assert_eq!(
+            request_vrf_with_queue(DEFAULT_QUEUE, Some(rotated.to_bytes()), false),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+        assert_eq!(
+            request_vrf_with_queue(rotated, Some(rotated.to_bytes()), false),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+
+    #[test]
+    fn request_vrf_runtime_falls_back_to_default_queue_without_override() {
+        request_vrf_with_queue(DEFAULT_QUEUE, None, false).unwrap();
+        assert_eq!(
+            request_vrf_with_queue(Address::new_from_array([6u8; 32]), None, false),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn settle_with_slot_hash_runtime_settles_after_timeout() {
+        let admin = Address::new_from_array([7u8; 32]);
+        let (config_pda, mut config_data) = sample_config();
+        let mut config_view = ConfigView::read_from_account_data(&config_data).unwrap();
+        config_view.set_vrf_timeout_sec(600);
+        config_view.write_to_account_data(&mut config_data).unwrap();
+        let (round_pda, round_data) = sample_round(ROUND_STATUS_VRF_REQUESTED);
+        // Newest entry's hash, XOR round id 81, reduces to ticket 150.
+        let mut hash = [0u8; 32];
+        hash[0] = 149 ^ 81;
+        let mut slot_hashes_data = 1u64.to_le_bytes().to_vec();
+        slot_hashes_data.extend_from_slice(&9_000u64.to_le_bytes());
+        slot_hashes_data.extend_from_slice(&hash);
+
+        let mut admin_account = TestAccount::new(admin.to_bytes(), Address::new_from_array([0u8; 32]), true, false, &[]);
+        let mut config_account = TestAccount::new(config_pda.to_bytes(), PROGRAM_ID, false, false, &config_data);
+        let mut round_account = TestAccount::new(round_pda.to_bytes(), PROGRAM_ID, false, true, &round_data);
+        let mut slot_hashes = TestAccount::new(SLOT_HASHES_SYSVAR_ID.to_bytes(), Address::new_from_array([0u8; 32]), false, false, &slot_hashes_data);
+        let accounts = [admin_account.view(), config_account.view(), round_account.view(), slot_hashes.view()];
+        let ix = crate::instruction_layouts::encode_round_id_ix("settle_with_slot_hash", RoundId(81));
+
+        TEST_UNIX_TIMESTAMP.store(130 + 599, Ordering::Relaxed);
+        assert_eq!(
+            process_instruction(&PROGRAM_ID, &accounts, &ix),
+            Err(JackpotCompatError::VrfTimeoutNotReached.into())
+        );
+
+        TEST_UNIX_TIMESTAMP.store(130 + 600, Ordering::Relaxed);
+        process_instruction(&PROGRAM_ID, &accounts, &ix).unwrap();
+
+        let parsed = RoundLifecycleView::read_from_account_data(round_account.data()).unwrap();
+        assert_eq!(parsed.status, ROUND_STATUS_SETTLED);
+        assert_eq!(RoundLifecycleView::read_winner_from_account_data(round_account.data()).unwrap(), [22u8; 32]);
+    }
+
     #[test]
     fn vrf_callback_runtime_settles_round() {
         let (config_pda, config_data) = sample_config();
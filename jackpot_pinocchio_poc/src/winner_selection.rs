@@ -0,0 +1,164 @@
+//! Reduction from VRF randomness to a 1-based winning ticket.
+//!
+//! The Anchor program (and every off-chain re-derivation) uses
+//! `u128::from_le_bytes(randomness[..16]) % total_tickets + 1`. This keeps
+//! that result for every draw inside the unbiased zone and only rejects the
+//! short tail above the largest multiple of `total_tickets`, retrying with the
+//! second 16 bytes. The tail is shorter than `total_tickets`, so for any `u64`
+//! ticket count a retry happens with probability below 2^-64.
+
+const HALF_LEN: usize = 16;
+
+/// Maps 32 bytes of randomness to a ticket in `1..=total_tickets`.
+///
+/// Returns 0 when `total_tickets` is 0; callers must reject empty rounds
+/// before selecting a winner.
+pub fn compute_winning_ticket(randomness: &[u8; 32], total_tickets: u64) -> u64 {
+    if total_tickets == 0 {
+        return 0;
+    }
+    let n = total_tickets as u128;
+    // Draws in `zone..=u128::MAX` would map onto the lowest tickets once more.
+    let zone = u128::MAX - (u128::MAX % n);
+
+    let first = read_half(randomness, 0);
+    for draw in [first, read_half(randomness, HALF_LEN)] {
+        if draw < zone {
+            return (draw % n) as u64 + 1;
+        }
+    }
+    // Both halves in the tail (< 2^-128): accept the negligible bias.
+    (first % n) as u64 + 1
+}
+
+/// 1-based index of the participant owning ticket `target` when tickets are
+/// numbered in deposit order: the first whose running weight reaches it.
+/// Returns `weights.len() + 1` when `target` exceeds the total weight.
+///
+/// Reference for the Fenwick descent settlement uses: tests and
+/// `benches/fenwick.rs` check that both pick the same owner. Settlement never
+/// calls it, so it is only built for tests and the `linear-scan` feature.
+#[cfg(any(test, feature = "linear-scan"))]
+pub fn linear_find_winner(weights: &[u64], target: u64) -> usize {
+    let mut cumulative = 0u64;
+    for (index_zero_based, weight) in weights.iter().enumerate() {
+        cumulative = cumulative.saturating_add(*weight);
+        if cumulative >= target {
+            return index_zero_based + 1;
+        }
+    }
+    weights.len() + 1
+}
+
+fn read_half(randomness: &[u8; 32], offset: usize) -> u128 {
+    let mut bytes = [0u8; HALF_LEN];
+    bytes.copy_from_slice(&randomness[offset..offset + HALF_LEN]);
+    u128::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn randomness_from_halves(first: u128, second: u128) -> [u8; 32] {
+        let mut randomness = [0u8; 32];
+        randomness[..16].copy_from_slice(&first.to_le_bytes());
+        randomness[16..].copy_from_slice(&second.to_le_bytes());
+        randomness
+    }
+
+    /// splitmix64, enough to spread test inputs across the whole byte range.
+    fn spread_randomness(seed: u64) -> [u8; 32] {
+        let mut state = seed;
+        let mut randomness = [0u8; 32];
+        for chunk in randomness.chunks_exact_mut(8) {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            chunk.copy_from_slice(&(z ^ (z >> 31)).to_le_bytes());
+        }
+        randomness
+    }
+
+    #[test]
+    fn is_deterministic() {
+        for seed in 0..64 {
+            let randomness = spread_randomness(seed);
+            assert_eq!(
+                compute_winning_ticket(&randomness, 12_345),
+                compute_winning_ticket(&randomness, 12_345)
+            );
+        }
+    }
+
+    #[test]
+    fn stays_within_ticket_range() {
+        let totals = [1u64, 2, 3, 7, 100, 1 << 32, u64::MAX - 1, u64::MAX];
+        let mut inputs = vec![[0u8; 32], [0xFFu8; 32]];
+        inputs.extend((0..128).map(spread_randomness));
+        for total in totals {
+            for randomness in &inputs {
+                let ticket = compute_winning_ticket(randomness, total);
+                assert!((1..=total).contains(&ticket), "ticket {ticket} outside 1..={total}");
+            }
+        }
+    }
+
+    #[test]
+    fn zero_total_tickets_returns_zero() {
+        assert_eq!(compute_winning_ticket(&[9u8; 32], 0), 0);
+    }
+
+    #[test]
+    fn matches_anchor_modulo_inside_unbiased_zone() {
+        for seed in 0..128 {
+            let randomness = spread_randomness(seed);
+            let first = read_half(&randomness, 0);
+            for total in [1u64, 10, 997, 1_000_000] {
+                let expected = (first % total as u128) as u64 + 1;
+                assert_eq!(compute_winning_ticket(&randomness, total), expected);
+            }
+        }
+        // All-zero randomness picks ticket 1, as the local smoke script expects.
+        assert_eq!(compute_winning_ticket(&[0u8; 32], 10), 1);
+    }
+
+    #[test]
+    fn rejects_biased_tail_and_uses_second_half() {
+        // 2^128 - 1 is divisible by 3, so u128::MAX itself is the only rejected draw.
+        let randomness = randomness_from_halves(u128::MAX, 4);
+        assert_eq!(compute_winning_ticket(&randomness, 3), 2);
+
+        let both_rejected = randomness_from_halves(u128::MAX, u128::MAX);
+        assert_eq!(compute_winning_ticket(&both_rejected, 3), 1);
+    }
+
+    #[test]
+    fn spreads_roughly_uniformly() {
+        const TOTAL: u64 = 10;
+        const SAMPLES: u64 = 20_000;
+        let mut buckets = [0u64; TOTAL as usize];
+        for seed in 0..SAMPLES {
+            let ticket = compute_winning_ticket(&spread_randomness(seed), TOTAL);
+            buckets[(ticket - 1) as usize] += 1;
+        }
+        let expected = SAMPLES / TOTAL;
+        for (idx, count) in buckets.iter().enumerate() {
+            assert!(
+                count.abs_diff(expected) < expected / 10,
+                "ticket {} drawn {count} times, expected about {expected}",
+                idx + 1
+            );
+        }
+    }
+
+    #[test]
+    fn linear_find_winner_walks_ticket_ranges() {
+        let weights = [3u64, 0, 5, 1];
+        let owners: Vec<usize> = (1..=9).map(|ticket| linear_find_winner(&weights, ticket)).collect();
+        assert_eq!(owners, vec![1, 1, 1, 3, 3, 3, 3, 3, 4]);
+        assert_eq!(linear_find_winner(&weights, 10), 5);
+        assert_eq!(linear_find_winner(&[], 1), 1);
+    }
+}
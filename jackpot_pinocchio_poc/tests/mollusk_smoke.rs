@@ -1,7 +1,7 @@
 use mollusk_svm::Mollusk;
 use mollusk_svm::program::create_program_account_loader_v3;
 use solana_account::Account;
-use solana_instruction::{AccountMeta, Instruction};
+use solana_instruction::{error::InstructionError, AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
 
 use jackpot_pinocchio_poc::{
@@ -12,10 +12,12 @@ use jackpot_pinocchio_poc::{
         DegenConfigView, ParticipantView, RoundLifecycleView, TokenAccountWithAmountView,
         DEGEN_CLAIM_STATUS_EXECUTING, DEGEN_CLAIM_STATUS_CLAIMED_SWAPPED, DEGEN_MODE_CLAIMED,
         DEGEN_MODE_EXECUTING, ROUND_ACCOUNT_LEN, ROUND_STATUS_CANCELLED, ROUND_STATUS_CLAIMED,
-        ROUND_STATUS_OPEN, ROUND_STATUS_SETTLED,
+        ROUND_STATUS_OPEN, ROUND_STATUS_SETTLED, ROUND_STATUS_VRF_REQUESTED,
     },
 };
 
+const FALLBACK_NOW_TS: i64 = 1_700_000_000;
+
 #[test]
 #[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
 fn loads_routed_runtime_program_elf() {
@@ -117,6 +119,112 @@ fn update_config_instruction_succeeds_in_mollusk() {
     assert_eq!(view.min_total_tickets, 200);
 }
 
+#[test]
+#[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
+fn settle_with_slot_hash_after_update_config_sets_vrf_timeout_in_mollusk() {
+    let program_id = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let round_id = 81u64;
+    let vrf_timeout_sec = 600u32;
+    let (config_pda, config_bump) = Pubkey::find_program_address(&[b"cfg"], &program_id);
+    let (round_pda, round_bump) =
+        Pubkey::find_program_address(&[b"round", &round_id.to_le_bytes()], &program_id);
+    let slot_hashes: Pubkey = "SysvarS1otHashes111111111111111111111111111"
+        .parse()
+        .expect("slot hashes sysvar id");
+
+    let mut mollusk = Mollusk::new(&program_id, "jackpot_pinocchio_poc");
+
+    let mut update_data = encode_update_config(250, 10_000, 60, 2, 200);
+    update_data.push(1);
+    update_data.extend_from_slice(&vrf_timeout_sec.to_le_bytes());
+    let update = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(config_pda, false),
+        ],
+        data: update_data,
+    };
+    let result = mollusk.process_instruction(
+        &update,
+        &[
+            (admin, signer_account()),
+            (config_pda, config_account(&program_id, config_bump, admin, 25, 1_000_000, 30, 1, 2)),
+        ],
+    );
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+    let config = result.get_account(&config_pda).expect("config account").clone();
+    let view = ConfigView::read_from_account_data(&config.data).expect("config layout");
+    assert_eq!(view.vrf_timeout_sec(), vrf_timeout_sec);
+
+    // Tickets 1..=100 belong to `first`, 101..=200 to `second`.
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let mut round = Account::new(1_000_000_000, ROUND_ACCOUNT_LEN, &program_id);
+    round.data[..8].copy_from_slice(&account_discriminator("Round"));
+    RoundLifecycleView {
+        round_id,
+        status: ROUND_STATUS_VRF_REQUESTED,
+        bump: round_bump,
+        start_ts: 10,
+        end_ts: 130,
+        first_deposit_ts: 25,
+        total_usdc: 1_250_000,
+        total_tickets: 0,
+        participants_count: 2,
+    }
+    .write_to_account_data(&mut round.data)
+    .expect("round write");
+    RoundLifecycleView::write_participant_pubkey_to_account_data(&mut round.data, 0, &first.to_bytes())
+        .expect("participant write");
+    RoundLifecycleView::write_participant_pubkey_to_account_data(&mut round.data, 1, &second.to_bytes())
+        .expect("participant write");
+    RoundLifecycleView::apply_deposit_to_account_data(&mut round.data, 1, 100).expect("deposit");
+    RoundLifecycleView::apply_deposit_to_account_data(&mut round.data, 2, 100).expect("deposit");
+
+    // Newest entry's hash, XOR round id 81, reduces to ticket 150.
+    let mut hash = [0u8; 32];
+    hash[0] = 149 ^ 81;
+    let mut slot_hashes_account = Account::new(1_000_000, 0, &Pubkey::default());
+    slot_hashes_account.data = 1u64.to_le_bytes().to_vec();
+    slot_hashes_account.data.extend_from_slice(&9_000u64.to_le_bytes());
+    slot_hashes_account.data.extend_from_slice(&hash);
+
+    let settle = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(round_pda, false),
+            AccountMeta::new_readonly(slot_hashes, false),
+        ],
+        data: encode_round_id_ix("settle_with_slot_hash", round_id),
+    };
+    let settle_accounts = vec![
+        (admin, signer_account()),
+        (config_pda, config),
+        (round_pda, round),
+        (slot_hashes, slot_hashes_account),
+    ];
+
+    mollusk.sysvars.clock.unix_timestamp = 130 + i64::from(vrf_timeout_sec) - 1;
+    let result = mollusk.process_instruction(&settle, &settle_accounts);
+    assert_eq!(result.raw_result, Err(InstructionError::Custom(6051)));
+
+    mollusk.sysvars.clock.unix_timestamp = 130 + i64::from(vrf_timeout_sec);
+    let result = mollusk.process_instruction(&settle, &settle_accounts);
+    assert!(result.program_result.is_ok(), "{:?}", result.program_result);
+
+    let updated_round = result.get_account(&round_pda).expect("round account");
+    let view = RoundLifecycleView::read_from_account_data(&updated_round.data).expect("round layout");
+    assert_eq!(view.status, ROUND_STATUS_SETTLED);
+    assert_eq!(
+        RoundLifecycleView::read_winner_from_account_data(&updated_round.data).expect("winner"),
+        second.to_bytes(),
+    );
+}
+
 #[test]
 #[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
 fn upsert_degen_config_instruction_succeeds_in_mollusk() {
@@ -300,7 +408,7 @@ fn start_round_instruction_succeeds_in_mollusk() {
         program_id,
         accounts: vec![
             AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(config_pda, false),
             AccountMeta::new(round_pda, false),
             AccountMeta::new(vault_ata, false),
             AccountMeta::new_readonly(usdc_mint, false),
@@ -311,22 +419,27 @@ fn start_round_instruction_succeeds_in_mollusk() {
         data: encode_round_id_ix("start_round", round_id),
     };
 
+    let mut config = config_account_with_usdc(
+        &program_id,
+        config_bump,
+        payer,
+        usdc_mint,
+        25,
+        1_000_000,
+        30,
+        1,
+        2,
+    );
+    // start_round only accepts last_round_id + 1.
+    let mut config_view = ConfigView::read_from_account_data(&config.data).expect("config layout");
+    config_view.set_last_round_id(round_id - 1);
+    config_view
+        .write_to_account_data(&mut config.data)
+        .expect("config write");
+
     let accounts = vec![
         (payer, signer_account()),
-        (
-            config_pda,
-            config_account_with_usdc(
-                &program_id,
-                config_bump,
-                payer,
-                usdc_mint,
-                25,
-                1_000_000,
-                30,
-                1,
-                2,
-            ),
-        ),
+        (config_pda, config),
         (round_pda, Account::new(1_000_000_000, ROUND_ACCOUNT_LEN, &program_id)),
         (vault_ata, token_account(&token_program, usdc_mint, round_pda, 0)),
         (usdc_mint, Account::new(1_000_000_000, 0, &token_program)),
@@ -350,6 +463,9 @@ fn start_round_instruction_succeeds_in_mollusk() {
         RoundLifecycleView::read_vault_pubkey_from_account_data(&updated.data).expect("vault pubkey"),
         vault_ata.to_bytes(),
     );
+    let updated_config = result.get_account(&config_pda).expect("config account");
+    let config_view = ConfigView::read_from_account_data(&updated_config.data).expect("config layout");
+    assert_eq!(config_view.last_round_id(), round_id);
 }
 
 #[test]
@@ -448,57 +564,11 @@ fn begin_degen_execution_instruction_succeeds_in_mollusk() {
 #[test]
 #[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
 fn claim_degen_fallback_instruction_succeeds_in_mollusk() {
-    let program_id = Pubkey::new_unique();
-    let winner = Pubkey::new_unique();
-    let treasury_owner = Pubkey::new_unique();
-    let round_id = 45u64;
-    let (config_pda, config_bump) = Pubkey::find_program_address(&[b"cfg"], &program_id);
-    let (round_pda, round_bump) =
-        Pubkey::find_program_address(&[b"round", &round_id.to_le_bytes()], &program_id);
-    let (degen_claim_pda, degen_claim_bump) = Pubkey::find_program_address(
-        &[b"degen_claim", &round_id.to_le_bytes(), winner.as_ref()],
-        &program_id,
-    );
-    let vault_ata = Pubkey::new_unique();
-    let winner_usdc_ata = Pubkey::new_unique();
-    let treasury_usdc_ata = Pubkey::new_unique();
-    let token_program = Pubkey::new_from_array(pinocchio_token::ID.to_bytes());
-    let usdc_mint = Pubkey::new_from_array([2u8; 32]);
-
-    let mut mollusk = Mollusk::new(&program_id, "jackpot_pinocchio_poc");
-    mollusk.add_program(&token_program, "token_stub_program");
-    mollusk.sysvars.clock.unix_timestamp = 1_700_000_000;
-
-    let mut data = Vec::with_capacity(8 + 8 + 1);
-    data.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
-    data.extend_from_slice(&round_id.to_le_bytes());
-    data.push(9);
-
-    let instruction = Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(winner, true),
-            AccountMeta::new_readonly(config_pda, false),
-            AccountMeta::new(round_pda, false),
-            AccountMeta::new(degen_claim_pda, false),
-            AccountMeta::new(vault_ata, false),
-            AccountMeta::new(winner_usdc_ata, false),
-            AccountMeta::new(treasury_usdc_ata, false),
-            AccountMeta::new_readonly(token_program, false),
-        ],
-        data,
-    };
-
-    let accounts = vec![
-        (winner, signer_account()),
-        (config_pda, config_account_with_treasury(&program_id, config_bump, winner, usdc_mint, treasury_usdc_ata, 25, 1_000_000, 30, 1, 2)),
-        (round_pda, degen_round_fallback_account(&program_id, round_bump, round_id, winner, vault_ata)),
-        (degen_claim_pda, degen_claim_fallback_ready_account(&program_id, degen_claim_bump, round_pda, winner, round_id)),
-        (vault_ata, token_account(&token_program, usdc_mint, round_pda, 1_000_000)),
-        (winner_usdc_ata, token_account(&token_program, usdc_mint, winner, 0)),
-        (treasury_usdc_ata, token_account(&token_program, usdc_mint, treasury_owner, 0)),
-        (token_program, create_program_account_loader_v3(&token_program)),
-    ];
+    let (mollusk, instruction, accounts) = claim_degen_fallback_case(FALLBACK_NOW_TS - 1);
+    let round_pda = instruction.accounts[2].pubkey;
+    let degen_claim_pda = instruction.accounts[3].pubkey;
+    let winner_usdc_ata = instruction.accounts[5].pubkey;
+    let treasury_usdc_ata = instruction.accounts[6].pubkey;
 
     let result = mollusk.process_instruction(&instruction, &accounts);
     assert!(result.program_result.is_ok(), "{:?}", result.program_result);
@@ -527,6 +597,26 @@ fn claim_degen_fallback_instruction_succeeds_in_mollusk() {
     assert_eq!(treasury_ata.amount, 2_500);
 }
 
+#[test]
+#[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
+fn claim_degen_fallback_before_deadline_is_rejected_in_mollusk() {
+    let (mollusk, instruction, accounts) = claim_degen_fallback_case(FALLBACK_NOW_TS + 1);
+    let degen_claim_pda = instruction.accounts[3].pubkey;
+    let winner_usdc_ata = instruction.accounts[5].pubkey;
+
+    let result = mollusk.process_instruction(&instruction, &accounts);
+    assert_eq!(result.raw_result, Err(InstructionError::Custom(6047)));
+
+    let claim_account = result.get_account(&degen_claim_pda).expect("degen claim account");
+    let claim = DegenClaimView::read_from_account_data(&claim_account.data).expect("degen claim layout");
+    assert_eq!(claim.status, jackpot_pinocchio_poc::legacy_layouts::DEGEN_CLAIM_STATUS_VRF_READY);
+
+    let winner_account = result.get_account(&winner_usdc_ata).expect("winner usdc ata");
+    let winner_ata = TokenAccountWithAmountView::read_from_account_data(&winner_account.data)
+        .expect("winner ata layout");
+    assert_eq!(winner_ata.amount, 0);
+}
+
 #[test]
 #[ignore = "requires prebuilt SBF fixture via scripts/run_mollusk_smoke.sh"]
 fn finalize_degen_success_instruction_succeeds_in_mollusk() {
@@ -616,6 +706,62 @@ fn finalize_degen_success_instruction_succeeds_in_mollusk() {
     assert!(degen_claim.claimed_at >= 0);
 }
 
+fn claim_degen_fallback_case(fallback_after: i64) -> (Mollusk, Instruction, Vec<(Pubkey, Account)>) {
+    let program_id = Pubkey::new_unique();
+    let winner = Pubkey::new_unique();
+    let treasury_owner = Pubkey::new_unique();
+    let round_id = 45u64;
+    let (config_pda, config_bump) = Pubkey::find_program_address(&[b"cfg"], &program_id);
+    let (round_pda, round_bump) =
+        Pubkey::find_program_address(&[b"round", &round_id.to_le_bytes()], &program_id);
+    let (degen_claim_pda, degen_claim_bump) = Pubkey::find_program_address(
+        &[b"degen_claim", &round_id.to_le_bytes(), winner.as_ref()],
+        &program_id,
+    );
+    let vault_ata = Pubkey::new_unique();
+    let winner_usdc_ata = Pubkey::new_unique();
+    let treasury_usdc_ata = Pubkey::new_unique();
+    let token_program = Pubkey::new_from_array(pinocchio_token::ID.to_bytes());
+    let usdc_mint = Pubkey::new_from_array([2u8; 32]);
+
+    let mut mollusk = Mollusk::new(&program_id, "jackpot_pinocchio_poc");
+    mollusk.add_program(&token_program, "token_stub_program");
+    mollusk.sysvars.clock.unix_timestamp = FALLBACK_NOW_TS;
+
+    let mut data = Vec::with_capacity(8 + 8 + 1);
+    data.extend_from_slice(&instruction_discriminator("claim_degen_fallback"));
+    data.extend_from_slice(&round_id.to_le_bytes());
+    data.push(9);
+
+    let instruction = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(winner, true),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(round_pda, false),
+            AccountMeta::new(degen_claim_pda, false),
+            AccountMeta::new(vault_ata, false),
+            AccountMeta::new(winner_usdc_ata, false),
+            AccountMeta::new(treasury_usdc_ata, false),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data,
+    };
+
+    let accounts = vec![
+        (winner, signer_account()),
+        (config_pda, config_account_with_treasury(&program_id, config_bump, winner, usdc_mint, treasury_usdc_ata, 25, 1_000_000, 30, 1, 2)),
+        (round_pda, degen_round_fallback_account(&program_id, round_bump, round_id, winner, vault_ata)),
+        (degen_claim_pda, degen_claim_fallback_ready_account(&program_id, degen_claim_bump, round_pda, winner, round_id, fallback_after)),
+        (vault_ata, token_account(&token_program, usdc_mint, round_pda, 1_000_000)),
+        (winner_usdc_ata, token_account(&token_program, usdc_mint, winner, 0)),
+        (treasury_usdc_ata, token_account(&token_program, usdc_mint, treasury_owner, 0)),
+        (token_program, create_program_account_loader_v3(&token_program)),
+    ];
+
+    (mollusk, instruction, accounts)
+}
+
 fn encode_upsert_degen_config(executor: Pubkey, fallback_timeout_sec: u32) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(8 + 32 + 4);
     bytes.extend_from_slice(&instruction_discriminator("upsert_degen_config"));
@@ -885,10 +1031,9 @@ fn degen_claim_fallback_ready_account(
     round: Pubkey,
     winner: Pubkey,
     round_id: u64,
+    fallback_after: i64,
 ) -> Account {
     let mut account = degen_claim_vrf_ready_account(program_id, bump, round, winner, round_id);
-    let now = 1_700_000_000i64;
-    let fallback_after = now - 1;
     account.data[104..112].copy_from_slice(&fallback_after.to_le_bytes());
     account
 }
@@ -157,7 +157,10 @@ async function main() {
   }
 
   // ── Step 4: start_round ─────────────────────────────────────
-  const roundId = Math.floor(Date.now() / 1000) % 100000;
+  // start_round only accepts config.last_round_id + 1 (reserved[8..16], LE).
+  const cfgBeforeStart = await (program.account as any).config.fetch(configPda);
+  const lastRoundId = new BN(Buffer.from(cfgBeforeStart.reserved).subarray(8, 16), "le");
+  const roundId = lastRoundId.addn(1).toNumber();
   const [roundPda] = PublicKey.findProgramAddressSync(
     [SEED_ROUND, new BN(roundId).toArrayLike(Buffer, "le", 8)],
     PROGRAM_ID
@@ -168,7 +171,7 @@ async function main() {
 
   log(`Starting round #${roundId}...`);
   try {
-    const tx = await program.methods
+    const startIx = await program.methods
       .startRound(new BN(roundId))
       .accounts({
         payer: admin.publicKey,
@@ -180,8 +183,17 @@ async function main() {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
-      .signers([admin])
-      .rpc({ skipPreflight: true });
+      .instruction();
+    // The IDL marks config read-only; the Pinocchio start_round writes it.
+    for (const key of startIx.keys) {
+      if (key.pubkey.equals(configPda)) key.isWritable = true;
+    }
+    const tx = await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(startIx),
+      [admin],
+      { skipPreflight: true, commitment: "confirmed" }
+    );
     ok(`start_round tx: ${tx}`);
   } catch (e: any) {
     fail(`start_round failed: ${e.message}`);